@@ -8,7 +8,7 @@ fn bench_get_build_date(
 
     c.bench_function(
         "constant::get_build_date",
-        |b| b.iter(|| constant::get_build_date()),
+        |b| b.iter(constant::get_build_date),
     );
 }
 
@@ -18,7 +18,7 @@ fn bench_get_commit_sha(
 
     c.bench_function(
         "constant::get_commit_sha",
-        |b| b.iter(|| constant::get_commit_sha()),
+        |b| b.iter(constant::get_commit_sha),
     );
 }
 
@@ -1,4 +1,5 @@
 use std::env;
+use std::path::PathBuf;
 
 use vergen_gitcl::BuildBuilder;
 use vergen_gitcl::CargoBuilder;
@@ -7,6 +8,19 @@ use vergen_gitcl::GitclBuilder;
 use vergen_gitcl::RustcBuilder;
 use vergen_gitcl::SysinfoBuilder;
 
+/// The NIST listing parser, shared with the library.
+#[allow(dead_code)]
+#[path = "src/constant/nist_ascii.rs"]
+mod nist_ascii;
+
+/// The capability table generator.
+#[path = "build/capability.rs"]
+mod capability;
+
+/// The CODATA table generator.
+#[path = "build/codata.rs"]
+mod codata;
+
 fn main() -> Result<
     (),
     Box<dyn std::error::Error>,
@@ -39,6 +53,25 @@ fn main() -> Result<
 
     emitter.emit()?;
 
+    let manifest_dir = PathBuf::from(
+        env::var("CARGO_MANIFEST_DIR")?,
+    );
+
+    let out_dir = PathBuf::from(
+        env::var("OUT_DIR")?,
+    );
+
+    codata::generate(
+        &manifest_dir,
+        &out_dir,
+    )?;
+
+    // Scans the FFI getters `codata` writes, so it runs second.
+    capability::generate(
+        &manifest_dir,
+        &out_dir,
+    )?;
+
     // 1. Attempt to get the environment variable's value.
     match env::var(ENV_VAR_NAME) {
         // 2. If the variable is successfully retrieved (Ok(value)), check if the value is "1".
@@ -0,0 +1,756 @@
+//! Generates the capability tables of `src/capability/mod.rs`.
+//!
+//! The public module tree is walked from `src/lib.rs` along `pub mod`
+//! declarations. Every free `pub fn` outside `ffi_apis` is listed as an
+//! algorithm, and every `#[no_mangle]` `extern "C"` function inside it as
+//! an FFI function, each with its normalized signature and the first
+//! paragraph of its documentation. Functions defined by a `macro_rules!`
+//! template (`gen_ffi_json!(rssn_get_..., ...)`) are listed once per
+//! invocation, including the invocations in files the module `include!`s
+//! from `OUT_DIR`. The build profile, optimization level and enabled
+//! features are recorded alongside.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+type BuildResult<T> = Result<
+    T,
+    Box<dyn std::error::Error>,
+>;
+
+/// A documented function found in the sources.
+
+struct Item {
+    module: String,
+    name: String,
+    signature: String,
+    summary: String,
+    is_unsafe: bool,
+}
+
+/// A `macro_rules!` template defining an exported function named by the
+/// first argument of each invocation.
+
+struct Template {
+    macro_name: String,
+    placeholder: String,
+    item: Item,
+}
+
+pub fn generate(
+    manifest_dir: &Path,
+    out_dir: &Path,
+) -> BuildResult<()> {
+
+    let src_dir =
+        manifest_dir.join("src");
+
+    println!(
+        "cargo:rerun-if-changed={}",
+        src_dir.display()
+    );
+
+    let mut modules = Vec::new();
+
+    walk(
+        &src_dir.join("lib.rs"),
+        "",
+        &mut modules,
+    )?;
+
+    let mut algorithms = Vec::new();
+
+    let mut functions = Vec::new();
+
+    let mut templates = Vec::new();
+
+    for (module, path) in &modules {
+
+        let text =
+            fs::read_to_string(path)?;
+
+        let lines: Vec<&str> = text
+            .lines()
+            .collect();
+
+        if module == "ffi_apis"
+            || module.starts_with(
+                "ffi_apis::",
+            )
+        {
+
+            scan_ffi(
+                module,
+                &lines,
+                &mut functions,
+                &mut templates,
+            );
+        } else {
+
+            scan_algorithms(
+                module,
+                &lines,
+                &mut algorithms,
+            );
+        }
+    }
+
+    // Expand the templates at their invocations, in the sources and in the
+    // generated files they include.
+    for (module, path) in &modules {
+
+        let source =
+            fs::read_to_string(path)?;
+
+        let mut text = source.clone();
+
+        for file in included(&source) {
+
+            if let Ok(generated) =
+                fs::read_to_string(
+                    out_dir.join(file),
+                )
+            {
+
+                text.push_str(
+                    &generated,
+                );
+            }
+        }
+
+        for template in &templates {
+
+            for (name, summary) in
+                invocations(
+                    &text,
+                    &template
+                        .macro_name,
+                )
+            {
+
+                functions.push(Item {
+                    module: module.clone(),
+                    signature: template
+                        .item
+                        .signature
+                        .replace(
+                            &template
+                                .placeholder,
+                            &name,
+                        ),
+                    summary: if summary
+                        .is_empty()
+                    {
+
+                        template
+                            .item
+                            .summary
+                            .clone()
+                    } else {
+
+                        summary
+                    },
+                    is_unsafe: template
+                        .item
+                        .is_unsafe,
+                    name,
+                });
+            }
+        }
+    }
+
+    algorithms.sort_by(|a, b| {
+
+        (&a.module, &a.name)
+            .cmp(&(&b.module, &b.name))
+    });
+
+    functions.sort_by(|a, b| {
+
+        a.name.cmp(&b.name)
+    });
+
+    functions.dedup_by(|a, b| {
+
+        a.name == b.name
+    });
+
+    let mut features: Vec<String> =
+        env::vars()
+            .filter_map(|(key, _)| {
+
+                key.strip_prefix(
+                    "CARGO_FEATURE_",
+                )
+                .map(str::to_lowercase)
+            })
+            .collect();
+
+    features.sort();
+
+    let mut out = String::from(
+        "// @generated by \
+         build/capability.rs; do not \
+         edit.\n\n",
+    );
+
+    let _ = writeln!(
+        out,
+        "/// The Cargo profile the \
+         library was built \
+         with.\nconst PROFILE: &str = \
+         {:?};\n",
+        env::var("PROFILE")
+            .unwrap_or_default()
+    );
+
+    let _ = writeln!(
+        out,
+        "/// The optimization \
+         level.\nconst OPT_LEVEL: \
+         &str = {:?};\n",
+        env::var("OPT_LEVEL")
+            .unwrap_or_default()
+    );
+
+    let _ = writeln!(
+        out,
+        "/// The enabled Cargo \
+         features, sorted.\nconst \
+         FEATURES: &[&str] = \
+         &{features:?};\n"
+    );
+
+    out.push_str(
+        "/// Module, name, signature \
+         and summary of every free \
+         public function.\nconst \
+         ALGORITHMS: &[(&str, &str, \
+         &str, &str)] = &[\n",
+    );
+
+    for item in &algorithms {
+
+        let _ = writeln!(
+            out,
+            "    ({:?}, {:?}, {:?}, \
+             {:?}),",
+            item.module,
+            item.name,
+            item.signature,
+            item.summary,
+        );
+    }
+
+    out.push_str(
+        "];\n\n/// Module, name, \
+         signature, summary and \
+         safety of every exported FFI \
+         function.\nconst \
+         FFI_FUNCTIONS: &[(&str, \
+         &str, &str, &str, bool)] = \
+         &[\n",
+    );
+
+    for item in &functions {
+
+        let _ = writeln!(
+            out,
+            "    ({:?}, {:?}, {:?}, \
+             {:?}, {}),",
+            item.module,
+            item.name,
+            item.signature,
+            item.summary,
+            item.is_unsafe,
+        );
+    }
+
+    out.push_str("];\n");
+
+    fs::write(
+        out_dir.join("capabilities.rs"),
+        out,
+    )?;
+
+    Ok(())
+}
+
+/// Collects the modules reachable from `path` through `pub mod`
+/// declarations, with their paths relative to the crate root.
+
+fn walk(
+    path: &Path,
+    module: &str,
+    modules: &mut Vec<(
+        String,
+        PathBuf,
+    )>,
+) -> BuildResult<()> {
+
+    let text =
+        fs::read_to_string(path)?;
+
+    modules.push((
+        module.to_string(),
+        path.to_path_buf(),
+    ));
+
+    // `lib.rs` and `mod.rs` own their directory, `name.rs` owns `name/`.
+    let dir = match path
+        .file_name()
+        .and_then(|f| f.to_str())
+    {
+        | Some("lib.rs" | "mod.rs") => {
+            path.parent()
+                .map(Path::to_path_buf)
+        },
+        | _ => {
+            path.parent()
+                .zip(path.file_stem())
+                .map(
+                    |(parent, stem)| {
+
+                        parent
+                            .join(stem)
+                    },
+                )
+        },
+    }
+    .ok_or(
+        "module without a directory",
+    )?;
+
+    for line in text.lines() {
+
+        let Some(name) = line
+            .trim()
+            .strip_prefix("pub mod ")
+            .and_then(|rest| {
+
+                rest.strip_suffix(';')
+            })
+        else {
+
+            continue;
+        };
+
+        let child = if module.is_empty()
+        {
+
+            name.to_string()
+        } else {
+
+            format!("{module}::{name}")
+        };
+
+        let file = dir
+            .join(format!("{name}.rs"));
+
+        if file.exists() {
+
+            walk(
+                &file,
+                &child,
+                modules,
+            )?;
+        } else {
+
+            walk(
+                &dir.join(name)
+                    .join("mod.rs"),
+                &child,
+                modules,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Lists the free `pub fn`s of a module.
+
+fn scan_algorithms(
+    module: &str,
+    lines: &[&str],
+    items: &mut Vec<Item>,
+) {
+
+    for (index, line) in lines
+        .iter()
+        .enumerate()
+    {
+
+        if ![
+            "pub fn ",
+            "pub const fn ",
+            "pub unsafe fn ",
+        ]
+        .iter()
+        .any(|prefix| {
+
+            line.starts_with(prefix)
+        }) {
+
+            continue;
+        }
+
+        if let Some(item) = item_at(
+            module,
+            lines,
+            index,
+        ) {
+
+            items.push(item);
+        }
+    }
+}
+
+/// Lists the exported functions of an FFI module, and the templates of
+/// the macros defining them.
+
+fn scan_ffi(
+    module: &str,
+    lines: &[&str],
+    items: &mut Vec<Item>,
+    templates: &mut Vec<Template>,
+) {
+
+    for (index, line) in lines
+        .iter()
+        .enumerate()
+    {
+
+        let trimmed = line.trim();
+
+        if !(trimmed
+            .starts_with("pub ")
+            && trimmed.contains(
+                "extern \"C\" fn ",
+            ))
+        {
+
+            continue;
+        }
+
+        let exported =
+            header(lines, index)
+                .0
+                .iter()
+                .any(|attribute| {
+
+                    attribute.contains(
+                        "no_mangle",
+                    )
+                });
+
+        let Some(item) = item_at(
+            module,
+            lines,
+            index,
+        )
+        .filter(|_| exported) else {
+
+            continue;
+        };
+
+        if item
+            .name
+            .starts_with('$')
+        {
+
+            let macro_name = lines
+                .get(.. index)
+                .unwrap_or_default()
+                .iter()
+                .rev()
+                .find_map(|line| {
+
+                    line.trim()
+                        .strip_prefix(
+                            "macro_rules! ",
+                        )
+                })
+                .map(|rest| {
+
+                    rest.trim_end_matches(
+                        [' ', '{'],
+                    )
+                    .to_string()
+                });
+
+            if let Some(macro_name) =
+                macro_name
+            {
+
+                templates.push(Template {
+                    macro_name,
+                    placeholder: item
+                        .name
+                        .clone(),
+                    item,
+                });
+            }
+        } else {
+
+            items.push(item);
+        }
+    }
+}
+
+/// Splits the lines above line `index` into the attribute lines of the
+/// item starting there and its documentation lines, both nearest first.
+///
+/// Attributes may come before or after the documentation, or between its
+/// lines (`#[unsafe(no_mangle)]` above `///` is common in the FFI modules).
+
+fn header<'a>(
+    lines: &[&'a str],
+    index: usize,
+) -> (
+    Vec<&'a str>,
+    Vec<&'a str>,
+) {
+
+    let mut attributes = Vec::new();
+
+    let mut docs = Vec::new();
+
+    // Attributes may span lines, so everything up to the end of the
+    // previous item belongs to the header.
+    for line in lines
+        .get(.. index)
+        .unwrap_or_default()
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+    {
+
+        if let Some(doc) =
+            line.strip_prefix("///")
+        {
+
+            docs.push(doc);
+        } else if line
+            .ends_with(['}', ';', '{'])
+        {
+
+            break;
+        } else {
+
+            attributes.push(line);
+        }
+    }
+
+    (attributes, docs)
+}
+
+/// Reads the function whose signature starts at line `index`.
+
+fn item_at(
+    module: &str,
+    lines: &[&str],
+    index: usize,
+) -> Option<Item> {
+
+    let mut signature = String::new();
+
+    for line in lines.get(index ..)? {
+
+        if let Some((head, _)) =
+            line.split_once('{')
+        {
+
+            signature.push_str(head);
+
+            break;
+        }
+
+        signature.push_str(line);
+
+        signature.push(' ');
+    }
+
+    let signature = normalize(
+        signature
+            .trim()
+            .trim_start_matches("pub "),
+    );
+
+    let name = signature
+        .split_once("fn ")?
+        .1
+        .split(['(', '<'])
+        .next()?
+        .trim()
+        .to_string();
+
+    Some(Item {
+        module: module.to_string(),
+        is_unsafe: signature
+            .starts_with("unsafe "),
+        summary: summary(lines, index),
+        name,
+        signature,
+    })
+}
+
+/// Collapses whitespace and drops the paths and trailing commas rustfmt
+/// leaves in signatures.
+
+fn normalize(
+    signature: &str
+) -> String {
+
+    let mut out = signature
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    for (from, to) in [
+        ("( ", "("),
+        (" )", ")"),
+        ("< ", "<"),
+        (" >", ">"),
+        (",)", ")"),
+        (",>", ">"),
+        (" ,", ","),
+        (" :", ":"),
+        (" ::", "::"),
+        ("$crate::ffi_apis::common::", ""),
+        ("crate::ffi_apis::common::", ""),
+        ("$crate::ffi_apis::handle::", ""),
+        ("crate::ffi_apis::handle::", ""),
+        ("std::os::raw::", ""),
+        ("std::ffi::", ""),
+    ] {
+
+        out = out.replace(from, to);
+    }
+
+    out.trim_end_matches(',')
+        .to_string()
+}
+
+/// The first paragraph of the documentation above line `index`.
+
+fn summary(
+    lines: &[&str],
+    index: usize,
+) -> String {
+
+    let (_, mut docs) =
+        header(lines, index);
+
+    docs.reverse();
+
+    docs.iter()
+        .map(|line| line.trim())
+        .take_while(|line| {
+
+            !line.is_empty()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The `OUT_DIR` files a source text `include!`s.
+
+fn included(text: &str) -> Vec<&str> {
+
+    text.split("env!(\"OUT_DIR\")")
+        .skip(1)
+        .filter_map(|rest| {
+
+            rest.split_once("\"/")?
+                .1
+                .split_once('"')
+                .map(|(file, _)| file)
+        })
+        .collect()
+}
+
+/// The first arguments of the invocations of `macro_name` in `text`,
+/// outside comments, with the first paragraph of the documentation the
+/// invocations pass before them.
+
+fn invocations(
+    text: &str,
+    macro_name: &str,
+) -> Vec<(String, String)> {
+
+    let code = text
+        .lines()
+        .filter(|line| {
+
+            let line =
+                line.trim_start();
+
+            !line.starts_with("//")
+                || line
+                    .starts_with("///")
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    code.split(&format!(
+        "{macro_name}!("
+    ))
+    .skip(1)
+    .filter_map(|rest| {
+
+        let mut docs = Vec::new();
+
+        let mut lines = rest
+            .trim_start()
+            .lines()
+            .map(str::trim);
+
+        let first =
+            lines.find(|line| {
+
+                match line
+                    .strip_prefix("///")
+                {
+                    | Some(doc) => {
+
+                        docs.push(
+                            doc.trim(),
+                        );
+
+                        false
+                    },
+                    | None => !line
+                        .starts_with(
+                            "#[",
+                        ),
+                }
+            })?;
+
+        let name: String = first
+            .chars()
+            .take_while(|c| {
+
+                c.is_alphanumeric()
+                    || *c == '_'
+            })
+            .collect();
+
+        let summary = docs
+            .iter()
+            .take_while(|line| {
+
+                !line.is_empty()
+            })
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        (!name.is_empty())
+            .then_some((name, summary))
+    })
+    .collect()
+}
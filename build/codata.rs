@@ -0,0 +1,851 @@
+//! Generates the CODATA constant tables from the vendored NIST listings in `data/codata/`.
+//!
+//! For the current edition this writes the `nist_const!` table, named
+//! after `CURRENT_EDITION` and included by `src/constant/mod.rs` through
+//! `codata_current.rs` with its `CURRENT_CONSTANTS` alias, and the
+//! matching `gen_ffi_handle!`, `gen_ffi_f64!`, `gen_ffi_json!` and
+//! `gen_ffi_bincode!` invocations included by the FFI modules. Earlier editions only get a `codata_records!` table, so their
+//! values are available through `constant::edition` without adding
+//! getters. Updating to a new adjustment therefore only needs a new
+//! `allascii_*.txt` listing.
+//!
+//! Every edition also gets the table of correlation coefficients listed in
+//! its `correlations_*.txt`, which must exist.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use crate::nist_ascii;
+use crate::nist_ascii::AsciiEntry;
+
+/// The listing the public constants and getters are generated from.
+
+const CURRENT_LISTING: &str =
+    "allascii_2022.txt";
+
+/// The `CodataEdition` variant of the current listing.
+
+const CURRENT_EDITION: &str =
+    "Codata2022";
+
+/// The earlier listings, with their registry and `CodataEdition` variant.
+
+const EARLIER_LISTINGS: &[(
+    &str,
+    &str,
+    &str,
+)] = &[
+    (
+        "allascii_2014.txt",
+        "CODATA_2014_CONSTANTS",
+        "Codata2014",
+    ),
+    (
+        "allascii_2018.txt",
+        "CODATA_2018_CONSTANTS",
+        "Codata2018",
+    ),
+];
+
+/// An entry of `data/codata/identifiers.txt`.
+
+struct Annotation {
+    ident: Option<String>,
+    symbol: String,
+}
+
+/// A row of the generated table.
+
+struct Row {
+    ident: String,
+    name: String,
+    symbol: String,
+    value: String,
+    digits: u32,
+    uncertainty: String,
+    unit: String,
+    group: &'static str,
+}
+
+type BuildResult<T> = Result<
+    T,
+    Box<dyn std::error::Error>,
+>;
+
+pub fn generate(
+    manifest_dir: &Path,
+    out_dir: &Path,
+) -> BuildResult<()> {
+
+    let data_dir = manifest_dir
+        .join("data")
+        .join("codata");
+
+    println!(
+        "cargo:rerun-if-changed={}",
+        data_dir.display()
+    );
+
+    let annotations = read_annotations(
+        &fs::read_to_string(
+            data_dir.join(
+                "identifiers.txt",
+            ),
+        )?,
+    )?;
+
+    let listing = fs::read_to_string(
+        data_dir.join(CURRENT_LISTING),
+    )?;
+
+    let rows = build_rows(
+        &nist_ascii::parse(&listing)?,
+        &annotations,
+    )?;
+
+    let year = CURRENT_EDITION
+        .trim_start_matches("Codata");
+
+    let registry = format!(
+        "CODATA_{year}_CONSTANTS"
+    );
+
+    fs::write(
+        out_dir.join(format!(
+            "codata_{year}.rs"
+        )),
+        constant_table(
+            "nist_const",
+            &registry,
+            CURRENT_EDITION,
+            CURRENT_LISTING,
+            &rows,
+        ),
+    )?;
+
+    // `constant` includes the current table through this file, so moving
+    // to a new edition needs no change there.
+    fs::write(
+        out_dir
+            .join("codata_current.rs"),
+        format!(
+            r#"// @generated by build/codata.rs; do not edit.
+
+include!(concat!(env!("OUT_DIR"), "/codata_{year}.rs"));
+
+/// Every constant of the current edition, `{registry}`.
+
+pub static CURRENT_CONSTANTS: &[PhysicalConstant] = {registry};
+"#
+        ),
+    )?;
+
+    write_correlations(
+        &data_dir,
+        out_dir,
+        CURRENT_LISTING,
+        &rows,
+    )?;
+
+    // Quantities renamed since an earlier adjustment share the current
+    // identifier, so they are filed under the current group as well.
+    let current_groups: HashMap<
+        &str,
+        &'static str,
+    > = rows
+        .iter()
+        .map(|row| {
+
+            (
+                row.ident.as_str(),
+                row.group,
+            )
+        })
+        .collect();
+
+    for &(listing, registry, edition) in
+        EARLIER_LISTINGS
+    {
+
+        let text = fs::read_to_string(
+            data_dir.join(listing),
+        )?;
+
+        let mut earlier_rows =
+            build_rows(
+                &nist_ascii::parse(
+                    &text,
+                )?,
+                &annotations,
+            )?;
+
+        for row in &mut earlier_rows {
+
+            if let Some(group) =
+                current_groups.get(
+                    row.ident.as_str(),
+                )
+            {
+
+                row.group = group;
+            }
+        }
+
+        fs::write(
+            out_dir.join(format!(
+                "{}.rs",
+                registry
+                    .trim_end_matches(
+                        "_CONSTANTS"
+                    )
+                    .to_lowercase()
+            )),
+            constant_table(
+                "codata_records",
+                registry,
+                edition,
+                listing,
+                &earlier_rows,
+            ),
+        )?;
+
+        write_correlations(
+            &data_dir,
+            out_dir,
+            listing,
+            &earlier_rows,
+        )?;
+    }
+
+    for (file, macro_name, suffix) in [
+        (
+            "codata_ffi_handle.rs",
+            "gen_ffi_handle",
+            "",
+        ),
+        (
+            "codata_ffi_f64.rs",
+            "gen_ffi_f64",
+            "_f64",
+        ),
+        (
+            "codata_ffi_json.rs",
+            "gen_ffi_json",
+            "_json",
+        ),
+        (
+            "codata_ffi_bincode.rs",
+            "gen_ffi_bincode",
+            "_bincode",
+        ),
+    ] {
+
+        fs::write(
+            out_dir.join(file),
+            ffi_getters(
+                macro_name,
+                suffix,
+                &rows,
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+fn read_annotations(
+    text: &str
+) -> BuildResult<
+    HashMap<String, Annotation>,
+> {
+
+    let mut annotations =
+        HashMap::new();
+
+    for line in text.lines() {
+
+        if line
+            .trim()
+            .is_empty()
+            || line.starts_with('#')
+        {
+
+            continue;
+        }
+
+        let fields: Vec<&str> = line
+            .split(" | ")
+            .map(str::trim)
+            .collect();
+
+        let [name, ident, symbol] =
+            fields[..]
+        else {
+
+            return Err(format!(
+                "malformed annotation \
+                 `{line}`"
+            )
+            .into());
+        };
+
+        annotations.insert(
+            name.to_string(),
+            Annotation {
+                ident: (ident != "-")
+                    .then(|| {
+
+                        ident
+                            .to_string()
+                    }),
+                symbol: symbol
+                    .to_string(),
+            },
+        );
+    }
+
+    Ok(annotations)
+}
+
+fn build_rows(
+    entries: &[AsciiEntry],
+    annotations: &HashMap<
+        String,
+        Annotation,
+    >,
+) -> BuildResult<Vec<Row>> {
+
+    let mut seen = HashSet::new();
+
+    let mut rows = Vec::new();
+
+    for entry in entries {
+
+        let annotation = annotations
+            .get(&entry.name);
+
+        let ident = annotation
+            .and_then(|a| {
+
+                a.ident.clone()
+            })
+            .unwrap_or_else(|| {
+
+                nist_ascii::identifier(
+                    &entry.name,
+                )
+            });
+
+        if !seen.insert(ident.clone()) {
+
+            return Err(format!(
+                "duplicate identifier \
+                 `{ident}` for `{}`",
+                entry.name
+            )
+            .into());
+        }
+
+        rows.push(Row {
+            ident,
+            name: entry.name.clone(),
+            symbol: annotation
+                .map(|a| {
+
+                    a.symbol.clone()
+                })
+                .unwrap_or_default(),
+            value: float_literal(
+                &entry.value_text,
+            ),
+            digits: entry
+                .significant_digits(),
+            uncertainty: entry
+                .uncertainty_text
+                .as_deref()
+                .map_or_else(
+                    || {
+
+                        if entry
+                            .truncated
+                        {
+
+                            "truncated"
+                        } else {
+
+                            "exact"
+                        }
+                        .to_string()
+                    },
+                    float_literal,
+                ),
+            unit: unicode_unit(
+                &entry.unit,
+            ),
+            group: group_of(
+                &entry.name,
+            ),
+        });
+    }
+
+    Ok(rows)
+}
+
+fn constant_table(
+    macro_name: &str,
+    registry: &str,
+    edition: &str,
+    listing: &str,
+    rows: &[Row],
+) -> String {
+
+    let mut out = format!(
+        "// @generated by \
+         build/codata.rs from \
+         data/codata/{listing}; do \
+         not edit.\n\n{macro_name}! \
+         {{\n    /// Every constant \
+         of the listing \
+         `data/codata/{listing}`, in \
+         listing order.\n    \
+         {registry},\n    {edition};\n"
+    );
+
+    for row in rows {
+
+        let _ = write!(
+            out,
+            "    (\n        \
+             {ident},\n        \
+             get_{getter},\n        \
+             get_{getter}_measured,\n        \
+             {name:?},\n        \
+             {symbol:?},\n        \
+             {value},\n        \
+             {digits},\n        \
+             {uncertainty},\n        \
+             {unit:?},\n        \
+             {group}\n    ),\n",
+            ident = row.ident,
+            getter = row
+                .ident
+                .to_lowercase(),
+            name = row.name,
+            symbol = row.symbol,
+            value = row.value,
+            digits = row.digits,
+            uncertainty =
+                row.uncertainty,
+            unit = row.unit,
+            group = row.group,
+        );
+    }
+
+    out.push_str("}\n");
+
+    out
+}
+
+/// Writes `correlations_<year>.rs`, the `CODATA_<year>_CORRELATIONS`
+/// table of the pairs listed in `data/codata/correlations_<year>.txt`
+/// for the listing `allascii_<year>.txt`, by identifier.
+
+fn write_correlations(
+    data_dir: &Path,
+    out_dir: &Path,
+    listing: &str,
+    rows: &[Row],
+) -> BuildResult<()> {
+
+    let year = listing
+        .trim_start_matches("allascii_")
+        .trim_end_matches(".txt");
+
+    let source = format!(
+        "correlations_{year}.txt"
+    );
+
+    let text = fs::read_to_string(
+        data_dir.join(&source),
+    )
+    .map_err(|e| {
+
+        format!("{source}: {e}")
+    })?;
+
+    let idents: HashMap<&str, &str> =
+        rows.iter()
+            .map(|row| {
+
+                (
+                    row.name.as_str(),
+                    row.ident.as_str(),
+                )
+            })
+            .collect();
+
+    let mut seen = HashSet::new();
+
+    let mut out = format!(
+        "// @generated by \
+         build/codata.rs from \
+         data/codata/{source}; do not \
+         edit.\n\n/// The correlation \
+         coefficients listed in \
+         `data/codata/{source}`, by \
+         identifier.\n\npub static \
+         CODATA_{year}_CORRELATIONS: \
+         &[(&str, &str, f64)] = &[\n"
+    );
+
+    for line in text.lines() {
+
+        if line
+            .trim()
+            .is_empty()
+            || line.starts_with('#')
+        {
+
+            continue;
+        }
+
+        let fields: Vec<&str> = line
+            .split(" | ")
+            .map(str::trim)
+            .collect();
+
+        let [a, b, coefficient] =
+            fields[..]
+        else {
+
+            return Err(format!(
+                "malformed correlation \
+                 `{line}` in {source}"
+            )
+            .into());
+        };
+
+        let ident = |name: &str| {
+
+            idents
+                .get(name)
+                .copied()
+                .ok_or_else(|| {
+
+                    format!(
+                        "unknown quantity \
+                         `{name}` in \
+                         {source}"
+                    )
+                })
+        };
+
+        let (a, b) =
+            (ident(a)?, ident(b)?);
+
+        let value: f64 =
+            coefficient.parse()?;
+
+        if !(-1.0 ..= 1.0)
+            .contains(&value)
+            || a == b
+            || !seen.insert((
+                a.min(b),
+                a.max(b),
+            ))
+        {
+
+            return Err(format!(
+                "invalid correlation \
+                 `{line}` in {source}"
+            )
+            .into());
+        }
+
+        let _ = writeln!(
+            out,
+            "    ({a:?}, {b:?}, {}),",
+            float_literal(coefficient)
+        );
+    }
+
+    out.push_str("];\n");
+
+    fs::write(
+        out_dir.join(format!(
+            "correlations_{year}.rs"
+        )),
+        out,
+    )?;
+
+    Ok(())
+}
+
+fn ffi_getters(
+    macro_name: &str,
+    suffix: &str,
+    rows: &[Row],
+) -> String {
+
+    let mut out = String::from(
+        "// @generated by \
+         build/codata.rs; do not \
+         edit.\n\n",
+    );
+
+    for row in rows {
+
+        let getter = row
+            .ident
+            .to_lowercase();
+
+        let _ = writeln!(
+            out,
+            "{macro_name}!(rssn_get_{getter}{suffix}, crate::constant::get_{getter});"
+        );
+    }
+
+    out
+}
+
+/// Formats a compact listing number as a Rust `f64` literal with `_` digit groups.
+
+fn float_literal(text: &str) -> String {
+
+    let (sign, unsigned) = text
+        .strip_prefix('-')
+        .map_or(("", text), |rest| {
+
+            ("-", rest)
+        });
+
+    let (mantissa, exponent) = unsigned
+        .split_once(['e', 'E'])
+        .map_or(
+            (unsigned, None),
+            |(m, e)| (m, Some(e)),
+        );
+
+    let (int_part, frac_part) =
+        mantissa
+            .split_once('.')
+            .unwrap_or((mantissa, ""));
+
+    let int_digits: Vec<char> =
+        int_part
+            .chars()
+            .collect();
+
+    let mut int_groups: Vec<String> =
+        int_digits
+            .rchunks(3)
+            .map(|g| g.iter().collect())
+            .collect();
+
+    int_groups.reverse();
+
+    let frac_digits: Vec<char> =
+        frac_part
+            .chars()
+            .collect();
+
+    let frac_groups: Vec<String> =
+        frac_digits
+            .chunks(3)
+            .map(|g| g.iter().collect())
+            .collect();
+
+    let mut literal = format!(
+        "{sign}{}.{}",
+        int_groups.join("_"),
+        if frac_groups.is_empty() {
+
+            "0".to_string()
+        } else {
+
+            frac_groups.join("_")
+        }
+    );
+
+    if let Some(exponent) = exponent {
+
+        literal.push('e');
+
+        literal.push_str(
+            exponent
+                .trim_start_matches(
+                    '+',
+                ),
+        );
+    }
+
+    literal
+}
+
+/// Rewrites the listing's ASCII unit notation (`m^3 kg^-1`) in the
+/// superscript notation used by `nist_const!` (`m³ kg⁻¹`).
+
+fn unicode_unit(unit: &str) -> String {
+
+    if unit.is_empty() {
+
+        return "dimensionless"
+            .to_string();
+    }
+
+    let mut out = String::new();
+
+    let mut in_exponent = false;
+
+    for c in unit.chars() {
+
+        match c {
+            | '^' => in_exponent = true,
+            | '-' if in_exponent => {
+                out.push('⁻')
+            },
+            | '0' ..= '9'
+                if in_exponent =>
+            {
+
+                out.push(superscript(
+                    c,
+                ));
+            },
+            | _ => {
+
+                in_exponent = false;
+
+                out.push(c);
+            },
+        }
+    }
+
+    out.replace("ohm", "Ω")
+}
+
+fn superscript(digit: char) -> char {
+
+    match digit {
+        | '0' => '⁰',
+        | '1' => '¹',
+        | '2' => '²',
+        | '3' => '³',
+        | '4' => '⁴',
+        | '5' => '⁵',
+        | '6' => '⁶',
+        | '7' => '⁷',
+        | '8' => '⁸',
+        | _ => '⁹',
+    }
+}
+
+/// Assigns a quantity to one of the `ConstantGroup` variants, following
+/// the subject categories of the NIST constants database.
+
+fn group_of(
+    name: &str
+) -> &'static str {
+
+    const UNIVERSAL: &[&str] = &[
+        "speed of light in vacuum",
+        "vacuum mag. permeability",
+        "vacuum electric permittivity",
+        "characteristic impedance of \
+         vacuum",
+        "Newtonian constant of \
+         gravitation",
+        "Planck constant",
+        "reduced Planck constant",
+        "Planck length",
+        "Planck mass",
+        "Planck temperature",
+        "Planck time",
+    ];
+
+    const ELECTROMAGNETIC: &[&str] = &[
+        "elementary charge",
+        "mag. flux quantum",
+        "conductance quantum",
+        "inverse of conductance \
+         quantum",
+        "Josephson constant",
+        "von Klitzing constant",
+        "Bohr magneton",
+        "nuclear magneton",
+    ];
+
+    const PHYSICO_CHEMICAL: &[&str] = &[
+        "Avogadro constant",
+        "atomic mass constant",
+        "Faraday constant",
+        "molar Planck constant",
+        "molar gas constant",
+        "Boltzmann constant",
+        "molar volume of ideal gas",
+        "Loschmidt constant",
+        "Sackur-Tetrode constant",
+        "Stefan-Boltzmann constant",
+        "first radiation constant",
+        "second radiation constant",
+        "Wien",
+        "molar mass constant",
+        "molar mass of carbon-12",
+        "luminous efficacy",
+        "hyperfine transition \
+         frequency of Cs-133",
+    ];
+
+    let starts =
+        |prefixes: &[&str]| {
+
+            prefixes
+                .iter()
+                .any(|p| {
+
+                    name.starts_with(p)
+                })
+        };
+
+    if name.ends_with(" relationship") {
+
+        "Conversion"
+    } else if name.starts_with(
+        "conventional value of",
+    ) || name
+        .starts_with("standard")
+    {
+
+        "Adopted"
+    } else if starts(&[
+        "atomic unit of",
+        "natural unit of",
+        "electron volt",
+        "unified atomic mass unit",
+    ]) {
+
+        "NonSiUnits"
+    } else if name.contains(" x unit")
+        || name.contains("silicon")
+        || name.contains("Si (220)")
+        || name == "Angstrom star"
+    {
+
+        "XRay"
+    } else if starts(UNIVERSAL) {
+
+        "Universal"
+    } else if starts(ELECTROMAGNETIC) {
+
+        "Electromagnetic"
+    } else if starts(PHYSICO_CHEMICAL) {
+
+        "PhysicoChemical"
+    } else {
+
+        "AtomicNuclear"
+    }
+}
@@ -0,0 +1,56 @@
+//! Errors raised by time-scale conversions.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// An error raised when converting between time scales.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum AstroError {
+    /// A UTC instant (as a Modified Julian Date) precedes 1972, when the
+    /// leap-second form of UTC began.
+    UtcOutOfRange(f64),
+    /// A name is not a known time scale.
+    UnknownTimeScale(String),
+}
+
+impl fmt::Display for AstroError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match self {
+            | Self::UtcOutOfRange(mjd) => {
+
+                write!(
+                    f,
+                    "MJD {mjd} precedes \
+                     the leap-second \
+                     table of UTC"
+                )
+            },
+            | Self::UnknownTimeScale(
+                name,
+            ) => {
+
+                write!(
+                    f,
+                    "unknown time scale \
+                     `{name}`"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for AstroError {
+}
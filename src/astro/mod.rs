@@ -0,0 +1,450 @@
+//! Astronomical constants and time scales.
+//!
+//! The constants follow the IAU conventions: the nominal solar and
+//! planetary values of IAU 2015 Resolution B3, the astronomical unit of
+//! IAU 2012 Resolution B2, the parsec of IAU 2015 Resolution B2, the
+//! Julian units of time and the time-scale defining constants of IAU 2006
+//! Resolution B3, and the current best estimates of the IAU 2009 system.
+//! Like the CODATA constants in [`crate::constant`], each is emitted as a
+//! bare `f64`, a getter and a getter returning a [`Measured`], and is
+//! registered as an [`AstronomicalConstant`].
+//!
+//! Values tied to physical constants are computed from them: the
+//! light-year and the light time for one astronomical unit from
+//! [`SPEED_OF_LIGHT`], and masses from their mass parameters and
+//! [`GRAVITATIONAL_CONSTANT`], with the uncertainty of `G`.
+//!
+//! [`time`] converts instants between TAI, TT, UTC and TDB and between
+//! calendar dates, Julian Dates and Modified Julian Dates.
+//!
+//! ```
+//! 
+//! use rssn_advanced::astro;
+//!
+//! let ly =
+//!     astro::lookup("LIGHT_YEAR")
+//!         .unwrap();
+//!
+//! assert_eq!(
+//!     ly.value,
+//!     9_460_730_472_580_800.0
+//! );
+//!
+//! assert!(ly.exact);
+//! ```
+
+#![allow(clippy::inline_always)]
+
+/// Errors raised by time-scale conversions.
+pub mod error;
+/// Structured records and lookups for the astronomical constants.
+pub mod registry;
+/// Time scales, leap seconds and Julian Dates.
+pub mod time;
+
+pub use error::AstroError;
+pub use registry::*;
+pub use time::TimeScale;
+
+use crate::constant::GRAVITATIONAL_CONSTANT;
+use crate::constant::Measured;
+use crate::constant::SPEED_OF_LIGHT;
+use crate::constant::get_gravitational_constant_measured;
+
+/// The relative standard uncertainty of `G`.
+///
+/// It dominates that of any mass obtained from a mass parameter `GM`,
+/// which is known some six orders of magnitude better.
+
+const G_RELATIVE_UNCERTAINTY: f64 =
+    get_gravitational_constant_measured()
+        .relative_uncertainty();
+
+/// Declares the table of astronomical constants.
+///
+/// Every entry emits a bare `f64` constant, a `const fn` getter and a
+/// `const fn` getter returning the value with its uncertainty as a
+/// [`Measured`], and is also recorded as an [`AstronomicalConstant`] in
+/// [`ASTRONOMICAL_CONSTANTS`].
+
+macro_rules! astro_const {
+    (
+        $(
+            (
+                $const_name:ident,
+                $fn_name:ident,
+                $measured_fn:ident,
+                $name:expr_2021,
+                $symbol:expr_2021,
+                $value:expr_2021,
+                $uncert:expr_2021,
+                $unit:expr_2021,
+                $group:ident
+            )
+        ),* $(,)?
+    ) => {
+        $(
+            #[doc = concat!("The ", $name, ".\n\n**Value:** ", stringify!($value), " ", $unit, "\n**Uncertainty:** ", stringify!($uncert))]
+            pub const $const_name: f64 = $value;
+
+            #[doc = concat!("Returns the ", $name)]
+            #[must_use]
+            #[inline(always)]
+            pub const fn $fn_name() -> f64 {
+                $const_name
+            }
+
+            #[doc = concat!("Returns the ", $name, " with its standard uncertainty")]
+            #[must_use]
+            #[inline(always)]
+            pub const fn $measured_fn() -> Measured {
+                Measured::new($const_name, $uncert)
+            }
+        )*
+
+        /// Every registered astronomical constant.
+        pub static ASTRONOMICAL_CONSTANTS: &[AstronomicalConstant] = &[
+            $(
+                AstronomicalConstant::new(
+                    stringify!($const_name),
+                    $name,
+                    $symbol,
+                    $const_name,
+                    $uncert,
+                    $unit,
+                    AstroGroup::$group,
+                ),
+            )*
+        ];
+    };
+}
+
+astro_const! {
+    // IAU 2015 Resolution B3: nominal solar and planetary values.
+    (
+        NOMINAL_SOLAR_RADIUS,
+        get_nominal_solar_radius,
+        get_nominal_solar_radius_measured,
+        "nominal solar radius",
+        "R_☉",
+        6.957e8,
+        0.0,
+        "m",
+        Nominal
+    ),
+    (
+        NOMINAL_SOLAR_IRRADIANCE,
+        get_nominal_solar_irradiance,
+        get_nominal_solar_irradiance_measured,
+        "nominal total solar irradiance",
+        "S_☉",
+        1361.0,
+        0.0,
+        "W m⁻²",
+        Nominal
+    ),
+    (
+        NOMINAL_SOLAR_LUMINOSITY,
+        get_nominal_solar_luminosity,
+        get_nominal_solar_luminosity_measured,
+        "nominal solar luminosity",
+        "L_☉",
+        3.828e26,
+        0.0,
+        "W",
+        Nominal
+    ),
+    (
+        NOMINAL_SOLAR_EFFECTIVE_TEMPERATURE,
+        get_nominal_solar_effective_temperature,
+        get_nominal_solar_effective_temperature_measured,
+        "nominal solar effective temperature",
+        "T_eff☉",
+        5772.0,
+        0.0,
+        "K",
+        Nominal
+    ),
+    (
+        NOMINAL_SOLAR_MASS_PARAMETER,
+        get_nominal_solar_mass_parameter,
+        get_nominal_solar_mass_parameter_measured,
+        "nominal solar mass parameter",
+        "(GM)_☉",
+        1.327_124_4e20,
+        0.0,
+        "m³ s⁻²",
+        Nominal
+    ),
+    (
+        NOMINAL_TERRESTRIAL_EQUATORIAL_RADIUS,
+        get_nominal_terrestrial_equatorial_radius,
+        get_nominal_terrestrial_equatorial_radius_measured,
+        "nominal terrestrial equatorial radius",
+        "R_eE",
+        6.378_1e6,
+        0.0,
+        "m",
+        Nominal
+    ),
+    (
+        NOMINAL_TERRESTRIAL_POLAR_RADIUS,
+        get_nominal_terrestrial_polar_radius,
+        get_nominal_terrestrial_polar_radius_measured,
+        "nominal terrestrial polar radius",
+        "R_pE",
+        6.356_8e6,
+        0.0,
+        "m",
+        Nominal
+    ),
+    (
+        NOMINAL_JOVIAN_EQUATORIAL_RADIUS,
+        get_nominal_jovian_equatorial_radius,
+        get_nominal_jovian_equatorial_radius_measured,
+        "nominal jovian equatorial radius",
+        "R_eJ",
+        7.149_2e7,
+        0.0,
+        "m",
+        Nominal
+    ),
+    (
+        NOMINAL_JOVIAN_POLAR_RADIUS,
+        get_nominal_jovian_polar_radius,
+        get_nominal_jovian_polar_radius_measured,
+        "nominal jovian polar radius",
+        "R_pJ",
+        6.685_4e7,
+        0.0,
+        "m",
+        Nominal
+    ),
+    (
+        NOMINAL_TERRESTRIAL_MASS_PARAMETER,
+        get_nominal_terrestrial_mass_parameter,
+        get_nominal_terrestrial_mass_parameter_measured,
+        "nominal terrestrial mass parameter",
+        "(GM)_E",
+        3.986_004e14,
+        0.0,
+        "m³ s⁻²",
+        Nominal
+    ),
+    (
+        NOMINAL_JOVIAN_MASS_PARAMETER,
+        get_nominal_jovian_mass_parameter,
+        get_nominal_jovian_mass_parameter_measured,
+        "nominal jovian mass parameter",
+        "(GM)_J",
+        1.266_865_3e17,
+        0.0,
+        "m³ s⁻²",
+        Nominal
+    ),
+    // Defining constants and units.
+    (
+        ASTRONOMICAL_UNIT,
+        get_astronomical_unit,
+        get_astronomical_unit_measured,
+        "astronomical unit",
+        "au",
+        149_597_870_700.0,
+        0.0,
+        "m",
+        Defining
+    ),
+    (
+        PARSEC,
+        get_parsec,
+        get_parsec_measured,
+        "parsec",
+        "pc",
+        ASTRONOMICAL_UNIT * 648_000.0
+            / std::f64::consts::PI,
+        0.0,
+        "m",
+        Defining
+    ),
+    (
+        JULIAN_DAY,
+        get_julian_day,
+        get_julian_day_measured,
+        "Julian day",
+        "d",
+        86_400.0,
+        0.0,
+        "s",
+        Defining
+    ),
+    (
+        JULIAN_YEAR,
+        get_julian_year,
+        get_julian_year_measured,
+        "Julian year",
+        "a",
+        365.25 * JULIAN_DAY,
+        0.0,
+        "s",
+        Defining
+    ),
+    (
+        JULIAN_CENTURY,
+        get_julian_century,
+        get_julian_century_measured,
+        "Julian century",
+        "cy",
+        100.0 * JULIAN_YEAR,
+        0.0,
+        "s",
+        Defining
+    ),
+    (
+        LIGHT_YEAR,
+        get_light_year,
+        get_light_year_measured,
+        "light-year",
+        "ly",
+        SPEED_OF_LIGHT * JULIAN_YEAR,
+        0.0,
+        "m",
+        Defining
+    ),
+    (
+        ASTRONOMICAL_UNIT_LIGHT_TIME,
+        get_astronomical_unit_light_time,
+        get_astronomical_unit_light_time_measured,
+        "light time for one astronomical unit",
+        "τ_A",
+        ASTRONOMICAL_UNIT / SPEED_OF_LIGHT,
+        0.0,
+        "s",
+        Defining
+    ),
+    (
+        TT_MINUS_TAI,
+        get_tt_minus_tai,
+        get_tt_minus_tai_measured,
+        "offset of TT from TAI",
+        "TT − TAI",
+        32.184,
+        0.0,
+        "s",
+        Defining
+    ),
+    (
+        L_G,
+        get_l_g,
+        get_l_g_measured,
+        "rate of TT with respect to TCG, 1 − d(TT)/d(TCG)",
+        "L_G",
+        6.969_290_134e-10,
+        0.0,
+        "",
+        Defining
+    ),
+    (
+        L_B,
+        get_l_b,
+        get_l_b_measured,
+        "rate of TDB with respect to TCB, 1 − d(TDB)/d(TCB)",
+        "L_B",
+        1.550_519_768e-8,
+        0.0,
+        "",
+        Defining
+    ),
+    (
+        TDB_0,
+        get_tdb_0,
+        get_tdb_0_measured,
+        "offset of TDB from TCB at the 1977 epoch",
+        "TDB₀",
+        -6.55e-5,
+        0.0,
+        "s",
+        Defining
+    ),
+    // IAU 2009 system of astronomical constants: current best estimates.
+    (
+        L_C,
+        get_l_c,
+        get_l_c_measured,
+        "average rate of TCG with respect to TCB",
+        "L_C",
+        1.480_826_867_41e-8,
+        2e-17,
+        "",
+        BestEstimate
+    ),
+    (
+        SOLAR_MASS_PARAMETER,
+        get_solar_mass_parameter,
+        get_solar_mass_parameter_measured,
+        "heliocentric gravitational constant (TDB-compatible)",
+        "GM_☉",
+        1.327_124_400_41e20,
+        1e10,
+        "m³ s⁻²",
+        BestEstimate
+    ),
+    (
+        EARTH_MASS_PARAMETER,
+        get_earth_mass_parameter,
+        get_earth_mass_parameter_measured,
+        "geocentric gravitational constant (TT-compatible)",
+        "GM_E",
+        3.986_004_415e14,
+        8e5,
+        "m³ s⁻²",
+        BestEstimate
+    ),
+    (
+        MOON_EARTH_MASS_RATIO,
+        get_moon_earth_mass_ratio,
+        get_moon_earth_mass_ratio_measured,
+        "ratio of the mass of the Moon to that of the Earth",
+        "M_M/M_E",
+        1.230_003_71e-2,
+        4e-10,
+        "",
+        BestEstimate
+    ),
+    // Masses from mass parameters and G.
+    (
+        SOLAR_MASS,
+        get_solar_mass,
+        get_solar_mass_measured,
+        "solar mass, GM_☉/G",
+        "M_☉",
+        SOLAR_MASS_PARAMETER
+            / GRAVITATIONAL_CONSTANT,
+        SOLAR_MASS * G_RELATIVE_UNCERTAINTY,
+        "kg",
+        Derived
+    ),
+    (
+        EARTH_MASS,
+        get_earth_mass,
+        get_earth_mass_measured,
+        "Earth mass, GM_E/G",
+        "M_E",
+        EARTH_MASS_PARAMETER
+            / GRAVITATIONAL_CONSTANT,
+        EARTH_MASS * G_RELATIVE_UNCERTAINTY,
+        "kg",
+        Derived
+    ),
+    (
+        JUPITER_MASS,
+        get_jupiter_mass,
+        get_jupiter_mass_measured,
+        "Jupiter mass, (GM)_J/G",
+        "M_J",
+        NOMINAL_JOVIAN_MASS_PARAMETER
+            / GRAVITATIONAL_CONSTANT,
+        JUPITER_MASS * G_RELATIVE_UNCERTAINTY,
+        "kg",
+        Derived
+    ),
+}
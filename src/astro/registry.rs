@@ -0,0 +1,213 @@
+//! Structured records for the astronomical constants declared with
+//! `astro_const!`.
+//!
+//! Each constant in [`crate::astro`] is also registered as an
+//! [`AstronomicalConstant`], so callers can query its uncertainty, unit
+//! and provenance at runtime or enumerate the whole table.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::constant::Measured;
+use crate::unit;
+use crate::unit::Dimension;
+use crate::unit::Quantity;
+
+/// The kind of an astronomical constant, by how its value is fixed.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum AstroGroup {
+    /// Nominal solar and planetary values of IAU 2015 Resolution B3,
+    /// exact conversion factors rather than measurements.
+    Nominal,
+    /// Units and constants exact by definition (au, pc, Julian units,
+    /// time-scale constants).
+    Defining,
+    /// Measured values of the IAU 2009 system of astronomical constants.
+    BestEstimate,
+    /// Values derived from other astronomical and physical constants.
+    Derived,
+}
+
+/// A registered astronomical constant together with its metadata.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+)]
+
+pub struct AstronomicalConstant {
+    /// The Rust identifier of the constant (e.g. `"ASTRONOMICAL_UNIT"`).
+    pub id: &'static str,
+    /// The quantity name (e.g. `"astronomical unit"`).
+    pub name: &'static str,
+    /// The conventional symbol (e.g. `"au"`).
+    pub symbol: &'static str,
+    /// The value in the unit given by `unit`.
+    pub value: f64,
+    /// The standard uncertainty, zero for exact values.
+    pub uncertainty: f64,
+    /// The relative standard uncertainty, zero for exact values.
+    pub relative_uncertainty: f64,
+    /// The unit (e.g. `"m³ s⁻²"`).
+    pub unit: &'static str,
+    /// Whether the value is exact by definition.
+    pub exact: bool,
+    /// How the value is fixed.
+    pub group: AstroGroup,
+}
+
+impl AstronomicalConstant {
+    /// Creates a record, deriving the relative uncertainty and the exact flag
+    /// from the standard uncertainty.
+    #[must_use]
+
+    pub const fn new(
+        id: &'static str,
+        name: &'static str,
+        symbol: &'static str,
+        value: f64,
+        uncertainty: f64,
+        unit: &'static str,
+        group: AstroGroup,
+    ) -> Self {
+
+        let exact = uncertainty <= 0.0;
+
+        let relative_uncertainty =
+            if exact {
+
+                0.0
+            } else {
+
+                uncertainty
+                    / value.abs()
+            };
+
+        Self {
+            id,
+            name,
+            symbol,
+            value,
+            uncertainty,
+            relative_uncertainty,
+            unit,
+            exact,
+            group,
+        }
+    }
+
+    /// Returns the dimension of `unit`, or `None` if the unit notation is
+    /// not recognised.
+    #[must_use]
+
+    pub fn dimension(
+        &self
+    ) -> Option<Dimension> {
+
+        unit::parse(self.unit)
+            .ok()
+            .map(|unit| unit.dimension)
+    }
+
+    /// Returns the value as a [`Quantity`] in coherent SI units, or `None`
+    /// if the unit notation is not recognised.
+    #[must_use]
+
+    pub fn quantity(
+        &self
+    ) -> Option<Quantity> {
+
+        Quantity::with_unit(
+            self.value,
+            self.unit,
+        )
+        .ok()
+    }
+
+    /// Returns the value together with its standard uncertainty.
+    #[must_use]
+
+    pub const fn measured(
+        &self
+    ) -> Measured {
+
+        Measured::new(
+            self.value,
+            self.uncertainty,
+        )
+    }
+}
+
+/// Returns every registered astronomical constant.
+#[must_use]
+
+pub const fn all()
+-> &'static [AstronomicalConstant] {
+
+    super::ASTRONOMICAL_CONSTANTS
+}
+
+/// Looks up a constant by its identifier (e.g. `"SOLAR_MASS"`).
+///
+/// The comparison ignores ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::astro;
+///
+/// let au = astro::lookup(
+///     "astronomical_unit",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     au.value,
+///     149_597_870_700.0
+/// );
+/// ```
+#[must_use]
+
+pub fn lookup(
+    id: &str
+) -> Option<&'static AstronomicalConstant>
+{
+
+    all()
+        .iter()
+        .find(|c| {
+
+            c.id.eq_ignore_ascii_case(
+                id,
+            )
+        })
+}
+
+/// Returns an iterator over the constants belonging to `group`.
+
+pub fn in_group(
+    group: AstroGroup
+) -> impl Iterator<
+    Item = &'static AstronomicalConstant,
+>{
+
+    all()
+        .iter()
+        .filter(move |c| {
+
+            c.group == group
+        })
+}
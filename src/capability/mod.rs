@@ -0,0 +1,348 @@
+//! Build and capability introspection.
+//!
+//! [`capabilities`] describes the library a host has loaded: the crate and
+//! `rssn` versions, the build profile, optimization level, panic strategy
+//! and enabled Cargo features, the CODATA adjustments shipped, and every
+//! public algorithm and exported FFI function with its signature. Host
+//! applications can check it at load time, before calling anything that
+//! may be missing from an older or differently configured build.
+//!
+//! The function tables are generated by `build/capability.rs` from the
+//! sources, so they cannot drift from what is compiled in.
+//!
+//! # Examples
+//!
+//! ```
+//! 
+//! use rssn_advanced::capability;
+//!
+//! let capabilities =
+//!     capability::capabilities();
+//!
+//! assert_eq!(
+//!     capabilities.package,
+//!     "rssn-advanced"
+//! );
+//!
+//! assert!(
+//!     capabilities
+//!         .algorithm("unit::buckingham::pi_groups")
+//!         .is_some()
+//! );
+//! ```
+
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::constant::CodataEdition;
+
+// The tables are generated by `build/capability.rs` from the sources.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/capabilities.rs"
+));
+
+/// How the library was built.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Build {
+    /// The date the library was built.
+    pub date: String,
+    /// The Git commit SHA the library was built from.
+    pub commit_sha: String,
+    /// The version of the Rust compiler.
+    pub rustc_version: String,
+    /// The target triple.
+    pub target_triple: String,
+    /// Operating system and version of the build environment.
+    pub system_info: String,
+    /// The Cargo profile (`"debug"` or `"release"`).
+    pub profile: String,
+    /// The optimization level (`"0"` to `"3"`, `"s"` or `"z"`).
+    pub opt_level: String,
+    /// Whether debug assertions are enabled.
+    pub debug_assertions: bool,
+    /// The panic strategy (`"unwind"` or `"abort"`).
+    pub panic: String,
+    /// The enabled Cargo features, sorted.
+    pub features: Vec<String>,
+}
+
+/// A public function of the Rust API.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Algorithm {
+    /// The module path below the crate root (e.g. `"unit::buckingham"`).
+    pub module: String,
+    /// The function name.
+    pub name: String,
+    /// The signature, with whitespace collapsed.
+    pub signature: String,
+    /// The first paragraph of the documentation.
+    pub summary: String,
+}
+
+/// A function exported over the C ABI.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct FfiFunction {
+    /// The module path below the crate root (e.g. `"ffi_apis::unit_ffi::json"`).
+    pub module: String,
+    /// The exported symbol.
+    pub name: String,
+    /// The Rust signature, with whitespace collapsed and FFI type paths
+    /// shortened (`c_char`, `BincodeBuffer`).
+    pub signature: String,
+    /// The first paragraph of the documentation.
+    pub summary: String,
+    /// Whether the function is `unsafe` and documents a `# Safety`
+    /// contract on its pointer arguments.
+    pub is_unsafe: bool,
+}
+
+/// Everything a host can negotiate against.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Capabilities {
+    /// The crate name.
+    pub package: String,
+    /// The crate version.
+    pub version: String,
+    /// The version of the `rssn` dependency.
+    pub rssn_version: String,
+    /// How the library was built.
+    pub build: Build,
+    /// The CODATA adjustment the constant getters return.
+    pub codata_edition: CodataEdition,
+    /// Every CODATA adjustment available through `constant::edition`.
+    pub codata_editions:
+        Vec<CodataEdition>,
+    /// The public functions of the Rust API, by module and name.
+    pub algorithms: Vec<Algorithm>,
+    /// The exported FFI functions, by name; empty unless the `ffi_api`
+    /// feature is enabled.
+    pub ffi_functions: Vec<FfiFunction>,
+}
+
+impl Capabilities {
+    /// Returns whether Cargo feature `name` is enabled.
+    #[must_use]
+
+    pub fn has_feature(
+        &self,
+        name: &str,
+    ) -> bool {
+
+        self.build
+            .features
+            .iter()
+            .any(|f| f == name)
+    }
+
+    /// Returns the algorithm with path `path` below the crate root
+    /// (e.g. `"constant::identify::identify"`).
+    #[must_use]
+
+    pub fn algorithm(
+        &self,
+        path: &str,
+    ) -> Option<&Algorithm> {
+
+        let (module, name) =
+            path.rsplit_once("::")?;
+
+        self.algorithms
+            .iter()
+            .find(|a| {
+
+                a.module == module
+                    && a.name == name
+            })
+    }
+
+    /// Returns the exported FFI function with symbol `name`.
+    #[must_use]
+
+    pub fn ffi_function(
+        &self,
+        name: &str,
+    ) -> Option<&FfiFunction> {
+
+        self.ffi_functions
+            .binary_search_by(|f| {
+
+                f.name
+                    .as_str()
+                    .cmp(name)
+            })
+            .ok()
+            .and_then(|i| {
+
+                self.ffi_functions
+                    .get(i)
+            })
+    }
+}
+
+static CAPABILITIES: LazyLock<
+    Capabilities,
+> = LazyLock::new(|| {
+
+    let rssn_version = env!(
+        "VERGEN_CARGO_DEPENDENCIES"
+    )
+    .split(',')
+    .find_map(|dependency| {
+
+        dependency
+            .trim()
+            .strip_prefix("rssn ")
+    })
+    .unwrap_or_default()
+    .to_string();
+
+    Capabilities {
+        package: env!("CARGO_PKG_NAME")
+            .to_string(),
+        version: env!(
+            "CARGO_PKG_VERSION"
+        )
+        .to_string(),
+        rssn_version,
+        build: Build {
+            date: crate::constant::BUILD_DATE
+                .to_string(),
+            commit_sha:
+                crate::constant::COMMIT_SHA
+                    .to_string(),
+            rustc_version:
+                crate::constant::RUSTC_VERSION
+                    .to_string(),
+            target_triple: crate::constant::CARGO_TARGET_TRIPLE
+                .to_string(),
+            system_info:
+                crate::constant::SYSTEM_INFO
+                    .to_string(),
+            profile: PROFILE.to_string(),
+            opt_level: OPT_LEVEL
+                .to_string(),
+            debug_assertions: cfg!(
+                debug_assertions
+            ),
+            panic: if cfg!(
+                panic = "abort"
+            ) {
+
+                "abort"
+            } else {
+
+                "unwind"
+            }
+            .to_string(),
+            features: FEATURES
+                .iter()
+                .map(|f| (*f).to_string())
+                .collect(),
+        },
+        codata_edition:
+            CodataEdition::LATEST,
+        codata_editions: CodataEdition::ALL
+            .to_vec(),
+        algorithms: ALGORITHMS
+            .iter()
+            .map(
+                |&(
+                    module,
+                    name,
+                    signature,
+                    summary,
+                )| {
+
+                    Algorithm {
+                        module: module
+                            .to_string(),
+                        name: name
+                            .to_string(),
+                        signature: signature
+                            .to_string(),
+                        summary: summary
+                            .to_string(),
+                    }
+                },
+            )
+            .collect(),
+        ffi_functions: if cfg!(
+            feature = "ffi_api"
+        ) {
+
+            FFI_FUNCTIONS
+                .iter()
+                .map(
+                    |&(
+                        module,
+                        name,
+                        signature,
+                        summary,
+                        is_unsafe,
+                    )| {
+
+                        FfiFunction {
+                            module: module
+                                .to_string(),
+                            name: name
+                                .to_string(),
+                            signature:
+                                signature
+                                    .to_string(),
+                            summary: summary
+                                .to_string(),
+                            is_unsafe,
+                        }
+                    },
+                )
+                .collect()
+        } else {
+
+            Vec::new()
+        },
+    }
+});
+
+/// Returns the capabilities of this build of the library.
+#[must_use]
+
+pub fn capabilities()
+-> &'static Capabilities {
+
+    &CAPABILITIES
+}
@@ -0,0 +1,528 @@
+//! Consistency checks between constants that are tied by exact relations.
+//!
+//! Many constants are defined in terms of others (`R = N_A k`,
+//! `K_J = 2e/h`, `m_e = 2hR∞/(cα²)`, ...). [`run`] evaluates every relation
+//! in [`RELATIONS`] with the listed inputs, propagating their correlated
+//! uncertainties with [`super::propagation`], and compares the result with
+//! the listed value of the related constant.
+//!
+//! The residual is expressed in units of the combined standard uncertainty
+//! of the listed and derived values. Measured values and exact values that
+//! are listed truncated (`ħ = 1.054 571 817... × 10⁻³⁴ J s`) also carry a
+//! rounding allowance of one unit in their last printed digit, counted from
+//! the digits quoted in the listing; exact values listed in full carry none.
+
+use serde::Serialize;
+
+use super::Dual;
+use super::Measured;
+use super::PhysicalConstant;
+use super::correlation;
+use super::propagation;
+
+/// Root of `x = 5 (1 - e^-x)`, which fixes Wien's wavelength displacement law.
+
+const WIEN_WAVELENGTH_ROOT: f64 =
+    4.965_114_231_744_276;
+
+/// Root of `x = 3 (1 - e^-x)`, which fixes Wien's frequency displacement law.
+
+const WIEN_FREQUENCY_ROOT: f64 =
+    2.821_439_372_122_079;
+
+/// The standard-state temperature `273.15 K`.
+
+const STANDARD_TEMPERATURE: f64 =
+    273.15;
+
+/// The relative error allowed for evaluating a relation in `f64`.
+
+const FLOATING_POINT_TOLERANCE: f64 =
+    16.0 * f64::EPSILON;
+
+/// An exact relation that gives a constant in terms of other constants.
+
+pub struct Relation {
+    /// The relation, e.g. `"R = N_A k"`.
+    pub name: &'static str,
+    /// The identifier of the constant on the left-hand side.
+    pub constant: &'static str,
+    /// The identifiers of the constants on the right-hand side.
+    pub inputs:
+        &'static [&'static str],
+    /// Evaluates the right-hand side from the inputs, in the order of
+    /// `inputs`. Returns `None` if the number of inputs does not match.
+    pub derive:
+        fn(&[Dual]) -> Option<Dual>,
+}
+
+/// Declares a table of [`Relation`]s.
+///
+/// Each entry reads `"name": CONSTANT = |arg: INPUT, ...| expression;`,
+/// where the arguments are bound to the listed values of the inputs.
+
+macro_rules! relations {
+    (
+        $(
+            $name:literal :
+            $constant:ident =
+            |$($arg:ident : $input:ident),* $(,)?|
+            $body:expr;
+        )*
+    ) => {
+        &[
+            $(
+                Relation {
+                    name: $name,
+                    constant: stringify!($constant),
+                    inputs: &[$(stringify!($input)),*],
+                    derive: |x| {
+                        let [$($arg),*]: [Dual; _] =
+                            x.try_into().ok()?;
+
+                        Some($body)
+                    },
+                },
+            )*
+        ]
+    };
+}
+
+/// Every relation checked by [`run`].
+
+pub static RELATIONS: &[Relation] = relations! {
+    "R = N_A k":
+        MOLAR_GAS_CONSTANT =
+        |n_a: AVOGADRO_CONSTANT, k: BOLTZMANN_CONSTANT|
+        n_a * k;
+    "F = N_A e":
+        FARADAY_CONSTANT =
+        |n_a: AVOGADRO_CONSTANT, e: ELEMENTARY_CHARGE|
+        n_a * e;
+    "K_J = 2e/h":
+        JOSEPHSON_CONSTANT =
+        |e: ELEMENTARY_CHARGE, h: PLANCK_CONSTANT|
+        2.0 * e / h;
+    "R_K = h/e²":
+        VON_KLITZING_CONSTANT =
+        |h: PLANCK_CONSTANT, e: ELEMENTARY_CHARGE|
+        h / e.powi(2);
+    "Φ₀ = h/2e":
+        MAGNETIC_FLUX_QUANTUM =
+        |h: PLANCK_CONSTANT, e: ELEMENTARY_CHARGE|
+        h / (2.0 * e);
+    "G₀ = 2e²/h":
+        CONDUCTANCE_QUANTUM =
+        |e: ELEMENTARY_CHARGE, h: PLANCK_CONSTANT|
+        2.0 * e.powi(2) / h;
+    "G₀⁻¹ = h/2e²":
+        INVERSE_OF_CONDUCTANCE_QUANTUM =
+        |h: PLANCK_CONSTANT, e: ELEMENTARY_CHARGE|
+        h / (2.0 * e.powi(2));
+    "ħ = h/2π":
+        REDUCED_PLANCK_CONSTANT =
+        |h: PLANCK_CONSTANT|
+        h / std::f64::consts::TAU;
+    "eV = e × 1 J/C":
+        ELECTRON_VOLT =
+        |e: ELEMENTARY_CHARGE|
+        e;
+    "σ = 2π⁵k⁴/(15h³c²)":
+        STEFAN_BOLTZMANN_CONSTANT =
+        |k: BOLTZMANN_CONSTANT, h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT|
+        2.0 * std::f64::consts::PI.powi(5) * k.powi(4)
+            / (15.0 * h.powi(3) * c.powi(2));
+    "c₁ = 2πhc²":
+        FIRST_RADIATION_CONSTANT =
+        |h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT|
+        std::f64::consts::TAU * h * c.powi(2);
+    "c₁L = 2hc²":
+        FIRST_RADIATION_CONSTANT_FOR_SPECTRAL_RADIANCE =
+        |h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT|
+        2.0 * h * c.powi(2);
+    "c₂ = hc/k":
+        SECOND_RADIATION_CONSTANT =
+        |h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT, k: BOLTZMANN_CONSTANT|
+        h * c / k;
+    "b = hc/(k x₅)":
+        WIEN_DISPLACEMENT_CONSTANT =
+        |h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT, k: BOLTZMANN_CONSTANT|
+        h * c / (k * WIEN_WAVELENGTH_ROOT);
+    "b′ = x₃ k/h":
+        WIEN_FREQUENCY_DISPLACEMENT_LAW_CONSTANT =
+        |k: BOLTZMANN_CONSTANT, h: PLANCK_CONSTANT|
+        WIEN_FREQUENCY_ROOT * k / h;
+    "V_m = RT/p (273.15 K, 101.325 kPa)":
+        MOLAR_VOLUME_IDEAL_GAS =
+        |r: MOLAR_GAS_CONSTANT, p: STANDARD_ATMOSPHERE|
+        r * STANDARD_TEMPERATURE / p;
+    "μ₀ = 2αh/(e²c)":
+        VACUUM_MAGNETIC_PERMEABILITY =
+        |alpha: FINE_STRUCTURE_CONSTANT, h: PLANCK_CONSTANT, e: ELEMENTARY_CHARGE, c: SPEED_OF_LIGHT|
+        2.0 * alpha * h / (e.powi(2) * c);
+    "ε₀ = 1/(μ₀c²)":
+        VACUUM_ELECTRIC_PERMITTIVITY =
+        |mu_0: VACUUM_MAGNETIC_PERMEABILITY, c: SPEED_OF_LIGHT|
+        (mu_0 * c.powi(2)).recip();
+    "Z₀ = μ₀c":
+        CHARACTERISTIC_IMPEDANCE_OF_VACUUM =
+        |mu_0: VACUUM_MAGNETIC_PERMEABILITY, c: SPEED_OF_LIGHT|
+        mu_0 * c;
+    "α⁻¹ = 1/α":
+        INVERSE_FINE_STRUCTURE_CONSTANT =
+        |alpha: FINE_STRUCTURE_CONSTANT|
+        alpha.recip();
+    "m_e = 2hR∞/(cα²)":
+        ELECTRON_MASS =
+        |h: PLANCK_CONSTANT, r_inf: RYDBERG_CONSTANT, c: SPEED_OF_LIGHT, alpha: FINE_STRUCTURE_CONSTANT|
+        2.0 * h * r_inf / (c * alpha.powi(2));
+    "E_h = 2R∞hc":
+        HARTREE_ENERGY =
+        |r_inf: RYDBERG_CONSTANT, h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT|
+        2.0 * r_inf * h * c;
+    "R∞hc/e (eV)":
+        RYDBERG_CONSTANT_TIMES_HC_IN_EV =
+        |r_inf: RYDBERG_CONSTANT, h: PLANCK_CONSTANT, c: SPEED_OF_LIGHT, e: ELEMENTARY_CHARGE|
+        r_inf * h * c / e;
+    "a₀ = α/(4πR∞)":
+        BOHR_RADIUS =
+        |alpha: FINE_STRUCTURE_CONSTANT, r_inf: RYDBERG_CONSTANT|
+        alpha / (2.0 * std::f64::consts::TAU * r_inf);
+    "r_e = α²a₀":
+        CLASSICAL_ELECTRON_RADIUS =
+        |alpha: FINE_STRUCTURE_CONSTANT, a_0: BOHR_RADIUS|
+        alpha.powi(2) * a_0;
+    "σ_e = (8π/3)r_e²":
+        THOMSON_CROSS_SECTION =
+        |r_e: CLASSICAL_ELECTRON_RADIUS|
+        8.0 * std::f64::consts::PI / 3.0 * r_e.powi(2);
+    "μ_B = eħ/2m_e":
+        BOHR_MAGNETON =
+        |e: ELEMENTARY_CHARGE, h: PLANCK_CONSTANT, m_e: ELECTRON_MASS|
+        e * h / (2.0 * std::f64::consts::TAU * m_e);
+    "μ_N = eħ/2m_p":
+        NUCLEAR_MAGNETON =
+        |e: ELEMENTARY_CHARGE, h: PLANCK_CONSTANT, m_p: PROTON_MASS_KG|
+        e * h / (2.0 * std::f64::consts::TAU * m_p);
+    "m_u = m_e/A_r(e)":
+        ATOMIC_MASS_CONSTANT =
+        |m_e: ELECTRON_MASS, a_r: ELECTRON_MASS_U|
+        m_e / a_r;
+    "m_p/m_e = A_r(p)/A_r(e)":
+        PROTON_ELECTRON_MASS_RATIO =
+        |a_p: PROTON_MASS_U, a_e: ELECTRON_MASS_U|
+        a_p / a_e;
+    "m_n/m_p = A_r(n)/A_r(p)":
+        NEUTRON_PROTON_MASS_RATIO =
+        |a_n: NEUTRON_MASS_U, a_p: PROTON_MASS_U|
+        a_n / a_p;
+    "m_e/m_μ = (m_μ/m_e)⁻¹":
+        ELECTRON_MUON_MASS_RATIO =
+        |ratio: MUON_ELECTRON_MASS_RATIO|
+        ratio.recip();
+};
+
+/// The outcome of checking one [`Relation`].
+#[derive(
+    Debug, Clone, PartialEq, Serialize,
+)]
+
+pub struct AuditResult {
+    /// The relation, e.g. `"R = N_A k"`.
+    pub relation: &'static str,
+    /// The identifier of the constant on the left-hand side.
+    pub constant: &'static str,
+    /// The listed value, with its uncertainty and rounding allowance.
+    pub listed: Measured,
+    /// The value derived from the inputs, with its propagated uncertainty
+    /// and rounding allowance.
+    pub derived: Measured,
+    /// The combined standard uncertainty of `listed - derived`.
+    pub combined_uncertainty: f64,
+    /// `(listed - derived) / combined_uncertainty`.
+    pub residual: f64,
+}
+
+impl AuditResult {
+    /// Returns whether the residual is within `threshold` combined
+    /// standard uncertainties.
+    #[must_use]
+
+    pub fn passes(
+        &self,
+        threshold: f64,
+    ) -> bool {
+
+        self.residual.abs() <= threshold
+    }
+}
+
+/// Returns one unit in the last listed digit of `constant`.
+///
+/// The digit count comes from the listing, so a quoted trailing zero
+/// (`6.644 657 345 0 e-27`) narrows the allowance; records without one
+/// fall back on the shortest decimal representation of the value.
+
+fn last_digit(
+    constant: &PhysicalConstant
+) -> f64 {
+
+    let printed = format!(
+        "{:e}",
+        constant.value
+    );
+
+    let Some((mantissa, exponent)) =
+        printed.split_once('e')
+    else {
+
+        return 0.0;
+    };
+
+    let digits = if constant.digits > 0
+    {
+
+        constant.digits as usize
+    } else {
+
+        mantissa
+            .chars()
+            .filter(
+                char::is_ascii_digit,
+            )
+            .count()
+    };
+
+    let (Ok(exponent), Ok(digits)) = (
+        exponent.parse::<i32>(),
+        i32::try_from(digits),
+    ) else {
+
+        return 0.0;
+    };
+
+    10f64.powi(
+        exponent
+            .saturating_sub(digits)
+            .saturating_add(1),
+    )
+}
+
+/// Returns the rounding allowance of a listed value: one unit in its last
+/// printed digit, or zero for exact values listed in full.
+
+fn rounding_allowance(
+    constant: &PhysicalConstant
+) -> f64 {
+
+    if constant.exact
+        && !constant.truncated
+    {
+
+        0.0
+    } else {
+
+        last_digit(constant)
+    }
+}
+
+/// Returns the covariance matrix of `constants`, with the rounding
+/// allowance of each value added to its variance.
+
+fn covariance_with_rounding(
+    ids: &[&str],
+    constants: &[&PhysicalConstant],
+) -> Option<Vec<Vec<f64>>> {
+
+    let mut covariance =
+        correlation::covariance_matrix(
+            ids,
+        )?;
+
+    for (index, (row, constant)) in
+        covariance
+            .iter_mut()
+            .zip(constants)
+            .enumerate()
+    {
+
+        if let Some(variance) =
+            row.get_mut(index)
+        {
+
+            *variance +=
+                rounding_allowance(
+                    constant,
+                )
+                .powi(2);
+        }
+    }
+
+    Some(covariance)
+}
+
+/// Checks a single relation, or returns `None` if it names an unknown
+/// constant.
+#[must_use]
+
+pub fn check(
+    relation: &Relation
+) -> Option<AuditResult> {
+
+    let derive = |x: &[Dual]| {
+
+        (relation.derive)(x).unwrap_or(
+            Dual::constant(f64::NAN),
+        )
+    };
+
+    // The listed constant followed by the inputs, so that the correlation
+    // between the two sides is taken into account.
+    let ids: Vec<&str> =
+        std::iter::once(
+            relation.constant,
+        )
+        .chain(
+            relation
+                .inputs
+                .iter()
+                .copied(),
+        )
+        .collect();
+
+    let constants = ids
+        .iter()
+        .map(|id| super::lookup(id))
+        .collect::<Option<Vec<_>>>()?;
+
+    let values: Vec<f64> = constants
+        .iter()
+        .map(|c| c.value)
+        .collect();
+
+    let covariance =
+        covariance_with_rounding(
+            &ids,
+            &constants,
+        )?;
+
+    let (listed_value, input_values) =
+        values.split_first()?;
+
+    let (listed_row, input_rows) =
+        covariance.split_first()?;
+
+    let listed = Measured::new(
+        *listed_value,
+        listed_row
+            .first()
+            .copied()
+            .unwrap_or_default()
+            .sqrt(),
+    );
+
+    let input_covariance: Vec<
+        Vec<f64>,
+    > = input_rows
+        .iter()
+        .map(|row| {
+
+            row.iter()
+                .skip(1)
+                .copied()
+                .collect()
+        })
+        .collect();
+
+    let derived =
+        propagation::propagate_with_covariance(
+            input_values,
+            &input_covariance,
+            derive,
+        )?;
+
+    let difference =
+        propagation::propagate_with_covariance(
+            &values,
+            &covariance,
+            |x| {
+
+                x.split_first().map_or(
+                    Dual::constant(
+                        f64::NAN,
+                    ),
+                    |(listed, inputs)| {
+
+                        *listed
+                            - derive(inputs)
+                    },
+                )
+            },
+        )?;
+
+    // Relations between values that are all exact and listed in full are
+    // limited only by the floating-point evaluation of the right-hand side.
+    let combined_uncertainty =
+        difference
+            .uncertainty
+            .max(
+                FLOATING_POINT_TOLERANCE
+                    * listed
+                        .value
+                        .abs(),
+            );
+
+    Some(AuditResult {
+        relation: relation.name,
+        constant: relation.constant,
+        listed,
+        derived,
+        combined_uncertainty,
+        residual: difference.value
+            / combined_uncertainty,
+    })
+}
+
+/// Checks every relation in [`RELATIONS`].
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::audit;
+///
+/// assert!(
+///     audit::run()
+///         .iter()
+///         .all(|result| {
+///
+///             result.passes(3.0)
+///         })
+/// );
+/// ```
+#[must_use]
+
+pub fn run() -> Vec<AuditResult> {
+
+    RELATIONS
+        .iter()
+        .filter_map(check)
+        .collect()
+}
+
+/// Returns the relations whose residual exceeds `threshold` combined
+/// standard uncertainties.
+#[must_use]
+
+pub fn failures(
+    threshold: f64
+) -> Vec<AuditResult> {
+
+    run()
+        .into_iter()
+        .filter(|result| {
+
+            !result.passes(threshold)
+        })
+        .collect()
+}
@@ -0,0 +1,739 @@
+//! Correlations between CODATA constants.
+//!
+//! Coefficients listed in `data/codata/correlations_<year>.txt`, one file
+//! per edition, are taken as published (see [`published`]). For CODATA
+//! 2018 and 2022 every other pair falls back to the model below, which is
+//! only an approximation of the coefficients of the adjustment.
+//!
+//! Since the 2019 redefinition of the SI, `h`, `e`, `k`, `N_A` and `c` are
+//! exact, and the uncertainty of most other constants comes from a small
+//! set of adjusted quantities: the fine-structure constant, the Rydberg
+//! constant, relative atomic masses, mass ratios, g-factors and magnetic
+//! moment ratios. The electron mass, for instance, is `m_e = 2hR∞/(cα²)`,
+//! so its uncertainty is essentially that of `α⁻²` and it is almost fully
+//! anti-correlated with `α`.
+//!
+//! [`DEPENDENCIES`] records, for the commonly used measured constants, the
+//! exponents of the power product of [`ADJUSTED_QUANTITIES`] they are
+//! derived from. Correlation coefficients follow from these sensitivities
+//! by treating the adjusted quantities as mutually uncorrelated, which
+//! reproduces the listed relative uncertainties to within the rounding of
+//! their last digit and the residual correlations of the adjustment
+//! itself. Constants without an entry are treated as uncorrelated with
+//! every other constant.
+//!
+//! The model holds for the adjustments made after the 2019 SI
+//! redefinition, each with its own uncertainties. In CODATA 2014, `h`,
+//! `e`, `k` and `N_A` were themselves adjusted and strongly correlated
+//! through the Josephson and von Klitzing constants, so only its published
+//! pairs have a coefficient; see [`has_correlations`].
+
+use super::CodataEdition;
+use super::PhysicalConstant;
+use super::edition;
+
+// The tables are generated by `build/codata.rs` from `data/codata/correlations_*.txt`.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/correlations_2022.rs"
+));
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/correlations_2018.rs"
+));
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/correlations_2014.rs"
+));
+
+/// The adjusted quantities the modelled constants are derived from.
+///
+/// They are treated as uncorrelated with each other.
+
+pub static ADJUSTED_QUANTITIES: &[&str] =
+    &[
+        "FINE_STRUCTURE_CONSTANT",
+        "RYDBERG_CONSTANT",
+        "ELECTRON_MASS_U",
+        "PROTON_MASS_U",
+        "NEUTRON_MASS_U",
+        "ALPHA_PARTICLE_MASS_U",
+        "DEUTERON_PROTON_MASS_RATIO",
+        "MUON_ELECTRON_MASS_RATIO",
+        "ELECTRON_G_FACTOR",
+        "MUON_G_FACTOR",
+        "PROTON_MAGNETIC_MOMENT_TO_NUCLEAR_MAGNETON_RATIO",
+        "NEUTRON_MAGNETIC_MOMENT_TO_NUCLEAR_MAGNETON_RATIO",
+        "GRAVITATIONAL_CONSTANT",
+    ];
+
+/// Exponents of the adjusted quantities in the power product each
+/// constant is derived from, up to exact factors.
+///
+/// `("ELECTRON_MASS", &[("FINE_STRUCTURE_CONSTANT", -2), ("RYDBERG_CONSTANT", 1)])`
+/// reads `m_e ∝ α⁻² R∞`.
+
+pub static DEPENDENCIES: &[(
+    &str,
+    &[(&str, i32)],
+)] = &[
+    (
+        "INVERSE_FINE_STRUCTURE_CONSTANT",
+        &[("FINE_STRUCTURE_CONSTANT", -1)],
+    ),
+    (
+        "VACUUM_MAGNETIC_PERMEABILITY",
+        &[("FINE_STRUCTURE_CONSTANT", 1)],
+    ),
+    (
+        "VACUUM_ELECTRIC_PERMITTIVITY",
+        &[("FINE_STRUCTURE_CONSTANT", -1)],
+    ),
+    (
+        "CHARACTERISTIC_IMPEDANCE_OF_VACUUM",
+        &[("FINE_STRUCTURE_CONSTANT", 1)],
+    ),
+    (
+        "HARTREE_ENERGY",
+        &[("RYDBERG_CONSTANT", 1)],
+    ),
+    (
+        "HARTREE_ENERGY_IN_EV",
+        &[("RYDBERG_CONSTANT", 1)],
+    ),
+    (
+        "RYDBERG_CONSTANT_TIMES_HC_IN_J",
+        &[("RYDBERG_CONSTANT", 1)],
+    ),
+    (
+        "RYDBERG_CONSTANT_TIMES_HC_IN_EV",
+        &[("RYDBERG_CONSTANT", 1)],
+    ),
+    (
+        "BOHR_RADIUS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", 1),
+            ("RYDBERG_CONSTANT", -1),
+        ],
+    ),
+    (
+        "CLASSICAL_ELECTRON_RADIUS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", 3),
+            ("RYDBERG_CONSTANT", -1),
+        ],
+    ),
+    (
+        "THOMSON_CROSS_SECTION",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", 6),
+            ("RYDBERG_CONSTANT", -2),
+        ],
+    ),
+    (
+        "COMPTON_WAVELENGTH",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+        ],
+    ),
+    (
+        "ELECTRON_MASS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+        ],
+    ),
+    (
+        "ELECTRON_MASS_ENERGY_EQUIVALENT",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+        ],
+    ),
+    (
+        "ELECTRON_MASS_ENERGY_EQUIVALENT_IN_MEV",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+        ],
+    ),
+    (
+        "BOHR_MAGNETON",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+        ],
+    ),
+    (
+        "ELECTRON_MAGNETIC_MOMENT",
+        &[
+            ("ELECTRON_G_FACTOR", 1),
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+        ],
+    ),
+    (
+        "ATOMIC_MASS_CONSTANT",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "ATOMIC_MASS_CONSTANT_ENERGY_EQUIVALENT",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "ATOMIC_MASS_CONSTANT_ENERGY_EQUIVALENT_IN_MEV",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "MOLAR_MASS_CONSTANT",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "PROTON_MASS_KG",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("PROTON_MASS_U", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "PROTON_MASS_ENERGY_EQUIVALENT",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("PROTON_MASS_U", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "PROTON_MASS_ENERGY_EQUIVALENT_IN_MEV",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("PROTON_MASS_U", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "NUCLEAR_MAGNETON",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+            ("PROTON_MASS_U", -1),
+            ("ELECTRON_MASS_U", 1),
+        ],
+    ),
+    (
+        "PROTON_MAGNETIC_MOMENT",
+        &[
+            (
+                "PROTON_MAGNETIC_MOMENT_TO_NUCLEAR_MAGNETON_RATIO",
+                1,
+            ),
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+            ("PROTON_MASS_U", -1),
+            ("ELECTRON_MASS_U", 1),
+        ],
+    ),
+    (
+        "NEUTRON_MAGNETIC_MOMENT",
+        &[
+            (
+                "NEUTRON_MAGNETIC_MOMENT_TO_NUCLEAR_MAGNETON_RATIO",
+                1,
+            ),
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+            ("PROTON_MASS_U", -1),
+            ("ELECTRON_MASS_U", 1),
+        ],
+    ),
+    (
+        "PROTON_ELECTRON_MASS_RATIO",
+        &[
+            ("PROTON_MASS_U", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "NEUTRON_PROTON_MASS_RATIO",
+        &[
+            ("NEUTRON_MASS_U", 1),
+            ("PROTON_MASS_U", -1),
+        ],
+    ),
+    (
+        "NEUTRON_MASS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("NEUTRON_MASS_U", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "DEUTERON_MASS_U",
+        &[
+            ("PROTON_MASS_U", 1),
+            ("DEUTERON_PROTON_MASS_RATIO", 1),
+        ],
+    ),
+    (
+        "DEUTERON_MASS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("PROTON_MASS_U", 1),
+            ("DEUTERON_PROTON_MASS_RATIO", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "ALPHA_PARTICLE_MASS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("ALPHA_PARTICLE_MASS_U", 1),
+            ("ELECTRON_MASS_U", -1),
+        ],
+    ),
+    (
+        "ELECTRON_MUON_MASS_RATIO",
+        &[("MUON_ELECTRON_MASS_RATIO", -1)],
+    ),
+    (
+        "MUON_MASS_U",
+        &[
+            ("ELECTRON_MASS_U", 1),
+            ("MUON_ELECTRON_MASS_RATIO", 1),
+        ],
+    ),
+    (
+        "MUON_MASS",
+        &[
+            ("FINE_STRUCTURE_CONSTANT", -2),
+            ("RYDBERG_CONSTANT", 1),
+            ("MUON_ELECTRON_MASS_RATIO", 1),
+        ],
+    ),
+    (
+        "MUON_MAGNETIC_MOMENT",
+        &[
+            ("MUON_G_FACTOR", 1),
+            ("FINE_STRUCTURE_CONSTANT", 2),
+            ("RYDBERG_CONSTANT", -1),
+            ("MUON_ELECTRON_MASS_RATIO", -1),
+        ],
+    ),
+];
+
+/// Returns the exponents of the adjusted quantities `id` is derived from.
+///
+/// An adjusted quantity, and any measured constant without an entry in
+/// [`DEPENDENCIES`], depends only on itself. Exact constants depend on
+/// nothing. Returns `None` if no constant has the identifier `id`.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::correlation;
+///
+/// assert_eq!(
+///     correlation::dependencies(
+///         "bohr_radius"
+///     )
+///     .unwrap(),
+///     [
+///         (
+///             "FINE_STRUCTURE_CONSTANT",
+///             1
+///         ),
+///         ("RYDBERG_CONSTANT", -1),
+///     ]
+/// );
+///
+/// assert!(
+///     correlation::dependencies(
+///         "SPEED_OF_LIGHT"
+///     )
+///     .unwrap()
+///     .is_empty()
+/// );
+/// ```
+#[must_use]
+
+pub fn dependencies(
+    id: &str
+) -> Option<Vec<(&'static str, i32)>> {
+
+    super::lookup(id)
+        .map(record_dependencies)
+}
+
+fn record_dependencies(
+    record: &'static PhysicalConstant
+) -> Vec<(&'static str, i32)> {
+
+    if record.exact {
+
+        return Vec::new();
+    }
+
+    DEPENDENCIES
+        .iter()
+        .find(|(id, _)| {
+
+            *id == record.id
+        })
+        .map_or_else(
+            || vec![(record.id, 1)],
+            |(_, exponents)| {
+
+                exponents.to_vec()
+            },
+        )
+}
+
+/// Returns the relative covariance of two records under the model.
+
+fn model_relative_covariance(
+    a: &'static PhysicalConstant,
+    b: &'static PhysicalConstant,
+) -> f64 {
+
+    let b_dependencies =
+        record_dependencies(b);
+
+    record_dependencies(a)
+        .into_iter()
+        .filter_map(
+            |(quantity, a_exponent)| {
+
+                let (_, b_exponent) =
+                b_dependencies
+                    .iter()
+                    .find(|(other, _)| {
+
+                        *other
+                            == quantity
+                    })?;
+
+                let relative =
+                edition::get(
+                    a.edition, quantity,
+                )?
+                .relative_uncertainty;
+
+                Some(
+                    f64::from(
+                        a_exponent,
+                    ) * f64::from(
+                        *b_exponent,
+                    ) * relative
+                        * relative,
+                )
+            },
+        )
+        .sum()
+}
+
+/// Returns the published correlation coefficients of `edition`, as
+/// pairs of identifiers with their coefficient.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::correlation;
+///
+/// assert!(correlation::published(
+///     CodataEdition::Codata2022
+/// )
+/// .contains(&(
+///     "FINE_STRUCTURE_CONSTANT",
+///     "INVERSE_FINE_STRUCTURE_CONSTANT",
+///     -1.0
+/// )));
+/// ```
+#[must_use]
+
+pub const fn published(
+    edition: CodataEdition
+) -> &'static [(
+    &'static str,
+    &'static str,
+    f64,
+)] {
+
+    match edition {
+        | CodataEdition::Codata2014 => {
+            CODATA_2014_CORRELATIONS
+        },
+        | CodataEdition::Codata2018 => {
+            CODATA_2018_CORRELATIONS
+        },
+        | CodataEdition::Codata2022 => {
+            CODATA_2022_CORRELATIONS
+        },
+    }
+}
+
+fn published_correlation(
+    a: &'static PhysicalConstant,
+    b: &'static PhysicalConstant,
+) -> Option<f64> {
+
+    published(a.edition)
+        .iter()
+        .find(|&&(x, y, _)| {
+
+            (x, y) == (a.id, b.id)
+                || (x, y)
+                    == (b.id, a.id)
+        })
+        .map(|&(.., r)| r)
+}
+
+/// Returns the correlation coefficient of two records: the published one
+/// if listed, else that of the model.
+
+fn record_correlation(
+    a: &'static PhysicalConstant,
+    b: &'static PhysicalConstant,
+) -> f64 {
+
+    if a.exact || b.exact {
+
+        return 0.0;
+    }
+
+    if a.id == b.id {
+
+        return 1.0;
+    }
+
+    if let Some(r) =
+        published_correlation(a, b)
+    {
+
+        return r;
+    }
+
+    let normalization =
+        (model_relative_covariance(
+            a, a,
+        ) * model_relative_covariance(
+            b, b,
+        ))
+        .sqrt();
+
+    if normalization > 0.0 {
+
+        (model_relative_covariance(
+            a, b,
+        ) / normalization)
+            .clamp(-1.0, 1.0)
+    } else {
+
+        0.0
+    }
+}
+
+/// Returns whether correlation coefficients are available between every
+/// two measured constants of `edition`, published or from the model.
+///
+/// They are for the adjustments made under the 2019 SI, CODATA 2018 and
+/// 2022, but not for CODATA 2014, where only the [`published`] pairs
+/// have one.
+#[must_use]
+
+pub const fn has_correlations(
+    edition: CodataEdition
+) -> bool {
+
+    !matches!(
+        edition,
+        CodataEdition::Codata2014
+    )
+}
+
+/// Returns the correlation coefficient of two constants of `edition`.
+///
+/// The coefficient is zero if either constant is exact, and one between a
+/// constant and itself. Returns `None` if either identifier is unknown in
+/// `edition`, or if both constants are measured, their pair is not
+/// [`published`] and `edition` has no model (see [`has_correlations`]).
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::correlation;
+///
+/// // `h` and `e` are exact since CODATA 2018, but were measured before.
+/// assert_eq!(
+///     correlation::correlation_in(
+///         CodataEdition::Codata2018,
+///         "PLANCK_CONSTANT",
+///         "ELEMENTARY_CHARGE",
+///     ),
+///     Some(0.0)
+/// );
+///
+/// assert_eq!(
+///     correlation::correlation_in(
+///         CodataEdition::Codata2014,
+///         "PLANCK_CONSTANT",
+///         "ELEMENTARY_CHARGE",
+///     ),
+///     None
+/// );
+/// ```
+#[must_use]
+
+pub fn correlation_in(
+    edition: CodataEdition,
+    a: &str,
+    b: &str,
+) -> Option<f64> {
+
+    let a = edition::get(edition, a)?;
+
+    let b = edition::get(edition, b)?;
+
+    if !(a.exact
+        || b.exact
+        || a.id == b.id
+        || has_correlations(edition)
+        || published_correlation(a, b)
+            .is_some())
+    {
+
+        return None;
+    }
+
+    Some(record_correlation(
+        a, b,
+    ))
+}
+
+/// Returns the correlation coefficient of two CODATA 2022 constants.
+///
+/// The coefficient is zero if either constant is exact. Returns `None` if
+/// either identifier is unknown.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::correlation;
+///
+/// let r = correlation::correlation(
+///     "ELECTRON_MASS",
+///     "FINE_STRUCTURE_CONSTANT",
+/// )
+/// .unwrap();
+///
+/// assert!(r < -0.99);
+/// ```
+#[must_use]
+
+pub fn correlation(
+    a: &str,
+    b: &str,
+) -> Option<f64> {
+
+    correlation_in(
+        CodataEdition::LATEST,
+        a,
+        b,
+    )
+}
+
+/// Returns the covariance of two constants, in the product of their units.
+///
+/// The variance of a constant is the square of its listed standard
+/// uncertainty. Returns `None` if either identifier is unknown.
+#[must_use]
+
+pub fn covariance(
+    a: &str,
+    b: &str,
+) -> Option<f64> {
+
+    let a = super::lookup(a)?;
+
+    let b = super::lookup(b)?;
+
+    Some(
+        record_correlation(a, b)
+            * a.uncertainty
+            * b.uncertainty,
+    )
+}
+
+/// Returns the correlation matrix of the constants named by `ids`.
+///
+/// Returns `None` if any identifier is unknown.
+#[must_use]
+
+pub fn correlation_matrix(
+    ids: &[&str]
+) -> Option<Vec<Vec<f64>>> {
+
+    matrix(ids, correlation)
+}
+
+/// Returns the covariance matrix of the constants named by `ids`.
+///
+/// Returns `None` if any identifier is unknown.
+#[must_use]
+
+pub fn covariance_matrix(
+    ids: &[&str]
+) -> Option<Vec<Vec<f64>>> {
+
+    matrix(ids, covariance)
+}
+
+fn matrix(
+    ids: &[&str],
+    entry: fn(
+        &str,
+        &str,
+    ) -> Option<f64>,
+) -> Option<Vec<Vec<f64>>> {
+
+    ids.iter()
+        .map(|a| {
+
+            ids.iter()
+                .map(|b| entry(a, b))
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,291 @@
+//! Constant values of the earlier CODATA adjustments.
+//!
+//! The bare constants and getters of [`crate::constant`] always refer to
+//! [`CodataEdition::LATEST`]. This module keeps the complete tables of the
+//! earlier adjustments as well, so that results can be reproduced with the
+//! values of a given edition and the changes between two editions can be
+//! inspected.
+//!
+//! Quantities that NIST renamed between adjustments (e.g. the 2014
+//! `"mag. constant"`, now `"vacuum mag. permeability"`) share the same
+//! identifier in every edition.
+
+use serde::Serialize;
+
+use super::CodataEdition;
+use super::PhysicalConstant;
+
+/// Returns every constant of `edition`, in listing order.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::edition;
+///
+/// assert!(
+///     edition::constants(
+///         CodataEdition::Codata2018
+///     )
+///     .iter()
+///     .all(|c| {
+///         c.edition
+///             == CodataEdition::Codata2018
+///     })
+/// );
+/// ```
+#[must_use]
+
+pub const fn constants(
+    edition: CodataEdition
+) -> &'static [PhysicalConstant] {
+
+    match edition {
+        | CodataEdition::Codata2014 => {
+            super::CODATA_2014_CONSTANTS
+        },
+        | CodataEdition::Codata2018 => {
+            super::CODATA_2018_CONSTANTS
+        },
+        | CodataEdition::Codata2022 => {
+            super::CODATA_2022_CONSTANTS
+        },
+    }
+}
+
+/// Looks up a constant of `edition` by identifier or NIST quantity name.
+///
+/// Both comparisons ignore ASCII case. A name used by any other shipped
+/// edition also resolves, so `"mag. constant"` finds the vacuum magnetic
+/// permeability of CODATA 2022.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::edition;
+///
+/// let m_e = edition::get(
+///     CodataEdition::Codata2018,
+///     "electron mass",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     m_e.value,
+///     9.109_383_701_5e-31
+/// );
+/// ```
+#[must_use]
+
+pub fn get(
+    edition: CodataEdition,
+    key: &str,
+) -> Option<&'static PhysicalConstant> {
+
+    let matches =
+        |c: &&PhysicalConstant| {
+
+            c.id.eq_ignore_ascii_case(
+                key,
+            ) || c
+                .name
+                .eq_ignore_ascii_case(
+                    key,
+                )
+        };
+
+    let table = constants(edition);
+
+    table
+        .iter()
+        .find(matches)
+        .or_else(|| {
+
+            let id = CodataEdition::ALL
+                .iter()
+                .find_map(|&other| {
+
+                    constants(other)
+                        .iter()
+                        .find(matches)
+                })?
+                .id;
+
+            table
+                .iter()
+                .find(|c| c.id == id)
+        })
+}
+
+/// The change of one constant between two editions.
+#[derive(
+    Debug, Clone, PartialEq, Serialize,
+)]
+
+pub struct ConstantChange {
+    /// The identifier of the constant.
+    pub id: &'static str,
+    /// The NIST quantity name in the newer edition.
+    pub name: &'static str,
+    /// The unit in the newer edition.
+    pub unit: &'static str,
+    /// The value in the older edition.
+    pub old_value: f64,
+    /// The value in the newer edition.
+    pub new_value: f64,
+    /// The standard uncertainty in the older edition.
+    pub old_uncertainty: f64,
+    /// The standard uncertainty in the newer edition.
+    pub new_uncertainty: f64,
+    /// `new_value - old_value`.
+    pub difference: f64,
+    /// The difference relative to the older value.
+    pub relative_difference: f64,
+    /// The difference in units of the older standard uncertainty, `None`
+    /// if the older value was exact.
+    pub sigma_shift: Option<f64>,
+}
+
+/// The differences between two editions, as returned by [`diff`].
+#[derive(
+    Debug, Clone, PartialEq, Serialize,
+)]
+
+pub struct EditionDiff {
+    /// The older edition.
+    pub from: CodataEdition,
+    /// The newer edition.
+    pub to: CodataEdition,
+    /// Constants whose value or uncertainty changed, in the order of `to`.
+    pub changed: Vec<ConstantChange>,
+    /// Identifiers of constants only present in `to`.
+    pub added: Vec<&'static str>,
+    /// Identifiers of constants only present in `from`.
+    pub removed: Vec<&'static str>,
+}
+
+/// Compares the constants of two editions.
+///
+/// Constants are matched by identifier. A constant counts as changed if
+/// either its value or its standard uncertainty differs.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::edition;
+///
+/// let diff = edition::diff(
+///     CodataEdition::Codata2018,
+///     CodataEdition::Codata2022,
+/// );
+///
+/// assert!(
+///     diff.changed
+///         .iter()
+///         .any(|c| c.id == "ELECTRON_MASS")
+/// );
+///
+/// assert!(
+///     !diff
+///         .changed
+///         .iter()
+///         .any(|c| c.id == "SPEED_OF_LIGHT")
+/// );
+/// ```
+#[must_use]
+
+pub fn diff(
+    from: CodataEdition,
+    to: CodataEdition,
+) -> EditionDiff {
+
+    let old_table = constants(from);
+
+    let new_table = constants(to);
+
+    let find = |table: &'static [PhysicalConstant],
+                id: &str| {
+
+        table
+            .iter()
+            .find(|c| c.id == id)
+    };
+
+    let mut changed = Vec::new();
+
+    let mut added = Vec::new();
+
+    for new in new_table {
+
+        let Some(old) =
+            find(old_table, new.id)
+        else {
+
+            added.push(new.id);
+
+            continue;
+        };
+
+        if old.value.to_bits()
+            == new.value.to_bits()
+            && old
+                .uncertainty
+                .to_bits()
+                == new
+                    .uncertainty
+                    .to_bits()
+        {
+
+            continue;
+        }
+
+        let difference =
+            new.value - old.value;
+
+        changed.push(ConstantChange {
+            id: new.id,
+            name: new.name,
+            unit: new.unit,
+            old_value: old.value,
+            new_value: new.value,
+            old_uncertainty: old
+                .uncertainty,
+            new_uncertainty: new
+                .uncertainty,
+            difference,
+            relative_difference:
+                difference
+                    / old.value.abs(),
+            sigma_shift: (!old.exact)
+                .then(|| {
+
+                    difference
+                        / old
+                            .uncertainty
+                }),
+        });
+    }
+
+    let removed = old_table
+        .iter()
+        .filter(|old| {
+
+            find(new_table, old.id)
+                .is_none()
+        })
+        .map(|old| old.id)
+        .collect();
+
+    EditionDiff {
+        from,
+        to,
+        changed,
+        added,
+        removed,
+    }
+}
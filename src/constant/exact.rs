@@ -0,0 +1,771 @@
+//! Exact values of the constants fixed by the 2019 SI, as rationals.
+//!
+//! Since the redefinition of the SI, `Δν_Cs`, `c`, `h`, `e`, `k`, `N_A` and
+//! `K_cd` have exact decimal values. The `f64` fields of the constant
+//! table round them, and the table lists derived values such as `K_J`,
+//! `R_K` or `ħ` truncated to ten digits. [`value`] returns every exact
+//! constant of the table as an [`ExactValue`]: a rational coefficient
+//! (a [`BigRational`], as used by rssn's `Expr::Rational`) times an
+//! integer power of π, which covers `ħ = h/2π`, `c₁ = 2πhc²` and
+//! `σ = 2π⁵k⁴/15h³c²`.
+//!
+//! [`ExactValue::to_decimal`] evaluates a value to any number of
+//! significant digits, with π computed by Machin's formula. The Wien
+//! displacement constants are exact too, but depend on the root of a
+//! transcendental equation and are not provided.
+//!
+//! # Examples
+//!
+//! ```
+//! 
+//! use rssn_advanced::constant::exact;
+//!
+//! let hbar = exact::value(
+//!     "REDUCED_PLANCK_CONSTANT",
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     hbar.to_decimal(30),
+//!     "1.05457181764615639126242800330e-34"
+//! );
+//!
+//! // The Josephson constant is rational: 2e/h.
+//! let k_j = exact::value(
+//!     "JOSEPHSON_CONSTANT",
+//! )
+//! .unwrap();
+//!
+//! assert!(k_j.is_rational());
+//! ```
+
+use std::fmt;
+use std::ops::Div;
+use std::ops::Mul;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::One;
+use num_traits::Pow;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::PhysicalConstant;
+use crate::unit::notation;
+
+/// The identifiers of the seven defining constants of the SI.
+
+pub const DEFINING: [&str; 7] = [
+    "HYPERFINE_TRANSITION_FREQUENCY_OF_CS_133",
+    "SPEED_OF_LIGHT",
+    "PLANCK_CONSTANT",
+    "ELEMENTARY_CHARGE",
+    "BOLTZMANN_CONSTANT",
+    "AVOGADRO_CONSTANT",
+    "LUMINOUS_EFFICACY",
+];
+
+/// An exact value `coefficient × π^pi_power`.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct ExactValue {
+    /// The rational coefficient.
+    pub coefficient: BigRational,
+    /// The power of π.
+    pub pi_power: i32,
+}
+
+impl ExactValue {
+    /// Creates a rational value.
+    #[must_use]
+
+    pub const fn rational(
+        coefficient: BigRational
+    ) -> Self {
+
+        Self {
+            coefficient,
+            pi_power: 0,
+        }
+    }
+
+    /// Creates the value `mantissa × 10^exponent`.
+    #[must_use]
+
+    pub fn decimal(
+        mantissa: i64,
+        exponent: i32,
+    ) -> Self {
+
+        Self::rational(
+            BigRational::from_integer(
+                BigInt::from(mantissa),
+            ) * power_of_ten(exponent),
+        )
+    }
+
+    /// Returns π.
+    #[must_use]
+
+    pub fn pi() -> Self {
+
+        Self {
+            coefficient:
+                BigRational::one(),
+            pi_power: 1,
+        }
+    }
+
+    /// Returns whether the value is rational.
+    #[must_use]
+
+    pub fn is_rational(&self) -> bool {
+
+        self.pi_power == 0
+            || self
+                .coefficient
+                .is_zero()
+    }
+
+    /// Raises the value to an integer power.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value is zero and `n` is negative.
+    #[must_use]
+
+    pub fn powi(
+        &self,
+        n: i32,
+    ) -> Self {
+
+        Self {
+            coefficient: Pow::pow(
+                &self.coefficient,
+                n,
+            ),
+            pi_power: self
+                .pi_power
+                .saturating_mul(n),
+        }
+    }
+
+    /// Returns a rational approximation whose relative error is below
+    /// `10^-digits`. Rational values are returned exactly.
+    #[must_use]
+
+    pub fn approximate(
+        &self,
+        digits: u32,
+    ) -> BigRational {
+
+        if self.is_rational() {
+
+            return self
+                .coefficient
+                .clone();
+        }
+
+        // The relative error of π^n is about |n| times that of π.
+        let guard = self
+            .pi_power
+            .unsigned_abs()
+            .checked_ilog10()
+            .unwrap_or(0)
+            .saturating_add(2);
+
+        let pi = pi(digits
+            .saturating_add(guard));
+
+        &self.coefficient
+            * Pow::pow(
+                &pi,
+                self.pi_power,
+            )
+    }
+
+    /// Formats the value in scientific notation with `digits` significant
+    /// digits, rounded to nearest, e.g. `"6.62607015e-34"` for `h` with
+    /// nine digits.
+    #[must_use]
+
+    pub fn to_decimal(
+        &self,
+        digits: u32,
+    ) -> String {
+
+        let digits = digits.max(1);
+
+        let value = self.approximate(
+            digits.saturating_add(5),
+        );
+
+        if value.is_zero() {
+
+            return "0".to_string();
+        }
+
+        let sign =
+            if value.is_negative() {
+
+                "-"
+            } else {
+
+                ""
+            };
+
+        let magnitude = value.abs();
+
+        let mut exponent =
+            decimal_exponent(
+                &magnitude,
+            );
+
+        let round = |exponent: i32| {
+
+            (&magnitude
+                * power_of_ten(
+                    i32::try_from(
+                        digits,
+                    )
+                    .unwrap_or(i32::MAX)
+                    .saturating_sub(1)
+                    .saturating_sub(
+                        exponent,
+                    ),
+                ))
+            .round()
+            .to_integer()
+            .to_string()
+        };
+
+        let mut mantissa =
+            round(exponent);
+
+        // Rounding up may carry into a new digit, as in 9.99... → 10.0.
+        if mantissa.len()
+            > digits as usize
+        {
+
+            exponent = exponent
+                .saturating_add(1);
+
+            mantissa = round(exponent);
+        }
+
+        let (first, rest) = mantissa
+            .split_at_checked(1)
+            .unwrap_or((&mantissa, ""));
+
+        if rest.is_empty() {
+
+            format!(
+                "{sign}{first}e{exponent}"
+            )
+        } else {
+
+            format!(
+                "{sign}{first}.\
+                 {rest}e{exponent}"
+            )
+        }
+    }
+
+    /// Returns the nearest `f64`.
+    #[must_use]
+
+    pub fn to_f64(&self) -> f64 {
+
+        self.approximate(20)
+            .to_f64()
+            .unwrap_or(f64::NAN)
+    }
+}
+
+impl From<i64> for ExactValue {
+    fn from(value: i64) -> Self {
+
+        Self::decimal(value, 0)
+    }
+}
+
+impl Mul for ExactValue {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self {
+            coefficient: self
+                .coefficient
+                * rhs.coefficient,
+            pi_power: self
+                .pi_power
+                .saturating_add(
+                    rhs.pi_power,
+                ),
+        }
+    }
+}
+
+impl Div for ExactValue {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+
+    fn div(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self {
+            coefficient: self
+                .coefficient
+                / rhs.coefficient,
+            pi_power: self
+                .pi_power
+                .saturating_sub(
+                    rhs.pi_power,
+                ),
+        }
+    }
+}
+
+impl fmt::Display for ExactValue {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        write!(
+            f,
+            "{}",
+            self.coefficient
+        )?;
+
+        if self.pi_power != 0 {
+
+            write!(
+                f,
+                " π{}",
+                notation::superscript(
+                    self.pi_power
+                )
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `10^exponent`.
+
+fn power_of_ten(
+    exponent: i32
+) -> BigRational {
+
+    Pow::pow(
+        BigRational::from_integer(
+            BigInt::from(10),
+        ),
+        exponent,
+    )
+}
+
+/// Returns the exponent `n` with `10^n ≤ magnitude < 10^(n+1)`, for a
+/// positive `magnitude`.
+
+fn decimal_exponent(
+    magnitude: &BigRational
+) -> i32 {
+
+    let length = |n: &BigInt| {
+
+        i32::try_from(
+            n.to_string().len(),
+        )
+        .unwrap_or(i32::MAX)
+    };
+
+    // Within one of the answer.
+    let mut exponent =
+        length(magnitude.numer())
+            .saturating_sub(length(
+                magnitude.denom(),
+            ));
+
+    while power_of_ten(exponent)
+        > *magnitude
+    {
+
+        exponent =
+            exponent.saturating_sub(1);
+    }
+
+    while power_of_ten(
+        exponent.saturating_add(1),
+    ) <= *magnitude
+    {
+
+        exponent =
+            exponent.saturating_add(1);
+    }
+
+    exponent
+}
+
+/// Returns `arctan(1/x) × scale`, truncated, for an integer `x > 1`.
+
+fn arctan_inverse(
+    x: u32,
+    scale: &BigInt,
+) -> BigInt {
+
+    let x_squared = BigInt::from(x) * x;
+
+    let mut power = scale / x;
+
+    let mut sum = BigInt::zero();
+
+    let mut k: u32 = 0;
+
+    while !power.is_zero() {
+
+        let term = &power
+            / BigInt::from(
+                k.saturating_mul(2)
+                    .saturating_add(1),
+            );
+
+        if k.is_multiple_of(2) {
+
+            sum += term;
+        } else {
+
+            sum -= term;
+        }
+
+        power /= &x_squared;
+
+        k = k.saturating_add(1);
+    }
+
+    sum
+}
+
+/// Returns a rational approximation of π with an absolute error below
+/// `10^-digits`, from Machin's formula
+/// `π = 16 arctan(1/5) − 4 arctan(1/239)`.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use num_traits::ToPrimitive;
+/// use rssn_advanced::constant::exact;
+///
+/// assert_eq!(
+///     exact::pi(30).to_f64(),
+///     Some(std::f64::consts::PI)
+/// );
+/// ```
+#[must_use]
+
+pub fn pi(digits: u32) -> BigRational {
+
+    // Guard digits absorb the truncation of the series terms.
+    let scale = Pow::pow(
+        BigInt::from(10),
+        digits.saturating_add(10),
+    );
+
+    let numerator =
+        arctan_inverse(5, &scale) * 16
+            - arctan_inverse(
+                239,
+                &scale,
+            ) * 4;
+
+    BigRational::new(numerator, scale)
+}
+
+/// The hyperfine transition frequency of caesium 133, `Δν_Cs`.
+
+fn delta_nu_cs() -> ExactValue {
+
+    ExactValue::decimal(
+        9_192_631_770,
+        0,
+    )
+}
+
+/// The speed of light in vacuum, `c`.
+
+fn c() -> ExactValue {
+
+    ExactValue::decimal(299_792_458, 0)
+}
+
+/// The Planck constant, `h`.
+
+fn h() -> ExactValue {
+
+    ExactValue::decimal(
+        662_607_015,
+        -42,
+    )
+}
+
+/// The elementary charge, `e`.
+
+fn e() -> ExactValue {
+
+    ExactValue::decimal(
+        1_602_176_634,
+        -28,
+    )
+}
+
+/// The Boltzmann constant, `k`.
+
+fn k() -> ExactValue {
+
+    ExactValue::decimal(1_380_649, -29)
+}
+
+/// The Avogadro constant, `N_A`.
+
+fn n_a() -> ExactValue {
+
+    ExactValue::decimal(602_214_076, 15)
+}
+
+/// The reduced Planck constant, `ħ = h/2π`.
+
+fn hbar() -> ExactValue {
+
+    h() / (ExactValue::from(2)
+        * ExactValue::pi())
+}
+
+/// The conventional Josephson constant `K_J-90`.
+
+fn k_j_90() -> ExactValue {
+
+    ExactValue::decimal(4_835_979, 8)
+}
+
+/// The conventional von Klitzing constant `R_K-90`.
+
+fn r_k_90() -> ExactValue {
+
+    ExactValue::decimal(25_812_807, -3)
+}
+
+/// The temperature `273.15 K` of the standard conditions.
+
+fn t_0() -> ExactValue {
+
+    ExactValue::decimal(27_315, -2)
+}
+
+/// Declares the table of exact values, as `ID => expression;` entries.
+
+macro_rules! exact_values {
+    (
+        $(
+            $id:ident => $value:expr;
+        )*
+    ) => {
+        /// The identifiers of every constant returned by [`value`].
+
+        pub static IDS: &[&str] = &[
+            $(stringify!($id)),*
+        ];
+
+        /// Evaluates the entry of the table named `id`.
+
+        fn evaluate(
+            id: &str
+        ) -> Option<ExactValue> {
+
+            $(
+                if id.eq_ignore_ascii_case(
+                    stringify!($id),
+                ) {
+
+                    return Some($value);
+                }
+            )*
+
+            None
+        }
+    };
+}
+
+exact_values! {
+    // The defining constants.
+    HYPERFINE_TRANSITION_FREQUENCY_OF_CS_133 => delta_nu_cs();
+    SPEED_OF_LIGHT => c();
+    PLANCK_CONSTANT => h();
+    ELEMENTARY_CHARGE => e();
+    BOLTZMANN_CONSTANT => k();
+    AVOGADRO_CONSTANT => n_a();
+    LUMINOUS_EFFICACY => ExactValue::from(683);
+    // Other names for the defining constants.
+    NATURAL_UNIT_OF_VELOCITY => c();
+    ATOMIC_UNIT_OF_CHARGE => e();
+    ELECTRON_VOLT => e();
+    // Derived constants.
+    REDUCED_PLANCK_CONSTANT => hbar();
+    ATOMIC_UNIT_OF_ACTION => hbar();
+    NATURAL_UNIT_OF_ACTION => hbar();
+    REDUCED_PLANCK_CONSTANT_IN_EV_S => hbar() / e();
+    NATURAL_UNIT_OF_ACTION_IN_EV_S => hbar() / e();
+    REDUCED_PLANCK_CONSTANT_TIMES_C_IN_MEV_FM =>
+        hbar() * c() / e() * ExactValue::decimal(1, 9);
+    PLANCK_CONSTANT_IN_EV_PER_HZ => h() / e();
+    ELEMENTARY_CHARGE_OVER_HBAR => e() / hbar();
+    JOSEPHSON_CONSTANT => ExactValue::from(2) * e() / h();
+    VON_KLITZING_CONSTANT => h() / e().powi(2);
+    MAGNETIC_FLUX_QUANTUM => h() / (ExactValue::from(2) * e());
+    CONDUCTANCE_QUANTUM => ExactValue::from(2) * e().powi(2) / h();
+    INVERSE_OF_CONDUCTANCE_QUANTUM => h() / (ExactValue::from(2) * e().powi(2));
+    FARADAY_CONSTANT => n_a() * e();
+    MOLAR_GAS_CONSTANT => n_a() * k();
+    MOLAR_PLANCK_CONSTANT => n_a() * h();
+    BOLTZMANN_CONSTANT_IN_EV_PER_K => k() / e();
+    BOLTZMANN_CONSTANT_IN_HZ_PER_K => k() / h();
+    BOLTZMANN_CONSTANT_IN_INVERSE_METER_PER_KELVIN => k() / (h() * c());
+    FIRST_RADIATION_CONSTANT =>
+        ExactValue::from(2) * ExactValue::pi() * h() * c().powi(2);
+    FIRST_RADIATION_CONSTANT_FOR_SPECTRAL_RADIANCE =>
+        ExactValue::from(2) * h() * c().powi(2);
+    SECOND_RADIATION_CONSTANT => h() * c() / k();
+    STEFAN_BOLTZMANN_CONSTANT =>
+        ExactValue::from(2) * ExactValue::pi().powi(5) * k().powi(4)
+            / (ExactValue::from(15) * h().powi(3) * c().powi(2));
+    // Energy equivalences.
+    ELECTRON_VOLT_HERTZ_RELATIONSHIP => e() / h();
+    ELECTRON_VOLT_INVERSE_METER_RELATIONSHIP => e() / (h() * c());
+    ELECTRON_VOLT_JOULE_RELATIONSHIP => e();
+    ELECTRON_VOLT_KELVIN_RELATIONSHIP => e() / k();
+    ELECTRON_VOLT_KILOGRAM_RELATIONSHIP => e() / c().powi(2);
+    HERTZ_ELECTRON_VOLT_RELATIONSHIP => h() / e();
+    HERTZ_INVERSE_METER_RELATIONSHIP => c().powi(-1);
+    HERTZ_JOULE_RELATIONSHIP => h();
+    HERTZ_KELVIN_RELATIONSHIP => h() / k();
+    HERTZ_KILOGRAM_RELATIONSHIP => h() / c().powi(2);
+    INVERSE_METER_ELECTRON_VOLT_RELATIONSHIP => h() * c() / e();
+    INVERSE_METER_HERTZ_RELATIONSHIP => c();
+    INVERSE_METER_JOULE_RELATIONSHIP => h() * c();
+    INVERSE_METER_KELVIN_RELATIONSHIP => h() * c() / k();
+    INVERSE_METER_KILOGRAM_RELATIONSHIP => h() / c();
+    JOULE_ELECTRON_VOLT_RELATIONSHIP => e().powi(-1);
+    JOULE_HERTZ_RELATIONSHIP => h().powi(-1);
+    JOULE_INVERSE_METER_RELATIONSHIP => (h() * c()).powi(-1);
+    JOULE_KELVIN_RELATIONSHIP => k().powi(-1);
+    JOULE_KILOGRAM_RELATIONSHIP => c().powi(-2);
+    KELVIN_ELECTRON_VOLT_RELATIONSHIP => k() / e();
+    KELVIN_HERTZ_RELATIONSHIP => k() / h();
+    KELVIN_INVERSE_METER_RELATIONSHIP => k() / (h() * c());
+    KELVIN_JOULE_RELATIONSHIP => k();
+    KELVIN_KILOGRAM_RELATIONSHIP => k() / c().powi(2);
+    KILOGRAM_ELECTRON_VOLT_RELATIONSHIP => c().powi(2) / e();
+    KILOGRAM_HERTZ_RELATIONSHIP => c().powi(2) / h();
+    KILOGRAM_INVERSE_METER_RELATIONSHIP => c() / h();
+    KILOGRAM_JOULE_RELATIONSHIP => c().powi(2);
+    KILOGRAM_KELVIN_RELATIONSHIP => c().powi(2) / k();
+    // Conventional electrical units of 1990.
+    CONVENTIONAL_VALUE_OF_JOSEPHSON_CONSTANT => k_j_90();
+    CONVENTIONAL_VALUE_OF_VON_KLITZING_CONSTANT => r_k_90();
+    CONVENTIONAL_VALUE_OF_VOLT_90 =>
+        k_j_90() * h() / (ExactValue::from(2) * e());
+    CONVENTIONAL_VALUE_OF_OHM_90 => h() / (e().powi(2) * r_k_90());
+    CONVENTIONAL_VALUE_OF_AMPERE_90 =>
+        k_j_90() * r_k_90() * e() / ExactValue::from(2);
+    CONVENTIONAL_VALUE_OF_COULOMB_90 =>
+        k_j_90() * r_k_90() * e() / ExactValue::from(2);
+    CONVENTIONAL_VALUE_OF_WATT_90 =>
+        k_j_90().powi(2) * r_k_90() * h() / ExactValue::from(4);
+    CONVENTIONAL_VALUE_OF_FARAD_90 => r_k_90() * e().powi(2) / h();
+    CONVENTIONAL_VALUE_OF_HENRY_90 => h() / (e().powi(2) * r_k_90());
+    // Conventional standard conditions.
+    STANDARD_ACCELERATION_OF_GRAVITY => ExactValue::decimal(980_665, -5);
+    STANDARD_ATMOSPHERE => ExactValue::from(101_325);
+    STANDARD_STATE_PRESSURE => ExactValue::from(100_000);
+    LOSCHMIDT_CONSTANT_273_15_K_100_KPA =>
+        ExactValue::from(100_000) / (k() * t_0());
+    LOSCHMIDT_CONSTANT_273_15_K_101_325_KPA =>
+        ExactValue::from(101_325) / (k() * t_0());
+    MOLAR_VOLUME_OF_IDEAL_GAS_273_15_K_100_KPA =>
+        n_a() * k() * t_0() / ExactValue::from(100_000);
+    MOLAR_VOLUME_IDEAL_GAS =>
+        n_a() * k() * t_0() / ExactValue::from(101_325);
+}
+
+/// Returns the exact value of the constant with identifier `id`, or
+/// `None` if it is not exact or not in [`IDS`].
+///
+/// The values are those of the 2019 SI, which hold for the CODATA 2018
+/// and later adjustments.
+#[must_use]
+
+pub fn value(
+    id: &str
+) -> Option<ExactValue> {
+
+    evaluate(id)
+}
+
+impl PhysicalConstant {
+    /// Returns the exact value of the constant, or `None` if it is
+    /// measured or its exact value is not provided by [`value`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::constant;
+    ///
+    /// let k_j = constant::lookup(
+    ///     "JOSEPHSON_CONSTANT",
+    /// )
+    /// .unwrap()
+    /// .exact_value()
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     k_j.to_decimal(20),
+    ///     "4.8359784841698363245e14"
+    /// );
+    /// ```
+    #[must_use]
+
+    pub fn exact_value(
+        &self
+    ) -> Option<ExactValue> {
+
+        if self.exact {
+
+            value(self.id)
+        } else {
+
+            None
+        }
+    }
+}
@@ -0,0 +1,668 @@
+//! Identification of numbers as constants or combinations of constants.
+//!
+//! [`identify`] takes a floating-point number, such as a value found in
+//! simulation output, and searches for expressions of the form
+//!
+//! ```text
+//! ±(p/q) π^k a^m b^n
+//! ```
+//!
+//! matching it to within a relative tolerance, where `p/q` is a small
+//! rational, `|k| ≤ 2`, and `a` and `b` are constants raised to the powers
+//! `±1/2`, `±1`, `±2` or `±3`. A single factor `a` ranges over every
+//! registered constant; products of two range over a basis of the most
+//! common ones (`c`, `h`, `ħ`, `e`, `k`, `N_A`, `G`, `α`, `m_e`, ...).
+//! Expressions without constants, such as `2π`, are found as well.
+//!
+//! Matches are returned as rssn expressions in the form of
+//! [`symbolic::simplify`](super::symbolic::simplify), simplest first.
+//!
+//! # Examples
+//!
+//! ```
+//! 
+//! use rssn::symbolic::core::Expr;
+//! use rssn_advanced::constant::identify;
+//!
+//! let candidates =
+//!     identify::identify(137.036, 1e-5);
+//!
+//! assert_eq!(
+//!     candidates[0].expr,
+//!     Expr::new_variable(
+//!         "INVERSE_FINE_STRUCTURE_CONSTANT"
+//!     )
+//! );
+//!
+//! assert!(candidates[0].relative_error < 1e-5);
+//! ```
+
+use std::f64::consts::PI;
+
+use num_rational::BigRational;
+use rssn::symbolic::core::Expr;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::PhysicalConstant;
+use super::all;
+use super::lookup;
+use super::symbolic::monomial;
+
+/// The constants combined in products of two factors.
+
+const BASIS: &[&str] = &[
+    "SPEED_OF_LIGHT",
+    "PLANCK_CONSTANT",
+    "REDUCED_PLANCK_CONSTANT",
+    "ELEMENTARY_CHARGE",
+    "BOLTZMANN_CONSTANT",
+    "AVOGADRO_CONSTANT",
+    "MOLAR_GAS_CONSTANT",
+    "GRAVITATIONAL_CONSTANT",
+    "STANDARD_ACCELERATION_OF_GRAVITY",
+    "FINE_STRUCTURE_CONSTANT",
+    "ELECTRON_MASS",
+    "PROTON_MASS_KG",
+    "NEUTRON_MASS",
+    "ATOMIC_MASS_CONSTANT",
+    "VACUUM_ELECTRIC_PERMITTIVITY",
+    "VACUUM_MAGNETIC_PERMEABILITY",
+    "BOHR_RADIUS",
+    "BOHR_MAGNETON",
+    "RYDBERG_CONSTANT",
+    "HARTREE_ENERGY",
+    "STEFAN_BOLTZMANN_CONSTANT",
+];
+
+/// The exponents of the factors, as numerator and denominator.
+
+const EXPONENTS: &[(i32, i32)] = &[
+    (1, 1),
+    (-1, 1),
+    (2, 1),
+    (-2, 1),
+    (3, 1),
+    (-3, 1),
+    (1, 2),
+    (-1, 2),
+];
+
+/// The largest numerator and denominator of the rational coefficient.
+
+const MAX_RATIONAL: i32 = 4;
+
+/// The largest power of π, in absolute value.
+
+const MAX_PI_POWER: i32 = 2;
+
+/// The largest accepted relative tolerance.
+
+const MAX_TOLERANCE: f64 = 1e-2;
+
+/// Options for [`identify_with`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct IdentifyOptions {
+    /// The largest relative error of a match, at most `1e-2`.
+    pub tolerance: f64,
+    /// The largest number of constants in a match, from 0 to 2.
+    pub max_factors: usize,
+    /// The largest number of matches returned.
+    pub limit: usize,
+}
+
+impl Default for IdentifyOptions {
+    fn default() -> Self {
+
+        Self {
+            tolerance: 1e-6,
+            max_factors: 2,
+            limit: 20,
+        }
+    }
+}
+
+/// An expression matching the number given to [`identify`].
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Candidate {
+    /// The expression, with the constants as in
+    /// [`symbolic`](super::symbolic).
+    pub expr: Expr,
+    /// The value of the expression with the CODATA 2022 values.
+    pub value: f64,
+    /// The relative error `|value - x| / |x|`.
+    pub relative_error: f64,
+    /// The complexity of the expression, which grows with the number of
+    /// constants, the size of the exponents and of the coefficient, and
+    /// the power of π. Constants without a conventional symbol, such as
+    /// the atomic units and the conversion relationships, count extra.
+    pub complexity: u32,
+}
+
+/// A constant raised to one of the [`EXPONENTS`].
+
+struct Factor {
+    constant: &'static PhysicalConstant,
+    exponent: (i32, i32),
+    /// The logarithm of the absolute value.
+    log: f64,
+    /// The complexity added to an expression.
+    cost: u32,
+}
+
+impl Factor {
+    /// Returns `constant^exponent`, or `None` for a root of a value that
+    /// is not positive.
+
+    fn new(
+        constant: &'static PhysicalConstant,
+        exponent: (i32, i32),
+    ) -> Option<Self> {
+
+        let (numerator, denominator) =
+            exponent;
+
+        if constant.value == 0.0
+            || (denominator > 1
+                && constant.value < 0.0)
+        {
+
+            return None;
+        }
+
+        let cost = numerator
+            .unsigned_abs()
+            .saturating_add(
+                denominator
+                    .unsigned_abs()
+                    .saturating_sub(1)
+                    .saturating_mul(2),
+            )
+            .saturating_add(u32::from(
+                numerator < 0,
+            ))
+            .saturating_add(u32::from(
+                constant
+                    .symbol
+                    .is_empty(),
+            ));
+
+        Some(Self {
+            constant,
+            exponent,
+            log: constant
+                .value
+                .abs()
+                .ln()
+                * f64::from(numerator)
+                / f64::from(
+                    denominator,
+                ),
+            cost,
+        })
+    }
+
+    /// Returns the value.
+
+    fn value(&self) -> f64 {
+
+        let (numerator, denominator) =
+            self.exponent;
+
+        if denominator == 1 {
+
+            self.constant
+                .value
+                .powi(numerator)
+        } else {
+
+            self.constant
+                .value
+                .sqrt()
+                .powi(numerator)
+        }
+    }
+
+    /// Returns the base and integer exponent of the factor as a term of a
+    /// power product, taking the square root for a half-integer exponent.
+
+    fn term(&self) -> (Expr, i32) {
+
+        let (numerator, denominator) =
+            self.exponent;
+
+        let base = self
+            .constant
+            .to_expr();
+
+        if denominator == 1 {
+
+            (base, numerator)
+        } else {
+
+            (
+                Expr::new_sqrt(base),
+                numerator,
+            )
+        }
+    }
+}
+
+/// A coefficient `(p/q) π^k`.
+
+struct Prefactor {
+    numerator: i32,
+    denominator: i32,
+    pi_power: i32,
+    value: f64,
+    /// The logarithm of the value.
+    log: f64,
+    /// The complexity added to an expression.
+    cost: u32,
+}
+
+/// Returns the coefficients in lowest terms.
+
+fn prefactors() -> Vec<Prefactor> {
+
+    let mut prefactors = Vec::new();
+
+    for numerator in 1 ..= MAX_RATIONAL
+    {
+
+        for denominator in
+            1 ..= MAX_RATIONAL
+        {
+
+            if gcd(
+                numerator,
+                denominator,
+            ) != 1
+            {
+
+                continue;
+            }
+
+            for pi_power in
+                -MAX_PI_POWER
+                    ..= MAX_PI_POWER
+            {
+
+                let value = f64::from(
+                    numerator,
+                )
+                    / f64::from(
+                        denominator,
+                    )
+                    * PI.powi(pi_power);
+
+                prefactors.push(
+                    Prefactor {
+                        numerator,
+                        denominator,
+                        pi_power,
+                        value,
+                        log: value.ln(),
+                        cost: numerator
+                            .saturating_add(
+                                denominator,
+                            )
+                            .saturating_sub(2)
+                            .unsigned_abs()
+                            .saturating_add(
+                                pi_power
+                                    .unsigned_abs()
+                                    .saturating_mul(
+                                        2,
+                                    ),
+                            ),
+                    },
+                );
+            }
+        }
+    }
+
+    prefactors
+}
+
+/// Returns the greatest common divisor of two positive integers.
+
+const fn gcd(
+    mut a: i32,
+    mut b: i32,
+) -> i32 {
+
+    while let Some(r) = a.checked_rem(b)
+    {
+
+        a = b;
+
+        b = r;
+    }
+
+    a
+}
+
+/// Returns every constant raised to every exponent.
+
+fn factors(
+    constants: impl IntoIterator<
+        Item = &'static PhysicalConstant,
+    >
+) -> Vec<Factor> {
+
+    constants
+        .into_iter()
+        .flat_map(|constant| {
+
+            EXPONENTS
+                .iter()
+                .filter_map(
+                    move |&exponent| {
+
+                        Factor::new(
+                            constant,
+                            exponent,
+                        )
+                    },
+                )
+        })
+        .collect()
+}
+
+/// Identifies `x` as a constant or a combination of constants, returning
+/// the candidates within the relative `tolerance`, simplest first.
+///
+/// See [`identify_with`].
+#[must_use]
+
+pub fn identify(
+    x: f64,
+    tolerance: f64,
+) -> Vec<Candidate> {
+
+    identify_with(
+        x,
+        &IdentifyOptions {
+            tolerance,
+            ..IdentifyOptions::default()
+        },
+    )
+}
+
+/// Identifies `x` as a constant or a combination of constants.
+///
+/// Returns at most `options.limit` candidates whose relative error is at
+/// most `options.tolerance`, ordered by increasing complexity and, for
+/// equal complexity, by increasing relative error and then in favour of
+/// the most common constants. The result is empty if
+/// `x` is zero, subnormal or not finite.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::symbolic::core::Expr;
+/// use rssn_advanced::constant::identify;
+/// use rssn_advanced::constant::identify::IdentifyOptions;
+///
+/// // 2π, with no constants allowed.
+/// let candidates =
+///     identify::identify_with(
+///         6.283_185,
+///         &IdentifyOptions {
+///             max_factors: 0,
+///             ..IdentifyOptions::default()
+///         },
+///     );
+///
+/// assert_eq!(
+///     candidates[0].expr,
+///     Expr::new_mul(
+///         Expr::BigInt(2.into()),
+///         Expr::Pi
+///     )
+/// );
+/// ```
+#[must_use]
+
+pub fn identify_with(
+    x: f64,
+    options: &IdentifyOptions,
+) -> Vec<Candidate> {
+
+    if !x.is_normal() {
+
+        return Vec::new();
+    }
+
+    let tolerance = options
+        .tolerance
+        .clamp(0.0, MAX_TOLERANCE);
+
+    // Relative errors up to the tolerance lie within this distance of the
+    // logarithm of |x|.
+    let window = -(-tolerance).ln_1p();
+
+    let target = x.abs().ln();
+
+    let singles =
+        if options.max_factors >= 1 {
+
+            factors(all())
+        } else {
+
+            Vec::new()
+        };
+
+    let basis =
+        if options.max_factors >= 2 {
+
+            factors(
+                BASIS
+                    .iter()
+                    .filter_map(|id| {
+
+                        lookup(id)
+                    }),
+            )
+        } else {
+
+            Vec::new()
+        };
+
+    let mut candidates = Vec::new();
+
+    for prefactor in prefactors() {
+
+        let rest =
+            target - prefactor.log;
+
+        let mut consider =
+            |factors: &[&Factor]| {
+
+                if let Some(candidate) =
+                    candidate(
+                        x,
+                        tolerance,
+                        &prefactor,
+                        factors,
+                    )
+                {
+
+                    // Ties are broken in favour of the basis constants.
+                    let uncommon = factors
+                        .iter()
+                        .filter(|f| {
+
+                            !BASIS.contains(
+                                &f.constant.id,
+                            )
+                        })
+                        .count();
+
+                    candidates.push((
+                        uncommon,
+                        candidate,
+                    ));
+                }
+            };
+
+        if rest.abs() <= window {
+
+            consider(&[]);
+        }
+
+        for a in &singles {
+
+            if (rest - a.log).abs()
+                <= window
+            {
+
+                consider(&[a]);
+            }
+        }
+
+        for (i, a) in basis
+            .iter()
+            .enumerate()
+        {
+
+            for b in basis.iter().skip(
+                i.saturating_add(1),
+            ) {
+
+                if a.constant.id
+                    != b.constant.id
+                    && (rest
+                        - a.log
+                        - b.log)
+                        .abs()
+                        <= window
+                {
+
+                    consider(&[a, b]);
+                }
+            }
+        }
+    }
+
+    candidates.sort_by(
+        |(m, a), (n, b)| {
+
+            a.complexity
+                .cmp(&b.complexity)
+                .then_with(|| {
+
+                    a.relative_error
+                        .total_cmp(
+                            &b.relative_error,
+                        )
+                })
+                .then_with(|| m.cmp(n))
+                .then_with(|| {
+
+                    a.expr.cmp(&b.expr)
+                })
+        },
+    );
+
+    candidates
+        .into_iter()
+        .take(options.limit)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// Returns the candidate `±prefactor ∏ factors` for `x`, or `None` if its
+/// relative error exceeds `tolerance`.
+
+fn candidate(
+    x: f64,
+    tolerance: f64,
+    prefactor: &Prefactor,
+    factors: &[&Factor],
+) -> Option<Candidate> {
+
+    let magnitude =
+        factors.iter().fold(
+            prefactor.value,
+            |v, f| v * f.value(),
+        );
+
+    // The sign of the coefficient makes that of the value agree with `x`.
+    let negate =
+        (magnitude < 0.0) != (x < 0.0);
+
+    let value = if negate {
+
+        -magnitude
+    } else {
+
+        magnitude
+    };
+
+    let relative_error =
+        ((value - x) / x).abs();
+
+    if relative_error > tolerance {
+
+        return None;
+    }
+
+    let mut coefficient =
+        BigRational::new(
+            prefactor
+                .numerator
+                .into(),
+            prefactor
+                .denominator
+                .into(),
+        );
+
+    if negate {
+
+        coefficient = -coefficient;
+    }
+
+    Some(Candidate {
+        expr: monomial(
+            coefficient,
+            prefactor.pi_power,
+            factors
+                .iter()
+                .map(|f| f.term()),
+        ),
+        value,
+        relative_error,
+        complexity: factors
+            .iter()
+            .fold(
+                prefactor.cost,
+                |cost, f| {
+
+                    cost.saturating_add(
+                        f.cost,
+                    )
+                },
+            )
+            .saturating_add(u32::from(
+                negate,
+            )),
+    })
+}
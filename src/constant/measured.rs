@@ -0,0 +1,695 @@
+//! Values with a standard uncertainty and first-order error propagation.
+//!
+//! A [`Measured`] carries a value together with its standard uncertainty.
+//! Arithmetic and the elementary functions propagate the uncertainty to
+//! first order, i.e. `σ_f = |∂f/∂x| σ_x`, with the contributions of the two
+//! operands of a binary operation added in quadrature.
+//!
+//! The operands are treated as uncorrelated. This is exact for independent
+//! measurements but overestimates or underestimates the uncertainty of
+//! expressions that use the same quantity twice (`x - x` has an uncertainty
+//! of `√2 σ_x`, not zero).
+
+use std::fmt;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A value with its standard uncertainty.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant;
+///
+/// let m_e = constant::get_electron_mass_measured();
+///
+/// let c = constant::get_speed_of_light_measured();
+///
+/// // The rest energy inherits the relative uncertainty of the mass,
+/// // since c is exact.
+/// let energy = m_e * c.powi(2);
+///
+/// assert!(
+///     (energy.relative_uncertainty()
+///         - m_e.relative_uncertainty())
+///     .abs()
+///         < 1e-20
+/// );
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Measured {
+    /// The value.
+    pub value: f64,
+    /// The standard uncertainty, zero for exact values.
+    pub uncertainty: f64,
+}
+
+impl Measured {
+    /// Creates a measured value. The sign of `uncertainty` is ignored.
+    #[must_use]
+
+    pub const fn new(
+        value: f64,
+        uncertainty: f64,
+    ) -> Self {
+
+        Self {
+            value,
+            uncertainty: uncertainty
+                .abs(),
+        }
+    }
+
+    /// Creates a value without uncertainty.
+    #[must_use]
+
+    pub const fn exact(
+        value: f64
+    ) -> Self {
+
+        Self::new(value, 0.0)
+    }
+
+    /// Creates a measured value from a relative standard uncertainty.
+    #[must_use]
+
+    pub const fn from_relative(
+        value: f64,
+        relative_uncertainty: f64,
+    ) -> Self {
+
+        Self::new(
+            value,
+            value
+                * relative_uncertainty,
+        )
+    }
+
+    /// Returns whether the value has no uncertainty.
+    #[must_use]
+
+    pub const fn is_exact(
+        self
+    ) -> bool {
+
+        self.uncertainty <= 0.0
+    }
+
+    /// Returns the relative standard uncertainty, zero for exact values.
+    #[must_use]
+
+    pub const fn relative_uncertainty(
+        self
+    ) -> f64 {
+
+        if self.is_exact() {
+
+            0.0
+        } else {
+
+            self.uncertainty
+                / self.value.abs()
+        }
+    }
+
+    /// Applies a function with value `value` and derivative `derivative`
+    /// at `self.value`, propagating the uncertainty to first order.
+    ///
+    /// This is the building block for the elementary functions and can be
+    /// used for any other differentiable function. An exact value stays
+    /// exact whatever the derivative, so a vertical tangent (e.g. `√x` at
+    /// zero) does not turn it into NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::constant::Measured;
+    ///
+    /// let x = Measured::new(2.0, 0.1);
+    ///
+    /// // f(x) = x³, f'(x) = 3x²
+    /// let y = x.propagate(
+    ///     8.0,
+    ///     12.0,
+    /// );
+    ///
+    /// assert!(
+    ///     (y.uncertainty - 1.2).abs()
+    ///         < 1e-12
+    /// );
+    /// ```
+    #[must_use]
+
+    pub const fn propagate(
+        self,
+        value: f64,
+        derivative: f64,
+    ) -> Self {
+
+        if self.is_exact() {
+
+            return Self::exact(value);
+        }
+
+        Self::new(
+            value,
+            derivative
+                * self.uncertainty,
+        )
+    }
+
+    /// Applies `f`, whose derivative at `self.value` is `derivative`,
+    /// falling back on the spread of `f` over one standard uncertainty
+    /// where the first-order uncertainty diverges.
+    ///
+    /// This covers the roots at zero, whose tangent is vertical: the
+    /// uncertainty of `√x` at `0 ± σ` is `√σ`, not infinity.
+
+    fn propagate_with(
+        self,
+        f: impl Fn(f64) -> f64,
+        derivative: f64,
+    ) -> Self {
+
+        let value = f(self.value);
+
+        if self.is_exact()
+            || derivative.is_finite()
+            || !value.is_finite()
+        {
+
+            return self.propagate(
+                value,
+                derivative,
+            );
+        }
+
+        // `f64::max` ignores the NaN of a root outside its domain.
+        let spread = (f(self.value
+            + self.uncertainty)
+            - value)
+            .abs()
+            .max(
+                (f(self.value
+                    - self
+                        .uncertainty)
+                    - value)
+                    .abs(),
+            );
+
+        Self::new(value, spread)
+    }
+
+    /// Combines the uncertainties of two uncorrelated operands with the
+    /// partial derivatives `d_self` and `d_other` of the result.
+    ///
+    /// An exact operand contributes nothing, whatever its derivative.
+
+    fn combine(
+        self,
+        other: Self,
+        value: f64,
+        d_self: f64,
+        d_other: f64,
+    ) -> Self {
+
+        let contribution =
+            |operand: Self, derivative: f64| {
+
+                if operand.is_exact() {
+
+                    0.0
+                } else {
+
+                    derivative
+                        * operand
+                            .uncertainty
+                }
+            };
+
+        Self::new(
+            value,
+            contribution(self, d_self)
+                .hypot(contribution(
+                    other,
+                    d_other,
+                )),
+        )
+    }
+
+    /// Raises to an integer power.
+    #[must_use]
+
+    pub fn powi(
+        self,
+        n: i32,
+    ) -> Self {
+
+        let derivative = if n == 0 {
+
+            0.0
+        } else {
+
+            f64::from(n)
+                * self.value.powi(
+                    n.saturating_sub(1),
+                )
+        };
+
+        self.propagate(
+            self.value.powi(n),
+            derivative,
+        )
+    }
+
+    /// Raises to a floating-point power.
+    #[must_use]
+
+    pub fn powf(
+        self,
+        exponent: f64,
+    ) -> Self {
+
+        self.propagate_with(
+            |x| x.powf(exponent),
+            exponent
+                * self.value.powf(
+                    exponent - 1.0,
+                ),
+        )
+    }
+
+    /// Raises to a measured power, propagating both uncertainties.
+    #[must_use]
+
+    pub fn pow(
+        self,
+        exponent: Self,
+    ) -> Self {
+
+        let value = self
+            .value
+            .powf(exponent.value);
+
+        // x^y ln x tends to zero with x^y.
+        let d_exponent = if exponent
+            .is_exact()
+            || value == 0.0
+        {
+
+            0.0
+        } else {
+
+            value * self.value.ln()
+        };
+
+        // The base contributes as in `powf`, bounded at a vertical
+        // tangent.
+        self.powf(exponent.value)
+            .combine(
+                exponent,
+                value,
+                1.0,
+                d_exponent,
+            )
+    }
+
+    /// Returns the reciprocal.
+    #[must_use]
+
+    pub fn recip(self) -> Self {
+
+        self.propagate(
+            self.value.recip(),
+            -self.value.powi(-2),
+        )
+    }
+
+    /// Returns the square root.
+    #[must_use]
+
+    pub fn sqrt(self) -> Self {
+
+        self.propagate_with(
+            f64::sqrt,
+            0.5 / self.value.sqrt(),
+        )
+    }
+
+    /// Returns the cube root.
+    #[must_use]
+
+    pub fn cbrt(self) -> Self {
+
+        let value = self.value.cbrt();
+
+        self.propagate_with(
+            f64::cbrt,
+            1.0 / (3.0 * value * value),
+        )
+    }
+
+    /// Returns the absolute value.
+    #[must_use]
+
+    pub const fn abs(self) -> Self {
+
+        Self::new(
+            self.value.abs(),
+            self.uncertainty,
+        )
+    }
+
+    /// Returns `e^self`.
+    #[must_use]
+
+    pub fn exp(self) -> Self {
+
+        let value = self.value.exp();
+
+        self.propagate(value, value)
+    }
+
+    /// Returns the natural logarithm.
+    #[must_use]
+
+    pub fn ln(self) -> Self {
+
+        self.propagate(
+            self.value.ln(),
+            self.value.recip(),
+        )
+    }
+
+    /// Returns the base-10 logarithm.
+    #[must_use]
+
+    pub fn log10(self) -> Self {
+
+        self.propagate(
+            self.value.log10(),
+            (self.value
+                * std::f64::consts::LN_10)
+                .recip(),
+        )
+    }
+
+    /// Returns the sine (argument in radians).
+    #[must_use]
+
+    pub fn sin(self) -> Self {
+
+        self.propagate(
+            self.value.sin(),
+            self.value.cos(),
+        )
+    }
+
+    /// Returns the cosine (argument in radians).
+    #[must_use]
+
+    pub fn cos(self) -> Self {
+
+        self.propagate(
+            self.value.cos(),
+            -self.value.sin(),
+        )
+    }
+
+    /// Returns the tangent (argument in radians).
+    #[must_use]
+
+    pub fn tan(self) -> Self {
+
+        let cos = self.value.cos();
+
+        self.propagate(
+            self.value.tan(),
+            (cos * cos).recip(),
+        )
+    }
+
+    /// Returns the arcsine in radians.
+    #[must_use]
+
+    pub fn asin(self) -> Self {
+
+        self.propagate(
+            self.value.asin(),
+            self.value
+                .mul_add(
+                    -self.value,
+                    1.0,
+                )
+                .sqrt()
+                .recip(),
+        )
+    }
+
+    /// Returns the arccosine in radians.
+    #[must_use]
+
+    pub fn acos(self) -> Self {
+
+        self.propagate(
+            self.value.acos(),
+            -self
+                .value
+                .mul_add(
+                    -self.value,
+                    1.0,
+                )
+                .sqrt()
+                .recip(),
+        )
+    }
+
+    /// Returns the arctangent in radians.
+    #[must_use]
+
+    pub fn atan(self) -> Self {
+
+        self.propagate(
+            self.value.atan(),
+            self.value
+                .mul_add(
+                    self.value,
+                    1.0,
+                )
+                .recip(),
+        )
+    }
+
+    /// Returns the hyperbolic sine.
+    #[must_use]
+
+    pub fn sinh(self) -> Self {
+
+        self.propagate(
+            self.value.sinh(),
+            self.value.cosh(),
+        )
+    }
+
+    /// Returns the hyperbolic cosine.
+    #[must_use]
+
+    pub fn cosh(self) -> Self {
+
+        self.propagate(
+            self.value.cosh(),
+            self.value.sinh(),
+        )
+    }
+
+    /// Returns the hyperbolic tangent.
+    #[must_use]
+
+    pub fn tanh(self) -> Self {
+
+        let value = self.value.tanh();
+
+        self.propagate(
+            value,
+            value.mul_add(-value, 1.0),
+        )
+    }
+}
+
+impl From<f64> for Measured {
+    fn from(value: f64) -> Self {
+
+        Self::exact(value)
+    }
+}
+
+impl fmt::Display for Measured {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match f.precision() {
+            | Some(precision) => {
+
+                write!(
+                    f,
+                    "{:.precision$e} \
+                     ± {:.precision$e}",
+                    self.value,
+                    self.uncertainty
+                )
+            },
+            | None => {
+
+                write!(
+                    f,
+                    "{} ± {}",
+                    self.value,
+                    self.uncertainty
+                )
+            },
+        }
+    }
+}
+
+impl Neg for Measured {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+
+        Self::new(
+            -self.value,
+            self.uncertainty,
+        )
+    }
+}
+
+impl Add for Measured {
+    type Output = Self;
+
+    fn add(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        self.combine(
+            rhs,
+            self.value + rhs.value,
+            1.0,
+            1.0,
+        )
+    }
+}
+
+impl Sub for Measured {
+    type Output = Self;
+
+    fn sub(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        self.combine(
+            rhs,
+            self.value - rhs.value,
+            1.0,
+            -1.0,
+        )
+    }
+}
+
+impl Mul for Measured {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        self.combine(
+            rhs,
+            self.value * rhs.value,
+            rhs.value,
+            self.value,
+        )
+    }
+}
+
+impl Div for Measured {
+    type Output = Self;
+
+    fn div(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        let value =
+            self.value / rhs.value;
+
+        self.combine(
+            rhs,
+            value,
+            rhs.value.recip(),
+            -value / rhs.value,
+        )
+    }
+}
+
+/// Implements a binary operator between `Measured` and `f64` in both
+/// orders by promoting the `f64` to an exact value.
+
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait<f64> for Measured {
+            type Output = Self;
+
+            fn $method(
+                self,
+                rhs: f64,
+            ) -> Self {
+
+                self.$method(
+                    Self::exact(rhs),
+                )
+            }
+        }
+
+        impl $trait<Measured> for f64 {
+            type Output = Measured;
+
+            fn $method(
+                self,
+                rhs: Measured,
+            ) -> Measured {
+
+                Measured::exact(self)
+                    .$method(rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add);
+
+impl_scalar_op!(Sub, sub);
+
+impl_scalar_op!(Mul, mul);
+
+impl_scalar_op!(Div, div);
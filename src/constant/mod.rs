@@ -0,0 +1,364 @@
+#![allow(clippy::inline_always)]
+
+/// Consistency checks between constants tied by exact relations.
+pub mod audit;
+/// Correlation coefficients and covariances of the CODATA constants.
+pub mod correlation;
+/// Values of earlier CODATA adjustments and the differences between them.
+pub mod edition;
+/// Exact rational values of the constants fixed by the 2019 SI.
+pub mod exact;
+/// Identification of numbers as constants or combinations of constants.
+pub mod identify;
+/// Values with a standard uncertainty and first-order error propagation.
+pub mod measured;
+/// Parser for the NIST ASCII listing of CODATA constants.
+pub mod nist_ascii;
+/// Correlated uncertainty propagation through functions of several constants.
+pub mod propagation;
+/// Structured records and lookups for the physical constants.
+pub mod registry;
+/// Fuzzy lookup of constants by name, alias or symbol.
+pub mod search;
+/// Constants as rssn symbols, with substitution and defining relations.
+pub mod symbolic;
+
+/// Looks up a constant of a CODATA edition by identifier or NIST quantity
+/// name; see [`edition::get`].
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant;
+/// use rssn_advanced::constant::CodataEdition;
+///
+/// let m_e = constant::get(
+///     CodataEdition::Codata2018,
+///     "electron mass",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     m_e.edition,
+///     CodataEdition::Codata2018
+/// );
+/// ```
+pub use edition::get;
+pub use measured::Measured;
+pub use propagation::Dual;
+pub use registry::*;
+pub use search::Match;
+pub use search::MatchKind;
+pub use search::find;
+
+/// The date the library was built.
+
+pub const BUILD_DATE: &str =
+    env!("VERGEN_BUILD_DATE");
+
+/// The Git commit SHA the library was built from.
+
+pub const COMMIT_SHA: &str =
+    env!("VERGEN_GIT_SHA");
+
+/// The version of the Rust compiler used to build the library.
+
+pub const RUSTC_VERSION: &str =
+    env!("VERGEN_RUSTC_SEMVER");
+
+/// The target triple for which the library was built.
+
+pub const CARGO_TARGET_TRIPLE: &str =
+    env!("VERGEN_CARGO_TARGET_TRIPLE");
+
+/// Operating system and version information of the build environment.
+
+pub const SYSTEM_INFO: &str =
+    env!("VERGEN_SYSINFO_OS_VERSION");
+
+// --- Getter functions ---
+
+/// Returns the build date (e.g., "2023-10-24").
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::constant::get_build_date;
+///
+/// let date = get_build_date();
+///
+/// assert!(!date.is_empty());
+/// ```
+#[must_use]
+#[inline(always)]
+
+pub const fn get_build_date()
+-> &'static str {
+
+    BUILD_DATE
+}
+
+/// Returns the Git short SHA for the current commit.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::constant::get_commit_sha;
+///
+/// let sha = get_commit_sha();
+///
+/// assert!(!sha.is_empty());
+/// ```
+#[must_use]
+#[inline(always)]
+
+pub const fn get_commit_sha()
+-> &'static str {
+
+    COMMIT_SHA
+}
+
+/// Returns the rustc semantic version (e.g., "1.70.0-nightly").
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::constant::get_rustc_version;
+///
+/// let version = get_rustc_version();
+///
+/// assert!(!version.is_empty());
+/// ```
+#[must_use]
+#[inline(always)]
+
+pub const fn get_rustc_version()
+-> &'static str {
+
+    RUSTC_VERSION
+}
+
+/// Returns the Cargo target triple (e.g., "x86_64-unknown-linux-gnu").
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::constant::get_cargo_target_triple;
+///
+/// let triple = get_cargo_target_triple();
+///
+/// assert!(!triple.is_empty());
+/// ```
+#[must_use]
+#[inline(always)]
+
+pub const fn get_cargo_target_triple()
+-> &'static str {
+
+    CARGO_TARGET_TRIPLE
+}
+
+/// Returns the system information string (e.g., "Linux Arch Linux").
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::constant::get_system_info;
+///
+/// let sys_info = get_system_info();
+///
+/// assert!(!sys_info.is_empty());
+/// ```
+#[must_use]
+#[inline(always)]
+
+pub const fn get_system_info()
+-> &'static str {
+
+    SYSTEM_INFO
+}
+
+// --- Math & Physics Constants ---
+
+/// Expands an uncertainty column of `nist_const!` into its numeric value.
+
+macro_rules! nist_uncertainty {
+    (exact) => {
+
+        0.0
+    };
+    (truncated) => {
+
+        0.0
+    };
+    ($uncert:literal) => {
+
+        $uncert
+    };
+}
+
+/// Expands an uncertainty column of `nist_const!` into its documentation
+/// text.
+
+macro_rules! nist_uncertainty_doc {
+    (exact) => {
+
+        "exact"
+    };
+    (truncated) => {
+
+        "exact (listed truncated)"
+    };
+    ($uncert:literal) => {
+
+        stringify!($uncert)
+    };
+}
+
+/// Expands an uncertainty column of `nist_const!` into whether the exact
+/// value is listed truncated.
+
+macro_rules! nist_truncated {
+    (truncated) => {
+
+        true
+    };
+    ($uncert:tt) => {
+
+        false
+    };
+}
+
+/// Declares a table of CODATA constants.
+///
+/// Every entry emits a bare `f64` constant, a `const fn` getter and a
+/// `const fn` getter returning the value with its uncertainty as a
+/// [`Measured`], and is also recorded as a [`PhysicalConstant`] in the static slice named by the
+/// first argument, so that the registry never drifts from the constants.
+
+macro_rules! nist_const {
+    (
+        $(#[$registry_meta:meta])*
+        $registry:ident,
+        $edition:ident;
+        $(
+            (
+                $const_name:ident,
+                $fn_name:ident,
+                $measured_fn:ident,
+                $name:expr_2021,
+                $symbol:expr_2021,
+                $value:expr_2021,
+                $digits:literal,
+                $uncert:tt,
+                $unit:expr_2021,
+                $group:ident
+            )
+        ),* $(,)?
+    ) => {
+        $(
+            #[doc = concat!($name, "\n\n**Value:** ", stringify!($value), " ", $unit, "\n**Uncertainty:** ", nist_uncertainty_doc!($uncert))]
+            pub const $const_name: f64 = $value;
+
+            #[doc = concat!("Returns the ", $name)]
+            #[must_use]
+            #[inline(always)]
+            pub const fn $fn_name() -> f64 {
+                $const_name
+            }
+
+            #[doc = concat!("Returns the ", $name, " with its standard uncertainty")]
+            #[must_use]
+            #[inline(always)]
+            pub const fn $measured_fn() -> Measured {
+                Measured::new($const_name, nist_uncertainty!($uncert))
+            }
+        )*
+
+        $(#[$registry_meta])*
+        pub static $registry: &[PhysicalConstant] = &[
+            $(
+                PhysicalConstant::new(
+                    stringify!($const_name),
+                    $name,
+                    $symbol,
+                    $const_name,
+                    nist_uncertainty!($uncert),
+                    $unit,
+                    ConstantGroup::$group,
+                    CodataEdition::$edition,
+                )
+                .with_truncated(nist_truncated!($uncert))
+                .with_digits($digits),
+            )*
+        ];
+    };
+}
+
+/// Declares the [`PhysicalConstant`] records of an earlier CODATA
+/// adjustment.
+///
+/// Takes the same rows as `nist_const!` but emits only the static slice,
+/// since the constants and getters always refer to the latest edition.
+
+macro_rules! codata_records {
+    (
+        $(#[$registry_meta:meta])*
+        $registry:ident,
+        $edition:ident;
+        $(
+            (
+                $const_name:ident,
+                $fn_name:ident,
+                $measured_fn:ident,
+                $name:expr_2021,
+                $symbol:expr_2021,
+                $value:expr_2021,
+                $digits:literal,
+                $uncert:tt,
+                $unit:expr_2021,
+                $group:ident
+            )
+        ),* $(,)?
+    ) => {
+        $(#[$registry_meta])*
+        pub static $registry: &[PhysicalConstant] = &[
+            $(
+                PhysicalConstant::new(
+                    stringify!($const_name),
+                    $name,
+                    $symbol,
+                    $value,
+                    nist_uncertainty!($uncert),
+                    $unit,
+                    ConstantGroup::$group,
+                    CodataEdition::$edition,
+                )
+                .with_truncated(nist_truncated!($uncert))
+                .with_digits($digits),
+            )*
+        ];
+    };
+}
+
+// The tables are generated by `build/codata.rs` from `data/codata/allascii_*.txt`.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_current.rs"
+));
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_2018.rs"
+));
+
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_2014.rs"
+));
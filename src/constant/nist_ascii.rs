@@ -0,0 +1,414 @@
+//! Parser for the NIST "Fundamental Physical Constants --- Complete Listing" (`allascii.txt`).
+//!
+//! The listing is a fixed-column text table (quantity, value at column 60,
+//! uncertainty at column 85, unit at column 110) with a free-form header, a
+//! `Quantity  Value  Uncertainty  Unit` caption and a dashed rule, followed
+//! by one constant per line. Values and uncertainties are printed with
+//! digit groups separated by spaces and an optional ` e<exp>` suffix; exact
+//! values carry `(exact)` in the uncertainty column and values that are
+//! exact but not finitely representable end in `...`.
+//!
+//! This module only depends on `std`, because the build script shares it
+//! to generate the `nist_const!` table from the vendored listings in
+//! `data/codata/`.
+
+use std::fmt;
+
+/// One row of a NIST ASCII constant listing.
+#[derive(Debug, Clone, PartialEq)]
+
+pub struct AsciiEntry {
+    /// The quantity name (e.g. `"speed of light in vacuum"`).
+    pub name: String,
+    /// The value.
+    pub value: f64,
+    /// The value digits as printed, without group spaces or a trailing `...`.
+    pub value_text: String,
+    /// The standard uncertainty, zero for exact values.
+    pub uncertainty: f64,
+    /// The uncertainty digits as printed, `None` for exact values.
+    pub uncertainty_text:
+        Option<String>,
+    /// Whether the uncertainty column reads `(exact)`.
+    pub exact: bool,
+    /// Whether the printed value is a truncation of an exact value (`...`).
+    pub truncated: bool,
+    /// The unit in the listing's ASCII notation (e.g. `"m^3 kg^-1 s^-2"`), empty for pure numbers.
+    pub unit: String,
+}
+
+impl AsciiEntry {
+    /// Returns the number of significant digits of the printed value,
+    /// trailing zeros included (`"6.644 657 345 0 e-27"` has 11).
+
+    #[must_use]
+
+    pub fn significant_digits(
+        &self
+    ) -> u32 {
+
+        let mantissa = self
+            .value_text
+            .split(['e', 'E'])
+            .next()
+            .unwrap_or_default();
+
+        let digits = mantissa
+            .chars()
+            .filter(
+                char::is_ascii_digit,
+            )
+            .skip_while(|&c| c == '0')
+            .count();
+
+        u32::try_from(digits)
+            .unwrap_or(u32::MAX)
+    }
+}
+
+/// An error raised while parsing a listing.
+#[derive(
+    Debug, Clone, PartialEq, Eq,
+)]
+
+pub struct ParseError {
+    /// The 1-based line number the error refers to.
+    pub line: usize,
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        write!(
+            f,
+            "line {}: {}",
+            self.line, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {
+}
+
+/// The first column of the value field.
+
+const VALUE_COLUMN: usize = 60;
+
+/// The first column of the uncertainty field.
+
+const UNCERTAINTY_COLUMN: usize = 85;
+
+/// The first column of the unit field.
+
+const UNIT_COLUMN: usize = 110;
+
+/// Parses a complete listing.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the dashed rule that opens the table body
+/// is missing, or if any row cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::nist_ascii;
+///
+/// let text = "  Quantity                                                       Value                 Uncertainty           Unit
+/// -----------------------------------------------------------------------------------------------------------------------------
+/// Newtonian constant of gravitation                           6.674 30 e-11            0.000 15 e-11            m^3 kg^-1 s^-2
+/// ";
+///
+/// let entries =
+///     nist_ascii::parse(text).unwrap();
+///
+/// assert_eq!(
+///     entries[0].uncertainty,
+///     0.000_15e-11
+/// );
+/// ```
+
+pub fn parse(
+    text: &str
+) -> Result<Vec<AsciiEntry>, ParseError>
+{
+
+    let mut in_body = false;
+
+    let mut entries = Vec::new();
+
+    for (index, line) in text
+        .lines()
+        .enumerate()
+    {
+
+        let line_no =
+            index.saturating_add(1);
+
+        if in_body {
+
+            if line
+                .trim()
+                .is_empty()
+            {
+
+                continue;
+            }
+
+            let entry = parse_row(line)
+                .map_err(|message| {
+
+                    ParseError {
+                        line: line_no,
+                        message,
+                    }
+                })?;
+
+            entries.push(entry);
+        } else if line
+            .starts_with("---")
+        {
+
+            in_body = true;
+        }
+    }
+
+    if in_body {
+
+        Ok(entries)
+    } else {
+
+        Err(ParseError {
+            line: 0,
+            message: "missing dashed \
+                      rule before the \
+                      table body"
+                .to_string(),
+        })
+    }
+}
+
+/// Returns the year of the CODATA adjustment named in a listing's header
+/// (e.g. `2018` for `"2018 CODATA adjustment"`), if any.
+
+#[must_use]
+
+pub fn edition_year(
+    text: &str
+) -> Option<u16> {
+
+    text.lines()
+        .take_while(|line| {
+            !line.starts_with("---")
+        })
+        .find_map(|line| {
+
+            let (year, _) = line
+                .trim()
+                .split_once(
+                    " CODATA adjustment",
+                )?;
+
+            year.parse().ok()
+        })
+}
+
+fn parse_row(
+    line: &str
+) -> Result<AsciiEntry, String> {
+
+    let column =
+        |from: usize,
+         to: Option<usize>| {
+
+            let end = to
+                .unwrap_or(line.len())
+                .min(line.len());
+
+            line.get(
+                from.min(end) .. end,
+            )
+            .map(str::trim)
+            .ok_or_else(|| {
+
+                "row is not ASCII"
+                    .to_string()
+            })
+        };
+
+    let name = column(
+        0,
+        Some(VALUE_COLUMN),
+    )?;
+
+    let value_field = column(
+        VALUE_COLUMN,
+        Some(UNCERTAINTY_COLUMN),
+    )?;
+
+    let uncertainty_field = column(
+        UNCERTAINTY_COLUMN,
+        Some(UNIT_COLUMN),
+    )?;
+
+    let unit =
+        column(UNIT_COLUMN, None)?;
+
+    if name.is_empty() {
+
+        return Err(
+            "missing quantity name"
+                .to_string(),
+        );
+    }
+
+    let truncated =
+        value_field.contains("...");
+
+    let value_text =
+        compact_number(value_field);
+
+    let value =
+        parse_number(&value_text)?;
+
+    let exact =
+        uncertainty_field == "(exact)";
+
+    let (uncertainty, uncertainty_text) =
+        if exact {
+
+            (0.0, None)
+        } else {
+
+            let text = compact_number(
+                uncertainty_field,
+            );
+
+            (
+                parse_number(&text)?,
+                Some(text),
+            )
+        };
+
+    Ok(AsciiEntry {
+        name: name.to_string(),
+        value,
+        value_text,
+        uncertainty,
+        uncertainty_text,
+        exact,
+        truncated,
+        unit: unit.to_string(),
+    })
+}
+
+/// Removes digit-group spaces and a trailing `...` from a printed number.
+
+fn compact_number(
+    field: &str
+) -> String {
+
+    field
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .replace("...", "")
+}
+
+fn parse_number(
+    text: &str
+) -> Result<f64, String> {
+
+    text.parse::<f64>()
+        .map_err(|_| {
+
+            format!(
+                "invalid number \
+                 `{text}`"
+            )
+        })
+}
+
+/// Derives an upper-case Rust identifier from a NIST quantity name.
+///
+/// The listing's abbreviations (`mag.`, `mom.`, `gyromag.`) are spelled
+/// out, `/` becomes `PER` and every other run of non-alphanumeric
+/// characters becomes a single underscore.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::nist_ascii::identifier;
+///
+/// assert_eq!(
+///     identifier("Bohr magneton in eV/T"),
+///     "BOHR_MAGNETON_IN_EV_PER_T"
+/// );
+///
+/// assert_eq!(
+///     identifier("neutron mag. mom."),
+///     "NEUTRON_MAGNETIC_MOMENT"
+/// );
+/// ```
+
+#[must_use]
+
+pub fn identifier(
+    name: &str
+) -> String {
+
+    let expanded = name
+        .replace(
+            "gyromag.",
+            "gyromagnetic",
+        )
+        .replace("mag.", "magnetic")
+        .replace("mom.", "moment")
+        .replace("h-bar", "hbar")
+        .replace("1st", "first")
+        .replace("2nd", "second")
+        .replace('/', " per ");
+
+    let mut ident = String::new();
+
+    let mut pending_separator = false;
+
+    for c in expanded.chars() {
+
+        if c.is_ascii_alphanumeric() {
+
+            if pending_separator
+                && !ident.is_empty()
+            {
+
+                ident.push('_');
+            }
+
+            pending_separator = false;
+
+            ident.push(
+                c.to_ascii_uppercase(),
+            );
+        } else {
+
+            pending_separator = true;
+        }
+    }
+
+    if ident.starts_with(|c: char| {
+
+        c.is_ascii_digit()
+    }) {
+
+        ident.insert(0, '_');
+    }
+
+    ident
+}
@@ -0,0 +1,486 @@
+//! Correlated uncertainty propagation through functions of several constants.
+//!
+//! [`propagate`] evaluates a function of several constants with
+//! forward-mode automatic differentiation: the function is written over
+//! [`Dual`] numbers and evaluated once per input to obtain its gradient
+//! `g`. The variance of the result is then `gᵀ Σ g`, where `Σ` is the
+//! covariance matrix of the inputs from [`super::correlation`].
+//!
+//! Unlike chaining [`Measured`] operations, this accounts for shared
+//! sources of uncertainty: `m_e / m_u` has the small uncertainty of the
+//! relative atomic mass of the electron, not the quadrature sum of the
+//! much larger uncertainties of the two masses.
+
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+use super::Measured;
+
+/// A dual number `value + derivative ε` with `ε² = 0`, used for
+/// forward-mode automatic differentiation.
+#[derive(
+    Debug, Clone, Copy, PartialEq,
+)]
+
+pub struct Dual {
+    /// The value of the expression.
+    pub value: f64,
+    /// The derivative of the expression with respect to the seeded input.
+    pub derivative: f64,
+}
+
+impl Dual {
+    /// Creates a dual number.
+    #[must_use]
+
+    pub const fn new(
+        value: f64,
+        derivative: f64,
+    ) -> Self {
+
+        Self {
+            value,
+            derivative,
+        }
+    }
+
+    /// Creates a constant, whose derivative is zero.
+    #[must_use]
+
+    pub const fn constant(
+        value: f64
+    ) -> Self {
+
+        Self::new(value, 0.0)
+    }
+
+    /// Creates the input being differentiated, whose derivative is one.
+    #[must_use]
+
+    pub const fn variable(
+        value: f64
+    ) -> Self {
+
+        Self::new(value, 1.0)
+    }
+
+    /// Applies a function with value `value` and derivative `derivative`
+    /// at `self.value`, using the chain rule.
+    #[must_use]
+
+    pub const fn chain(
+        self,
+        value: f64,
+        derivative: f64,
+    ) -> Self {
+
+        Self::new(
+            value,
+            derivative
+                * self.derivative,
+        )
+    }
+
+    /// Raises to an integer power.
+    #[must_use]
+
+    pub fn powi(
+        self,
+        n: i32,
+    ) -> Self {
+
+        let derivative = if n == 0 {
+
+            0.0
+        } else {
+
+            f64::from(n)
+                * self.value.powi(
+                    n.saturating_sub(1),
+                )
+        };
+
+        self.chain(
+            self.value.powi(n),
+            derivative,
+        )
+    }
+
+    /// Raises to a floating-point power.
+    #[must_use]
+
+    pub fn powf(
+        self,
+        exponent: f64,
+    ) -> Self {
+
+        self.chain(
+            self.value
+                .powf(exponent),
+            exponent
+                * self.value.powf(
+                    exponent - 1.0,
+                ),
+        )
+    }
+
+    /// Returns the reciprocal.
+    #[must_use]
+
+    pub fn recip(self) -> Self {
+
+        self.chain(
+            self.value.recip(),
+            -self.value.powi(-2),
+        )
+    }
+
+    /// Returns the square root.
+    #[must_use]
+
+    pub fn sqrt(self) -> Self {
+
+        let value = self.value.sqrt();
+
+        self.chain(value, 0.5 / value)
+    }
+
+    /// Returns `e^self`.
+    #[must_use]
+
+    pub fn exp(self) -> Self {
+
+        let value = self.value.exp();
+
+        self.chain(value, value)
+    }
+
+    /// Returns the natural logarithm.
+    #[must_use]
+
+    pub fn ln(self) -> Self {
+
+        self.chain(
+            self.value.ln(),
+            self.value.recip(),
+        )
+    }
+
+    /// Returns the sine (argument in radians).
+    #[must_use]
+
+    pub fn sin(self) -> Self {
+
+        self.chain(
+            self.value.sin(),
+            self.value.cos(),
+        )
+    }
+
+    /// Returns the cosine (argument in radians).
+    #[must_use]
+
+    pub fn cos(self) -> Self {
+
+        self.chain(
+            self.value.cos(),
+            -self.value.sin(),
+        )
+    }
+}
+
+impl From<f64> for Dual {
+    fn from(value: f64) -> Self {
+
+        Self::constant(value)
+    }
+}
+
+impl Neg for Dual {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+
+        Self::new(
+            -self.value,
+            -self.derivative,
+        )
+    }
+}
+
+impl Add for Dual {
+    type Output = Self;
+
+    fn add(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self::new(
+            self.value + rhs.value,
+            self.derivative
+                + rhs.derivative,
+        )
+    }
+}
+
+impl Sub for Dual {
+    type Output = Self;
+
+    fn sub(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self::new(
+            self.value - rhs.value,
+            self.derivative
+                - rhs.derivative,
+        )
+    }
+}
+
+impl Mul for Dual {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self::new(
+            self.value * rhs.value,
+            self.derivative
+                .mul_add(
+                    rhs.value,
+                    self.value
+                        * rhs
+                            .derivative,
+                ),
+        )
+    }
+}
+
+impl Div for Dual {
+    type Output = Self;
+
+    fn div(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        let value =
+            self.value / rhs.value;
+
+        Self::new(
+            value,
+            value.mul_add(
+                -rhs.derivative,
+                self.derivative,
+            ) / rhs.value,
+        )
+    }
+}
+
+/// Implements a binary operator between `Dual` and `f64` in both orders by
+/// promoting the `f64` to a constant.
+
+macro_rules! impl_scalar_op {
+    ($trait:ident, $method:ident) => {
+        impl $trait<f64> for Dual {
+            type Output = Self;
+
+            fn $method(
+                self,
+                rhs: f64,
+            ) -> Self {
+
+                self.$method(
+                    Self::constant(rhs),
+                )
+            }
+        }
+
+        impl $trait<Dual> for f64 {
+            type Output = Dual;
+
+            fn $method(
+                self,
+                rhs: Dual,
+            ) -> Dual {
+
+                Dual::constant(self)
+                    .$method(rhs)
+            }
+        }
+    };
+}
+
+impl_scalar_op!(Add, add);
+
+impl_scalar_op!(Sub, sub);
+
+impl_scalar_op!(Mul, mul);
+
+impl_scalar_op!(Div, div);
+
+/// Evaluates `f` at the constants named by `ids` and propagates their
+/// correlated uncertainties to the result.
+///
+/// `f` receives the inputs in the order of `ids`. Returns `None` if any
+/// identifier is unknown.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant;
+/// use rssn_advanced::constant::propagation;
+///
+/// // m_e / m_u is the relative atomic mass of the electron.
+/// let ratio = propagation::propagate(
+///     &["ELECTRON_MASS", "ATOMIC_MASS_CONSTANT"],
+///     |x| x[0] / x[1],
+/// )
+/// .unwrap();
+///
+/// let independent = constant::get_electron_mass_measured()
+///     / constant::get_atomic_mass_constant_measured();
+///
+/// assert!(
+///     ratio.uncertainty
+///         < independent.uncertainty / 10.0
+/// );
+/// ```
+#[must_use]
+
+pub fn propagate<F>(
+    ids: &[&str],
+    f: F,
+) -> Option<Measured>
+where
+    F: Fn(&[Dual]) -> Dual,
+{
+
+    let values = ids
+        .iter()
+        .map(|id| {
+
+            super::lookup(id)
+                .map(|c| c.value)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let covariance =
+        super::correlation::covariance_matrix(ids)?;
+
+    propagate_with_covariance(
+        &values,
+        &covariance,
+        f,
+    )
+}
+
+/// Evaluates `f` at `values` and propagates the uncertainty described by
+/// the covariance matrix `covariance` to the result.
+///
+/// Returns `None` unless `covariance` is a square matrix of the same size
+/// as `values`.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::propagation;
+///
+/// // Two fully correlated inputs cancel in a difference.
+/// let difference =
+///     propagation::propagate_with_covariance(
+///         &[3.0, 2.0],
+///         &[
+///             vec![0.04, 0.04],
+///             vec![0.04, 0.04],
+///         ],
+///         |x| x[0] - x[1],
+///     )
+///     .unwrap();
+///
+/// assert!(difference.uncertainty < 1e-12);
+/// ```
+#[must_use]
+
+pub fn propagate_with_covariance<F>(
+    values: &[f64],
+    covariance: &[Vec<f64>],
+    f: F,
+) -> Option<Measured>
+where
+    F: Fn(&[Dual]) -> Dual,
+{
+
+    if covariance.len() != values.len()
+        || covariance
+            .iter()
+            .any(|row| {
+
+                row.len()
+                    != values.len()
+            })
+    {
+
+        return None;
+    }
+
+    let value = f(&values
+        .iter()
+        .copied()
+        .map(Dual::constant)
+        .collect::<Vec<_>>())
+    .value;
+
+    let gradient: Vec<f64> = (0 .. values
+        .len())
+        .map(|seed| {
+
+            let inputs: Vec<Dual> = values
+                .iter()
+                .enumerate()
+                .map(|(index, &v)| {
+
+                    if index == seed {
+
+                        Dual::variable(v)
+                    } else {
+
+                        Dual::constant(v)
+                    }
+                })
+                .collect();
+
+            f(&inputs).derivative
+        })
+        .collect();
+
+    let variance: f64 = covariance
+        .iter()
+        .zip(&gradient)
+        .map(|(row, g_i)| {
+
+            g_i * row
+                .iter()
+                .zip(&gradient)
+                .map(|(c, g_j)| c * g_j)
+                .sum::<f64>()
+        })
+        .sum();
+
+    Some(Measured::new(
+        value,
+        variance
+            .max(0.0)
+            .sqrt(),
+    ))
+}
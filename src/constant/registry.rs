@@ -0,0 +1,394 @@
+//! Structured records for the physical constants declared with `nist_const!`.
+//!
+//! Each constant in [`crate::constant`] is also registered as a
+//! [`PhysicalConstant`], so callers can query its uncertainty, unit and
+//! group at runtime or enumerate the whole table instead of naming every
+//! getter.
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Measured;
+use crate::unit;
+use crate::unit::Dimension;
+use crate::unit::Quantity;
+
+/// The CODATA adjustment a set of constant values was taken from.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum CodataEdition {
+    /// The 2014 CODATA adjustment.
+    Codata2014,
+    /// The 2018 CODATA adjustment.
+    Codata2018,
+    /// The 2022 CODATA adjustment.
+    Codata2022,
+}
+
+impl CodataEdition {
+    /// Every adjustment shipped with the crate, oldest first.
+
+    pub const ALL: [Self; 3] = [
+        Self::Codata2014,
+        Self::Codata2018,
+        Self::Codata2022,
+    ];
+    /// The most recent adjustment shipped with the crate.
+
+    pub const LATEST: Self =
+        Self::Codata2022;
+
+    /// Returns the year of the adjustment (e.g. `2022`).
+    #[must_use]
+
+    pub const fn year(self) -> u16 {
+
+        match self {
+            | Self::Codata2014 => 2014,
+            | Self::Codata2018 => 2018,
+            | Self::Codata2022 => 2022,
+        }
+    }
+
+    /// Returns the display name of the adjustment (e.g. `"CODATA 2022"`).
+    #[must_use]
+
+    pub const fn name(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Codata2014 => {
+                "CODATA 2014"
+            },
+            | Self::Codata2018 => {
+                "CODATA 2018"
+            },
+            | Self::Codata2022 => {
+                "CODATA 2022"
+            },
+        }
+    }
+
+    /// Returns the edition of the adjustment published in `year`, if it is
+    /// shipped with the crate.
+    #[must_use]
+
+    pub const fn from_year(
+        year: u16
+    ) -> Option<Self> {
+
+        match year {
+            | 2014 => {
+                Some(Self::Codata2014)
+            },
+            | 2018 => {
+                Some(Self::Codata2018)
+            },
+            | 2022 => {
+                Some(Self::Codata2022)
+            },
+            | _ => None,
+        }
+    }
+}
+
+/// The subject group of a constant, following the NIST categories.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum ConstantGroup {
+    /// Universal constants (c, h, G, ...).
+    Universal,
+    /// Electromagnetic constants (e, `K_J`, `R_K`, ...).
+    Electromagnetic,
+    /// Atomic and nuclear constants, including particle properties.
+    AtomicNuclear,
+    /// Physico-chemical constants (`N_A`, k, R, F, ...).
+    PhysicoChemical,
+    /// Adopted values such as conventional electrical units and the standard atmosphere.
+    Adopted,
+    /// Non-SI units accepted for use with the SI, including atomic and natural units.
+    NonSiUnits,
+    /// X-ray and silicon lattice values.
+    XRay,
+    /// Energy-equivalence conversion relationships.
+    Conversion,
+}
+
+/// A registered physical constant together with its metadata.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+)]
+
+pub struct PhysicalConstant {
+    /// The Rust identifier of the constant (e.g. `"SPEED_OF_LIGHT"`).
+    pub id: &'static str,
+    /// The NIST quantity name (e.g. `"speed of light in vacuum"`).
+    pub name: &'static str,
+    /// The conventional symbol (e.g. `"c"`).
+    pub symbol: &'static str,
+    /// The value in the unit given by `unit`.
+    pub value: f64,
+    /// The number of significant digits of the listed value, zero if
+    /// unknown.
+    pub digits: u32,
+    /// The standard uncertainty, zero for exact values.
+    pub uncertainty: f64,
+    /// The relative standard uncertainty, zero for exact values.
+    pub relative_uncertainty: f64,
+    /// The unit, in the notation used by `nist_const!` (e.g. `"m s⁻¹"`).
+    pub unit: &'static str,
+    /// Whether the value is exact by definition.
+    pub exact: bool,
+    /// Whether the exact value is listed truncated (`1.054 571 817...`)
+    /// rather than in full.
+    pub truncated: bool,
+    /// The subject group of the constant.
+    pub group: ConstantGroup,
+    /// The CODATA adjustment the value was taken from.
+    pub edition: CodataEdition,
+}
+
+impl PhysicalConstant {
+    /// Creates a record, deriving the relative uncertainty and the exact flag
+    /// from the standard uncertainty.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+
+    pub const fn new(
+        id: &'static str,
+        name: &'static str,
+        symbol: &'static str,
+        value: f64,
+        uncertainty: f64,
+        unit: &'static str,
+        group: ConstantGroup,
+        edition: CodataEdition,
+    ) -> Self {
+
+        let exact = uncertainty <= 0.0;
+
+        let relative_uncertainty =
+            if exact {
+
+                0.0
+            } else {
+
+                uncertainty
+                    / value.abs()
+            };
+
+        Self {
+            id,
+            name,
+            symbol,
+            value,
+            digits: 0,
+            uncertainty,
+            relative_uncertainty,
+            unit,
+            exact,
+            truncated: false,
+            group,
+            edition,
+        }
+    }
+
+    /// Marks an exact value as listed truncated.
+    #[must_use]
+
+    pub const fn with_truncated(
+        mut self,
+        truncated: bool,
+    ) -> Self {
+
+        self.truncated = truncated;
+
+        self
+    }
+
+    /// Sets the number of significant digits of the listed value.
+    #[must_use]
+
+    pub const fn with_digits(
+        mut self,
+        digits: u32,
+    ) -> Self {
+
+        self.digits = digits;
+
+        self
+    }
+
+    /// Returns the dimension of `unit`, or `None` if the unit notation is
+    /// not recognised (the test suite checks that every registered unit
+    /// is).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::constant;
+    ///
+    /// let g = constant::lookup(
+    ///     "GRAVITATIONAL_CONSTANT",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(
+    ///     g.dimension()
+    ///         .unwrap()
+    ///         .to_string(),
+    ///     "L³ M⁻¹ T⁻²"
+    /// );
+    /// ```
+    #[must_use]
+
+    pub fn dimension(
+        &self
+    ) -> Option<Dimension> {
+
+        unit::parse(self.unit)
+            .ok()
+            .map(|unit| unit.dimension)
+    }
+
+    /// Returns the value as a [`Quantity`] in coherent SI units, or `None`
+    /// if the unit notation is not recognised.
+    #[must_use]
+
+    pub fn quantity(
+        &self
+    ) -> Option<Quantity> {
+
+        Quantity::with_unit(
+            self.value,
+            self.unit,
+        )
+        .ok()
+    }
+
+    /// Returns the value together with its standard uncertainty.
+    #[must_use]
+
+    pub const fn measured(
+        &self
+    ) -> Measured {
+
+        Measured::new(
+            self.value,
+            self.uncertainty,
+        )
+    }
+}
+
+/// Returns every registered constant of the latest CODATA edition.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant;
+///
+/// assert!(
+///     constant::all()
+///         .iter()
+///         .any(|c| c.symbol == "G")
+/// );
+/// ```
+#[must_use]
+
+pub const fn all()
+-> &'static [PhysicalConstant] {
+
+    super::CURRENT_CONSTANTS
+}
+
+/// Looks up a constant by its identifier (e.g. `"GRAVITATIONAL_CONSTANT"`).
+///
+/// The comparison ignores ASCII case.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant;
+///
+/// let g = constant::lookup(
+///     "gravitational_constant",
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     g.uncertainty,
+///     0.000_15e-11
+/// );
+/// ```
+#[must_use]
+
+pub fn lookup(
+    id: &str
+) -> Option<&'static PhysicalConstant> {
+
+    all()
+        .iter()
+        .find(|c| {
+
+            c.id.eq_ignore_ascii_case(
+                id,
+            )
+        })
+}
+
+/// Returns an iterator over the constants belonging to `group`.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant;
+/// use rssn_advanced::constant::ConstantGroup;
+///
+/// assert!(
+///     constant::in_group(
+///         ConstantGroup::Electromagnetic
+///     )
+///     .any(|c| c.id == "JOSEPHSON_CONSTANT")
+/// );
+/// ```
+
+pub fn in_group(
+    group: ConstantGroup
+) -> impl Iterator<
+    Item = &'static PhysicalConstant,
+> {
+
+    all()
+        .iter()
+        .filter(move |c| {
+
+            c.group == group
+        })
+}
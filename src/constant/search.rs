@@ -0,0 +1,740 @@
+//! Fuzzy lookup of constants by name, alias or symbol.
+//!
+//! [`find`] matches a query against the identifier, the NIST quantity name
+//! and the symbol of every registered constant, and against a table of
+//! common aliases (`"mu0"`, `"hbar"`, `"boltzmann"`, ...). Both sides are
+//! first normalized: case is folded, Latin diacritics are stripped, Greek
+//! letters are spelled out (`μ` becomes `mu`), sub- and superscripts become
+//! plain digits and everything else that is not a letter or a digit is
+//! dropped, so that `"a_0"`, `"a₀"` and `"A0"` all agree. Queries of four
+//! or more characters also tolerate a typo in every four.
+//!
+//! ```
+//! 
+//! use rssn_advanced::constant::search;
+//!
+//! for query in [
+//!     "a_0",
+//!     "bohr radius",
+//!     "Bohr raduis",
+//! ] {
+//!
+//!     assert_eq!(
+//!         search::best(query)
+//!             .unwrap()
+//!             .id,
+//!         "BOHR_RADIUS"
+//!     );
+//! }
+//! ```
+
+use serde::Serialize;
+
+use super::PhysicalConstant;
+use super::all;
+use super::lookup;
+
+/// Common names and spellings of constants, as `(alias, identifier)`.
+
+const ALIASES: &[(&str, &str)] = &[
+    ("c0", "SPEED_OF_LIGHT"),
+    ("speed of light", "SPEED_OF_LIGHT"),
+    ("mu0", "VACUUM_MAGNETIC_PERMEABILITY"),
+    ("magnetic constant", "VACUUM_MAGNETIC_PERMEABILITY"),
+    ("vacuum permeability", "VACUUM_MAGNETIC_PERMEABILITY"),
+    ("permeability of free space", "VACUUM_MAGNETIC_PERMEABILITY"),
+    ("eps0", "VACUUM_ELECTRIC_PERMITTIVITY"),
+    ("electric constant", "VACUUM_ELECTRIC_PERMITTIVITY"),
+    ("vacuum permittivity", "VACUUM_ELECTRIC_PERMITTIVITY"),
+    ("permittivity of free space", "VACUUM_ELECTRIC_PERMITTIVITY"),
+    ("impedance of free space", "CHARACTERISTIC_IMPEDANCE_OF_VACUUM"),
+    ("hbar", "REDUCED_PLANCK_CONSTANT"),
+    ("dirac constant", "REDUCED_PLANCK_CONSTANT"),
+    ("planck", "PLANCK_CONSTANT"),
+    ("k_B", "BOLTZMANN_CONSTANT"),
+    ("boltzmann", "BOLTZMANN_CONSTANT"),
+    ("avogadro", "AVOGADRO_CONSTANT"),
+    ("gas constant", "MOLAR_GAS_CONSTANT"),
+    ("faraday", "FARADAY_CONSTANT"),
+    ("gravitational constant", "GRAVITATIONAL_CONSTANT"),
+    ("newton constant", "GRAVITATIONAL_CONSTANT"),
+    ("big G", "GRAVITATIONAL_CONSTANT"),
+    ("g0", "STANDARD_ACCELERATION_OF_GRAVITY"),
+    ("standard gravity", "STANDARD_ACCELERATION_OF_GRAVITY"),
+    ("electron charge", "ELEMENTARY_CHARGE"),
+    ("sommerfeld constant", "FINE_STRUCTURE_CONSTANT"),
+    ("rydberg", "RYDBERG_CONSTANT"),
+    ("hartree", "HARTREE_ENERGY"),
+    ("stefan-boltzmann", "STEFAN_BOLTZMANN_CONSTANT"),
+    ("sigma_SB", "STEFAN_BOLTZMANN_CONSTANT"),
+    ("wien constant", "WIEN_DISPLACEMENT_CONSTANT"),
+    ("flux quantum", "MAGNETIC_FLUX_QUANTUM"),
+    ("josephson", "JOSEPHSON_CONSTANT"),
+    ("von klitzing", "VON_KLITZING_CONSTANT"),
+    ("amu", "UNIFIED_ATOMIC_MASS_UNIT"),
+    ("dalton", "UNIFIED_ATOMIC_MASS_UNIT"),
+    ("Da", "UNIFIED_ATOMIC_MASS_UNIT"),
+    ("G_F", "FERMI_COUPLING_CONSTANT"),
+    ("fermi constant", "FERMI_COUPLING_CONSTANT"),
+    ("weinberg angle", "WEAK_MIXING_ANGLE"),
+    ("loschmidt", "LOSCHMIDT_CONSTANT_273_15_K_101_325_KPA"),
+];
+
+/// How a query matched a key.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+)]
+
+pub enum MatchKind {
+    /// The query equals the key exactly.
+    Exact,
+    /// The query equals the key after normalization.
+    Normalized,
+    /// The normalized query begins the normalized key.
+    Prefix,
+    /// The normalized query lies within the normalized key.
+    Substring,
+    /// The normalized query is within a few edits of the normalized key.
+    Fuzzy,
+}
+
+/// A constant found by [`find`].
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+)]
+
+pub struct Match {
+    /// The constant.
+    pub constant:
+        &'static PhysicalConstant,
+    /// The identifier, name, symbol or alias that matched best.
+    pub key: &'static str,
+    /// How the query matched `key`.
+    pub kind: MatchKind,
+    /// The score in `(0, 1]`, 1 for an exact match.
+    pub score: f64,
+}
+
+/// Normalizes a query or key for comparison.
+///
+/// Case is folded, diacritics are stripped, Greek letters are spelled out,
+/// sub- and superscript digits become plain digits and all remaining
+/// characters that are not alphanumeric are dropped.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::search::normalize;
+///
+/// assert_eq!(normalize("μ₀"), "mu0");
+///
+/// assert_eq!(
+///     normalize("Ångström"),
+///     "angstrom"
+/// );
+///
+/// assert_eq!(normalize("R_∞"), "rinf");
+/// ```
+#[must_use]
+
+pub fn normalize(text: &str) -> String {
+
+    let mut normalized =
+        String::with_capacity(
+            text.len(),
+        );
+
+    for c in text
+        .chars()
+        .flat_map(char::to_lowercase)
+    {
+
+        match fold(c) {
+            | Some(folded) => {
+
+                normalized
+                    .push_str(folded);
+            },
+            | None if c
+                .is_alphanumeric() =>
+            {
+
+                normalized.push(c);
+            },
+            | None => {},
+        }
+    }
+
+    normalized
+}
+
+/// Returns the ASCII spelling of a lowercase letter or digit outside ASCII,
+/// or `None` if the character is kept or dropped as it is.
+
+const fn fold(
+    c: char
+) -> Option<&'static str> {
+
+    Some(match c {
+        | 'à' | 'á' | 'â' | 'ã'
+        | 'ä' | 'å' | 'ā' | 'ă'
+        | 'ą' => "a",
+        | 'æ' => "ae",
+        | 'ç' | 'ć' | 'č' => "c",
+        | 'ď' | 'đ' => "d",
+        | 'è' | 'é' | 'ê' | 'ë'
+        | 'ē' | 'ė' | 'ę' | 'ě' => {
+            "e"
+        },
+        | 'ğ' => "g",
+        | 'ì' | 'í' | 'î' | 'ï'
+        | 'ī' | 'ı' => "i",
+        | 'ł' => "l",
+        | 'ñ' | 'ń' | 'ň' => "n",
+        | 'ò' | 'ó' | 'ô' | 'õ'
+        | 'ö' | 'ø' | 'ō' | 'ő' => {
+            "o"
+        },
+        | 'œ' => "oe",
+        | 'ř' => "r",
+        | 'ß' => "ss",
+        | 'ś' | 'š' | 'ş' => "s",
+        | 'ť' | 'ţ' => "t",
+        | 'ù' | 'ú' | 'û' | 'ü'
+        | 'ū' | 'ů' | 'ű' => "u",
+        | 'ý' | 'ÿ' => "y",
+        | 'ź' | 'ż' | 'ž' => "z",
+        | 'α' => "alpha",
+        | 'β' => "beta",
+        | 'γ' => "gamma",
+        | 'δ' => "delta",
+        | 'ε' | 'ϵ' => "epsilon",
+        | 'ζ' => "zeta",
+        | 'η' => "eta",
+        | 'θ' | 'ϑ' => "theta",
+        | 'ι' => "iota",
+        | 'κ' => "kappa",
+        | 'λ' => "lambda",
+        | 'μ' | 'µ' => "mu",
+        | 'ν' => "nu",
+        | 'ξ' => "xi",
+        | 'ο' => "omicron",
+        | 'π' => "pi",
+        | 'ρ' => "rho",
+        | 'σ' | 'ς' => "sigma",
+        | 'τ' => "tau",
+        | 'υ' => "upsilon",
+        | 'φ' | 'ϕ' => "phi",
+        | 'χ' => "chi",
+        | 'ψ' => "psi",
+        | 'ω' => "omega",
+        | 'ħ' => "hbar",
+        | 'ƛ' => "lambdabar",
+        | '∞' => "inf",
+        | '⁰' | '₀' => "0",
+        | '¹' | '₁' => "1",
+        | '²' | '₂' => "2",
+        | '³' | '₃' => "3",
+        | '⁴' | '₄' => "4",
+        | '⁵' | '₅' => "5",
+        | '⁶' | '₆' => "6",
+        | '⁷' | '₇' => "7",
+        | '⁸' | '₈' => "8",
+        | '⁹' | '₉' => "9",
+        | _ => return None,
+    })
+}
+
+/// Returns the optimal string alignment distance between `a` and `b`: the
+/// number of insertions, deletions, substitutions and transpositions of
+/// adjacent characters turning one into the other.
+
+fn distance(
+    a: &[char],
+    b: &[char],
+) -> usize {
+
+    let cell =
+        |row: &[usize],
+         column: usize| {
+
+            row.get(column)
+                .copied()
+                .unwrap_or(usize::MAX)
+        };
+
+    let mut before = Vec::new();
+
+    let mut previous: Vec<usize> =
+        (0 ..= b.len()).collect();
+
+    for (i, x) in a.iter().enumerate() {
+
+        let mut current =
+            Vec::with_capacity(
+                previous.len(),
+            );
+
+        current
+            .push(i.saturating_add(1));
+
+        for (j, y) in
+            b.iter().enumerate()
+        {
+
+            let deletion = cell(
+                &previous,
+                j.saturating_add(1),
+            )
+            .saturating_add(1);
+
+            let insertion =
+                cell(&current, j)
+                    .saturating_add(1);
+
+            let substitution =
+                cell(&previous, j)
+                    .saturating_add(
+                        usize::from(
+                            x != y,
+                        ),
+                    );
+
+            let mut best = deletion
+                .min(insertion)
+                .min(substitution);
+
+            if let (Some(i1), Some(j1)) = (
+                i.checked_sub(1),
+                j.checked_sub(1),
+            ) && a.get(i1) == Some(y)
+                && b.get(j1) == Some(x)
+            {
+
+                best = best.min(
+                    cell(&before, j1)
+                        .saturating_add(
+                            1,
+                        ),
+                );
+            }
+
+            current.push(best);
+        }
+
+        before = previous;
+
+        previous = current;
+    }
+
+    previous
+        .last()
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Returns `part / whole` for two character counts.
+
+fn ratio(
+    part: usize,
+    whole: usize,
+) -> f64 {
+
+    let count = |n: usize| {
+
+        f64::from(
+            u32::try_from(n)
+                .unwrap_or(u32::MAX),
+        )
+    };
+
+    count(part) / count(whole)
+}
+
+/// A normalized query.
+
+struct Query<'a> {
+    /// The query as given.
+    raw: &'a str,
+    /// The normalized query.
+    normalized: String,
+    /// The characters of `normalized`.
+    chars: Vec<char>,
+}
+
+impl Query<'_> {
+    /// Scores the query against one key.
+
+    fn score(
+        &self,
+        key: &str,
+    ) -> Option<(MatchKind, f64)> {
+
+        if key == self.raw {
+
+            return Some((
+                MatchKind::Exact,
+                1.0,
+            ));
+        }
+
+        let normalized = normalize(key);
+
+        if normalized.is_empty() {
+
+            return None;
+        }
+
+        if normalized == self.normalized
+        {
+
+            return Some((
+                MatchKind::Normalized,
+                0.9,
+            ));
+        }
+
+        let length = self.chars.len();
+
+        let key_chars: Vec<char> =
+            normalized
+                .chars()
+                .collect();
+
+        let fraction = ratio(
+            length,
+            key_chars.len(),
+        );
+
+        let mut candidates = Vec::new();
+
+        if length >= 3 {
+
+            if normalized.starts_with(
+                &self.normalized,
+            ) {
+
+                candidates.push((
+                    MatchKind::Prefix,
+                    0.2f64.mul_add(
+                        fraction,
+                        0.6,
+                    ),
+                ));
+            } else if normalized
+                .contains(
+                    &self.normalized,
+                )
+            {
+
+                candidates.push((
+                    MatchKind::Substring,
+                    0.2f64.mul_add(
+                        fraction, 0.3,
+                    ),
+                ));
+            }
+        }
+
+        // One edit is tolerated for every four characters of the query.
+        let tolerance = length / 4;
+
+        if tolerance > 0
+            && key_chars
+                .len()
+                .abs_diff(length)
+                <= tolerance
+        {
+
+            let edits = distance(
+                &self.chars,
+                &key_chars,
+            );
+
+            if edits <= tolerance {
+
+                candidates.push((
+                    MatchKind::Fuzzy,
+                    0.8 * (1.0
+                        - ratio(
+                            edits,
+                            length,
+                        )),
+                ));
+            }
+        }
+
+        candidates
+            .into_iter()
+            .max_by(|a, b| {
+
+                a.1.total_cmp(&b.1)
+            })
+    }
+}
+
+/// Returns the keys a constant can be found by: its identifier, name and
+/// symbol and its aliases.
+
+fn keys(
+    constant: &'static PhysicalConstant
+) -> impl Iterator<Item = &'static str>
+{
+
+    [
+        constant.id,
+        constant.name,
+        constant.symbol,
+    ]
+    .into_iter()
+    .chain(
+        ALIASES
+            .iter()
+            .filter(move |(_, id)| {
+
+                *id == constant.id
+            })
+            .map(|(alias, _)| *alias),
+    )
+    .filter(|key| !key.is_empty())
+}
+
+/// Finds the constants matching `query` by identifier, name, symbol or
+/// alias, best match first.
+///
+/// Each constant appears at most once, scored by the key it matches best.
+/// Ties are broken in favour of the shorter identifier, so that the
+/// constant in SI units comes before its variants in other units. The
+/// result is empty if the query has no letters or digits.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::search;
+/// use rssn_advanced::constant::search::MatchKind;
+///
+/// let matches = search::find("ħ");
+///
+/// assert_eq!(
+///     matches[0].constant.id,
+///     "REDUCED_PLANCK_CONSTANT"
+/// );
+///
+/// assert_eq!(
+///     matches[0].kind,
+///     MatchKind::Exact
+/// );
+///
+/// assert_eq!(
+///     search::find("mu0")[0].constant.id,
+///     "VACUUM_MAGNETIC_PERMEABILITY"
+/// );
+/// ```
+#[must_use]
+
+pub fn find(query: &str) -> Vec<Match> {
+
+    let raw = query.trim();
+
+    let normalized = normalize(raw);
+
+    if normalized.is_empty() {
+
+        return Vec::new();
+    }
+
+    let query = Query {
+        raw,
+        chars: normalized
+            .chars()
+            .collect(),
+        normalized,
+    };
+
+    let mut matches: Vec<Match> = all()
+        .iter()
+        .filter_map(|constant| {
+
+            keys(constant)
+                .filter_map(|key| {
+
+                    query.score(key).map(
+                        |(kind, score)| {
+
+                            Match {
+                                constant,
+                                key,
+                                kind,
+                                score,
+                            }
+                        },
+                    )
+                })
+                .max_by(|a, b| {
+
+                    a.score
+                        .total_cmp(
+                            &b.score,
+                        )
+                })
+        })
+        .collect();
+
+    matches.sort_by(|a, b| {
+
+        b.score
+            .total_cmp(&a.score)
+            .then_with(|| {
+
+                a.constant
+                    .id
+                    .len()
+                    .cmp(
+                        &b.constant
+                            .id
+                            .len(),
+                    )
+            })
+            .then_with(|| {
+
+                a.constant
+                    .id
+                    .cmp(b.constant.id)
+            })
+    });
+
+    matches
+}
+
+/// Returns the constant matching `query` best, if any.
+///
+/// An identifier is looked up directly, as by [`lookup`]; any other query
+/// takes the first result of [`find`].
+#[must_use]
+
+pub fn best(
+    query: &str
+) -> Option<&'static PhysicalConstant> {
+
+    lookup(query.trim()).or_else(|| {
+
+        find(query)
+            .first()
+            .map(|m| m.constant)
+    })
+}
+
+/// The lowest score of a partial match [`resolve`] accepts.
+
+const RESOLVE_MIN_SCORE: f64 = 0.7;
+
+/// How far a partial match accepted by [`resolve`] must score above the
+/// runner-up.
+
+const RESOLVE_MARGIN: f64 = 0.05;
+
+/// Returns the constant `query` names unambiguously.
+///
+/// An identifier, or an exact or normalized match of a name, symbol or
+/// alias, is accepted as by [`best`]. A partial match is accepted only if
+/// it scores at least 0.7 and clearly above any other constant, so that
+/// `"mass"` does not pick one of the dozens of masses.
+///
+/// # Errors
+///
+/// Returns the candidates, best first, if the query is ambiguous; none if
+/// it matches no constant.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::constant::search;
+///
+/// assert_eq!(
+///     search::resolve("bohr radius")
+///         .unwrap()
+///         .id,
+///     "BOHR_RADIUS"
+/// );
+///
+/// assert!(
+///     search::resolve("mass")
+///         .unwrap_err()
+///         .len()
+///         > 1
+/// );
+/// ```
+
+pub fn resolve(
+    query: &str
+) -> Result<
+    &'static PhysicalConstant,
+    Vec<Match>,
+> {
+
+    if let Some(constant) =
+        lookup(query.trim())
+    {
+
+        return Ok(constant);
+    }
+
+    let matches = find(query);
+
+    let clear = match matches.as_slice()
+    {
+        | [] => false,
+        | [top, ..]
+            if matches!(
+                top.kind,
+                MatchKind::Exact
+                    | MatchKind::Normalized
+            ) =>
+        {
+            true
+        },
+        | [top] => {
+            top.score
+                >= RESOLVE_MIN_SCORE
+        },
+        | [top, second, ..] => {
+
+            top.score
+                >= RESOLVE_MIN_SCORE
+                && second.score
+                    + RESOLVE_MARGIN
+                    <= top.score
+        },
+    };
+
+    match matches.first() {
+        | Some(top) if clear => {
+            Ok(top.constant)
+        },
+        | _ => Err(matches),
+    }
+}
+
+/// Returns the alias table as `(alias, identifier)` pairs.
+#[must_use]
+
+pub const fn aliases() -> &'static [(
+    &'static str,
+    &'static str,
+)] {
+
+    ALIASES
+}
@@ -0,0 +1,1793 @@
+//! Physical constants as symbols of rssn expressions.
+//!
+//! A formula is built with the constants kept as named atoms: every
+//! constant of the table is an rssn [`Expr::Variable`] named after its
+//! identifier, so `ħ` is the variable `REDUCED_PLANCK_CONSTANT` and `m_e`
+//! is `ELECTRON_MASS`. [`constant`] also resolves the listed symbols.
+//!
+//! Values enter only at the end. [`substitute`] replaces the constants of
+//! an expression by their values in a chosen CODATA edition, and
+//! [`evaluate`] computes the value of the expression together with its
+//! standard uncertainty, propagated from the correlated constants.
+//!
+//! Before that, [`simplify`] rewrites the constants tied to others by a
+//! defining relation (`K_J = 2e/h`, `R_K = h/e²`, `ħ = h/2π`,
+//! `μ₀ = 2αh/e²c`, `a₀ = ħ/αm_e c`, ...) and collects the resulting power
+//! products, so that `R_K K_J²` becomes `4/h`. The same relations are
+//! available as rssn rewrite rules through [`rules`].
+//!
+//! # Examples
+//!
+//! ```
+//! 
+//! use rssn::symbolic::core::Expr;
+//! use rssn_advanced::constant::symbolic;
+//!
+//! let r_k = symbolic::constant("R_K").unwrap();
+//!
+//! let k_j = symbolic::constant("K_J").unwrap();
+//!
+//! let h = symbolic::constant("h").unwrap();
+//!
+//! // R_K K_J² h = 4
+//! let product = Expr::new_mul(
+//!     Expr::new_mul(
+//!         r_k,
+//!         Expr::new_pow(
+//!             k_j,
+//!             Expr::new_constant(2.0),
+//!         ),
+//!     ),
+//!     h,
+//! );
+//!
+//! assert_eq!(
+//!     symbolic::simplify(&product),
+//!     Expr::BigInt(4.into())
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::One;
+use num_traits::Pow;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+use rssn::symbolic::calculus;
+use rssn::symbolic::core::Expr;
+use rssn::symbolic::rewriting::RewriteRule;
+
+use super::CodataEdition;
+use super::Dual;
+use super::Measured;
+use super::PhysicalConstant;
+use super::correlation;
+use super::edition;
+use super::propagation;
+
+/// A constant expressed as a power product of other constants.
+
+struct Relation {
+    /// The identifier of the constant.
+    id: &'static str,
+    /// The rational coefficient, as numerator and denominator.
+    coefficient: (i64, i64),
+    /// The power of π.
+    pi_power: i32,
+    /// The other constants and their exponents.
+    factors: &'static [(
+        &'static str,
+        i32,
+    )],
+}
+
+const H: &str = "PLANCK_CONSTANT";
+
+const E: &str = "ELEMENTARY_CHARGE";
+
+const C: &str = "SPEED_OF_LIGHT";
+
+const K: &str = "BOLTZMANN_CONSTANT";
+
+const N_A: &str = "AVOGADRO_CONSTANT";
+
+const ALPHA: &str =
+    "FINE_STRUCTURE_CONSTANT";
+
+const M_E: &str = "ELECTRON_MASS";
+
+const M_P: &str = "PROTON_MASS_KG";
+
+const M_U: &str =
+    "ATOMIC_MASS_CONSTANT";
+
+/// The defining relations, in terms of `h`, `e`, `c`, `k`, `N_A`, `α`,
+/// `m_e`, `m_p` and `m_u`.
+
+static RELATIONS: &[Relation] = &[
+    Relation {
+        id: "REDUCED_PLANCK_CONSTANT",
+        coefficient: (1, 2),
+        pi_power: -1,
+        factors: &[(H, 1)],
+    },
+    Relation {
+        id: "ELECTRON_VOLT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(E, 1)],
+    },
+    Relation {
+        id: "JOSEPHSON_CONSTANT",
+        coefficient: (2, 1),
+        pi_power: 0,
+        factors: &[(E, 1), (H, -1)],
+    },
+    Relation {
+        id: "VON_KLITZING_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(H, 1), (E, -2)],
+    },
+    Relation {
+        id: "MAGNETIC_FLUX_QUANTUM",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(H, 1), (E, -1)],
+    },
+    Relation {
+        id: "CONDUCTANCE_QUANTUM",
+        coefficient: (2, 1),
+        pi_power: 0,
+        factors: &[(E, 2), (H, -1)],
+    },
+    Relation {
+        id: "INVERSE_OF_CONDUCTANCE_QUANTUM",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(H, 1), (E, -2)],
+    },
+    Relation {
+        id: "ELEMENTARY_CHARGE_OVER_HBAR",
+        coefficient: (2, 1),
+        pi_power: 1,
+        factors: &[(E, 1), (H, -1)],
+    },
+    Relation {
+        id: "FARADAY_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(N_A, 1), (E, 1)],
+    },
+    Relation {
+        id: "MOLAR_GAS_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(N_A, 1), (K, 1)],
+    },
+    Relation {
+        id: "MOLAR_PLANCK_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(N_A, 1), (H, 1)],
+    },
+    Relation {
+        id: "FIRST_RADIATION_CONSTANT",
+        coefficient: (2, 1),
+        pi_power: 1,
+        factors: &[(H, 1), (C, 2)],
+    },
+    Relation {
+        id: "FIRST_RADIATION_CONSTANT_FOR_SPECTRAL_RADIANCE",
+        coefficient: (2, 1),
+        pi_power: 0,
+        factors: &[(H, 1), (C, 2)],
+    },
+    Relation {
+        id: "SECOND_RADIATION_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(H, 1), (C, 1), (K, -1)],
+    },
+    Relation {
+        id: "STEFAN_BOLTZMANN_CONSTANT",
+        coefficient: (2, 15),
+        pi_power: 5,
+        factors: &[(K, 4), (H, -3), (C, -2)],
+    },
+    Relation {
+        id: "VACUUM_MAGNETIC_PERMEABILITY",
+        coefficient: (2, 1),
+        pi_power: 0,
+        factors: &[(ALPHA, 1), (H, 1), (E, -2), (C, -1)],
+    },
+    Relation {
+        id: "VACUUM_ELECTRIC_PERMITTIVITY",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(E, 2), (ALPHA, -1), (H, -1), (C, -1)],
+    },
+    Relation {
+        id: "CHARACTERISTIC_IMPEDANCE_OF_VACUUM",
+        coefficient: (2, 1),
+        pi_power: 0,
+        factors: &[(ALPHA, 1), (H, 1), (E, -2)],
+    },
+    Relation {
+        id: "INVERSE_FINE_STRUCTURE_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(ALPHA, -1)],
+    },
+    Relation {
+        id: "HARTREE_ENERGY",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(ALPHA, 2), (M_E, 1), (C, 2)],
+    },
+    Relation {
+        id: "RYDBERG_CONSTANT",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(ALPHA, 2), (M_E, 1), (C, 1), (H, -1)],
+    },
+    Relation {
+        id: "RYDBERG_CONSTANT_TIMES_C_IN_HZ",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(ALPHA, 2), (M_E, 1), (C, 2), (H, -1)],
+    },
+    Relation {
+        id: "RYDBERG_CONSTANT_TIMES_HC_IN_J",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(ALPHA, 2), (M_E, 1), (C, 2)],
+    },
+    Relation {
+        id: "BOHR_RADIUS",
+        coefficient: (1, 2),
+        pi_power: -1,
+        factors: &[(H, 1), (ALPHA, -1), (M_E, -1), (C, -1)],
+    },
+    Relation {
+        id: "CLASSICAL_ELECTRON_RADIUS",
+        coefficient: (1, 2),
+        pi_power: -1,
+        factors: &[(ALPHA, 1), (H, 1), (M_E, -1), (C, -1)],
+    },
+    Relation {
+        id: "THOMSON_CROSS_SECTION",
+        coefficient: (2, 3),
+        pi_power: -1,
+        factors: &[(ALPHA, 2), (H, 2), (M_E, -2), (C, -2)],
+    },
+    Relation {
+        id: "COMPTON_WAVELENGTH",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(H, 1), (M_E, -1), (C, -1)],
+    },
+    Relation {
+        id: "REDUCED_COMPTON_WAVELENGTH",
+        coefficient: (1, 2),
+        pi_power: -1,
+        factors: &[(H, 1), (M_E, -1), (C, -1)],
+    },
+    Relation {
+        id: "QUANTUM_OF_CIRCULATION",
+        coefficient: (1, 2),
+        pi_power: 0,
+        factors: &[(H, 1), (M_E, -1)],
+    },
+    Relation {
+        id: "QUANTUM_OF_CIRCULATION_TIMES_2",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(H, 1), (M_E, -1)],
+    },
+    Relation {
+        id: "BOHR_MAGNETON",
+        coefficient: (1, 4),
+        pi_power: -1,
+        factors: &[(E, 1), (H, 1), (M_E, -1)],
+    },
+    Relation {
+        id: "NUCLEAR_MAGNETON",
+        coefficient: (1, 4),
+        pi_power: -1,
+        factors: &[(E, 1), (H, 1), (M_P, -1)],
+    },
+    Relation {
+        id: "ELECTRON_MASS_ENERGY_EQUIVALENT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(M_E, 1), (C, 2)],
+    },
+    Relation {
+        id: "PROTON_MASS_ENERGY_EQUIVALENT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(M_P, 1), (C, 2)],
+    },
+    Relation {
+        id: "ATOMIC_MASS_CONSTANT_ENERGY_EQUIVALENT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(M_U, 1), (C, 2)],
+    },
+    Relation {
+        id: "MOLAR_MASS_CONSTANT",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(N_A, 1), (M_U, 1)],
+    },
+    Relation {
+        id: "PROTON_ELECTRON_MASS_RATIO",
+        coefficient: (1, 1),
+        pi_power: 0,
+        factors: &[(M_P, 1), (M_E, -1)],
+    },
+];
+
+/// Returns the constant with identifier `id` in any shipped edition,
+/// comparing exactly.
+
+fn record(
+    id: &str
+) -> Option<&'static PhysicalConstant> {
+
+    CodataEdition::ALL
+        .iter()
+        .rev()
+        .find_map(|&edition| {
+
+            edition::constants(edition)
+                .iter()
+                .find(|c| c.id == id)
+        })
+}
+
+impl PhysicalConstant {
+    /// Returns the constant as an rssn symbol, the variable named after
+    /// its identifier.
+    #[must_use]
+
+    pub fn to_expr(&self) -> Expr {
+
+        Expr::new_variable(self.id)
+    }
+}
+
+/// Returns the symbol of the constant with identifier or listed symbol
+/// `key`, or `None` if there is no such constant.
+///
+/// Identifiers are compared ignoring ASCII case. A symbol shared by
+/// several entries (`h` is also the Planck constant in eV/Hz) resolves to
+/// the first one in listing order, which is the entry in SI units.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::symbolic::core::Expr;
+/// use rssn_advanced::constant::symbolic;
+///
+/// assert_eq!(
+///     symbolic::constant("ħ"),
+///     Some(Expr::new_variable(
+///         "REDUCED_PLANCK_CONSTANT"
+///     ))
+/// );
+///
+/// assert_eq!(
+///     symbolic::constant("m_e"),
+///     symbolic::constant("electron_mass")
+/// );
+/// ```
+#[must_use]
+
+pub fn constant(
+    key: &str
+) -> Option<Expr> {
+
+    super::lookup(key)
+        .or_else(|| {
+
+            super::all()
+                .iter()
+                .find(|c| {
+
+                    c.symbol == key
+                })
+        })
+        .map(PhysicalConstant::to_expr)
+}
+
+/// Returns the symbol of every constant of the current edition, with its
+/// identifier.
+#[must_use]
+
+pub fn symbols()
+-> Vec<(&'static str, Expr)> {
+
+    super::all()
+        .iter()
+        .map(|c| (c.id, c.to_expr()))
+        .collect()
+}
+
+/// Returns `true` if `name` is the identifier of a constant, i.e. if the
+/// variable `name` stands for a constant.
+#[must_use]
+
+pub fn is_constant(name: &str) -> bool {
+
+    record(name).is_some()
+}
+
+/// Returns the identifiers of the constants occurring in `expr`, sorted
+/// and without repetitions.
+#[must_use]
+
+pub fn constants_in(
+    expr: &Expr
+) -> Vec<&'static str> {
+
+    let mut ids = Vec::new();
+
+    ast(expr).pre_order_walk(
+        &mut |node| {
+
+            if let Expr::Variable(name) =
+                node
+                && let Some(c) =
+                    record(name)
+            {
+
+                ids.push(c.id);
+            }
+        },
+    );
+
+    ids.sort_unstable();
+
+    ids.dedup();
+
+    ids
+}
+
+/// Replaces every constant of `expr` by its value in `edition`.
+///
+/// Constants the edition does not list, and all other variables, are
+/// kept.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::symbolic::core::Expr;
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::symbolic;
+///
+/// // m_e c² with the 2014 electron mass.
+/// let energy = Expr::new_mul(
+///     symbolic::constant("m_e").unwrap(),
+///     Expr::new_pow(
+///         symbolic::constant("c").unwrap(),
+///         Expr::new_constant(2.0),
+///     ),
+/// );
+///
+/// let value = symbolic::substitute(
+///     &energy,
+///     CodataEdition::Codata2014,
+/// );
+///
+/// assert!(symbolic::constants_in(&value).is_empty());
+/// ```
+#[must_use]
+
+pub fn substitute(
+    expr: &Expr,
+    edition: CodataEdition,
+) -> Expr {
+
+    replace_constants(
+        &ast(expr),
+        edition,
+    )
+}
+
+/// Replaces the constants of the tree `expr` by their values in
+/// `edition`.
+///
+/// Arithmetic and the functions [`evaluate`] supports are rebuilt here
+/// rather than by `calculus::substitute`, which caches rewritten nodes by
+/// `Expr`. That equality takes constants within `f64::EPSILON` of each
+/// other as equal, so the values of small constants such as `h` and `k`
+/// could be swapped.
+
+fn replace_constants(
+    expr: &Expr,
+    edition: CodataEdition,
+) -> Expr {
+
+    let arc = |a: &Expr| {
+
+        Arc::new(replace_constants(
+            a,
+            edition,
+        ))
+    };
+
+    let all = |terms: &[Expr]| {
+
+        terms
+            .iter()
+            .map(|term| {
+
+                replace_constants(
+                    term,
+                    edition,
+                )
+            })
+            .collect()
+    };
+
+    match expr {
+        | Expr::Variable(name) => {
+            record(name)
+                .and_then(|c| {
+
+                    edition::get(
+                        edition, c.id,
+                    )
+                })
+                .map_or_else(
+                    || expr.clone(),
+                    |c| {
+
+                        Expr::Constant(
+                            c.value,
+                        )
+                    },
+                )
+        },
+        | Expr::Add(a, b) => {
+            Expr::Add(arc(a), arc(b))
+        },
+        | Expr::Sub(a, b) => {
+            Expr::Sub(arc(a), arc(b))
+        },
+        | Expr::Mul(a, b) => {
+            Expr::Mul(arc(a), arc(b))
+        },
+        | Expr::Div(a, b) => {
+            Expr::Div(arc(a), arc(b))
+        },
+        | Expr::Power(a, b) => {
+            Expr::Power(arc(a), arc(b))
+        },
+        | Expr::Neg(a) => {
+            Expr::Neg(arc(a))
+        },
+        | Expr::Sqrt(a) => {
+            Expr::Sqrt(arc(a))
+        },
+        | Expr::Exp(a) => {
+            Expr::Exp(arc(a))
+        },
+        | Expr::Log(a) => {
+            Expr::Log(arc(a))
+        },
+        | Expr::Sin(a) => {
+            Expr::Sin(arc(a))
+        },
+        | Expr::Cos(a) => {
+            Expr::Cos(arc(a))
+        },
+        | Expr::Tan(a) => {
+            Expr::Tan(arc(a))
+        },
+        | Expr::AddList(terms) => {
+            Expr::AddList(all(terms))
+        },
+        | Expr::MulList(factors) => {
+            Expr::MulList(all(factors))
+        },
+        | _ => {
+            constants_in(expr)
+                .into_iter()
+                .filter_map(|id| {
+
+                    edition::get(
+                        edition, id,
+                    )
+                })
+                .fold(
+                    expr.clone(),
+                    |expr, c| {
+
+                        calculus::substitute(
+                            &expr,
+                            c.id,
+                            &Expr::Constant(
+                                c.value,
+                            ),
+                        )
+                    },
+                )
+        },
+    }
+}
+
+/// Evaluates `expr` with the values of `edition`, propagating the
+/// uncertainties of its constants.
+///
+/// The constants are correlated as in the adjustment of `edition` (see
+/// [`correlation::correlation_in`]). Returns `None` if `expr` contains a
+/// variable that is not a constant of `edition`, two measured constants
+/// of an edition without correlations, or an operation other than
+/// arithmetic, powers, square roots, `exp`, `log`, `sin`, `cos` and
+/// `tan`.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::symbolic::core::Expr;
+/// use rssn_advanced::constant::CodataEdition;
+/// use rssn_advanced::constant::symbolic;
+///
+/// // The Hartree energy from α, m_e and c.
+/// let hartree = Expr::new_mul(
+///     Expr::new_pow(
+///         symbolic::constant("α").unwrap(),
+///         Expr::new_constant(2.0),
+///     ),
+///     Expr::new_mul(
+///         symbolic::constant("m_e").unwrap(),
+///         Expr::new_pow(
+///             symbolic::constant("c").unwrap(),
+///             Expr::new_constant(2.0),
+///         ),
+///     ),
+/// );
+///
+/// let value = symbolic::evaluate(
+///     &hartree,
+///     CodataEdition::Codata2022,
+/// )
+/// .unwrap();
+///
+/// assert!(
+///     (value.value / 4.359_744_722_2e-18 - 1.0).abs()
+///         < 1e-10
+/// );
+///
+/// assert!(value.relative_uncertainty() < 1e-11);
+/// ```
+#[must_use]
+
+pub fn evaluate(
+    expr: &Expr,
+    edition: CodataEdition,
+) -> Option<Measured> {
+
+    let expr = ast(expr);
+
+    let records = constants_in(&expr)
+        .into_iter()
+        .map(|id| {
+
+            edition::get(edition, id)
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let values: Vec<f64> = records
+        .iter()
+        .map(|c| c.value)
+        .collect();
+
+    let covariance: Vec<Vec<f64>> =
+        records
+            .iter()
+            .map(|a| {
+
+                records
+                    .iter()
+                    .map(|b| {
+
+                        correlation::correlation_in(
+                            edition, a.id, b.id,
+                        )
+                        .map(|rho| {
+
+                            rho * a.uncertainty
+                                * b.uncertainty
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Option<_>>()?;
+
+    let bind = |x: &[Dual]| {
+
+        dual(
+            &expr,
+            &|name: &str| {
+
+                records
+                    .iter()
+                    .position(|c| {
+
+                        c.id == name
+                    })
+                    .and_then(|i| {
+
+                        x.get(i)
+                    })
+                    .copied()
+            },
+        )
+    };
+
+    let constants: Vec<Dual> = values
+        .iter()
+        .map(|&v| Dual::constant(v))
+        .collect();
+
+    bind(&constants)?;
+
+    propagation::propagate_with_covariance(
+        &values,
+        &covariance,
+        |x| {
+
+            bind(x).unwrap_or(Dual::constant(
+                    f64::NAN,
+                ))
+        },
+    )
+}
+
+/// Returns the defining relation of the constant with identifier `id`,
+/// e.g. `2 e/h` for the Josephson constant, or `None` if the constant is
+/// not tied to others by one.
+#[must_use]
+
+pub fn relation(
+    id: &str
+) -> Option<Expr> {
+
+    RELATIONS
+        .iter()
+        .find(|r| r.id == id)
+        .map(|r| {
+
+            rebuild(&[Term::relation(
+                r,
+            )])
+        })
+}
+
+/// Returns the defining relations as rssn rewrite rules, each replacing a
+/// constant by its expression in terms of `h`, `e`, `c`, `k`, `N_A`, `α`,
+/// `m_e`, `m_p` and `m_u`.
+#[must_use]
+
+pub fn rules() -> Vec<RewriteRule> {
+
+    RELATIONS
+        .iter()
+        .map(|r| {
+
+            RewriteRule {
+                lhs: Expr::Variable(
+                    r.id.to_string(),
+                ),
+                rhs: rebuild(&[
+                    Term::relation(r),
+                ]),
+            }
+        })
+        .collect()
+}
+
+/// Rewrites the constants of `expr` with their defining relations and
+/// collects like factors and like terms.
+///
+/// Each term of the result is a rational coefficient times a power of π
+/// times a power product of the remaining factors.
+///
+/// Functions such as `sqrt` or `exp` are simplified inside but otherwise
+/// kept, and a non-integer exponent keeps its power unexpanded.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn::symbolic::core::Expr;
+/// use rssn_advanced::constant::symbolic;
+///
+/// // ħ - h/2π = 0
+/// let h = symbolic::constant("h").unwrap();
+///
+/// let difference = Expr::new_sub(
+///     symbolic::constant("ħ").unwrap(),
+///     Expr::new_div(
+///         h,
+///         Expr::new_mul(
+///             Expr::new_constant(2.0),
+///             Expr::new_pi(),
+///         ),
+///     ),
+/// );
+///
+/// assert_eq!(
+///     symbolic::simplify(&difference),
+///     Expr::BigInt(0.into())
+/// );
+/// ```
+#[must_use]
+
+pub fn simplify(expr: &Expr) -> Expr {
+
+    rebuild(&expand(&ast(expr)))
+}
+
+/// Returns the power product `coefficient π^pi_power ∏ base^exponent` in
+/// the form [`simplify`] gives its terms.
+
+pub(crate) fn monomial(
+    coefficient: BigRational,
+    pi_power: i32,
+    factors: impl IntoIterator<
+        Item = (Expr, i32),
+    >,
+) -> Expr {
+
+    let mut term = Term::number(
+        Coefficient::Exact(coefficient),
+    );
+
+    term.pi_power = pi_power;
+
+    for (base, exponent) in factors {
+
+        term = term.mul(&Term::factor(
+            base,
+            exponent,
+        ));
+    }
+
+    rebuild(&[term])
+}
+
+/// Converts `expr` to a tree without DAG nodes.
+
+fn ast(expr: &Expr) -> Expr {
+
+    expr.to_ast()
+        .unwrap_or_else(|_| {
+
+            expr.clone()
+        })
+}
+
+/// Evaluates `expr` on dual numbers, with `value` giving the value of
+/// each variable.
+
+fn dual(
+    expr: &Expr,
+    value: &dyn Fn(
+        &str,
+    ) -> Option<Dual>,
+) -> Option<Dual> {
+
+    let unary =
+        |a: &Expr| dual(a, value);
+
+    Some(match expr {
+        | Expr::Dag(_) => {
+            dual(&ast(expr), value)?
+        },
+        | Expr::Constant(x) => {
+            Dual::constant(*x)
+        },
+        | Expr::BigInt(n) => {
+            Dual::constant(n.to_f64()?)
+        },
+        | Expr::Rational(q) => {
+            Dual::constant(q.to_f64()?)
+        },
+        | Expr::Pi => {
+            Dual::constant(
+                std::f64::consts::PI,
+            )
+        },
+        | Expr::E => {
+            Dual::constant(
+                std::f64::consts::E,
+            )
+        },
+        | Expr::Variable(name) => {
+            value(name)?
+        },
+        | Expr::Add(a, b) => {
+            unary(a)? + unary(b)?
+        },
+        | Expr::Sub(a, b) => {
+            unary(a)? - unary(b)?
+        },
+        | Expr::Mul(a, b) => {
+            unary(a)? * unary(b)?
+        },
+        | Expr::Div(a, b) => {
+            unary(a)? / unary(b)?
+        },
+        | Expr::Neg(a) => -unary(a)?,
+        | Expr::AddList(terms) => {
+            terms
+                .iter()
+                .try_fold(
+                    Dual::constant(0.0),
+                    |sum, term| {
+
+                        Some(
+                        sum + unary(
+                            term,
+                        )?,
+                    )
+                    },
+                )?
+        },
+        | Expr::MulList(factors) => {
+            factors
+                .iter()
+                .try_fold(
+                Dual::constant(1.0),
+                |product, factor| {
+
+                    Some(
+                        product
+                            * unary(
+                                factor,
+                            )?,
+                    )
+                },
+            )?
+        },
+        | Expr::Power(
+            base,
+            exponent,
+        ) => {
+
+            let base = unary(base)?;
+
+            let exponent =
+                unary(exponent)?;
+
+            if exponent.derivative
+                == 0.0
+            {
+
+                base.powf(
+                    exponent.value,
+                )
+            } else {
+
+                (base.ln() * exponent)
+                    .exp()
+            }
+        },
+        | Expr::Sqrt(a) => {
+            unary(a)?.sqrt()
+        },
+        | Expr::Exp(a) => {
+            unary(a)?.exp()
+        },
+        | Expr::Log(a) => {
+            unary(a)?.ln()
+        },
+        | Expr::Sin(a) => {
+            unary(a)?.sin()
+        },
+        | Expr::Cos(a) => {
+            unary(a)?.cos()
+        },
+        | Expr::Tan(a) => {
+
+            let a = unary(a)?;
+
+            a.sin() / a.cos()
+        },
+        | _ => return None,
+    })
+}
+
+/// A numeric coefficient: exact while only rationals are involved.
+
+#[derive(Clone)]
+
+enum Coefficient {
+    Exact(BigRational),
+    Float(f64),
+}
+
+impl Coefficient {
+    fn one() -> Self {
+
+        Self::Exact(BigRational::one())
+    }
+
+    fn from_f64(x: f64) -> Self {
+
+        if x.is_finite()
+            && x.fract() == 0.0
+            && let Some(q) =
+                BigRational::from_float(
+                    x,
+                )
+        {
+
+            Self::Exact(q)
+        } else {
+
+            Self::Float(x)
+        }
+    }
+
+    fn to_f64(&self) -> f64 {
+
+        match self {
+            | Self::Exact(q) => {
+                q.to_f64()
+                    .unwrap_or(f64::NAN)
+            },
+            | Self::Float(x) => *x,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+
+        match self {
+            | Self::Exact(q) => {
+                q.is_zero()
+            },
+            | Self::Float(x) => {
+                *x == 0.0
+            },
+        }
+    }
+
+    fn is_negative(&self) -> bool {
+
+        match self {
+            | Self::Exact(q) => {
+                q.is_negative()
+            },
+            | Self::Float(x) => {
+                *x < 0.0
+            },
+        }
+    }
+
+    fn mul(
+        &self,
+        other: &Self,
+    ) -> Self {
+
+        match (self, other) {
+            | (
+                Self::Exact(a),
+                Self::Exact(b),
+            ) => Self::Exact(a * b),
+            | _ => {
+                Self::Float(
+                    self.to_f64()
+                        * other
+                            .to_f64(),
+                )
+            },
+        }
+    }
+
+    fn add(
+        &self,
+        other: &Self,
+    ) -> Self {
+
+        match (self, other) {
+            | (
+                Self::Exact(a),
+                Self::Exact(b),
+            ) => Self::Exact(a + b),
+            | _ => {
+                Self::Float(
+                    self.to_f64()
+                        + other
+                            .to_f64(),
+                )
+            },
+        }
+    }
+
+    fn powi(
+        &self,
+        n: i32,
+    ) -> Option<Self> {
+
+        if n < 0 && self.is_zero() {
+
+            return None;
+        }
+
+        Some(match self {
+            | Self::Exact(q) => {
+                Self::Exact(Pow::pow(
+                    q, n,
+                ))
+            },
+            | Self::Float(x) => {
+                Self::Float(x.powi(n))
+            },
+        })
+    }
+}
+
+/// A coefficient times a power of π times a power product of other
+/// factors.
+
+#[derive(Clone)]
+
+struct Term {
+    coefficient: Coefficient,
+    pi_power: i32,
+    factors: BTreeMap<Expr, i32>,
+}
+
+impl Term {
+    const fn number(
+        coefficient: Coefficient
+    ) -> Self {
+
+        Self {
+            coefficient,
+            pi_power: 0,
+            factors: BTreeMap::new(),
+        }
+    }
+
+    fn factor(
+        base: Expr,
+        exponent: i32,
+    ) -> Self {
+
+        let mut term = Self::number(
+            Coefficient::one(),
+        );
+
+        if exponent != 0 {
+
+            term.factors
+                .insert(base, exponent);
+        }
+
+        term
+    }
+
+    fn relation(
+        relation: &Relation
+    ) -> Self {
+
+        let (numerator, denominator) =
+            relation.coefficient;
+
+        Self {
+            coefficient: Coefficient::Exact(
+                BigRational::new(
+                    numerator.into(),
+                    denominator.into(),
+                ),
+            ),
+            pi_power: relation.pi_power,
+            factors: relation
+                .factors
+                .iter()
+                .map(|&(id, exponent)| {
+
+                    (
+                        Expr::Variable(
+                            id.to_string(),
+                        ),
+                        exponent,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn mul(
+        &self,
+        other: &Self,
+    ) -> Self {
+
+        let mut factors =
+            self.factors.clone();
+
+        for (base, &exponent) in
+            &other.factors
+        {
+
+            let entry = factors
+                .entry(base.clone())
+                .or_insert(0);
+
+            *entry = entry
+                .saturating_add(
+                    exponent,
+                );
+        }
+
+        factors.retain(
+            |_, exponent| {
+
+                *exponent != 0
+            },
+        );
+
+        Self {
+            coefficient: self
+                .coefficient
+                .mul(
+                    &other.coefficient,
+                ),
+            pi_power: self
+                .pi_power
+                .saturating_add(
+                    other.pi_power,
+                ),
+            factors,
+        }
+    }
+
+    fn powi(
+        &self,
+        n: i32,
+    ) -> Option<Self> {
+
+        Some(Self {
+            coefficient: self
+                .coefficient
+                .powi(n)?,
+            pi_power: self
+                .pi_power
+                .saturating_mul(n),
+            factors: self
+                .factors
+                .iter()
+                .filter(|_| n != 0)
+                .map(|(base, exponent)| {
+
+                    (
+                        base.clone(),
+                        exponent
+                            .saturating_mul(n),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    fn negate(&self) -> Self {
+
+        self.mul(&Self::number(
+            Coefficient::Exact(
+                -BigRational::one(),
+            ),
+        ))
+    }
+
+    /// Returns whether `self` and `other` differ only in their
+    /// coefficient.
+
+    fn is_like(
+        &self,
+        other: &Self,
+    ) -> bool {
+
+        self.pi_power == other.pi_power
+            && self.factors
+                == other.factors
+    }
+}
+
+/// Expands `expr` into a sum of terms.
+
+fn expand(expr: &Expr) -> Vec<Term> {
+
+    let single =
+        |term: Term| vec![term];
+
+    match expr {
+        | Expr::Dag(_) => {
+            expand(&ast(expr))
+        },
+        | Expr::Constant(x) => {
+            single(Term::number(
+                Coefficient::from_f64(*x),
+            ))
+        },
+        | Expr::BigInt(n) => {
+            single(Term::number(
+                Coefficient::Exact(
+                    BigRational::from_integer(
+                        n.clone(),
+                    ),
+                ),
+            ))
+        },
+        | Expr::Rational(q) => {
+            single(Term::number(
+                Coefficient::Exact(
+                    q.clone(),
+                ),
+            ))
+        },
+        | Expr::Pi => {
+            single(Term {
+                pi_power: 1,
+                ..Term::number(
+                    Coefficient::one(),
+                )
+            })
+        },
+        | Expr::Variable(name) => {
+            single(
+                RELATIONS
+                    .iter()
+                    .find(|r| r.id == name)
+                    .map_or_else(
+                        || {
+
+                            Term::factor(
+                                expr.clone(),
+                                1,
+                            )
+                        },
+                        Term::relation,
+                    ),
+            )
+        },
+        | Expr::Add(a, b) => {
+            sum([
+                expand(a),
+                expand(b),
+            ])
+        },
+        | Expr::AddList(terms) => {
+            sum(terms.iter().map(expand))
+        },
+        | Expr::Sub(a, b) => {
+            sum([
+                expand(a),
+                expand(b)
+                    .iter()
+                    .map(Term::negate)
+                    .collect(),
+            ])
+        },
+        | Expr::Neg(a) => {
+            expand(a)
+                .iter()
+                .map(Term::negate)
+                .collect()
+        },
+        | Expr::Mul(a, b) => {
+            single(
+                product(&expand(a)).mul(
+                    &product(&expand(b)),
+                ),
+            )
+        },
+        | Expr::MulList(factors) => {
+            single(
+                factors.iter().fold(
+                    Term::number(
+                        Coefficient::one(),
+                    ),
+                    |term, factor| {
+
+                        term.mul(&product(
+                            &expand(factor),
+                        ))
+                    },
+                ),
+            )
+        },
+        | Expr::Div(a, b) => {
+
+            let numerator =
+                product(&expand(a));
+
+            let denominator =
+                product(&expand(b));
+
+            single(
+                denominator
+                    .powi(-1)
+                    .map_or_else(
+                        || {
+
+                            Term::factor(
+                                Expr::Div(
+                                    Arc::new(
+                                        rebuild(std::slice::from_ref(&numerator)),
+                                    ),
+                                    Arc::new(
+                                        rebuild(std::slice::from_ref(&denominator)),
+                                    ),
+                                ),
+                                1,
+                            )
+                        },
+                        |inverse| {
+
+                            numerator
+                                .mul(&inverse)
+                        },
+                    ),
+            )
+        },
+        | Expr::Power(base, exponent) => {
+
+            let base = expand(base);
+
+            let exponent =
+                rebuild(&expand(exponent));
+
+            let integer = match &exponent {
+                | Expr::BigInt(n) => {
+                    n.to_i32()
+                },
+                | _ => None,
+            };
+
+            single(
+                integer
+                    .filter(|_| {
+
+                        base.len() == 1
+                    })
+                    .and_then(|n| {
+
+                        product(&base)
+                            .powi(n)
+                    })
+                    .unwrap_or_else(|| {
+
+                        Term::factor(
+                            Expr::Power(
+                                Arc::new(
+                                    rebuild(
+                                        &base,
+                                    ),
+                                ),
+                                Arc::new(
+                                    exponent,
+                                ),
+                            ),
+                            1,
+                        )
+                    }),
+            )
+        },
+        | Expr::Sqrt(a) => {
+            single(Term::factor(
+                Expr::Sqrt(Arc::new(
+                    simplify(a),
+                )),
+                1,
+            ))
+        },
+        | Expr::Exp(a) => {
+            single(Term::factor(
+                Expr::Exp(Arc::new(
+                    simplify(a),
+                )),
+                1,
+            ))
+        },
+        | Expr::Log(a) => {
+            single(Term::factor(
+                Expr::Log(Arc::new(
+                    simplify(a),
+                )),
+                1,
+            ))
+        },
+        | Expr::Sin(a) => {
+            single(Term::factor(
+                Expr::Sin(Arc::new(
+                    simplify(a),
+                )),
+                1,
+            ))
+        },
+        | Expr::Cos(a) => {
+            single(Term::factor(
+                Expr::Cos(Arc::new(
+                    simplify(a),
+                )),
+                1,
+            ))
+        },
+        | Expr::Tan(a) => {
+            single(Term::factor(
+                Expr::Tan(Arc::new(
+                    simplify(a),
+                )),
+                1,
+            ))
+        },
+        | _ => {
+            single(Term::factor(
+                expr.clone(),
+                1,
+            ))
+        },
+    }
+}
+
+/// Combines the like terms of several sums, dropping zero terms.
+
+fn sum(
+    sums: impl IntoIterator<
+        Item = Vec<Term>,
+    >
+) -> Vec<Term> {
+
+    let mut terms: Vec<Term> =
+        Vec::new();
+
+    for term in sums
+        .into_iter()
+        .flatten()
+    {
+
+        if let Some(like) = terms
+            .iter_mut()
+            .find(|t| t.is_like(&term))
+        {
+
+            like.coefficient = like
+                .coefficient
+                .add(&term.coefficient);
+        } else {
+
+            terms.push(term);
+        }
+    }
+
+    terms.retain(|t| {
+
+        !t.coefficient
+            .is_zero()
+    });
+
+    terms
+}
+
+/// Returns a sum of terms as a single term, keeping a sum of several
+/// terms as one factor.
+
+fn product(terms: &[Term]) -> Term {
+
+    match terms {
+        | [] => {
+            Term::number(
+                Coefficient::Exact(
+                    BigRational::zero(),
+                ),
+            )
+        },
+        | [term] => term.clone(),
+        | _ => {
+            Term::factor(
+                rebuild(terms),
+                1,
+            )
+        },
+    }
+}
+
+/// Multiplies `factors`, or returns `None` if there are none.
+
+fn multiply(
+    factors: Vec<Expr>
+) -> Option<Expr> {
+
+    factors
+        .into_iter()
+        .reduce(|a, b| {
+
+            Expr::Mul(
+                Arc::new(a),
+                Arc::new(b),
+            )
+        })
+}
+
+/// Returns `base` raised to `exponent`, omitting an exponent of one.
+
+fn power(
+    base: Expr,
+    exponent: i32,
+) -> Expr {
+
+    if exponent == 1 {
+
+        base
+    } else {
+
+        Expr::Power(
+            Arc::new(base),
+            Arc::new(Expr::BigInt(
+                exponent.into(),
+            )),
+        )
+    }
+}
+
+/// Converts a term with a non-negative coefficient to an expression.
+
+fn rebuild_term(term: &Term) -> Expr {
+
+    let mut numerator = Vec::new();
+
+    let mut denominator = Vec::new();
+
+    match &term.coefficient {
+        | Coefficient::Exact(q) => {
+
+            let q = q.abs();
+
+            if !q.numer().is_one() {
+
+                numerator.push(
+                    Expr::BigInt(
+                        q.numer()
+                            .clone(),
+                    ),
+                );
+            }
+
+            if !q.denom().is_one() {
+
+                denominator.push(
+                    Expr::BigInt(
+                        q.denom()
+                            .clone(),
+                    ),
+                );
+            }
+        },
+        | Coefficient::Float(x) => {
+
+            if (x.abs() - 1.0).abs()
+                > f64::EPSILON
+            {
+
+                numerator.push(
+                    Expr::Constant(
+                        x.abs(),
+                    ),
+                );
+            }
+        },
+    }
+
+    if term.pi_power > 0 {
+
+        numerator.push(power(
+            Expr::Pi,
+            term.pi_power,
+        ));
+    } else if term.pi_power < 0 {
+
+        denominator.push(power(
+            Expr::Pi,
+            term.pi_power
+                .saturating_neg(),
+        ));
+    }
+
+    for (base, &exponent) in
+        &term.factors
+    {
+
+        if exponent > 0 {
+
+            numerator.push(power(
+                base.clone(),
+                exponent,
+            ));
+        } else {
+
+            denominator.push(power(
+                base.clone(),
+                exponent
+                    .saturating_neg(),
+            ));
+        }
+    }
+
+    let numerator = multiply(numerator)
+        .unwrap_or_else(|| {
+
+            Expr::BigInt(BigInt::one())
+        });
+
+    match multiply(denominator) {
+        | Some(denominator) => {
+            Expr::Div(
+                Arc::new(numerator),
+                Arc::new(denominator),
+            )
+        },
+        | None => numerator,
+    }
+}
+
+/// Converts a sum of terms to an expression.
+
+fn rebuild(terms: &[Term]) -> Expr {
+
+    terms
+        .iter()
+        .fold(None, |sum, term| {
+
+            let negative = term
+                .coefficient
+                .is_negative();
+
+            let magnitude =
+                rebuild_term(term);
+
+            Some(match sum {
+                | None if negative => {
+                    Expr::Neg(Arc::new(
+                        magnitude,
+                    ))
+                },
+                | None => magnitude,
+                | Some(sum)
+                    if negative =>
+                {
+                    Expr::Sub(
+                        Arc::new(sum),
+                        Arc::new(
+                            magnitude,
+                        ),
+                    )
+                },
+                | Some(sum) => {
+                    Expr::Add(
+                        Arc::new(sum),
+                        Arc::new(
+                            magnitude,
+                        ),
+                    )
+                },
+            })
+        })
+        .unwrap_or_else(|| {
+
+            Expr::BigInt(BigInt::zero())
+        })
+}
@@ -0,0 +1,570 @@
+//! Embedded element and isotope tables.
+//!
+//! Standard atomic weights are those of the IUPAC Commission on Isotopic
+//! Abundances and Atomic Weights (CIAAW) as of 2021, with the abridged
+//! interval notation for the elements whose isotopic composition varies
+//! in normal materials. Isotope masses are from the 2020 Atomic Mass
+//! Evaluation (AME2020), and representative isotopic compositions and
+//! ground-state spins and parities from the NIST and NUBASE tables. For
+//! `¹⁸⁰Ta`, which occurs in nature only as its long-lived isomer, the spin
+//! is that of the isomer.
+//!
+//! Elements with no stable isotope carry the mass number of their
+//! longest-lived isotope; isotope data stops at californium.
+
+use super::AtomicWeight;
+use super::Element;
+use super::Isotope;
+use super::NuclearSpin;
+use super::Parity;
+
+/// Expands a standard atomic weight column of `elements!`.
+
+macro_rules! weight {
+    (-) => {
+
+        None
+    };
+    (
+        (
+            $value:literal,
+            $uncertainty:literal
+        )
+    ) => {
+
+        Some(
+            AtomicWeight::Value {
+                value: $value,
+                uncertainty:
+                    $uncertainty,
+            },
+        )
+    };
+    (
+        [
+            $lower:literal,
+            $upper:literal;
+            $conventional:literal
+        ]
+    ) => {
+
+        Some(
+            AtomicWeight::Interval {
+                lower: $lower,
+                upper: $upper,
+                conventional:
+                    $conventional,
+            },
+        )
+    };
+}
+
+/// Expands an abundance column of `isotopes!`.
+
+macro_rules! abundance {
+    (-) => {
+
+        None
+    };
+    ($abundance:literal) => {
+
+        Some($abundance)
+    };
+}
+
+/// Expands a parity column of `isotopes!`.
+
+macro_rules! parity {
+    (+) => {
+
+        Parity::Positive
+    };
+    (-) => {
+
+        Parity::Negative
+    };
+}
+
+/// Declares the element table.
+///
+/// Each row is `(Z, symbol, name, weight, mass number)`, where the weight
+/// is `(value, uncertainty)`, `[lower, upper; conventional]` or `-` for
+/// an element without a standard atomic weight.
+
+macro_rules! elements {
+    ($(($z:literal, $symbol:literal, $name:literal, $weight:tt, $mass_number:literal)),* $(,)?) => {
+        /// Every element from hydrogen to oganesson, in order of `Z`.
+        pub static ELEMENTS: &[Element] = &[
+            $(
+                Element {
+                    z: $z,
+                    symbol: $symbol,
+                    name: $name,
+                    atomic_weight: weight!($weight),
+                    mass_number: $mass_number,
+                },
+            )*
+        ];
+    };
+}
+
+/// Declares the isotope table.
+///
+/// Each row is `(Z, A, mass, abundance, 2J parity)`, with `-` for an
+/// isotope that does not occur in nature in measurable amounts.
+
+macro_rules! isotopes {
+    ($(($z:literal, $mass_number:literal, $mass:literal, $abundance:tt, $twice:literal $parity:tt)),* $(,)?) => {
+        /// The tabulated isotopes, sorted by `Z` and then mass number.
+        pub static ISOTOPES: &[Isotope] = &[
+            $(
+                Isotope {
+                    z: $z,
+                    mass_number: $mass_number,
+                    mass: $mass,
+                    abundance: abundance!($abundance),
+                    spin: NuclearSpin {
+                        twice: $twice,
+                        parity: parity!($parity),
+                    },
+                },
+            )*
+        ];
+    };
+}
+
+elements! {
+    (1, "H", "hydrogen", [1.007_84, 1.008_11; 1.008], 1),
+    (2, "He", "helium", (4.002_602, 0.000_002), 4),
+    (3, "Li", "lithium", [6.938, 6.997; 6.94], 7),
+    (4, "Be", "beryllium", (9.012_183_1, 0.000_000_5), 9),
+    (5, "B", "boron", [10.806, 10.821; 10.81], 11),
+    (6, "C", "carbon", [12.009_6, 12.011_6; 12.011], 12),
+    (7, "N", "nitrogen", [14.006_43, 14.007_28; 14.007], 14),
+    (8, "O", "oxygen", [15.999_03, 15.999_77; 15.999], 16),
+    (9, "F", "fluorine", (18.998_403_162, 0.000_000_005), 19),
+    (10, "Ne", "neon", (20.179_7, 0.000_6), 20),
+    (11, "Na", "sodium", (22.989_769_28, 0.000_000_02), 23),
+    (12, "Mg", "magnesium", [24.304, 24.307; 24.305], 24),
+    (13, "Al", "aluminium", (26.981_538_4, 0.000_000_3), 27),
+    (14, "Si", "silicon", [28.084, 28.086; 28.085], 28),
+    (15, "P", "phosphorus", (30.973_761_998, 0.000_000_005), 31),
+    (16, "S", "sulfur", [32.059, 32.076; 32.06], 32),
+    (17, "Cl", "chlorine", [35.446, 35.457; 35.45], 35),
+    (18, "Ar", "argon", [39.792, 39.963; 39.95], 40),
+    (19, "K", "potassium", (39.098_3, 0.000_1), 39),
+    (20, "Ca", "calcium", (40.078, 0.004), 40),
+    (21, "Sc", "scandium", (44.955_907, 0.000_004), 45),
+    (22, "Ti", "titanium", (47.867, 0.001), 48),
+    (23, "V", "vanadium", (50.941_5, 0.000_1), 51),
+    (24, "Cr", "chromium", (51.996_1, 0.000_6), 52),
+    (25, "Mn", "manganese", (54.938_043, 0.000_002), 55),
+    (26, "Fe", "iron", (55.845, 0.002), 56),
+    (27, "Co", "cobalt", (58.933_194, 0.000_003), 59),
+    (28, "Ni", "nickel", (58.693_4, 0.000_4), 58),
+    (29, "Cu", "copper", (63.546, 0.003), 63),
+    (30, "Zn", "zinc", (65.38, 0.02), 64),
+    (31, "Ga", "gallium", (69.723, 0.001), 69),
+    (32, "Ge", "germanium", (72.63, 0.008), 74),
+    (33, "As", "arsenic", (74.921_595, 0.000_006), 75),
+    (34, "Se", "selenium", (78.971, 0.008), 80),
+    (35, "Br", "bromine", [79.901, 79.907; 79.904], 79),
+    (36, "Kr", "krypton", (83.798, 0.002), 84),
+    (37, "Rb", "rubidium", (85.467_8, 0.000_3), 85),
+    (38, "Sr", "strontium", (87.62, 0.01), 88),
+    (39, "Y", "yttrium", (88.905_838, 0.000_002), 89),
+    (40, "Zr", "zirconium", (91.224, 0.002), 90),
+    (41, "Nb", "niobium", (92.906_37, 0.000_01), 93),
+    (42, "Mo", "molybdenum", (95.95, 0.01), 98),
+    (43, "Tc", "technetium", -, 98),
+    (44, "Ru", "ruthenium", (101.07, 0.02), 102),
+    (45, "Rh", "rhodium", (102.905_49, 0.000_02), 103),
+    (46, "Pd", "palladium", (106.42, 0.01), 106),
+    (47, "Ag", "silver", (107.868_2, 0.000_2), 107),
+    (48, "Cd", "cadmium", (112.414, 0.004), 114),
+    (49, "In", "indium", (114.818, 0.001), 115),
+    (50, "Sn", "tin", (118.71, 0.007), 120),
+    (51, "Sb", "antimony", (121.76, 0.001), 121),
+    (52, "Te", "tellurium", (127.6, 0.03), 130),
+    (53, "I", "iodine", (126.904_47, 0.000_03), 127),
+    (54, "Xe", "xenon", (131.293, 0.006), 132),
+    (55, "Cs", "caesium", (132.905_451_96, 0.000_000_06), 133),
+    (56, "Ba", "barium", (137.327, 0.007), 138),
+    (57, "La", "lanthanum", (138.905_47, 0.000_07), 139),
+    (58, "Ce", "cerium", (140.116, 0.001), 140),
+    (59, "Pr", "praseodymium", (140.907_66, 0.000_01), 141),
+    (60, "Nd", "neodymium", (144.242, 0.003), 142),
+    (61, "Pm", "promethium", -, 145),
+    (62, "Sm", "samarium", (150.36, 0.02), 152),
+    (63, "Eu", "europium", (151.964, 0.001), 153),
+    (64, "Gd", "gadolinium", (157.25, 0.03), 158),
+    (65, "Tb", "terbium", (158.925_354, 0.000_007), 159),
+    (66, "Dy", "dysprosium", (162.5, 0.001), 164),
+    (67, "Ho", "holmium", (164.930_329, 0.000_005), 165),
+    (68, "Er", "erbium", (167.259, 0.003), 166),
+    (69, "Tm", "thulium", (168.934_219, 0.000_005), 169),
+    (70, "Yb", "ytterbium", (173.045, 0.01), 174),
+    (71, "Lu", "lutetium", (174.966_8, 0.000_1), 175),
+    (72, "Hf", "hafnium", (178.486, 0.006), 180),
+    (73, "Ta", "tantalum", (180.947_88, 0.000_02), 181),
+    (74, "W", "tungsten", (183.84, 0.01), 184),
+    (75, "Re", "rhenium", (186.207, 0.001), 187),
+    (76, "Os", "osmium", (190.23, 0.03), 192),
+    (77, "Ir", "iridium", (192.217, 0.002), 193),
+    (78, "Pt", "platinum", (195.084, 0.009), 195),
+    (79, "Au", "gold", (196.966_57, 0.000_004), 197),
+    (80, "Hg", "mercury", (200.592, 0.003), 202),
+    (81, "Tl", "thallium", [204.382, 204.385; 204.38], 205),
+    (82, "Pb", "lead", [206.14, 207.94; 207.2], 208),
+    (83, "Bi", "bismuth", (208.980_4, 0.000_01), 209),
+    (84, "Po", "polonium", -, 209),
+    (85, "At", "astatine", -, 210),
+    (86, "Rn", "radon", -, 222),
+    (87, "Fr", "francium", -, 223),
+    (88, "Ra", "radium", -, 226),
+    (89, "Ac", "actinium", -, 227),
+    (90, "Th", "thorium", (232.037_7, 0.000_4), 232),
+    (91, "Pa", "protactinium", (231.035_88, 0.000_01), 231),
+    (92, "U", "uranium", (238.028_91, 0.000_03), 238),
+    (93, "Np", "neptunium", -, 237),
+    (94, "Pu", "plutonium", -, 244),
+    (95, "Am", "americium", -, 243),
+    (96, "Cm", "curium", -, 247),
+    (97, "Bk", "berkelium", -, 247),
+    (98, "Cf", "californium", -, 251),
+    (99, "Es", "einsteinium", -, 252),
+    (100, "Fm", "fermium", -, 257),
+    (101, "Md", "mendelevium", -, 258),
+    (102, "No", "nobelium", -, 259),
+    (103, "Lr", "lawrencium", -, 262),
+    (104, "Rf", "rutherfordium", -, 267),
+    (105, "Db", "dubnium", -, 268),
+    (106, "Sg", "seaborgium", -, 269),
+    (107, "Bh", "bohrium", -, 270),
+    (108, "Hs", "hassium", -, 269),
+    (109, "Mt", "meitnerium", -, 278),
+    (110, "Ds", "darmstadtium", -, 281),
+    (111, "Rg", "roentgenium", -, 282),
+    (112, "Cn", "copernicium", -, 285),
+    (113, "Nh", "nihonium", -, 286),
+    (114, "Fl", "flerovium", -, 289),
+    (115, "Mc", "moscovium", -, 290),
+    (116, "Lv", "livermorium", -, 293),
+    (117, "Ts", "tennessine", -, 294),
+    (118, "Og", "oganesson", -, 294),
+}
+
+isotopes! {
+    (1, 1, 1.007_825_032_23, 0.999_885, 1 +),
+    (1, 2, 2.014_101_778_12, 0.000_115, 2 +),
+    (1, 3, 3.016_049_277_9, -, 1 +),
+    (2, 3, 3.016_029_320_1, 0.000_001_34, 1 +),
+    (2, 4, 4.002_603_254_13, 0.999_998_66, 0 +),
+    (3, 6, 6.015_122_887_4, 0.075_9, 2 +),
+    (3, 7, 7.016_003_436_6, 0.924_1, 3 -),
+    (4, 9, 9.012_183_065, 1.0, 3 -),
+    (5, 10, 10.012_936_95, 0.199, 6 +),
+    (5, 11, 11.009_305_36, 0.801, 3 -),
+    (6, 12, 12.0, 0.989_3, 0 +),
+    (6, 13, 13.003_354_835_07, 0.010_7, 1 -),
+    (6, 14, 14.003_241_988_4, -, 0 +),
+    (7, 14, 14.003_074_004_43, 0.996_36, 2 +),
+    (7, 15, 15.000_108_898_88, 0.003_64, 1 -),
+    (8, 16, 15.994_914_619_57, 0.997_57, 0 +),
+    (8, 17, 16.999_131_756_5, 0.000_38, 5 +),
+    (8, 18, 17.999_159_612_86, 0.002_05, 0 +),
+    (9, 19, 18.998_403_162_73, 1.0, 1 +),
+    (10, 20, 19.992_440_176_2, 0.904_8, 0 +),
+    (10, 21, 20.993_846_685, 0.002_7, 3 +),
+    (10, 22, 21.991_385_114, 0.092_5, 0 +),
+    (11, 23, 22.989_769_282, 1.0, 3 +),
+    (12, 24, 23.985_041_697, 0.789_9, 0 +),
+    (12, 25, 24.985_836_976, 0.1, 5 +),
+    (12, 26, 25.982_592_968, 0.110_1, 0 +),
+    (13, 27, 26.981_538_53, 1.0, 5 +),
+    (14, 28, 27.976_926_534_65, 0.922_23, 0 +),
+    (14, 29, 28.976_494_664_9, 0.046_85, 1 +),
+    (14, 30, 29.973_770_136, 0.030_92, 0 +),
+    (15, 31, 30.973_761_998_42, 1.0, 1 +),
+    (16, 32, 31.972_071_174_4, 0.949_9, 0 +),
+    (16, 33, 32.971_458_909_8, 0.007_5, 3 +),
+    (16, 34, 33.967_867_004, 0.042_5, 0 +),
+    (16, 36, 35.967_080_71, 0.000_1, 0 +),
+    (17, 35, 34.968_852_682, 0.757_6, 3 +),
+    (17, 37, 36.965_902_602, 0.242_4, 3 +),
+    (18, 36, 35.967_545_105, 0.003_336, 0 +),
+    (18, 38, 37.962_732_11, 0.000_629, 0 +),
+    (18, 40, 39.962_383_123_7, 0.996_035, 0 +),
+    (19, 39, 38.963_706_486_4, 0.932_581, 3 +),
+    (19, 40, 39.963_998_166, 0.000_117, 8 -),
+    (19, 41, 40.961_825_257_9, 0.067_302, 3 +),
+    (20, 40, 39.962_590_863, 0.969_41, 0 +),
+    (20, 42, 41.958_617_83, 0.006_47, 0 +),
+    (20, 43, 42.958_766_44, 0.001_35, 7 -),
+    (20, 44, 43.955_481_56, 0.020_86, 0 +),
+    (20, 46, 45.953_689, 0.000_04, 0 +),
+    (20, 48, 47.952_522_76, 0.001_87, 0 +),
+    (21, 45, 44.955_908_28, 1.0, 7 -),
+    (22, 46, 45.952_627_72, 0.082_5, 0 +),
+    (22, 47, 46.951_758_79, 0.074_4, 5 -),
+    (22, 48, 47.947_941_98, 0.737_2, 0 +),
+    (22, 49, 48.947_865_68, 0.054_1, 7 -),
+    (22, 50, 49.944_786_89, 0.051_8, 0 +),
+    (23, 50, 49.947_156_01, 0.002_5, 12 +),
+    (23, 51, 50.943_957_04, 0.997_5, 7 -),
+    (24, 50, 49.946_041_83, 0.043_45, 0 +),
+    (24, 52, 51.940_506_23, 0.837_89, 0 +),
+    (24, 53, 52.940_648_15, 0.095_01, 3 -),
+    (24, 54, 53.938_879_16, 0.023_65, 0 +),
+    (25, 55, 54.938_043_91, 1.0, 5 -),
+    (26, 54, 53.939_608_99, 0.058_45, 0 +),
+    (26, 56, 55.934_936_33, 0.917_54, 0 +),
+    (26, 57, 56.935_392_84, 0.021_19, 1 -),
+    (26, 58, 57.933_274_43, 0.002_82, 0 +),
+    (27, 59, 58.933_194_29, 1.0, 7 -),
+    (28, 58, 57.935_342_41, 0.680_77, 0 +),
+    (28, 60, 59.930_785_88, 0.262_23, 0 +),
+    (28, 61, 60.931_055_57, 0.011_399, 3 -),
+    (28, 62, 61.928_345_37, 0.036_346, 0 +),
+    (28, 64, 63.927_966_82, 0.009_255, 0 +),
+    (29, 63, 62.929_597_72, 0.691_5, 3 -),
+    (29, 65, 64.927_789_7, 0.308_5, 3 -),
+    (30, 64, 63.929_142_01, 0.491_7, 0 +),
+    (30, 66, 65.926_033_81, 0.277_3, 0 +),
+    (30, 67, 66.927_127_75, 0.040_4, 5 -),
+    (30, 68, 67.924_844_55, 0.184_5, 0 +),
+    (30, 70, 69.925_319_2, 0.006_1, 0 +),
+    (31, 69, 68.925_573_5, 0.601_08, 3 -),
+    (31, 71, 70.924_702_58, 0.398_92, 3 -),
+    (32, 70, 69.924_248_75, 0.205_7, 0 +),
+    (32, 72, 71.922_075_826, 0.274_5, 0 +),
+    (32, 73, 72.923_458_956, 0.077_5, 9 +),
+    (32, 74, 73.921_177_761, 0.365, 0 +),
+    (32, 76, 75.921_402_726, 0.077_3, 0 +),
+    (33, 75, 74.921_594_57, 1.0, 3 -),
+    (34, 74, 73.922_475_934, 0.008_9, 0 +),
+    (34, 76, 75.919_213_704, 0.093_7, 0 +),
+    (34, 77, 76.919_914_154, 0.076_3, 1 -),
+    (34, 78, 77.917_309_28, 0.237_7, 0 +),
+    (34, 80, 79.916_521_8, 0.496_1, 0 +),
+    (34, 82, 81.916_699_5, 0.087_3, 0 +),
+    (35, 79, 78.918_337_6, 0.506_9, 3 -),
+    (35, 81, 80.916_289_7, 0.493_1, 3 -),
+    (36, 78, 77.920_364_94, 0.003_55, 0 +),
+    (36, 80, 79.916_378_08, 0.022_86, 0 +),
+    (36, 82, 81.913_482_73, 0.115_93, 0 +),
+    (36, 83, 82.914_127_16, 0.115, 9 +),
+    (36, 84, 83.911_497_728_2, 0.569_87, 0 +),
+    (36, 86, 85.910_610_626_9, 0.172_79, 0 +),
+    (37, 85, 84.911_789_737_9, 0.721_7, 5 -),
+    (37, 87, 86.909_180_531, 0.278_3, 3 -),
+    (38, 84, 83.913_419_1, 0.005_6, 0 +),
+    (38, 86, 85.909_260_6, 0.098_6, 0 +),
+    (38, 87, 86.908_877_5, 0.07, 9 +),
+    (38, 88, 87.905_612_5, 0.825_8, 0 +),
+    (39, 89, 88.905_840_3, 1.0, 1 -),
+    (40, 90, 89.904_697_7, 0.514_5, 0 +),
+    (40, 91, 90.905_639_6, 0.112_2, 5 +),
+    (40, 92, 91.905_034_7, 0.171_5, 0 +),
+    (40, 94, 93.906_310_8, 0.173_8, 0 +),
+    (40, 96, 95.908_271_4, 0.028, 0 +),
+    (41, 93, 92.906_373, 1.0, 9 +),
+    (42, 92, 91.906_807_96, 0.145_3, 0 +),
+    (42, 94, 93.905_084_9, 0.091_5, 0 +),
+    (42, 95, 94.905_838_77, 0.158_4, 5 +),
+    (42, 96, 95.904_676_12, 0.166_7, 0 +),
+    (42, 97, 96.906_018_12, 0.096, 5 +),
+    (42, 98, 97.905_404_82, 0.243_9, 0 +),
+    (42, 100, 99.907_471_8, 0.098_2, 0 +),
+    (43, 97, 96.906_366_7, -, 9 +),
+    (43, 98, 97.907_212_4, -, 12 +),
+    (43, 99, 98.906_250_8, -, 9 +),
+    (44, 96, 95.907_590_25, 0.055_4, 0 +),
+    (44, 98, 97.905_286_8, 0.018_7, 0 +),
+    (44, 99, 98.905_934_1, 0.127_6, 5 +),
+    (44, 100, 99.904_214_3, 0.126, 0 +),
+    (44, 101, 100.905_576_9, 0.170_6, 5 +),
+    (44, 102, 101.904_344_1, 0.315_5, 0 +),
+    (44, 104, 103.905_427_5, 0.186_2, 0 +),
+    (45, 103, 102.905_498, 1.0, 1 -),
+    (46, 102, 101.905_602_2, 0.010_2, 0 +),
+    (46, 104, 103.904_030_5, 0.111_4, 0 +),
+    (46, 105, 104.905_079_6, 0.223_3, 5 +),
+    (46, 106, 105.903_480_4, 0.273_3, 0 +),
+    (46, 108, 107.903_891_6, 0.264_6, 0 +),
+    (46, 110, 109.905_172_2, 0.117_2, 0 +),
+    (47, 107, 106.905_091_6, 0.518_39, 1 -),
+    (47, 109, 108.904_755_3, 0.481_61, 1 -),
+    (48, 106, 105.906_459_9, 0.012_5, 0 +),
+    (48, 108, 107.904_183_4, 0.008_9, 0 +),
+    (48, 110, 109.903_006_61, 0.124_9, 0 +),
+    (48, 111, 110.904_182_87, 0.128, 1 +),
+    (48, 112, 111.902_762_87, 0.241_3, 0 +),
+    (48, 113, 112.904_408_13, 0.122_2, 1 +),
+    (48, 114, 113.903_365_09, 0.287_3, 0 +),
+    (48, 116, 115.904_763_15, 0.074_9, 0 +),
+    (49, 113, 112.904_061_84, 0.042_9, 9 +),
+    (49, 115, 114.903_878_776, 0.957_1, 9 +),
+    (50, 112, 111.904_823_87, 0.009_7, 0 +),
+    (50, 114, 113.902_782_7, 0.006_6, 0 +),
+    (50, 115, 114.903_344_699, 0.003_4, 1 +),
+    (50, 116, 115.901_742_8, 0.145_4, 0 +),
+    (50, 117, 116.902_953_98, 0.076_8, 1 +),
+    (50, 118, 117.901_606_57, 0.242_2, 0 +),
+    (50, 119, 118.903_311_17, 0.085_9, 1 +),
+    (50, 120, 119.902_201_63, 0.325_8, 0 +),
+    (50, 122, 121.903_443_8, 0.046_3, 0 +),
+    (50, 124, 123.905_276_6, 0.057_9, 0 +),
+    (51, 121, 120.903_812, 0.572_1, 5 +),
+    (51, 123, 122.904_213_2, 0.427_9, 7 +),
+    (52, 120, 119.904_059_3, 0.000_9, 0 +),
+    (52, 122, 121.903_043_5, 0.025_5, 0 +),
+    (52, 123, 122.904_269_8, 0.008_9, 1 +),
+    (52, 124, 123.902_817_1, 0.047_4, 0 +),
+    (52, 125, 124.904_429_9, 0.070_7, 1 +),
+    (52, 126, 125.903_310_9, 0.188_4, 0 +),
+    (52, 128, 127.904_461_28, 0.317_4, 0 +),
+    (52, 130, 129.906_222_748, 0.340_8, 0 +),
+    (53, 127, 126.904_471_9, 1.0, 5 +),
+    (54, 124, 123.905_892, 0.000_952, 0 +),
+    (54, 126, 125.904_298_3, 0.000_89, 0 +),
+    (54, 128, 127.903_531, 0.019_102, 0 +),
+    (54, 129, 128.904_780_861_1, 0.264_006, 1 +),
+    (54, 130, 129.903_509_349, 0.040_71, 0 +),
+    (54, 131, 130.905_084_06, 0.212_324, 3 +),
+    (54, 132, 131.904_155_085_6, 0.269_086, 0 +),
+    (54, 134, 133.905_394_66, 0.104_357, 0 +),
+    (54, 136, 135.907_214_484, 0.088_573, 0 +),
+    (55, 133, 132.905_451_961, 1.0, 7 +),
+    (56, 130, 129.906_320_7, 0.001_06, 0 +),
+    (56, 132, 131.905_061_1, 0.001_01, 0 +),
+    (56, 134, 133.904_508_18, 0.024_17, 0 +),
+    (56, 135, 134.905_688_38, 0.065_92, 3 +),
+    (56, 136, 135.904_575_73, 0.078_54, 0 +),
+    (56, 137, 136.905_827_14, 0.112_32, 3 +),
+    (56, 138, 137.905_247, 0.716_98, 0 +),
+    (57, 138, 137.907_114_9, 0.000_888_1, 10 +),
+    (57, 139, 138.906_356_3, 0.999_111_9, 7 +),
+    (58, 136, 135.907_129_21, 0.001_85, 0 +),
+    (58, 138, 137.905_991, 0.002_51, 0 +),
+    (58, 140, 139.905_443_1, 0.884_5, 0 +),
+    (58, 142, 141.909_250_4, 0.111_14, 0 +),
+    (59, 141, 140.907_657_6, 1.0, 5 +),
+    (60, 142, 141.907_729, 0.271_52, 0 +),
+    (60, 143, 142.909_82, 0.121_74, 7 -),
+    (60, 144, 143.910_093, 0.237_98, 0 +),
+    (60, 145, 144.912_579_3, 0.082_93, 7 -),
+    (60, 146, 145.913_122_6, 0.171_89, 0 +),
+    (60, 148, 147.916_899_3, 0.057_56, 0 +),
+    (60, 150, 149.920_902_2, 0.056_38, 0 +),
+    (61, 145, 144.912_755_9, -, 5 +),
+    (61, 147, 146.915_145, -, 7 +),
+    (62, 144, 143.912_006_5, 0.030_7, 0 +),
+    (62, 147, 146.914_904_4, 0.149_9, 7 -),
+    (62, 148, 147.914_829_2, 0.112_4, 0 +),
+    (62, 149, 148.917_192_1, 0.138_2, 7 -),
+    (62, 150, 149.917_282_9, 0.073_8, 0 +),
+    (62, 152, 151.919_739_7, 0.267_5, 0 +),
+    (62, 154, 153.922_216_9, 0.227_5, 0 +),
+    (63, 151, 150.919_857_8, 0.478_1, 5 +),
+    (63, 153, 152.921_238, 0.521_9, 5 +),
+    (64, 152, 151.919_799_5, 0.002, 0 +),
+    (64, 154, 153.920_874_1, 0.021_8, 0 +),
+    (64, 155, 154.922_630_5, 0.148, 3 -),
+    (64, 156, 155.922_131_2, 0.204_7, 0 +),
+    (64, 157, 156.923_968_6, 0.156_5, 3 -),
+    (64, 158, 157.924_112_3, 0.248_4, 0 +),
+    (64, 160, 159.927_062_4, 0.218_6, 0 +),
+    (65, 159, 158.925_354_7, 1.0, 3 +),
+    (66, 156, 155.924_284_7, 0.000_56, 0 +),
+    (66, 158, 157.924_415_9, 0.000_95, 0 +),
+    (66, 160, 159.925_204_6, 0.023_29, 0 +),
+    (66, 161, 160.926_940_5, 0.188_89, 5 +),
+    (66, 162, 161.926_805_6, 0.254_75, 0 +),
+    (66, 163, 162.928_738_3, 0.248_96, 5 -),
+    (66, 164, 163.929_181_9, 0.282_6, 0 +),
+    (67, 165, 164.930_328_8, 1.0, 7 -),
+    (68, 162, 161.928_788_4, 0.001_39, 0 +),
+    (68, 164, 163.929_208_8, 0.016_01, 0 +),
+    (68, 166, 165.930_299_5, 0.335_03, 0 +),
+    (68, 167, 166.932_054_6, 0.228_69, 7 +),
+    (68, 168, 167.932_376_7, 0.269_78, 0 +),
+    (68, 170, 169.935_470_2, 0.149_1, 0 +),
+    (69, 169, 168.934_217_9, 1.0, 1 +),
+    (70, 168, 167.933_889_6, 0.001_23, 0 +),
+    (70, 170, 169.934_766_4, 0.029_82, 0 +),
+    (70, 171, 170.936_330_2, 0.140_9, 1 -),
+    (70, 172, 171.936_385_9, 0.216_8, 0 +),
+    (70, 173, 172.938_215_1, 0.161_03, 5 -),
+    (70, 174, 173.938_866_4, 0.320_26, 0 +),
+    (70, 176, 175.942_576_4, 0.129_96, 0 +),
+    (71, 175, 174.940_775_2, 0.974_01, 7 +),
+    (71, 176, 175.942_689_7, 0.025_99, 14 -),
+    (72, 174, 173.940_046_1, 0.001_6, 0 +),
+    (72, 176, 175.941_407_6, 0.052_6, 0 +),
+    (72, 177, 176.943_227_7, 0.186, 7 -),
+    (72, 178, 177.943_705_8, 0.272_8, 0 +),
+    (72, 179, 178.945_823_2, 0.136_2, 9 +),
+    (72, 180, 179.946_557, 0.350_8, 0 +),
+    (73, 180, 179.947_464_8, 0.000_120_1, 18 -),
+    (73, 181, 180.947_995_8, 0.999_879_9, 7 +),
+    (74, 180, 179.946_710_8, 0.001_2, 0 +),
+    (74, 182, 181.948_203_94, 0.265, 0 +),
+    (74, 183, 182.950_222_75, 0.143_1, 1 -),
+    (74, 184, 183.950_930_92, 0.306_4, 0 +),
+    (74, 186, 185.954_362_8, 0.284_3, 0 +),
+    (75, 185, 184.952_954_5, 0.374, 5 +),
+    (75, 187, 186.955_750_1, 0.626, 5 +),
+    (76, 184, 183.952_488_5, 0.000_2, 0 +),
+    (76, 186, 185.953_835, 0.015_9, 0 +),
+    (76, 187, 186.955_747_4, 0.019_6, 1 -),
+    (76, 188, 187.955_835_2, 0.132_4, 0 +),
+    (76, 189, 188.958_144_2, 0.161_5, 3 -),
+    (76, 190, 189.958_443_7, 0.262_6, 0 +),
+    (76, 192, 191.961_477, 0.407_8, 0 +),
+    (77, 191, 190.960_589_3, 0.373, 3 +),
+    (77, 193, 192.962_921_6, 0.627, 3 +),
+    (78, 190, 189.959_929_7, 0.000_12, 0 +),
+    (78, 192, 191.961_038_7, 0.007_82, 0 +),
+    (78, 194, 193.962_680_9, 0.328_6, 0 +),
+    (78, 195, 194.964_791_7, 0.337_8, 1 -),
+    (78, 196, 195.964_952_09, 0.252_1, 0 +),
+    (78, 198, 197.967_894_9, 0.073_56, 0 +),
+    (79, 197, 196.966_568_79, 1.0, 3 +),
+    (80, 196, 195.965_832_6, 0.001_5, 0 +),
+    (80, 198, 197.966_768_6, 0.099_7, 0 +),
+    (80, 199, 198.968_280_64, 0.168_7, 1 -),
+    (80, 200, 199.968_326_59, 0.231, 0 +),
+    (80, 201, 200.970_302_84, 0.131_8, 3 -),
+    (80, 202, 201.970_643_4, 0.298_6, 0 +),
+    (80, 204, 203.973_493_98, 0.068_7, 0 +),
+    (81, 203, 202.972_344_6, 0.295_2, 1 +),
+    (81, 205, 204.974_427_8, 0.704_8, 1 +),
+    (82, 204, 203.973_044, 0.014, 0 +),
+    (82, 206, 205.974_465_7, 0.241, 0 +),
+    (82, 207, 206.975_897_3, 0.221, 1 -),
+    (82, 208, 207.976_652_5, 0.524, 0 +),
+    (83, 209, 208.980_399_1, 1.0, 9 -),
+    (84, 209, 208.982_430_8, -, 1 -),
+    (84, 210, 209.982_874_1, -, 0 +),
+    (85, 210, 209.987_147_9, -, 10 +),
+    (85, 211, 210.987_496_6, -, 9 -),
+    (86, 222, 222.017_578_2, -, 0 +),
+    (87, 223, 223.019_736, -, 3 -),
+    (88, 226, 226.025_410_3, -, 0 +),
+    (89, 227, 227.027_752_3, -, 3 -),
+    (90, 230, 230.033_134_1, -, 0 +),
+    (90, 232, 232.038_055_8, 1.0, 0 +),
+    (91, 231, 231.035_884_2, 1.0, 3 -),
+    (92, 234, 234.040_952_3, 0.000_054, 0 +),
+    (92, 235, 235.043_930_1, 0.007_204, 7 -),
+    (92, 238, 238.050_788_4, 0.992_742, 0 +),
+    (93, 237, 237.048_173_6, -, 5 +),
+    (94, 239, 239.052_163_6, -, 1 +),
+    (94, 244, 244.064_205_3, -, 0 +),
+    (95, 241, 241.056_829_3, -, 5 -),
+    (95, 243, 243.061_381_3, -, 5 -),
+    (96, 247, 247.070_354_1, -, 9 -),
+    (97, 247, 247.070_307_3, -, 3 -),
+    (98, 251, 251.079_588_6, -, 1 +),
+}
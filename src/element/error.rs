@@ -0,0 +1,113 @@
+//! Errors raised by element lookups and formula evaluation.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// An error raised when parsing or evaluating a chemical formula.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum ElementError {
+    /// The formula could not be parsed.
+    Syntax {
+        /// The formula as given.
+        formula: String,
+        /// What went wrong.
+        message: String,
+    },
+    /// A symbol is not a known element.
+    UnknownElement(String),
+    /// An isotope label names an isotope that is not tabulated.
+    UnknownIsotope {
+        /// The element symbol.
+        symbol: String,
+        /// The mass number of the label.
+        mass_number: u16,
+    },
+    /// An element has no standard atomic weight, so a mass for natural
+    /// isotopic composition is undefined; label it with an isotope instead.
+    NoStandardAtomicWeight(String),
+    /// An element has no naturally occurring isotope, so it has no natural
+    /// isotope pattern.
+    NoNaturalIsotopes(String),
+}
+
+impl fmt::Display for ElementError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match self {
+            | Self::Syntax {
+                formula,
+                message,
+            } => {
+
+                write!(
+                    f,
+                    "invalid formula \
+                     `{formula}`: \
+                     {message}"
+                )
+            },
+            | Self::UnknownElement(
+                symbol,
+            ) => {
+
+                write!(
+                    f,
+                    "unknown element \
+                     `{symbol}`"
+                )
+            },
+            | Self::UnknownIsotope {
+                symbol,
+                mass_number,
+            } => {
+
+                write!(
+                    f,
+                    "unknown isotope \
+                     `{mass_number}\
+                     {symbol}`"
+                )
+            },
+            | Self::NoStandardAtomicWeight(
+                symbol,
+            ) => {
+
+                write!(
+                    f,
+                    "`{symbol}` has no \
+                     standard atomic \
+                     weight"
+                )
+            },
+            | Self::NoNaturalIsotopes(
+                symbol,
+            ) => {
+
+                write!(
+                    f,
+                    "`{symbol}` has no \
+                     naturally occurring \
+                     isotope"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error
+    for ElementError
+{
+}
@@ -0,0 +1,906 @@
+//! Parser for molecular formulas such as `"C6H12O6"` or `"Ca(OH)2"`.
+//!
+//! A formula is a sequence of element symbols, each followed by an
+//! optional count. Parentheses and square brackets group a sequence,
+//! which may be followed by a count (`"K4[Fe(CN)6]"`). A bracket that
+//! starts with a digit is an isotope label instead: `"[13C]"` is one atom
+//! of carbon 13, and `"[13C]2"` two. `D` and `T` stand for `[2H]` and
+//! `[3H]`. Adducts such as hydrates are joined by `·`, `•`, `.` or `*`,
+//! each with an optional leading coefficient (`"CuSO4·5H2O"`).
+//!
+//! Unlabelled atoms have the natural isotopic composition of their
+//! element.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Element;
+use super::ElementError;
+use super::Isotope;
+use crate::constant::MOLAR_MASS_CONSTANT;
+
+/// An atom of a formula: an element of natural isotopic composition, or
+/// a single isotope of it.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Atom {
+    /// The atomic number.
+    pub z: u8,
+    /// The mass number of an isotope label, `None` for natural
+    /// composition.
+    pub mass_number: Option<u16>,
+}
+
+impl Atom {
+    /// Returns the element of the atom.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::UnknownElement`] if `z` is out of range.
+
+    pub fn element(
+        &self
+    ) -> Result<
+        &'static Element,
+        ElementError,
+    > {
+
+        super::by_number(self.z)
+            .ok_or_else(|| {
+
+                ElementError::UnknownElement(
+                    self.z.to_string(),
+                )
+            })
+    }
+
+    /// Returns the labelled isotope, or `None` for natural composition.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::UnknownIsotope`] if the label names an
+    /// isotope that is not tabulated.
+
+    pub fn isotope(
+        &self
+    ) -> Result<
+        Option<&'static Isotope>,
+        ElementError,
+    > {
+
+        let Some(mass_number) =
+            self.mass_number
+        else {
+
+            return Ok(None);
+        };
+
+        let element = self.element()?;
+
+        element
+            .isotope(mass_number)
+            .map(Some)
+            .ok_or_else(|| {
+
+                ElementError::UnknownIsotope {
+                    symbol: element
+                        .symbol
+                        .to_string(),
+                    mass_number,
+                }
+            })
+    }
+
+    /// Returns the relative atomic mass: the isotope mass of a label, or
+    /// the standard atomic weight.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NoStandardAtomicWeight`] for an unlabelled
+    /// element without a standard atomic weight.
+
+    pub fn relative_mass(
+        &self
+    ) -> Result<f64, ElementError> {
+
+        if let Some(isotope) =
+            self.isotope()?
+        {
+
+            return Ok(isotope.mass);
+        }
+
+        let element = self.element()?;
+
+        element
+            .standard_atomic_weight()
+            .ok_or_else(|| {
+
+                ElementError::NoStandardAtomicWeight(
+                    element
+                        .symbol
+                        .to_string(),
+                )
+            })
+    }
+
+    /// Returns the mass of the most abundant isotope, or of the label.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NoNaturalIsotopes`] for an unlabelled
+    /// element that does not occur in nature.
+
+    pub fn monoisotopic_mass(
+        &self
+    ) -> Result<f64, ElementError> {
+
+        if let Some(isotope) =
+            self.isotope()?
+        {
+
+            return Ok(isotope.mass);
+        }
+
+        let element = self.element()?;
+
+        element
+            .natural_isotopes()
+            .max_by(|a, b| {
+
+                a.abundance.partial_cmp(
+                    &b.abundance,
+                )
+                .unwrap_or(
+                    std::cmp::Ordering::Equal,
+                )
+            })
+            .map(|isotope| isotope.mass)
+            .ok_or_else(|| {
+
+                ElementError::NoNaturalIsotopes(
+                    element
+                        .symbol
+                        .to_string(),
+                )
+            })
+    }
+}
+
+/// A molecular formula: a count for each atom.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Default,
+)]
+
+pub struct Formula {
+    atoms: BTreeMap<Atom, u32>,
+}
+
+impl Formula {
+    /// Returns the atoms and their counts, ordered by atomic number with
+    /// the natural composition before isotope labels.
+
+    pub fn atoms(
+        &self
+    ) -> impl Iterator<Item = (Atom, u32)> + '_
+    {
+
+        self.atoms
+            .iter()
+            .map(|(atom, count)| {
+
+                (*atom, *count)
+            })
+    }
+
+    /// Returns the number of atoms of element `z`, labelled or not.
+    #[must_use]
+
+    pub fn count(
+        &self,
+        z: u8,
+    ) -> u32 {
+
+        self.atoms
+            .iter()
+            .filter(|(atom, _)| {
+
+                atom.z == z
+            })
+            .fold(
+                0,
+                |sum, (_, count)| {
+
+                    sum.saturating_add(
+                        *count,
+                    )
+                },
+            )
+    }
+
+    /// Returns the relative molecular mass (the molecular weight), from
+    /// the standard atomic weights and the masses of labelled isotopes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NoStandardAtomicWeight`] if an unlabelled
+    /// element has no standard atomic weight.
+
+    pub fn relative_mass(
+        &self
+    ) -> Result<f64, ElementError> {
+
+        self.sum(Atom::relative_mass)
+    }
+
+    /// Returns the molar mass in kg mol⁻¹, the relative molecular mass
+    /// times the molar mass constant `M_u`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NoStandardAtomicWeight`] if an unlabelled
+    /// element has no standard atomic weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::element::formula;
+    ///
+    /// let glucose =
+    ///     formula::parse("C6H12O6")
+    ///         .unwrap();
+    ///
+    /// let molar_mass = glucose
+    ///     .molar_mass()
+    ///     .unwrap();
+    ///
+    /// assert!(
+    ///     (molar_mass * 1e3 - 180.156)
+    ///         .abs()
+    ///         < 1e-3
+    /// );
+    /// ```
+
+    pub fn molar_mass(
+        &self
+    ) -> Result<f64, ElementError> {
+
+        Ok(
+            self.relative_mass()?
+                * MOLAR_MASS_CONSTANT,
+        )
+    }
+
+    /// Returns the monoisotopic mass in daltons, from the most abundant
+    /// isotope of each element and the masses of labelled isotopes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NoNaturalIsotopes`] if an unlabelled
+    /// element does not occur in nature.
+
+    pub fn monoisotopic_mass(
+        &self
+    ) -> Result<f64, ElementError> {
+
+        self.sum(
+            Atom::monoisotopic_mass,
+        )
+    }
+
+    /// Sums a per-atom mass over the formula.
+
+    fn sum(
+        &self,
+        mass: fn(
+            &Atom,
+        ) -> Result<
+            f64,
+            ElementError,
+        >,
+    ) -> Result<f64, ElementError> {
+
+        self.atoms
+            .iter()
+            .try_fold(
+                0.0,
+                |sum, (atom, count)| {
+
+                    Ok(mass(atom)?
+                        .mul_add(
+                            f64::from(
+                                *count,
+                            ),
+                            sum,
+                        ))
+                },
+            )
+    }
+}
+
+impl FromStr for Formula {
+    type Err = ElementError;
+
+    fn from_str(
+        s: &str
+    ) -> Result<Self, Self::Err> {
+
+        parse(s)
+    }
+}
+
+impl fmt::Display for Formula {
+    /// Writes the formula in Hill order: carbon, then hydrogen, then the
+    /// other elements alphabetically, or all alphabetically without
+    /// carbon. Isotope labels follow their element.
+
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        let has_carbon =
+            self.count(6) > 0;
+
+        let mut atoms: Vec<_> = self
+            .atoms()
+            .map(|(atom, count)| {
+
+                let symbol = atom
+                    .element()
+                    .map_or("?", |e| {
+
+                        e.symbol
+                    });
+
+                let rank = match atom.z {
+                    | 6 if has_carbon => 0,
+                    | 1 if has_carbon => 1,
+                    | _ => 2,
+                };
+
+                (
+                    (
+                        rank,
+                        symbol,
+                        atom.mass_number,
+                    ),
+                    count,
+                )
+            })
+            .collect();
+
+        atoms.sort_unstable();
+
+        for (
+            (_, symbol, mass_number),
+            count,
+        ) in atoms
+        {
+
+            match mass_number {
+                | Some(mass_number) => {
+
+                    write!(
+                        f,
+                        "[{mass_number}{symbol}]"
+                    )?;
+                },
+                | None => {
+
+                    f.write_str(
+                        symbol,
+                    )?;
+                },
+            }
+
+            if count != 1 {
+
+                write!(f, "{count}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a molecular formula.
+///
+/// # Errors
+///
+/// Returns [`ElementError::Syntax`] if the formula is malformed,
+/// [`ElementError::UnknownElement`] for an unknown symbol and
+/// [`ElementError::UnknownIsotope`] for a label of an isotope that is not
+/// tabulated.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::element::formula;
+///
+/// let lime =
+///     formula::parse("Ca(OH)2")
+///         .unwrap();
+///
+/// assert_eq!(lime.count(8), 2);
+///
+/// assert_eq!(
+///     lime.to_string(),
+///     "CaH2O2"
+/// );
+///
+/// let labelled =
+///     formula::parse("[13C]H3OD")
+///         .unwrap();
+///
+/// assert_eq!(
+///     labelled.to_string(),
+///     "[13C]H3[2H]O"
+/// );
+/// ```
+
+pub fn parse(
+    formula: &str
+) -> Result<Formula, ElementError> {
+
+    let trimmed = formula.trim();
+
+    let mut parser = Parser {
+        formula,
+        rest: trimmed,
+    };
+
+    if trimmed.is_empty() {
+
+        return Err(parser
+            .error("empty formula"));
+    }
+
+    let mut atoms =
+        parser.component()?;
+
+    while let Some(
+        '·' | '•' | '.' | '*',
+    ) = parser.peek()
+    {
+
+        parser.bump();
+
+        let adduct =
+            parser.component()?;
+
+        parser.merge(
+            &mut atoms,
+            adduct,
+            1,
+        )?;
+    }
+
+    match parser.peek() {
+        | None => {
+            Ok(Formula {
+                atoms,
+            })
+        },
+        | Some(c @ (')' | ']')) => {
+            Err(
+                parser.error(&format!(
+                    "unbalanced `{c}`"
+                )),
+            )
+        },
+        | Some(c) => {
+            Err(
+                parser.error(&format!(
+                    "unexpected `{c}`"
+                )),
+            )
+        },
+    }
+}
+
+/// A recursive-descent parser over the remaining input.
+
+struct Parser<'a> {
+    formula: &'a str,
+    rest: &'a str,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+
+        self.rest
+            .chars()
+            .next()
+    }
+
+    fn bump(&mut self) {
+
+        let mut chars =
+            self.rest.chars();
+
+        chars.next();
+
+        self.rest = chars.as_str();
+    }
+
+    fn error(
+        &self,
+        message: &str,
+    ) -> ElementError {
+
+        ElementError::Syntax {
+            formula: self
+                .formula
+                .to_string(),
+            message: message
+                .to_string(),
+        }
+    }
+
+    /// Adds `times` copies of `atoms` to `into`.
+
+    fn merge(
+        &self,
+        into: &mut BTreeMap<Atom, u32>,
+        atoms: BTreeMap<Atom, u32>,
+        times: u32,
+    ) -> Result<(), ElementError> {
+
+        for (atom, count) in atoms {
+
+            let total = into
+                .entry(atom)
+                .or_insert(0);
+
+            *total = count
+                .checked_mul(times)
+                .and_then(|count| {
+
+                    total.checked_add(
+                        count,
+                    )
+                })
+                .ok_or_else(|| {
+
+                    self.error(
+                        "count out of \
+                         range",
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    /// `count? sequence`
+
+    fn component(
+        &mut self
+    ) -> Result<
+        BTreeMap<Atom, u32>,
+        ElementError,
+    > {
+
+        let coefficient = self
+            .count()?
+            .unwrap_or(1);
+
+        let sequence =
+            self.sequence()?;
+
+        if sequence.is_empty() {
+
+            return Err(self.error(
+                "expected an element \
+                 symbol",
+            ));
+        }
+
+        let mut atoms = BTreeMap::new();
+
+        self.merge(
+            &mut atoms,
+            sequence,
+            coefficient,
+        )?;
+
+        Ok(atoms)
+    }
+
+    /// `(primary count?)*`
+
+    fn sequence(
+        &mut self
+    ) -> Result<
+        BTreeMap<Atom, u32>,
+        ElementError,
+    > {
+
+        let mut atoms = BTreeMap::new();
+
+        loop {
+
+            let primary = match self
+                .peek()
+            {
+                | Some('(') => {
+                    self.group(')')?
+                },
+                | Some('[') => {
+                    self.bracket()?
+                },
+                | Some(c)
+                    if c.is_ascii_uppercase() =>
+                {
+                    BTreeMap::from([(
+                        self.symbol()?,
+                        1,
+                    )])
+                },
+                | _ => return Ok(atoms),
+            };
+
+            let count = self
+                .count()?
+                .unwrap_or(1);
+
+            self.merge(
+                &mut atoms,
+                primary,
+                count,
+            )?;
+        }
+    }
+
+    /// `'(' sequence ')'` or `'[' sequence ']'`
+
+    fn group(
+        &mut self,
+        close: char,
+    ) -> Result<
+        BTreeMap<Atom, u32>,
+        ElementError,
+    > {
+
+        self.bump();
+
+        let atoms = self.sequence()?;
+
+        if atoms.is_empty() {
+
+            return Err(self
+                .error("empty group"));
+        }
+
+        if self.peek() != Some(close) {
+
+            return Err(self.error(
+                &format!(
+                    "expected `{close}`"
+                ),
+            ));
+        }
+
+        self.bump();
+
+        Ok(atoms)
+    }
+
+    /// `'[' mass-number symbol ']'` or a bracketed group
+
+    fn bracket(
+        &mut self
+    ) -> Result<
+        BTreeMap<Atom, u32>,
+        ElementError,
+    > {
+
+        let label = self
+            .rest
+            .get(1 ..)
+            .and_then(|rest| {
+
+                rest.chars().next()
+            })
+            .is_some_and(|c| {
+
+                c.is_ascii_digit()
+            });
+
+        if !label {
+
+            return self.group(']');
+        }
+
+        self.bump();
+
+        let mass_number = self
+            .count()?
+            .and_then(|n| {
+
+                u16::try_from(n).ok()
+            })
+            .ok_or_else(|| {
+
+                self.error(
+                    "mass number out \
+                     of range",
+                )
+            })?;
+
+        if !self
+            .peek()
+            .is_some_and(|c| {
+
+                c.is_ascii_uppercase()
+            })
+        {
+
+            return Err(self.error(
+                "expected an element \
+                 symbol after the \
+                 mass number",
+            ));
+        }
+
+        let element = self.symbol()?;
+
+        if element
+            .mass_number
+            .is_some()
+        {
+
+            return Err(self.error(
+                "`D` and `T` cannot \
+                 carry a mass number",
+            ));
+        }
+
+        if self.peek() != Some(']') {
+
+            return Err(self.error(
+                "expected `]`",
+            ));
+        }
+
+        self.bump();
+
+        let atom = Atom {
+            z: element.z,
+            mass_number: Some(
+                mass_number,
+            ),
+        };
+
+        atom.isotope()?;
+
+        Ok(BTreeMap::from([(
+            atom, 1,
+        )]))
+    }
+
+    /// An uppercase letter followed by lowercase letters.
+
+    fn symbol(
+        &mut self
+    ) -> Result<Atom, ElementError>
+    {
+
+        let end = self
+            .rest
+            .char_indices()
+            .skip(1)
+            .find(|(_, c)| {
+
+                !c.is_ascii_lowercase()
+            })
+            .map_or(
+                self.rest.len(),
+                |(i, _)| i,
+            );
+
+        let (symbol, rest) = self
+            .rest
+            .split_at_checked(end)
+            .unwrap_or((self.rest, ""));
+
+        self.rest = rest;
+
+        let mass_number = match symbol {
+            | "D" => Some(2),
+            | "T" => Some(3),
+            | _ => None,
+        };
+
+        if mass_number.is_some() {
+
+            return Ok(Atom {
+                z: 1,
+                mass_number,
+            });
+        }
+
+        super::by_symbol(symbol)
+            .map(|element| {
+
+                Atom {
+                    z: element.z,
+                    mass_number: None,
+                }
+            })
+            .ok_or_else(|| {
+
+                ElementError::UnknownElement(
+                    symbol.to_string(),
+                )
+            })
+    }
+
+    /// `digit*`, rejecting a count of zero.
+
+    fn count(
+        &mut self
+    ) -> Result<Option<u32>, ElementError>
+    {
+
+        let end = self
+            .rest
+            .find(|c: char| {
+
+                !c.is_ascii_digit()
+            })
+            .unwrap_or(self.rest.len());
+
+        let Some((digits, rest)) = self
+            .rest
+            .split_at_checked(end)
+            .filter(|(digits, _)| {
+
+                !digits.is_empty()
+            })
+        else {
+
+            return Ok(None);
+        };
+
+        let count: u32 = digits
+            .parse()
+            .map_err(|_| {
+
+                self.error(
+                    "count out of \
+                     range",
+                )
+            })?;
+
+        if count == 0 {
+
+            return Err(self.error(
+                "a count must be \
+                 positive",
+            ));
+        }
+
+        self.rest = rest;
+
+        Ok(Some(count))
+    }
+}
@@ -0,0 +1,586 @@
+//! Chemical elements, isotopes and molecular formulas.
+//!
+//! Every element from hydrogen to oganesson is recorded as an
+//! [`Element`] with its IUPAC standard atomic weight, which is an
+//! [`AtomicWeight::Interval`] for the elements whose isotopic composition
+//! varies in normal materials. The naturally occurring isotopes, and the
+//! longest-lived ones of the radioactive elements up to californium, are
+//! recorded as [`Isotope`]s with their masses from the 2020 Atomic Mass
+//! Evaluation, natural abundances and ground-state nuclear spins.
+//!
+//! [`formula`] parses molecular formulas such as `"Ca(OH)2"`,
+//! `"CuSO4·5H2O"` or `"[13C]H4"` and computes molar masses, and
+//! [`pattern`] their isotope patterns.
+//!
+//! ```
+//! 
+//! use rssn_advanced::element;
+//!
+//! let iron =
+//!     element::lookup("Fe").unwrap();
+//!
+//! assert_eq!(iron.z, 26);
+//!
+//! assert_eq!(
+//!     element::lookup("26"),
+//!     Some(iron)
+//! );
+//!
+//! assert_eq!(
+//!     element::lookup("iron"),
+//!     Some(iron)
+//! );
+//!
+//! assert_eq!(
+//!     iron.standard_atomic_weight(),
+//!     Some(55.845)
+//! );
+//! ```
+
+/// The embedded element and isotope tables.
+mod data;
+/// Errors raised by element lookups and formula evaluation.
+pub mod error;
+/// Molecular formula parsing and molar masses.
+pub mod formula;
+/// Isotope pattern distributions of formulas.
+pub mod pattern;
+
+use std::fmt;
+
+pub use error::ElementError;
+pub use formula::Formula;
+pub use pattern::Peak;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::constant::Measured;
+
+/// The standard deviation of a rectangular distribution over an interval
+/// of unit width, `1/(2√3)`.
+
+const RECTANGULAR: f64 =
+    0.288_675_134_594_812_9;
+
+/// A standard atomic weight.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum AtomicWeight {
+    /// A single value with its uncertainty.
+    Value {
+        /// The atomic weight.
+        value: f64,
+        /// The uncertainty, covering the variation in normal materials.
+        uncertainty: f64,
+    },
+    /// An interval covering the variation in normal materials.
+    Interval {
+        /// The lower bound.
+        lower: f64,
+        /// The upper bound.
+        upper: f64,
+        /// The conventional value for trade and commerce.
+        conventional: f64,
+    },
+}
+
+impl AtomicWeight {
+    /// Returns the value to use for a sample of unknown provenance: the
+    /// value itself, or the conventional value of an interval.
+    #[must_use]
+
+    pub const fn value(&self) -> f64 {
+
+        match *self {
+            | Self::Value {
+                value,
+                ..
+            } => value,
+            | Self::Interval {
+                conventional,
+                ..
+            } => conventional,
+        }
+    }
+
+    /// Returns the atomic weight with a standard uncertainty.
+    ///
+    /// An interval is taken as a rectangular distribution, giving its
+    /// midpoint with an uncertainty of `(upper − lower)/(2√3)`, as the
+    /// CIAAW recommends.
+    #[must_use]
+
+    pub const fn measured(
+        &self
+    ) -> Measured {
+
+        match *self {
+            | Self::Value {
+                value,
+                uncertainty,
+            } => {
+                Measured::new(
+                    value,
+                    uncertainty,
+                )
+            },
+            | Self::Interval {
+                lower,
+                upper,
+                ..
+            } => {
+                Measured::new(
+                    f64::midpoint(
+                        lower, upper,
+                    ),
+                    (upper - lower)
+                        * RECTANGULAR,
+                )
+            },
+        }
+    }
+
+    /// Returns whether `weight` lies within the interval, or within the
+    /// uncertainty of the value.
+    #[must_use]
+
+    pub fn contains(
+        &self,
+        weight: f64,
+    ) -> bool {
+
+        match *self {
+            | Self::Value {
+                value,
+                uncertainty,
+            } => {
+                (weight - value).abs()
+                    <= uncertainty
+            },
+            | Self::Interval {
+                lower,
+                upper,
+                ..
+            } => {
+                (lower ..= upper)
+                    .contains(&weight)
+            },
+        }
+    }
+}
+
+/// The parity of a nuclear state.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum Parity {
+    /// Even parity, written `+`.
+    Positive,
+    /// Odd parity, written `−`.
+    Negative,
+}
+
+/// The spin and parity of a nuclear ground state, such as `7/2−`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct NuclearSpin {
+    /// Twice the spin quantum number, so that half-integer spins are
+    /// exact.
+    pub twice: u8,
+    /// The parity.
+    pub parity: Parity,
+}
+
+impl NuclearSpin {
+    /// Returns the spin quantum number.
+    #[must_use]
+
+    pub fn value(&self) -> f64 {
+
+        f64::from(self.twice) / 2.0
+    }
+}
+
+impl fmt::Display for NuclearSpin {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        let parity = match self.parity {
+            | Parity::Positive => '+',
+            | Parity::Negative => '−',
+        };
+
+        if self
+            .twice
+            .is_multiple_of(2)
+        {
+
+            write!(
+                f,
+                "{}{parity}",
+                self.twice / 2
+            )
+        } else {
+
+            write!(
+                f,
+                "{}/2{parity}",
+                self.twice
+            )
+        }
+    }
+}
+
+/// A chemical element.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+)]
+
+pub struct Element {
+    /// The atomic number.
+    pub z: u8,
+    /// The symbol (e.g. `"Fe"`).
+    pub symbol: &'static str,
+    /// The IUPAC name in lower case (e.g. `"iron"`).
+    pub name: &'static str,
+    /// The standard atomic weight, `None` for elements without stable
+    /// isotopes or a characteristic terrestrial composition.
+    pub atomic_weight:
+        Option<AtomicWeight>,
+    /// The mass number of the most abundant isotope, or of the
+    /// longest-lived one for elements without a standard atomic weight.
+    pub mass_number: u16,
+}
+
+impl Element {
+    /// Returns the value of the standard atomic weight, the conventional
+    /// value for an interval.
+    #[must_use]
+
+    pub fn standard_atomic_weight(
+        &self
+    ) -> Option<f64> {
+
+        self.atomic_weight
+            .map(|weight| {
+
+                weight.value()
+            })
+    }
+
+    /// Returns the tabulated isotopes, in order of mass number.
+    #[must_use]
+
+    pub fn isotopes(
+        &self
+    ) -> &'static [Isotope] {
+
+        let start = data::ISOTOPES
+            .partition_point(|i| {
+
+                i.z < self.z
+            });
+
+        let end = data::ISOTOPES
+            .partition_point(|i| {
+
+                i.z <= self.z
+            });
+
+        data::ISOTOPES
+            .get(start .. end)
+            .unwrap_or_default()
+    }
+
+    /// Returns the isotope with mass number `mass_number`, if tabulated.
+    #[must_use]
+
+    pub fn isotope(
+        &self,
+        mass_number: u16,
+    ) -> Option<&'static Isotope> {
+
+        self.isotopes()
+            .iter()
+            .find(|i| {
+
+                i.mass_number
+                    == mass_number
+            })
+    }
+
+    /// Returns the isotopes that occur in nature.
+
+    pub fn natural_isotopes(
+        &self
+    ) -> impl Iterator<Item = &'static Isotope>
+    {
+
+        self.isotopes()
+            .iter()
+            .filter(|i| {
+
+                i.abundance
+                    .is_some()
+            })
+    }
+
+    /// Returns the isotope of mass number [`Element::mass_number`], if
+    /// tabulated.
+    #[must_use]
+
+    pub fn reference_isotope(
+        &self
+    ) -> Option<&'static Isotope> {
+
+        self.isotope(self.mass_number)
+    }
+}
+
+impl fmt::Display for Element {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(self.symbol)
+    }
+}
+
+/// A nuclide of a chemical element.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+)]
+
+pub struct Isotope {
+    /// The atomic number.
+    pub z: u8,
+    /// The mass number.
+    pub mass_number: u16,
+    /// The atomic mass in daltons (unified atomic mass units).
+    pub mass: f64,
+    /// The representative natural abundance as an amount fraction, `None`
+    /// if the isotope does not occur in nature in measurable amounts.
+    pub abundance: Option<f64>,
+    /// The spin and parity of the ground state.
+    pub spin: NuclearSpin,
+}
+
+impl Isotope {
+    /// Returns the element of the isotope, `None` if `z` is out of range.
+    #[must_use]
+
+    pub fn element(
+        &self
+    ) -> Option<&'static Element> {
+
+        by_number(self.z)
+    }
+
+    /// Returns the mass excess `m − A` in daltons.
+    #[must_use]
+
+    pub fn mass_excess(&self) -> f64 {
+
+        self.mass
+            - f64::from(
+                self.mass_number,
+            )
+    }
+}
+
+impl fmt::Display for Isotope {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        write!(
+            f,
+            "{}{}",
+            self.mass_number,
+            self.element()
+                .map_or("?", |e| {
+
+                    e.symbol
+                })
+        )
+    }
+}
+
+/// Accepted alternative spellings of element names.
+
+const NAME_ALIASES: &[(&str, &str)] = &[
+    (
+        "aluminum",
+        "aluminium",
+    ),
+    ("cesium", "caesium"),
+    ("sulphur", "sulfur"),
+];
+
+/// Returns every element, in order of atomic number.
+#[must_use]
+
+pub const fn all() -> &'static [Element]
+{
+
+    data::ELEMENTS
+}
+
+/// Returns every tabulated isotope, sorted by atomic number and then mass
+/// number.
+#[must_use]
+
+pub const fn isotopes()
+-> &'static [Isotope] {
+
+    data::ISOTOPES
+}
+
+/// Looks up an element by its atomic number.
+#[must_use]
+
+pub fn by_number(
+    z: u8
+) -> Option<&'static Element> {
+
+    all().get(
+        usize::from(z)
+            .checked_sub(1)?,
+    )
+}
+
+/// Looks up an element by its symbol.
+///
+/// The comparison is case-sensitive, since `"Co"` and `"CO"` differ.
+#[must_use]
+
+pub fn by_symbol(
+    symbol: &str
+) -> Option<&'static Element> {
+
+    all()
+        .iter()
+        .find(|e| e.symbol == symbol)
+}
+
+/// Looks up an element by its name, ignoring ASCII case.
+///
+/// The American spellings `aluminum` and `cesium` and the British
+/// `sulphur` are accepted.
+#[must_use]
+
+pub fn by_name(
+    name: &str
+) -> Option<&'static Element> {
+
+    let name = NAME_ALIASES
+        .iter()
+        .find(|(alias, _)| {
+
+            alias.eq_ignore_ascii_case(
+                name,
+            )
+        })
+        .map_or(name, |(_, name)| {
+
+            name
+        });
+
+    all()
+        .iter()
+        .find(|e| {
+
+            e.name
+                .eq_ignore_ascii_case(
+                    name,
+                )
+        })
+}
+
+/// Looks up an element by its symbol, atomic number or name.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::element;
+///
+/// for key in [
+///     "Cs",
+///     "55",
+///     "caesium",
+///     "Cesium",
+/// ] {
+///
+///     assert_eq!(
+///         element::lookup(key)
+///             .unwrap()
+///             .symbol,
+///         "Cs"
+///     );
+/// }
+/// ```
+#[must_use]
+
+pub fn lookup(
+    key: &str
+) -> Option<&'static Element> {
+
+    let key = key.trim();
+
+    by_symbol(key)
+        .or_else(|| {
+
+            key.parse()
+                .ok()
+                .and_then(by_number)
+        })
+        .or_else(|| by_name(key))
+}
+
+/// Looks up an isotope by atomic number and mass number.
+#[must_use]
+
+pub fn isotope(
+    z: u8,
+    mass_number: u16,
+) -> Option<&'static Isotope> {
+
+    by_number(z)?.isotope(mass_number)
+}
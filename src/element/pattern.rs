@@ -0,0 +1,282 @@
+//! Isotope pattern distributions of molecular formulas.
+//!
+//! The distribution of a formula is the convolution of the isotopic
+//! compositions of its atoms, computed by repeated squaring for each
+//! element and aggregated by nominal mass: every [`Peak`] gathers the
+//! molecules of one total mass number, at their abundance-weighted mean
+//! mass. Labelled atoms contribute their single isotope.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::ElementError;
+use super::Formula;
+use super::formula::Atom;
+
+/// Contributions below this abundance are dropped during the convolution.
+
+const PRUNE: f64 = 1e-16;
+
+/// A peak of an isotope pattern.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Peak {
+    /// The nominal mass, the total mass number of the molecules.
+    pub mass_number: u32,
+    /// The abundance-weighted mean mass in daltons.
+    pub mass: f64,
+    /// The amount fraction of all molecules falling in this peak.
+    pub abundance: f64,
+}
+
+/// A distribution keyed by nominal mass, holding the abundance and the
+/// abundance-weighted mass.
+
+type Distribution =
+    BTreeMap<u32, (f64, f64)>;
+
+impl Formula {
+    /// Returns the isotope pattern, keeping the peaks whose abundance is
+    /// at least `threshold` times that of the most abundant peak, in order
+    /// of mass.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ElementError::NoNaturalIsotopes`] if an unlabelled
+    /// element does not occur in nature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::element::formula;
+    ///
+    /// let peaks = formula::parse("Cl2")
+    ///     .unwrap()
+    ///     .isotope_pattern(0.01)
+    ///     .unwrap();
+    ///
+    /// let nominal: Vec<_> = peaks
+    ///     .iter()
+    ///     .map(|p| p.mass_number)
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     nominal,
+    ///     [70, 72, 74]
+    /// );
+    /// ```
+
+    pub fn isotope_pattern(
+        &self,
+        threshold: f64,
+    ) -> Result<Vec<Peak>, ElementError>
+    {
+
+        let mut total =
+            Distribution::from([(
+                0,
+                (1.0, 0.0),
+            )]);
+
+        for (atom, count) in
+            self.atoms()
+        {
+
+            let single =
+                composition(atom)?;
+
+            total = convolve(
+                &total,
+                &power(&single, count),
+            );
+        }
+
+        let base = total
+            .values()
+            .map(|(abundance, _)| {
+
+                *abundance
+            })
+            .fold(0.0, f64::max);
+
+        let cutoff =
+            base * threshold.max(0.0);
+
+        Ok(total
+            .into_iter()
+            .filter(
+                |(
+                    _,
+                    (abundance, _),
+                )| {
+
+                    *abundance >= cutoff
+                },
+            )
+            .map(
+                |(
+                    mass_number,
+                    (
+                        abundance,
+                        weighted,
+                    ),
+                )| {
+
+                    Peak {
+                        mass_number,
+                        mass: weighted
+                            / abundance,
+                        abundance,
+                    }
+                },
+            )
+            .collect())
+    }
+}
+
+/// Returns the isotopic composition of a single atom.
+
+fn composition(
+    atom: Atom
+) -> Result<Distribution, ElementError>
+{
+
+    if let Some(isotope) =
+        atom.isotope()?
+    {
+
+        return Ok(Distribution::from(
+            [(
+                u32::from(
+                    isotope.mass_number,
+                ),
+                (1.0, isotope.mass),
+            )],
+        ));
+    }
+
+    let element = atom.element()?;
+
+    let distribution: Distribution =
+        element
+            .natural_isotopes()
+            .filter_map(|isotope| {
+
+                let abundance =
+                    isotope.abundance?;
+
+                Some((
+                    u32::from(
+                        isotope.mass_number,
+                    ),
+                    (
+                        abundance,
+                        abundance
+                            * isotope.mass,
+                    ),
+                ))
+            })
+            .collect();
+
+    if distribution.is_empty() {
+
+        return Err(
+            ElementError::NoNaturalIsotopes(
+                element
+                    .symbol
+                    .to_string(),
+            ),
+        );
+    }
+
+    Ok(distribution)
+}
+
+/// Returns the distribution of the sum of two independent masses.
+
+fn convolve(
+    a: &Distribution,
+    b: &Distribution,
+) -> Distribution {
+
+    let mut product =
+        Distribution::new();
+
+    for (m, (p, pm)) in a {
+
+        for (n, (q, qn)) in b {
+
+            let abundance = p * q;
+
+            if abundance < PRUNE {
+
+                continue;
+            }
+
+            let entry = product
+                .entry(
+                    m.saturating_add(
+                        *n,
+                    ),
+                )
+                .or_insert((0.0, 0.0));
+
+            entry.0 += abundance;
+
+            // The mean mass of the pair is the sum of the means.
+            entry.1 +=
+                pm.mul_add(*q, qn * p);
+        }
+    }
+
+    product
+}
+
+/// Returns the distribution of the sum of `count` independent copies.
+
+fn power(
+    single: &Distribution,
+    count: u32,
+) -> Distribution {
+
+    let mut result = Distribution::from(
+        [(0, (1.0, 0.0))],
+    );
+
+    let mut square = single.clone();
+
+    let mut remaining = count;
+
+    while remaining > 0 {
+
+        if remaining & 1 == 1 {
+
+            result = convolve(
+                &result,
+                &square,
+            );
+        }
+
+        remaining >>= 1;
+
+        if remaining > 0 {
+
+            square = convolve(
+                &square,
+                &square,
+            );
+        }
+    }
+
+    result
+}
@@ -0,0 +1,116 @@
+//! Bincode-based FFI API for the astro module.
+//!
+//! This provides binary serialization for high-performance interop.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::astro_ffi::json::TimeConversion;
+use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::astro_ffi::handle::lookup;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_bincode_buffer;
+use crate::ffi_apis::common::to_bincode_buffer;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+
+/// Returns every registered astronomical constant record as a `bincode_next` buffer.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constants_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    to_bincode_buffer(
+        &crate::astro::all(),
+    )
+}
+
+/// Returns the registry record of the astronomical constant with
+/// identifier `id` as a `bincode_next` buffer.
+///
+/// Returns an empty buffer if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constant_bincode(
+    id: *const c_char
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_bincode_buffer(record)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Converts an instant between time scales.
+///
+/// `request_buf` holds a `bincode_next` encoded `TimeConversion`, and
+/// the result the encoded Modified Julian Date.
+/// Returns an empty buffer if the request cannot be decoded or the
+/// instant cannot be converted.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_astro_convert_time_bincode(
+    request_buf: BincodeBuffer
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        TimeConversion,
+    > = from_bincode_buffer(
+        &request_buf,
+    );
+
+    let mjd = request.and_then(
+        |request| {
+
+            check(
+                crate::astro::time::convert(
+                    request.mjd,
+                    request.from,
+                    request.to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match mjd {
+        | Some(mjd) => {
+            to_bincode_buffer(&mjd)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Returns the leap-second table as a `bincode_next` buffer.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_leap_seconds_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    to_bincode_buffer(
+        &crate::astro::time::LEAP_SECONDS,
+    )
+}
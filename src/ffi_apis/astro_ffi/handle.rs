@@ -0,0 +1,152 @@
+//! Handle-based FFI API for the astro module.
+//!
+//! This provides traditional C-style functions returning strings and
+//! plain values.
+
+use std::os::raw::c_char;
+
+use crate::astro::TimeScale;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::at_index;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::error::require;
+
+/// Returns the number of registered astronomical constants.
+#[unsafe(no_mangle)]
+
+pub const extern "C" fn rssn_get_astro_constant_count()
+-> usize {
+
+    crate::astro::all().len()
+}
+
+/// Returns the identifier of the registered astronomical constant at
+/// `index` as a C string.
+///
+/// Returns null if `index` is out of range.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constant_id(
+    index: usize
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    match at_index(
+        crate::astro::all(),
+        index,
+    ) {
+        | Some(record) => {
+            to_c_string(
+                record
+                    .id
+                    .to_string(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the value of the astronomical constant with identifier `id`
+/// (e.g. `"ASTRONOMICAL_UNIT"`) as a C string.
+///
+/// Returns null if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constant_value(
+    id: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_c_string(
+                record
+                    .value
+                    .to_string(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Converts an instant, as a Modified Julian Date, between the time
+/// scales named `from` and `to` (`"TAI"`, `"TT"`, `"UTC"` or `"TDB"`).
+///
+/// Returns NaN if a time scale is unknown or a UTC instant precedes 1972.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_astro_convert_time(
+    mjd: f64,
+    from: *const c_char,
+    to: *const c_char,
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    let scale =
+        |name: *const c_char| {
+
+            unsafe {
+
+            c_str_to_str(name)
+        }
+        .and_then(|name| {
+
+            check(
+                TimeScale::from_name(name),
+                FfiStatus::NotFound,
+            )
+        })
+        };
+
+    match (
+        scale(from),
+        scale(to),
+    ) {
+        | (Some(from), Some(to)) => {
+            check(
+                crate::astro::time::convert(
+                    mjd, from, to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+            .unwrap_or(f64::NAN)
+        },
+        | _ => f64::NAN,
+    }
+}
+
+/// Looks up the astronomical constant with identifier `id`, recording a
+/// `NotFound` error if there is none.
+
+pub(crate) fn lookup(
+    id: &str
+) -> Option<
+    &'static crate::astro::AstronomicalConstant,
+>{
+
+    require(
+        crate::astro::lookup(id),
+        FfiStatus::NotFound,
+        || {
+
+            format!(
+                "no astronomical \
+                 constant with \
+                 identifier `{id}`"
+            )
+        },
+    )
+}
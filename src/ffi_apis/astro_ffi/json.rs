@@ -0,0 +1,154 @@
+//! JSON-based FFI API for the astro module.
+//!
+//! This provides string-based serialization for easy language interop.
+
+use std::os::raw::c_char;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::astro::TimeScale;
+use crate::ffi_apis::astro_ffi::handle::lookup;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_json_string;
+use crate::ffi_apis::common::to_json_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+
+/// A request to convert an instant between time scales.
+#[derive(
+    Debug, Clone, Serialize, Deserialize,
+)]
+
+pub struct TimeConversion {
+    /// The instant as a Modified Julian Date in `from`.
+    pub mjd: f64,
+    /// The time scale of `mjd`.
+    pub from: TimeScale,
+    /// The time scale to convert to.
+    pub to: TimeScale,
+}
+
+/// Returns every registered astronomical constant record as a JSON array.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constants_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    to_json_string(&crate::astro::all())
+}
+
+/// Returns the registry record of the astronomical constant with
+/// identifier `id` (e.g. `"SOLAR_MASS"`) as a JSON object.
+///
+/// Returns null if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constant_json(
+    id: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_json_string(record)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the value and standard uncertainty of the astronomical
+/// constant with identifier `id` as a JSON `Measured` object.
+///
+/// Returns null if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_astro_constant_measured_json(
+    id: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_json_string(
+                &record.measured(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Converts an instant between time scales.
+///
+/// `request_json` is a JSON `TimeConversion` object, e.g.
+/// `{"mjd": 60000.0, "from": "Utc", "to": "Tdb"}`, and the result the
+/// Modified Julian Date as a JSON number.
+/// Returns null if the request cannot be parsed or the instant cannot be
+/// converted.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_astro_convert_time_json(
+    request_json: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        TimeConversion,
+    > = from_json_string(request_json);
+
+    let mjd = request.and_then(
+        |request| {
+
+            check(
+                crate::astro::time::convert(
+                    request.mjd,
+                    request.from,
+                    request.to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match mjd {
+        | Some(mjd) => {
+            to_json_string(&mjd)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the leap-second table as a JSON array.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_leap_seconds_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    to_json_string(
+        &crate::astro::time::LEAP_SECONDS,
+    )
+}
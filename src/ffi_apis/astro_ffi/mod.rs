@@ -0,0 +1,36 @@
+//! FFI APIs for the astro module.
+//!
+//! This module provides three different FFI API versions:
+//! - **Handle-based**: Traditional C-style functions returning strings
+//!   and plain values
+//! - **JSON-based**: String serialization for easy language interop
+//! - **Bincode-based**: Binary serialization for high performance
+//!
+//! # Examples
+//!
+//! ## Handle-based API (C/C++)
+//! ```c
+//! char* au = rssn_get_astro_constant_value("ASTRONOMICAL_UNIT");
+//! double tdb = rssn_astro_convert_time(60000.0, "UTC", "TDB");
+//! rssn_free_string(au);
+//! ```
+//!
+//! ## JSON-based API (Python, JavaScript, etc.)
+//! ```c
+//! char* json = rssn_astro_convert_time_json(
+//!     "{\"mjd\": 60000.0, \"from\": \"Utc\", \"to\": \"Tai\"}");
+//! // 60000.00042824074
+//! rssn_free_string(json);
+//! ```
+
+/// Bincode-based FFI bindings for astronomical constants and time scales.
+pub mod bincode_api;
+/// Handle-based FFI bindings for astronomical constants and time scales.
+pub mod handle;
+/// JSON-based FFI bindings for astronomical constants and time scales.
+pub mod json;
+
+// Re-export all functions for convenience
+pub use bincode_api::*;
+pub use handle::*;
+pub use json::*;
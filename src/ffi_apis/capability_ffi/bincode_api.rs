@@ -0,0 +1,22 @@
+//! Bincode-based FFI API for the capability module.
+//!
+//! This provides binary serialization for high-performance interop.
+
+use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::common::to_bincode_buffer;
+
+/// Returns the capabilities of the library as a `bincode_next` encoded
+/// `Capabilities`.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_capabilities_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    to_bincode_buffer(
+        crate::capability::capabilities(
+        ),
+    )
+}
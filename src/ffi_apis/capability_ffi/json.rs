@@ -0,0 +1,23 @@
+//! JSON-based FFI API for the capability module.
+//!
+//! This provides string-based serialization for easy language interop.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::to_json_string;
+
+/// Returns the capabilities of the library as a JSON `Capabilities`
+/// object.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_capabilities_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    to_json_string(
+        crate::capability::capabilities(
+        ),
+    )
+}
@@ -0,0 +1,35 @@
+//! FFI APIs for the capability module.
+//!
+//! This module provides two FFI API versions:
+//! - **JSON-based**: String serialization for easy language interop
+//! - **Bincode-based**: Binary serialization for high performance
+//!
+//! Both return the `Capabilities` of the loaded library, so a host can
+//! check the build, the enabled features and the exported functions
+//! before calling them.
+//!
+//! # Examples
+//!
+//! ## JSON-based API (Python, JavaScript, etc.)
+//! ```c
+//! char* json = rssn_get_capabilities_json();
+//! // {"package": "rssn-advanced", "version": "...", "build": {...},
+//! //  "ffi_functions": [{"name": "rssn_unit_convert_json", ...}, ...]}
+//! rssn_free_string(json);
+//! ```
+//!
+//! ## Bincode-based API (High performance)
+//! ```c
+//! BincodeBuffer buf = rssn_get_capabilities_bincode();
+//! // Deserialize binary data
+//! rssn_free_bincode_buffer(buf);
+//! ```
+
+/// Bincode-based FFI bindings for capabilities.
+pub mod bincode_api;
+/// JSON-based FFI bindings for capabilities using serialized data.
+pub mod json;
+
+// Re-export all functions for convenience
+pub use bincode_api::*;
+pub use json::*;
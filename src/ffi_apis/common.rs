@@ -1,13 +1,18 @@
 //! Common FFI utilities shared across all FFI modules.
 //!
 //! This module provides shared types and functions for the three FFI API versions:
-//! - Handle-based (opaque pointers)
+//! - Handle-based (opaque handles, see [`crate::ffi_apis::handle`])
 //! - JSON-based (string serialization)
-//! - Bincode-based (binary serialization)
+//! - Bincode-based (binary serialization, see [`crate::ffi_apis::envelope`])
 
 use std::ffi::CString;
 use std::os::raw::c_char;
 
+use crate::ffi_apis::envelope;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::error::set_last_error;
+
 /// A buffer containing binary data from bincode serialization.
 ///
 /// The caller is responsible for freeing this buffer using `rssn_free_bincode_buffer`.
@@ -146,7 +151,7 @@ pub extern "C" fn rssn_free_bincode_buffer(
 
 /// Helper function to create a C string from a Rust string.
 ///
-/// Returns null on error.
+/// Returns null and sets the last error if the string contains a NUL byte.
 
 #[must_use]
 
@@ -156,7 +161,13 @@ pub fn to_c_string(
 
     match CString::new(s) {
         | Ok(c_str) => c_str.into_raw(),
-        | Err(_) => {
+        | Err(e) => {
+
+            set_last_error(
+                FfiStatus::InteriorNul,
+                e.to_string(),
+            );
+
             std::ptr::null_mut()
         },
     }
@@ -164,7 +175,7 @@ pub fn to_c_string(
 
 /// Helper function to serialize to JSON and return as C string.
 ///
-/// Returns null on error.
+/// Returns null and sets the last error on failure.
 
 pub fn to_json_string<
     T: serde::Serialize,
@@ -174,7 +185,13 @@ pub fn to_json_string<
 
     match serde_json::to_string(value) {
         | Ok(json) => to_c_string(json),
-        | Err(_) => {
+        | Err(e) => {
+
+            set_last_error(
+                FfiStatus::Serialization,
+                e.to_string(),
+            );
+
             std::ptr::null_mut()
         },
     }
@@ -182,7 +199,8 @@ pub fn to_json_string<
 
 /// Helper function to deserialize from JSON C string.
 ///
-/// Returns None on error.
+/// Returns None and sets the last error if the pointer is null, the string
+/// is not valid UTF-8 or the JSON does not decode to `T`.
 
 #[must_use]
 
@@ -192,81 +210,95 @@ pub fn from_json_string<
     json: *const c_char
 ) -> Option<T> {
 
-    if json.is_null() {
-
-        return None;
-    }
-
-    unsafe {
-
-        let c_str =
-            std::ffi::CStr::from_ptr(
-                json,
-            );
+    let json = unsafe {
 
-        c_str
-            .to_str()
-            .ok()
-            .and_then(|s| {
+        c_str_to_str(json)
+    }?;
 
-                serde_json::from_str(s)
-                    .ok()
-            })
-    }
+    check(
+        serde_json::from_str(json),
+        FfiStatus::Deserialization,
+    )
 }
 
 /// Helper function to serialize to `bincode_next` and return as buffer.
 ///
-/// Returns empty buffer on error.
+/// The payload is wrapped in the versioned [`envelope`].
+/// Returns empty buffer and sets the last error on failure.
 
 pub fn to_bincode_buffer<
-    T: serde::Serialize,
+    T: serde::Serialize
+        + crate::ffi_apis::schema::Schema,
 >(
     value: &T
 ) -> BincodeBuffer {
 
-    match bincode_next::serde::encode_to_vec(
-        value,
-        bincode_next::config::standard(),
-    ) {
-        | Ok(bytes) => BincodeBuffer::from_vec(bytes),
-        | Err(_) => BincodeBuffer::empty(),
+    match envelope::encode(value) {
+        | Ok(bytes) => {
+            BincodeBuffer::from_vec(
+                bytes,
+            )
+        },
+        | Err(e) => {
+
+            set_last_error(
+                e.status(),
+                e.to_string(),
+            );
+
+            BincodeBuffer::empty()
+        },
     }
 }
 
 /// Helper function to deserialize from bincode buffer.
 ///
-/// Returns None on error.
+/// Returns None and sets the last error if the buffer is empty, its
+/// [`envelope`] is damaged or holds another type, or the payload does not
+/// decode to `T`.
 
 #[must_use]
 
 pub fn from_bincode_buffer<
-    T: serde::de::DeserializeOwned,
+    T: serde::de::DeserializeOwned
+        + crate::ffi_apis::schema::Schema,
 >(
     buffer: &BincodeBuffer
 ) -> Option<T> {
 
     if buffer.is_null() {
 
+        set_last_error(
+            FfiStatus::NullPointer,
+            "the input buffer is empty",
+        );
+
         return None;
     }
 
-    unsafe {
+    let slice = unsafe {
 
-        let slice = buffer.as_slice();
+        buffer.as_slice()
+    };
 
-        bincode_next::serde::decode_from_slice(
-            slice,
-            bincode_next::config::standard(),
-        )
-        .ok()
-        .map(|(v, _)| v)
+    match envelope::decode(slice) {
+        | Ok(value) => Some(value),
+        | Err(e) => {
+
+            set_last_error(
+                e.status(),
+                e.to_string(),
+            );
+
+            None
+        },
     }
 }
 
 /// Helper to convert a C string pointer to a Rust string slice.
 ///
-/// Returns None if the pointer is null or the string is not valid UTF-8.
+/// Returns None and sets the last error if the pointer is null or the
+/// string is not valid UTF-8.
 ///
 /// # Safety
 ///
@@ -289,12 +321,20 @@ pub unsafe fn c_str_to_str<'a>(
 
         if s.is_null() {
 
+            set_last_error(
+                FfiStatus::NullPointer,
+                "a string argument is \
+                 null",
+            );
+
             None
         } else {
 
-            std::ffi::CStr::from_ptr(s)
-                .to_str()
-                .ok()
+            check(
+                std::ffi::CStr::from_ptr(s)
+                    .to_str(),
+                FfiStatus::InvalidUtf8,
+            )
         }
     }
 }
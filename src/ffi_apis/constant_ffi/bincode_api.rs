@@ -2,8 +2,15 @@
 //!
 //! This provides binary serialization for high-performance interop.
 
+use std::os::raw::c_char;
+
 use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_bincode_buffer;
+use crate::ffi_apis::common::to_bincode_buffer;
+use crate::ffi_apis::constant_ffi::handle::lookup;
 use crate::ffi_apis::constant_ffi::json::BuildInfo;
+use crate::ffi_apis::constant_ffi::json::constant_records;
 
 /// Returns all build information as a `bincode_next` buffer.
 /// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
@@ -12,6 +19,8 @@ use crate::ffi_apis::constant_ffi::json::BuildInfo;
 pub extern "C" fn rssn_get_build_info_bincode()
 -> BincodeBuffer {
 
+    let _call = ffi_call!();
+
     let info = BuildInfo {
         build_date : crate::constant::get_build_date().to_string(),
         commit_sha : crate::constant::get_commit_sha().to_string(),
@@ -20,13 +29,7 @@ pub extern "C" fn rssn_get_build_info_bincode()
         system_info : crate::constant::get_system_info().to_string(),
     };
 
-    match bincode_next::serde::encode_to_vec(
-        &info,
-        bincode_next::config::standard(),
-    ) {
-        | Ok(bytes) => BincodeBuffer::from_vec(bytes),
-        | Err(_) => BincodeBuffer::empty(),
-    }
+    to_bincode_buffer(&info)
 }
 
 /// Returns the build date as a `bincode_next` buffer.
@@ -36,17 +39,13 @@ pub extern "C" fn rssn_get_build_info_bincode()
 pub extern "C" fn rssn_get_build_date_bincode()
 -> BincodeBuffer {
 
+    let _call = ffi_call!();
+
     let date =
         crate::constant::get_build_date(
         );
 
-    match bincode_next::serde::encode_to_vec(
-        date,
-        bincode_next::config::standard(),
-    ) {
-        | Ok(bytes) => BincodeBuffer::from_vec(bytes),
-        | Err(_) => BincodeBuffer::empty(),
-    }
+    to_bincode_buffer(&date)
 }
 
 /// Returns the commit SHA as a `bincode_next` buffer.
@@ -56,176 +55,206 @@ pub extern "C" fn rssn_get_build_date_bincode()
 pub extern "C" fn rssn_get_commit_sha_bincode()
 -> BincodeBuffer {
 
+    let _call = ffi_call!();
+
     let sha =
         crate::constant::get_commit_sha(
         );
 
-    match bincode_next::serde::encode_to_vec(
-        sha,
-        bincode_next::config::standard(),
-    ) {
-        | Ok(bytes) => BincodeBuffer::from_vec(bytes),
-        | Err(_) => BincodeBuffer::empty(),
-    }
+    to_bincode_buffer(&sha)
 }
 
-macro_rules! gen_ffi_bincode {
-    ($ffi_name:ident, $internal_getter:path) => {
-/// Generates a FFI function that retrieves a constant value,
-/// serializes it using `bincode_next`, and returns it as a `BincodeBuffer`.
-///
-/// # Safety
-///
+/// Returns every registered physical constant record as a `bincode_next` buffer.
 /// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
-        #[unsafe(no_mangle)]
-        pub extern "C" fn $ffi_name() -> BincodeBuffer {
-            let value = $internal_getter();
-
-            match bincode_next::serde::encode_to_vec(
-                &value,
-                bincode_next::config::standard(),
-            ) {
-                Ok(bytes) => BincodeBuffer::from_vec(bytes),
-                Err(_) => BincodeBuffer::empty(),
-            }
-        }
-    };
-}
-
-// --- FFI Implementations ---
-
-// // --- Build Info ---
-// gen_ffi_bincode!(rssn_get_build_date_bincode, crate::constant::get_build_date);
-// gen_ffi_bincode!(rssn_get_commit_sha_bincode, crate::constant::get_commit_sha);
-// gen_ffi_bincode!(rssn_get_rustc_version_bincode, crate::constant::get_rustc_version);
-// gen_ffi_bincode!(rssn_get_cargo_target_triple_bincode, crate::constant::get_cargo_target_triple);
-// gen_ffi_bincode!(rssn_get_system_info_bincode, crate::constant::get_system_info);
-// I will comment out these calls for now but personally I think it's a good idea to have them instead of the original functions.
-
-// --- Fundamental & Electromagnetic ---
-gen_ffi_bincode!(
-    rssn_get_speed_of_light_bincode,
-    crate::constant::get_speed_of_light
-);
-
-gen_ffi_bincode!(rssn_get_planck_constant_bincode, crate::constant::get_planck_constant);
-
-gen_ffi_bincode!(rssn_get_reduced_planck_constant_bincode, crate::constant::get_reduced_planck_constant);
-
-gen_ffi_bincode!(rssn_get_elementary_charge_bincode, crate::constant::get_elementary_charge);
-
-gen_ffi_bincode!(rssn_get_gravitational_constant_bincode, crate::constant::get_gravitational_constant);
-
-gen_ffi_bincode!(rssn_get_fine_structure_constant_bincode, crate::constant::get_fine_structure_constant);
-
-gen_ffi_bincode!(rssn_get_inverse_fine_structure_constant_bincode, crate::constant::get_inverse_fine_structure_constant);
-
-gen_ffi_bincode!(rssn_get_vacuum_magnetic_permeability_bincode, crate::constant::get_vacuum_magnetic_permeability);
-
-gen_ffi_bincode!(rssn_get_vacuum_electric_permittivity_bincode, crate::constant::get_vacuum_electric_permittivity);
-
-gen_ffi_bincode!(rssn_get_josephson_constant_bincode, crate::constant::get_josephson_constant);
-
-gen_ffi_bincode!(rssn_get_von_klitzing_constant_bincode, crate::constant::get_von_klitzing_constant);
-
-gen_ffi_bincode!(rssn_get_magnetic_flux_quantum_bincode, crate::constant::get_magnetic_flux_quantum);
-
-// --- Atomic & Particle masses ---
-gen_ffi_bincode!(
-    rssn_get_electron_mass_bincode,
-    crate::constant::get_electron_mass
-);
-
-gen_ffi_bincode!(rssn_get_electron_mass_u_bincode, crate::constant::get_electron_mass_u);
+#[unsafe(no_mangle)]
 
-gen_ffi_bincode!(
-    rssn_get_proton_mass_kg_bincode,
-    crate::constant::get_proton_mass_kg
-);
+pub extern "C" fn rssn_get_constants_bincode()
+-> BincodeBuffer {
 
-gen_ffi_bincode!(
-    rssn_get_proton_mass_u_bincode,
-    crate::constant::get_proton_mass_u
-);
+    let _call = ffi_call!();
 
-gen_ffi_bincode!(
-    rssn_get_neutron_mass_u_bincode,
-    crate::constant::get_neutron_mass_u
-);
+    to_bincode_buffer(
+        &crate::constant::all(),
+    )
+}
 
-gen_ffi_bincode!(rssn_get_deuteron_mass_u_bincode, crate::constant::get_deuteron_mass_u);
+/// Returns the flat record of every registered constant as a `bincode_next` buffer.
+///
+/// The buffer holds a `Vec<ConstantRecord>`, the binary form of `rssn_get_constant_table`.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
 
-gen_ffi_bincode!(rssn_get_alpha_particle_mass_u_bincode, crate::constant::get_alpha_particle_mass_u);
+pub extern "C" fn rssn_get_constant_records_bincode()
+-> BincodeBuffer {
 
-gen_ffi_bincode!(rssn_get_atomic_mass_constant_bincode, crate::constant::get_atomic_mass_constant);
+    let _call = ffi_call!();
 
-gen_ffi_bincode!(rssn_get_rydberg_constant_bincode, crate::constant::get_rydberg_constant);
+    to_bincode_buffer(
+        &constant_records(),
+    )
+}
 
-gen_ffi_bincode!(
-    rssn_get_bohr_radius_bincode,
-    crate::constant::get_bohr_radius
-);
+/// Returns the registry record of the constant with identifier `id` (e.g. `"SPEED_OF_LIGHT"`) as a `bincode_next` buffer.
+///
+/// Returns an empty buffer if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
 
-gen_ffi_bincode!(
-    rssn_get_hartree_energy_bincode,
-    crate::constant::get_hartree_energy
-);
+pub extern "C" fn rssn_get_constant_bincode(
+    id: *const c_char
+) -> BincodeBuffer {
 
-gen_ffi_bincode!(rssn_get_classical_electron_radius_bincode, crate::constant::get_classical_electron_radius);
+    let _call = ffi_call!();
 
-gen_ffi_bincode!(rssn_get_thomson_cross_section_bincode, crate::constant::get_thomson_cross_section);
+    let record = unsafe {
 
-// --- Magnetic & G-factors ---
-gen_ffi_bincode!(
-    rssn_get_bohr_magneton_bincode,
-    crate::constant::get_bohr_magneton
-);
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_bincode_buffer(record)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
 
-gen_ffi_bincode!(rssn_get_nuclear_magneton_bincode, crate::constant::get_nuclear_magneton);
+/// Returns the value and standard uncertainty of the constant with identifier `id` as a `bincode_next` encoded `Measured`.
+///
+/// Returns an empty buffer if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
 
-gen_ffi_bincode!(rssn_get_electron_g_factor_bincode, crate::constant::get_electron_g_factor);
+pub extern "C" fn rssn_get_constant_measured_bincode(
+    id: *const c_char
+) -> BincodeBuffer {
 
-gen_ffi_bincode!(
-    rssn_get_muon_g_factor_bincode,
-    crate::constant::get_muon_g_factor
-);
+    let _call = ffi_call!();
 
-gen_ffi_bincode!(rssn_get_proton_magnetic_moment_bincode, crate::constant::get_proton_magnetic_moment);
+    let record = unsafe {
 
-gen_ffi_bincode!(rssn_get_neutron_magnetic_moment_bincode, crate::constant::get_neutron_magnetic_moment);
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_bincode_buffer(
+                &record.measured(),
+            )
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
 
-gen_ffi_bincode!(rssn_get_shielded_proton_gyromagnetic_ratio_bincode, crate::constant::get_shielded_proton_gyromagnetic_ratio);
+/// Returns the records of every constant in a group as a `bincode_next` buffer.
+///
+/// `group_buf` holds a `bincode_next` encoded `ConstantGroup`.
+/// Returns an empty buffer if the group cannot be decoded.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
 
-// --- Thermodynamic & Physicochemical ---
-gen_ffi_bincode!(rssn_get_boltzmann_constant_bincode, crate::constant::get_boltzmann_constant);
+pub extern "C" fn rssn_get_constants_in_group_bincode(
+    group_buf: BincodeBuffer
+) -> BincodeBuffer {
 
-gen_ffi_bincode!(rssn_get_avogadro_constant_bincode, crate::constant::get_avogadro_constant);
+    let _call = ffi_call!();
 
-gen_ffi_bincode!(rssn_get_molar_gas_constant_bincode, crate::constant::get_molar_gas_constant);
+    let group: Option<
+        crate::constant::ConstantGroup,
+    > = from_bincode_buffer(&group_buf);
 
-gen_ffi_bincode!(rssn_get_faraday_constant_bincode, crate::constant::get_faraday_constant);
+    match group {
+        | Some(group) => {
 
-gen_ffi_bincode!(rssn_get_stefan_boltzmann_constant_bincode, crate::constant::get_stefan_boltzmann_constant);
+            let records: Vec<_> =
+                crate::constant::in_group(
+                    group,
+                )
+                .collect();
 
-gen_ffi_bincode!(rssn_get_wien_displacement_constant_bincode, crate::constant::get_wien_displacement_constant);
+            to_bincode_buffer(&records)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
 
-gen_ffi_bincode!(rssn_get_molar_volume_ideal_gas_bincode, crate::constant::get_molar_volume_ideal_gas);
+/// Returns the constants matching `query` by name, alias or symbol as a
+/// `bincode_next` encoded sequence of matches, best first.
+///
+/// Returns an empty buffer if `query` is not valid UTF-8.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
 
-gen_ffi_bincode!(rssn_get_first_radiation_constant_bincode, crate::constant::get_first_radiation_constant);
+pub extern "C" fn rssn_find_constants_bincode(
+    query: *const c_char
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    match unsafe {
+
+        c_str_to_str(query)
+    } {
+        | Some(query) => {
+            to_bincode_buffer(
+                &crate::constant::find(
+                    query,
+                ),
+            )
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
 
-gen_ffi_bincode!(rssn_get_second_radiation_constant_bincode, crate::constant::get_second_radiation_constant);
+macro_rules! gen_ffi_bincode {
+    (
+        $ffi_name:ident,
+        $internal_getter:path
+    ) => {
+        /// Generates a FFI function that retrieves a constant value,
+        /// serializes it using `bincode_next`, and returns it as a `BincodeBuffer`.
+        ///
+        /// # Safety
+        ///
+        /// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+        #[unsafe(no_mangle)]
 
-// --- Ratios ---
-gen_ffi_bincode!(rssn_get_proton_electron_mass_ratio_bincode, crate::constant::get_proton_electron_mass_ratio);
+        pub extern "C" fn $ffi_name()
+        -> BincodeBuffer {
 
-gen_ffi_bincode!(rssn_get_muon_electron_mass_ratio_bincode, crate::constant::get_muon_electron_mass_ratio);
+            let _call = ffi_call!();
 
-gen_ffi_bincode!(rssn_get_neutron_proton_mass_ratio_bincode, crate::constant::get_neutron_proton_mass_ratio);
+            let value =
+                $internal_getter();
 
-gen_ffi_bincode!(rssn_get_electron_muon_mass_ratio_bincode, crate::constant::get_electron_muon_mass_ratio);
+            to_bincode_buffer(&value)
+        }
+    };
+}
 
-gen_ffi_bincode!(rssn_get_deuteron_proton_mass_ratio_bincode, crate::constant::get_deuteron_proton_mass_ratio);
+// --- FFI Implementations ---
 
-gen_ffi_bincode!(rssn_get_electron_charge_to_mass_quotient_bincode, crate::constant::get_electron_charge_to_mass_quotient);
+// // --- Build Info ---
+// gen_ffi_bincode!(rssn_get_build_date_bincode, crate::constant::get_build_date);
+// gen_ffi_bincode!(rssn_get_commit_sha_bincode, crate::constant::get_commit_sha);
+// gen_ffi_bincode!(rssn_get_rustc_version_bincode, crate::constant::get_rustc_version);
+// gen_ffi_bincode!(rssn_get_cargo_target_triple_bincode, crate::constant::get_cargo_target_triple);
+// gen_ffi_bincode!(rssn_get_system_info_bincode, crate::constant::get_system_info);
+// I will comment out these calls for now but personally I think it's a good idea to have them instead of the original functions.
 
-gen_ffi_bincode!(rssn_get_muon_magnetic_moment_bincode, crate::constant::get_muon_magnetic_moment);
+// --- CODATA Constants ---
+// One getter per constant, generated by `build/codata.rs` from `data/codata/allascii_2022.txt`.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_ffi_bincode.rs"
+));
@@ -4,6 +4,21 @@
 
 use std::ffi::CString;
 use std::os::raw::c_char;
+use std::sync::LazyLock;
+
+use rssn::symbolic::core::Expr;
+
+use crate::constant::CodataEdition;
+use crate::constant::Measured;
+use crate::constant::symbolic;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::at_index;
+use crate::ffi_apis::error::require;
+use crate::ffi_apis::error::set_last_error;
+use crate::ffi_apis::handle;
+use crate::ffi_apis::handle::RssnHandle;
 
 /// Returns the build date as a C string.
 /// The caller must free the returned string using `rssn_free_string`.
@@ -12,16 +27,12 @@ use std::os::raw::c_char;
 pub extern "C" fn rssn_get_build_date()
 -> *mut c_char {
 
-    let date =
-        crate::constant::get_build_date(
-        );
+    let _call = ffi_call!();
 
-    match CString::new(date) {
-        | Ok(c_str) => c_str.into_raw(),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
-    }
+    to_c_string(
+        crate::constant::get_build_date()
+            .to_string(),
+    )
 }
 
 /// Returns the commit SHA as a C string.
@@ -31,16 +42,12 @@ pub extern "C" fn rssn_get_build_date()
 pub extern "C" fn rssn_get_commit_sha()
 -> *mut c_char {
 
-    let sha =
-        crate::constant::get_commit_sha(
-        );
+    let _call = ffi_call!();
 
-    match CString::new(sha) {
-        | Ok(c_str) => c_str.into_raw(),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
-    }
+    to_c_string(
+        crate::constant::get_commit_sha()
+            .to_string(),
+    )
 }
 
 /// Returns the rustc version as a C string.
@@ -50,14 +57,12 @@ pub extern "C" fn rssn_get_commit_sha()
 pub extern "C" fn rssn_get_rustc_version()
 -> *mut c_char {
 
-    let version = crate::constant::get_rustc_version();
+    let _call = ffi_call!();
 
-    match CString::new(version) {
-        | Ok(c_str) => c_str.into_raw(),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
-    }
+    to_c_string(
+        crate::constant::get_rustc_version()
+            .to_string(),
+    )
 }
 
 /// Returns the cargo target triple as a C string.
@@ -67,14 +72,12 @@ pub extern "C" fn rssn_get_rustc_version()
 pub extern "C" fn rssn_get_cargo_target_triple()
 -> *mut c_char {
 
-    let triple = crate::constant::get_cargo_target_triple();
+    let _call = ffi_call!();
 
-    match CString::new(triple) {
-        | Ok(c_str) => c_str.into_raw(),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
-    }
+    to_c_string(
+        crate::constant::get_cargo_target_triple()
+            .to_string(),
+    )
 }
 
 /// Returns the system info as a C string.
@@ -84,179 +87,682 @@ pub extern "C" fn rssn_get_cargo_target_triple()
 pub extern "C" fn rssn_get_system_info()
 -> *mut c_char {
 
-    let info = crate::constant::get_system_info();
+    let _call = ffi_call!();
+
+    to_c_string(
+        crate::constant::get_system_info()
+            .to_string(),
+    )
+}
+
+/// Returns the number of registered physical constants.
+#[unsafe(no_mangle)]
+
+pub const extern "C" fn rssn_get_constant_count()
+-> usize {
+
+    crate::constant::all().len()
+}
 
-    match CString::new(info) {
-        | Ok(c_str) => c_str.into_raw(),
-        | Err(_) => {
-            std::ptr::null_mut()
+/// Returns the identifier of the registered constant at `index` as a C string.
+///
+/// Together with `rssn_get_constant_count` this enumerates the registry.
+/// Returns null if `index` is out of range.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_constant_id(
+    index: usize
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    match at_index(
+        crate::constant::all(),
+        index,
+    ) {
+        | Some(record) => {
+            to_c_string(
+                record
+                    .id
+                    .to_string(),
+            )
         },
+        | None => std::ptr::null_mut(),
     }
 }
 
-macro_rules! gen_ffi_handle {
-    ($ffi_name:ident, $internal_getter:path) => {
-/// Generates an `FFI` function that retrieves a constant value.
+/// Returns the value of the constant with identifier `id` (e.g. `"SPEED_OF_LIGHT"`) as a C string.
+///
+/// Returns null if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_constant_value(
+    id: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_c_string(
+                record
+                    .value
+                    .to_string(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the value of the constant with identifier `id` (e.g.
+/// `"SPEED_OF_LIGHT"`) as a `double`.
+///
+/// Returns `NaN` if `id` is not valid UTF-8 or no such constant exists.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_constant_value_f64(
+    id: *const c_char
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    unsafe {
+
+        c_str_to_str(id)
+    }
+    .and_then(lookup)
+    .map_or(f64::NAN, |record| {
+
+        record.value
+    })
+}
+
+/// Flag of an [`RssnConstantRecord`] whose value is exact by definition.
+
+pub const RSSN_CONSTANT_EXACT: u32 = 1;
+
+/// Flag of an [`RssnConstantRecord`] whose exact value is listed truncated.
+
+pub const RSSN_CONSTANT_TRUNCATED: u32 =
+    2;
+
+/// One registered physical constant, laid out for C.
 ///
-/// This function converts the value to a `C` string and returns a pointer to it.
+/// The strings are NUL-terminated, live as long as the program and must
+/// not be freed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+
+pub struct RssnConstantRecord {
+    /// The identifier (e.g. `"SPEED_OF_LIGHT"`).
+    pub id: *const c_char,
+    /// The NIST quantity name (e.g. `"speed of light in vacuum"`).
+    pub name: *const c_char,
+    /// The conventional symbol (e.g. `"c"`).
+    pub symbol: *const c_char,
+    /// The unit of `value` (e.g. `"m s⁻¹"`).
+    pub unit: *const c_char,
+    /// The value.
+    pub value: f64,
+    /// The standard uncertainty, zero for exact values.
+    pub uncertainty: f64,
+    /// The relative standard uncertainty, zero for exact values.
+    pub relative_uncertainty: f64,
+    /// `RSSN_CONSTANT_EXACT` and `RSSN_CONSTANT_TRUNCATED` bits.
+    pub flags: u32,
+}
+
+/// An array of [`RssnConstantRecord`]s.
+///
+/// The caller must free the array using `rssn_free_constant_table`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+
+pub struct RssnConstantTable {
+    /// Pointer to the first record.
+    pub records:
+        *mut RssnConstantRecord,
+    /// Number of records.
+    pub len: usize,
+}
+
+/// Returns the flags of `record`.
+#[must_use]
+
+pub(crate) const fn flags(
+    record: &crate::constant::PhysicalConstant
+) -> u32 {
+
+    let mut flags = 0;
+
+    if record.exact {
+
+        flags |= RSSN_CONSTANT_EXACT;
+    }
+
+    if record.truncated {
+
+        flags |=
+            RSSN_CONSTANT_TRUNCATED;
+    }
+
+    flags
+}
+
+/// The NUL-terminated identifier, name, symbol and unit of every
+/// registered constant, in registry order.
+
+static STRINGS: LazyLock<
+    Vec<[CString; 4]>,
+> = LazyLock::new(|| {
+
+    crate::constant::all()
+        .iter()
+        .map(|record| {
+
+            [
+                record.id,
+                record.name,
+                record.symbol,
+                record.unit,
+            ]
+            .map(|text| {
+
+                CString::new(text)
+                    .unwrap_or_default()
+            })
+        })
+        .collect()
+});
+
+/// Returns every registered physical constant as one C array.
+///
+/// The caller must free the table using `rssn_free_constant_table`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_constant_table()
+-> RssnConstantTable {
+
+    let records: Box<[RssnConstantRecord]> =
+        crate::constant::all()
+            .iter()
+            .zip(STRINGS.iter())
+            .map(|(record, [id, name, symbol, unit])| {
+
+                RssnConstantRecord {
+                    id: id.as_ptr(),
+                    name: name.as_ptr(),
+                    symbol: symbol.as_ptr(),
+                    unit: unit.as_ptr(),
+                    value: record.value,
+                    uncertainty: record
+                        .uncertainty,
+                    relative_uncertainty:
+                        record
+                            .relative_uncertainty,
+                    flags: flags(record),
+                }
+            })
+            .collect();
+
+    let len = records.len();
+
+    RssnConstantTable {
+        records: Box::into_raw(records)
+            .cast(),
+        len,
+    }
+}
+
+/// Frees a table returned by `rssn_get_constant_table`.
 ///
 /// # Safety
 ///
-/// The caller is responsible for memory management. The returned string must
-/// be freed using `rssn_free_string` to avoid memory leaks.
-        #[unsafe(no_mangle)]
-        pub extern "C" fn $ffi_name() -> *mut std::os::raw::c_char {
-            let value = $internal_getter();
+/// `table` must have been returned by `rssn_get_constant_table` and not
+/// yet freed.
+#[unsafe(no_mangle)]
 
-            // Convert to string (works for both f64 and &str)
-            let val_str = value.to_string();
+pub unsafe extern "C" fn rssn_free_constant_table(
+    table: RssnConstantTable
+) {
 
-            match std::ffi::CString::new(val_str) {
-                Ok(c_str) => c_str.into_raw(),
-                Err(_) => std::ptr::null_mut(),
-            }
+    if !table
+        .records
+        .is_null()
+    {
+
+        unsafe {
+
+            drop(Box::from_raw(
+                std::ptr::slice_from_raw_parts_mut(
+                    table.records,
+                    table.len,
+                ),
+            ));
         }
-    };
+    }
 }
 
-// --- FFI Implementations ---
+/// Looks up a constant by name, alias or symbol (e.g. `"mu0"`, `"ħ"` or
+/// `"bohr radius"`) and writes its value to `out_value`.
+///
+/// Returns `false`, leaving `out_value` untouched, if `out_value` is null,
+/// or `name` is not valid UTF-8 or does not name one constant. A name that
+/// matches nothing records `NotFound`; one that matches several constants
+/// without a clear winner (e.g. `"mass"`) records `Ambiguous`, with the
+/// best candidates in the message.
+///
+/// # Safety
+///
+/// `out_value` must be null or valid for writing an `f64`.
+#[unsafe(no_mangle)]
 
-// // --- Build Info (Original Strings) ---
-// gen_ffi_handle!(rssn_get_build_date, crate::constant::get_build_date);
-// gen_ffi_handle!(rssn_get_commit_sha, crate::constant::get_commit_sha);
-// gen_ffi_handle!(rssn_get_rustc_version, crate::constant::get_rustc_version);
-// gen_ffi_handle!(rssn_get_cargo_target_triple, crate::constant::get_cargo_target_triple);
-// gen_ffi_handle!(rssn_get_system_info, crate::constant::get_system_info);
-// I will comment out these calls for now but personally I think it's a good idea to have them instead of the original functions.
+pub unsafe extern "C" fn rssn_constant_lookup(
+    name: *const c_char,
+    out_value: *mut f64,
+) -> bool {
 
-// --- Fundamental Constants ---
-gen_ffi_handle!(
-    rssn_get_speed_of_light,
-    crate::constant::get_speed_of_light
-);
+    let _call = ffi_call!();
 
-gen_ffi_handle!(rssn_get_planck_constant, crate::constant::get_planck_constant);
+    if out_value.is_null() {
 
-gen_ffi_handle!(rssn_get_reduced_planck_constant, crate::constant::get_reduced_planck_constant);
+        set_last_error(
+            FfiStatus::NullPointer,
+            "`out_value` is null",
+        );
 
-gen_ffi_handle!(rssn_get_elementary_charge, crate::constant::get_elementary_charge);
+        return false;
+    }
 
-gen_ffi_handle!(rssn_get_gravitational_constant, crate::constant::get_gravitational_constant);
+    let record = unsafe {
 
-gen_ffi_handle!(rssn_get_fine_structure_constant, crate::constant::get_fine_structure_constant);
+        c_str_to_str(name)
+    }
+    .and_then(resolve);
 
-gen_ffi_handle!(rssn_get_inverse_fine_structure_constant, crate::constant::get_inverse_fine_structure_constant);
+    match record {
+        | Some(record) => {
 
-// --- Electromagnetic Constants ---
-gen_ffi_handle!(rssn_get_vacuum_magnetic_permeability, crate::constant::get_vacuum_magnetic_permeability);
+            unsafe {
 
-gen_ffi_handle!(rssn_get_vacuum_electric_permittivity, crate::constant::get_vacuum_electric_permittivity);
+                *out_value =
+                    record.value;
+            }
 
-gen_ffi_handle!(rssn_get_josephson_constant, crate::constant::get_josephson_constant);
+            true
+        },
+        | None => false,
+    }
+}
 
-gen_ffi_handle!(rssn_get_von_klitzing_constant, crate::constant::get_von_klitzing_constant);
+/// Looks up the constant with identifier `id`, recording a `NotFound`
+/// error if there is none.
 
-gen_ffi_handle!(rssn_get_magnetic_flux_quantum, crate::constant::get_magnetic_flux_quantum);
+pub(crate) fn lookup(
+    id: &str
+) -> Option<
+    &'static crate::constant::PhysicalConstant,
+>{
 
-// --- Atomic & Particle Constants ---
-gen_ffi_handle!(
-    rssn_get_electron_mass,
-    crate::constant::get_electron_mass
-);
+    require(
+        crate::constant::lookup(id),
+        FfiStatus::NotFound,
+        || {
 
-gen_ffi_handle!(rssn_get_electron_mass_u, crate::constant::get_electron_mass_u);
+            format!(
+                "no constant with \
+                 identifier `{id}`"
+            )
+        },
+    )
+}
 
-gen_ffi_handle!(
-    rssn_get_proton_mass_kg,
-    crate::constant::get_proton_mass_kg
-);
+/// Resolves a name, alias or symbol to one constant, recording a
+/// `NotFound` error if it matches nothing and an `Ambiguous` one, with the
+/// best candidates, if it matches several without a clear winner.
+
+pub(crate) fn resolve(
+    name: &str
+) -> Option<
+    &'static crate::constant::PhysicalConstant,
+>{
+
+    crate::constant::search::resolve(
+        name,
+    )
+    .map_err(|candidates| {
+
+        if candidates.is_empty() {
+
+            set_last_error(
+                FfiStatus::NotFound,
+                format!(
+                    "no constant matches \
+                     `{name}`"
+                ),
+            );
+        } else {
+
+            let ids: Vec<&str> =
+                candidates
+                    .iter()
+                    .take(5)
+                    .map(|m| {
+
+                        m.constant.id
+                    })
+                    .collect();
+
+            set_last_error(
+                FfiStatus::Ambiguous,
+                format!(
+                    "`{name}` does not \
+                     name one constant; \
+                     candidates: {}",
+                    ids.join(", ")
+                ),
+            );
+        }
+    })
+    .ok()
+}
 
-gen_ffi_handle!(
-    rssn_get_proton_mass_u,
-    crate::constant::get_proton_mass_u
-);
+/// Returns a handle to the value and standard uncertainty of the constant
+/// with identifier `id` (e.g. `"ELECTRON_MASS"`).
+///
+/// Returns the null handle if `id` is not valid UTF-8 or no such constant
+/// exists. The handle must be freed using `rssn_handle_free`.
+#[unsafe(no_mangle)]
 
-gen_ffi_handle!(
-    rssn_get_neutron_mass_u,
-    crate::constant::get_neutron_mass_u
-);
+pub extern "C" fn rssn_constant_measured_handle(
+    id: *const c_char
+) -> RssnHandle {
 
-gen_ffi_handle!(rssn_get_deuteron_mass_u, crate::constant::get_deuteron_mass_u);
+    let _call = ffi_call!();
 
-gen_ffi_handle!(rssn_get_alpha_particle_mass_u, crate::constant::get_alpha_particle_mass_u);
+    unsafe {
 
-gen_ffi_handle!(rssn_get_atomic_mass_constant, crate::constant::get_atomic_mass_constant);
+        c_str_to_str(id)
+    }
+    .and_then(lookup)
+    .map_or(
+        RssnHandle::NULL,
+        |record| {
+
+            handle::insert(
+                record.measured(),
+            )
+        },
+    )
+}
 
-gen_ffi_handle!(rssn_get_rydberg_constant, crate::constant::get_rydberg_constant);
+/// Returns a handle to the symbolic `Expr` of the constant named by `key`,
+/// an identifier or a symbol (e.g. `"ħ"`).
+///
+/// Returns the null handle if `key` is not valid UTF-8 or no such constant
+/// exists. The handle must be freed using `rssn_handle_free`.
+#[unsafe(no_mangle)]
 
-gen_ffi_handle!(
-    rssn_get_bohr_radius,
-    crate::constant::get_bohr_radius
-);
+pub extern "C" fn rssn_constant_expr_handle(
+    key: *const c_char
+) -> RssnHandle {
 
-gen_ffi_handle!(
-    rssn_get_hartree_energy,
-    crate::constant::get_hartree_energy
-);
+    let _call = ffi_call!();
 
-gen_ffi_handle!(rssn_get_classical_electron_radius, crate::constant::get_classical_electron_radius);
+    unsafe {
 
-gen_ffi_handle!(rssn_get_thomson_cross_section, crate::constant::get_thomson_cross_section);
+        c_str_to_str(key)
+    }
+    .and_then(|key| {
+
+        require(
+            symbolic::constant(key),
+            FfiStatus::NotFound,
+            || {
+
+                format!(
+                    "no constant \
+                     `{key}`"
+                )
+            },
+        )
+    })
+    .map_or(
+        RssnHandle::NULL,
+        handle::insert,
+    )
+}
 
-// --- Magnetic Moments & Factors ---
-gen_ffi_handle!(
-    rssn_get_bohr_magneton,
-    crate::constant::get_bohr_magneton
-);
+/// Returns a handle to a new measured value. The sign of `uncertainty` is
+/// ignored.
+///
+/// The handle must be freed using `rssn_handle_free`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_measured_new(
+    value: f64,
+    uncertainty: f64,
+) -> RssnHandle {
 
-gen_ffi_handle!(rssn_get_nuclear_magneton, crate::constant::get_nuclear_magneton);
+    let _call = ffi_call!();
 
-gen_ffi_handle!(rssn_get_electron_g_factor, crate::constant::get_electron_g_factor);
+    handle::insert(Measured::new(
+        value,
+        uncertainty,
+    ))
+}
 
-gen_ffi_handle!(
-    rssn_get_muon_g_factor,
-    crate::constant::get_muon_g_factor
+/// Returns the value of the measured value behind `handle`.
+///
+/// Returns `NaN` if `handle` is stale or not a `Measured`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_measured_value(
+    handle: RssnHandle
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    handle::with(
+        handle,
+        |m: &Measured| m.value,
+    )
+    .unwrap_or(f64::NAN)
+}
+
+/// Returns the standard uncertainty of the measured value behind `handle`.
+///
+/// Returns `NaN` if `handle` is stale or not a `Measured`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_measured_uncertainty(
+    handle: RssnHandle
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    handle::with(
+        handle,
+        |m: &Measured| m.uncertainty,
+    )
+    .unwrap_or(f64::NAN)
+}
+
+handle_ffi_binary!(
+    /// Returns a handle to the sum of two measured values, with their
+    /// uncertainties added in quadrature.
+    rssn_measured_add,
+    Measured,
+    Measured,
+    Measured,
+    |a, b| *a + *b
+);
+
+handle_ffi_binary!(
+    /// Returns a handle to the difference of two measured values, with their
+    /// uncertainties added in quadrature.
+    rssn_measured_sub,
+    Measured,
+    Measured,
+    Measured,
+    |a, b| *a - *b
+);
+
+handle_ffi_binary!(
+    /// Returns a handle to the product of two measured values, with their
+    /// relative uncertainties added in quadrature.
+    rssn_measured_mul,
+    Measured,
+    Measured,
+    Measured,
+    |a, b| *a * *b
 );
 
-gen_ffi_handle!(rssn_get_proton_magnetic_moment, crate::constant::get_proton_magnetic_moment);
+handle_ffi_binary!(
+    /// Returns a handle to the quotient of two measured values, with their
+    /// relative uncertainties added in quadrature.
+    rssn_measured_div,
+    Measured,
+    Measured,
+    Measured,
+    |a, b| *a / *b
+);
 
-gen_ffi_handle!(rssn_get_neutron_magnetic_moment, crate::constant::get_neutron_magnetic_moment);
+handle_ffi_unary!(
+    /// Returns a handle to the square root of a measured value.
+    rssn_measured_sqrt,
+    Measured,
+    |m| m.sqrt()
+);
 
-gen_ffi_handle!(rssn_get_shielded_proton_gyromagnetic_ratio, crate::constant::get_shielded_proton_gyromagnetic_ratio);
+handle_ffi_unary!(
+    /// Returns a handle to a symbolic expression with its constants
+    /// rewritten by their defining relations and like terms collected.
+    rssn_expr_simplify,
+    Expr,
+    |expr| symbolic::simplify(expr)
+);
 
-// --- Physico-Chemical & Thermodynamic ---
-gen_ffi_handle!(rssn_get_boltzmann_constant, crate::constant::get_boltzmann_constant);
+/// Evaluates the symbolic expression behind `handle` with the latest
+/// CODATA values and returns a handle to the resulting measured value.
+///
+/// Returns the null handle if `handle` is stale or not an `Expr`, or the
+/// expression contains a variable that is not a constant or an operation
+/// that cannot be evaluated. The handle must be freed using
+/// `rssn_handle_free`.
+#[unsafe(no_mangle)]
 
-gen_ffi_handle!(rssn_get_avogadro_constant, crate::constant::get_avogadro_constant);
+pub extern "C" fn rssn_expr_evaluate(
+    handle: RssnHandle
+) -> RssnHandle {
 
-gen_ffi_handle!(rssn_get_molar_gas_constant, crate::constant::get_molar_gas_constant);
+    let _call = ffi_call!();
 
-gen_ffi_handle!(rssn_get_faraday_constant, crate::constant::get_faraday_constant);
+    handle::with(
+        handle,
+        |expr: &Expr| {
 
-gen_ffi_handle!(rssn_get_stefan_boltzmann_constant, crate::constant::get_stefan_boltzmann_constant);
+            require(
+            symbolic::evaluate(
+                expr,
+                CodataEdition::LATEST,
+            ),
+            FfiStatus::AlgorithmFailure,
+            || {
+
+                "the expression is not \
+                 made of constants and \
+                 evaluable operations"
+                    .to_string()
+            },
+        )
+        },
+    )
+    .flatten()
+    .map_or(
+        RssnHandle::NULL,
+        handle::insert,
+    )
+}
 
-gen_ffi_handle!(rssn_get_wien_displacement_constant, crate::constant::get_wien_displacement_constant);
+macro_rules! gen_ffi_handle {
+    (
+        $ffi_name:ident,
+        $internal_getter:path
+    ) => {
+        /// Generates an `FFI` function that retrieves a constant value.
+        ///
+        /// This function converts the value to a `C` string and returns a pointer to it.
+        /// The matching `_f64` getter returns the value as a `double` instead.
+        ///
+        /// # Safety
+        ///
+        /// The caller is responsible for memory management. The returned string must
+        /// be freed using `rssn_free_string` to avoid memory leaks.
+        #[unsafe(no_mangle)]
 
-gen_ffi_handle!(rssn_get_molar_volume_ideal_gas, crate::constant::get_molar_volume_ideal_gas);
+        pub extern "C" fn $ffi_name()
+        -> *mut std::os::raw::c_char {
 
-gen_ffi_handle!(rssn_get_first_radiation_constant, crate::constant::get_first_radiation_constant);
+            let _call = ffi_call!();
 
-gen_ffi_handle!(rssn_get_second_radiation_constant, crate::constant::get_second_radiation_constant);
+            let value =
+                $internal_getter();
 
-// --- Ratios & Quotients ---
-gen_ffi_handle!(rssn_get_proton_electron_mass_ratio, crate::constant::get_proton_electron_mass_ratio);
+            // Convert to string (works for both f64 and &str)
+            to_c_string(
+                value.to_string(),
+            )
+        }
+    };
+}
 
-gen_ffi_handle!(rssn_get_muon_electron_mass_ratio, crate::constant::get_muon_electron_mass_ratio);
+// --- FFI Implementations ---
 
-gen_ffi_handle!(rssn_get_neutron_proton_mass_ratio, crate::constant::get_neutron_proton_mass_ratio);
+// // --- Build Info (Original Strings) ---
+// gen_ffi_handle!(rssn_get_build_date, crate::constant::get_build_date);
+// gen_ffi_handle!(rssn_get_commit_sha, crate::constant::get_commit_sha);
+// gen_ffi_handle!(rssn_get_rustc_version, crate::constant::get_rustc_version);
+// gen_ffi_handle!(rssn_get_cargo_target_triple, crate::constant::get_cargo_target_triple);
+// gen_ffi_handle!(rssn_get_system_info, crate::constant::get_system_info);
+// I will comment out these calls for now but personally I think it's a good idea to have them instead of the original functions.
 
-gen_ffi_handle!(rssn_get_electron_muon_mass_ratio, crate::constant::get_electron_muon_mass_ratio);
+// --- CODATA Constants ---
+// One getter per constant, generated by `build/codata.rs` from `data/codata/allascii_2022.txt`.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_ffi_handle.rs"
+));
+
+macro_rules! gen_ffi_f64 {
+    (
+        $ffi_name:ident,
+        $internal_getter:path
+    ) => {
+        /// Generates an `FFI` function that returns a constant value as a `double`.
+        #[unsafe(no_mangle)]
 
-gen_ffi_handle!(rssn_get_deuteron_proton_mass_ratio, crate::constant::get_deuteron_proton_mass_ratio);
+        pub const extern "C" fn $ffi_name()
+        -> f64 {
 
-gen_ffi_handle!(rssn_get_electron_charge_to_mass_quotient, crate::constant::get_electron_charge_to_mass_quotient);
+            $internal_getter()
+        }
+    };
+}
 
-gen_ffi_handle!(rssn_get_muon_magnetic_moment, crate::constant::get_muon_magnetic_moment);
+// One `double` getter per constant, generated alongside the string getters.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_ffi_f64.rs"
+));
 
 #[unsafe(no_mangle)]
 /// Frees a C string that was allocated by the `rssn_get_*` functions in this module.
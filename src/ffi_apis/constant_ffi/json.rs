@@ -7,9 +7,18 @@ use std::os::raw::c_char;
 use serde::Deserialize;
 use serde::Serialize;
 
-use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_json_string;
+use crate::ffi_apis::common::to_json_string;
+use crate::ffi_apis::constant_ffi::handle::flags;
+use crate::ffi_apis::constant_ffi::handle::lookup;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::require;
 
 /// Build information structure for JSON serialization.
+///
+/// `rssn_get_capabilities_json` reports the build profile, features and
+/// exported functions as well.
 #[derive(
     Debug, Clone, Serialize, Deserialize,
 )]
@@ -27,6 +36,67 @@ pub struct BuildInfo {
     pub system_info: String,
 }
 
+/// The serialized form of an `RssnConstantRecord`, for the bulk JSON and
+/// `bincode_next` exports.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct ConstantRecord {
+    /// The identifier (e.g. `"SPEED_OF_LIGHT"`).
+    pub id: String,
+    /// The NIST quantity name (e.g. `"speed of light in vacuum"`).
+    pub name: String,
+    /// The conventional symbol (e.g. `"c"`).
+    pub symbol: String,
+    /// The unit of `value` (e.g. `"m s⁻¹"`).
+    pub unit: String,
+    /// The value.
+    pub value: f64,
+    /// The standard uncertainty, zero for exact values.
+    pub uncertainty: f64,
+    /// The relative standard uncertainty, zero for exact values.
+    pub relative_uncertainty: f64,
+    /// `RSSN_CONSTANT_EXACT` and `RSSN_CONSTANT_TRUNCATED` bits.
+    pub flags: u32,
+}
+
+/// Returns the flat record of every registered constant.
+
+pub(crate) fn constant_records()
+-> Vec<ConstantRecord> {
+
+    crate::constant::all()
+        .iter()
+        .map(|record| {
+
+            ConstantRecord {
+                id: record.id.to_string(),
+                name: record
+                    .name
+                    .to_string(),
+                symbol: record
+                    .symbol
+                    .to_string(),
+                unit: record
+                    .unit
+                    .to_string(),
+                value: record.value,
+                uncertainty: record
+                    .uncertainty,
+                relative_uncertainty:
+                    record
+                        .relative_uncertainty,
+                flags: flags(record),
+            }
+        })
+        .collect()
+}
+
 /// Returns all build information as a JSON string.
 /// The caller must free the returned string using `rssn_free_string`.
 #[unsafe(no_mangle)]
@@ -34,19 +104,22 @@ pub struct BuildInfo {
 pub extern "C" fn rssn_get_build_info_json()
 -> *mut c_char {
 
-    let info = BuildInfo {
+    let _call = ffi_call!();
+
+    to_json_string(&build_info())
+}
+
+/// Returns the build information of the library.
+
+pub(crate) fn build_info() -> BuildInfo
+{
+
+    BuildInfo {
         build_date : crate::constant::get_build_date().to_string(),
         commit_sha : crate::constant::get_commit_sha().to_string(),
         rustc_version : crate::constant::get_rustc_version().to_string(),
         cargo_target_triple : crate::constant::get_cargo_target_triple().to_string(),
         system_info : crate::constant::get_system_info().to_string(),
-    };
-
-    match serde_json::to_string(&info) {
-        | Ok(json) => to_c_string(json),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
     }
 }
 
@@ -57,16 +130,13 @@ pub extern "C" fn rssn_get_build_info_json()
 pub extern "C" fn rssn_get_build_date_json()
 -> *mut c_char {
 
+    let _call = ffi_call!();
+
     let date =
         crate::constant::get_build_date(
         );
 
-    match serde_json::to_string(&date) {
-        | Ok(json) => to_c_string(json),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
-    }
+    to_json_string(&date)
 }
 
 /// Returns the commit SHA as a JSON string.
@@ -76,194 +146,318 @@ pub extern "C" fn rssn_get_build_date_json()
 pub extern "C" fn rssn_get_commit_sha_json()
 -> *mut c_char {
 
+    let _call = ffi_call!();
+
     let sha =
         crate::constant::get_commit_sha(
         );
 
-    match serde_json::to_string(&sha) {
-        | Ok(json) => to_c_string(json),
-        | Err(_) => {
-            std::ptr::null_mut()
-        },
-    }
+    to_json_string(&sha)
 }
 
-macro_rules! gen_ffi_json {
-    (
-        $ffi_name:ident,
-        $internal_getter:path
-    ) => {
-        /// Generates an `FFI` function that retrieves a constant value.
-        ///
-        /// This function serializes the value to a `JSON` string and returns
-        /// a `C` string pointer.
-        ///
-        /// # Safety
-        ///
-        /// The caller must free the returned string using `rssn_free_string`.
-        #[unsafe(no_mangle)]
+/// Returns every registered physical constant record as a JSON array.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-        pub extern "C" fn $ffi_name()
-        -> *mut std::os::raw::c_char {
+pub extern "C" fn rssn_get_constants_json()
+-> *mut c_char {
 
-            let value =
-                $internal_getter();
+    let _call = ffi_call!();
 
-            // Serialize the value (f64 or &str) to a JSON string
-            match serde_json::to_string(
-                &value,
-            ) {
-                | Ok(json) => {
-                    to_c_string(json)
-                },
-                | Err(_) => {
-                    std::ptr::null_mut()
-                },
-            }
-        }
-    };
+    to_json_string(
+        &crate::constant::all(),
+    )
 }
 
-// --- FFI Implementations ---
+/// Returns the flat record of every registered constant as a JSON array.
+///
+/// Each record holds the identifier, name, symbol, unit, value,
+/// uncertainties and flags, as in `rssn_get_constant_table`.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-// // --- Build Info (Original Strings) ---
-// gen_ffi_json!(rssn_get_build_date_json, crate::constant::get_build_date);
-// gen_ffi_json!(rssn_get_commit_sha_json, crate::constant::get_commit_sha);
-// gen_ffi_json!(rssn_get_rustc_version_json, crate::constant::get_rustc_version);
-// gen_ffi_json!(rssn_get_cargo_target_triple_json, crate::constant::get_cargo_target_triple);
-// gen_ffi_json!(rssn_get_system_info_json, crate::constant::get_system_info);
-// I will comment out these calls for now but personally I think it's a good idea to have them instead of the original functions.
+pub extern "C" fn rssn_get_constant_records_json()
+-> *mut c_char {
 
-// --- Fundamental Constants ---
-gen_ffi_json!(
-    rssn_get_speed_of_light_json,
-    crate::constant::get_speed_of_light
-);
+    let _call = ffi_call!();
 
-gen_ffi_json!(rssn_get_planck_constant_json, crate::constant::get_planck_constant);
+    to_json_string(&constant_records())
+}
 
-gen_ffi_json!(rssn_get_elementary_charge_json, crate::constant::get_elementary_charge);
+/// Returns the registry record of the constant with identifier `id` (e.g. `"SPEED_OF_LIGHT"`) as a JSON object.
+///
+/// Returns null if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-gen_ffi_json!(rssn_get_gravitational_constant_json, crate::constant::get_gravitational_constant);
+pub extern "C" fn rssn_get_constant_json(
+    id: *const c_char
+) -> *mut c_char {
 
-gen_ffi_json!(
-    rssn_get_electron_mass_json,
-    crate::constant::get_electron_mass
-);
+    let _call = ffi_call!();
 
-gen_ffi_json!(rssn_get_boltzmann_constant_json, crate::constant::get_boltzmann_constant);
+    let record = unsafe {
 
-gen_ffi_json!(rssn_get_avogadro_constant_json, crate::constant::get_avogadro_constant);
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
 
-gen_ffi_json!(rssn_get_fine_structure_constant_json, crate::constant::get_fine_structure_constant);
+    match record {
+        | Some(record) => {
+            to_json_string(record)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
 
-gen_ffi_json!(rssn_get_rydberg_constant_json, crate::constant::get_rydberg_constant);
+/// Returns the value and standard uncertainty of the constant with identifier `id` as a JSON `Measured` object.
+///
+/// Returns null if `id` is not valid UTF-8 or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-// --- Electromagnetic Constants ---
-gen_ffi_json!(rssn_get_vacuum_electric_permittivity_json, crate::constant::get_vacuum_electric_permittivity);
+pub extern "C" fn rssn_get_constant_measured_json(
+    id: *const c_char
+) -> *mut c_char {
 
-gen_ffi_json!(rssn_get_vacuum_magnetic_permeability_json, crate::constant::get_vacuum_magnetic_permeability);
+    let _call = ffi_call!();
 
-gen_ffi_json!(rssn_get_josephson_constant_json, crate::constant::get_josephson_constant);
+    let record = unsafe {
 
-gen_ffi_json!(rssn_get_von_klitzing_constant_json, crate::constant::get_von_klitzing_constant);
+        c_str_to_str(id)
+    }
+    .and_then(lookup);
 
-gen_ffi_json!(rssn_get_magnetic_flux_quantum_json, crate::constant::get_magnetic_flux_quantum);
+    match record {
+        | Some(record) => {
+            to_json_string(
+                &record.measured(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
 
-// --- Atomic & Particle Constants ---
-gen_ffi_json!(
-    rssn_get_proton_mass_kg_json,
-    crate::constant::get_proton_mass_kg
-);
+/// Returns the records of every constant in a group as a JSON array.
+///
+/// `group_json` is a JSON string naming a `ConstantGroup` variant (e.g. `"Electromagnetic"`).
+/// Returns null if the group cannot be parsed.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-gen_ffi_json!(
-    rssn_get_neutron_mass_u_json,
-    crate::constant::get_neutron_mass_u
-);
+pub extern "C" fn rssn_get_constants_in_group_json(
+    group_json: *const c_char
+) -> *mut c_char {
 
-gen_ffi_json!(rssn_get_atomic_mass_constant_json, crate::constant::get_atomic_mass_constant);
+    let _call = ffi_call!();
 
-gen_ffi_json!(rssn_get_proton_electron_mass_ratio_json, crate::constant::get_proton_electron_mass_ratio);
+    let group: Option<
+        crate::constant::ConstantGroup,
+    > = from_json_string(group_json);
 
-// --- Magnetic Moments & Factors ---
-gen_ffi_json!(
-    rssn_get_bohr_magneton_json,
-    crate::constant::get_bohr_magneton
-);
+    match group {
+        | Some(group) => {
 
-gen_ffi_json!(rssn_get_nuclear_magneton_json, crate::constant::get_nuclear_magneton);
+            let records: Vec<_> =
+                crate::constant::in_group(
+                    group,
+                )
+                .collect();
 
-gen_ffi_json!(rssn_get_electron_g_factor_json, crate::constant::get_electron_g_factor);
+            to_json_string(&records)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
 
-// --- Thermodynamic Constants ---
-gen_ffi_json!(rssn_get_molar_gas_constant_json, crate::constant::get_molar_gas_constant);
+/// Returns every constant record of a CODATA edition as a JSON array.
+///
+/// `edition_json` is a JSON string naming a `CodataEdition` variant (e.g. `"Codata2018"`).
+/// Returns null if the edition cannot be parsed.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-gen_ffi_json!(rssn_get_faraday_constant_json, crate::constant::get_faraday_constant);
+pub extern "C" fn rssn_get_constants_for_edition_json(
+    edition_json: *const c_char
+) -> *mut c_char {
 
-gen_ffi_json!(rssn_get_stefan_boltzmann_constant_json, crate::constant::get_stefan_boltzmann_constant);
+    let _call = ffi_call!();
 
-// --- Planck Constants ---
-gen_ffi_json!(rssn_get_reduced_planck_constant_json, crate::constant::get_reduced_planck_constant);
+    let edition: Option<
+        crate::constant::CodataEdition,
+    > = from_json_string(edition_json);
 
-gen_ffi_json!(rssn_get_inverse_fine_structure_constant_json, crate::constant::get_inverse_fine_structure_constant);
+    match edition {
+        | Some(edition) => {
+            to_json_string(
+                &crate::constant::edition::constants(
+                    edition,
+                ),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
 
-// --- Atomic & Molecular Constants ---
-gen_ffi_json!(
-    rssn_get_bohr_radius_json,
-    crate::constant::get_bohr_radius
-);
+/// Returns the record of one constant of a CODATA edition as a JSON object.
+///
+/// `edition_json` is a JSON string naming a `CodataEdition` variant and
+/// `key` is an identifier or NIST quantity name (e.g. `"electron mass"`).
+/// Returns null if either argument is invalid or no such constant exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-gen_ffi_json!(
-    rssn_get_hartree_energy_json,
-    crate::constant::get_hartree_energy
-);
+pub extern "C" fn rssn_get_constant_for_edition_json(
+    edition_json: *const c_char,
+    key: *const c_char,
+) -> *mut c_char {
 
-gen_ffi_json!(rssn_get_electron_mass_u_json, crate::constant::get_electron_mass_u);
+    let _call = ffi_call!();
 
-gen_ffi_json!(
-    rssn_get_proton_mass_u_json,
-    crate::constant::get_proton_mass_u
-);
+    let edition: Option<
+        crate::constant::CodataEdition,
+    > = from_json_string(edition_json);
 
-gen_ffi_json!(rssn_get_deuteron_mass_u_json, crate::constant::get_deuteron_mass_u);
+    let record = edition.and_then(
+        |edition| {
 
-gen_ffi_json!(rssn_get_alpha_particle_mass_u_json, crate::constant::get_alpha_particle_mass_u);
+            unsafe {
 
-gen_ffi_json!(rssn_get_classical_electron_radius_json, crate::constant::get_classical_electron_radius);
+                c_str_to_str(key)
+            }
+            .and_then(|key| {
+
+                require(
+                    crate::constant::edition::get(
+                        edition, key,
+                    ),
+                    FfiStatus::NotFound,
+                    || {
+
+                        format!(
+                            "no constant \
+                             `{key}` in \
+                             {}",
+                            edition.name()
+                        )
+                    },
+                )
+            })
+        },
+    );
+
+    match record {
+        | Some(record) => {
+            to_json_string(record)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the differences between two CODATA editions as a JSON object.
+///
+/// `from_json` and `to_json` are JSON strings naming `CodataEdition` variants.
+/// Returns null if either edition cannot be parsed.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-gen_ffi_json!(rssn_get_thomson_cross_section_json, crate::constant::get_thomson_cross_section);
+pub extern "C" fn rssn_diff_codata_editions_json(
+    from_json: *const c_char,
+    to_json: *const c_char,
+) -> *mut c_char {
 
-gen_ffi_json!(rssn_get_wien_displacement_constant_json, crate::constant::get_wien_displacement_constant);
+    let _call = ffi_call!();
 
-gen_ffi_json!(rssn_get_first_radiation_constant_json, crate::constant::get_first_radiation_constant);
+    let from: Option<
+        crate::constant::CodataEdition,
+    > = from_json_string(from_json);
+
+    let to: Option<
+        crate::constant::CodataEdition,
+    > = from_json_string(to_json);
+
+    match (from, to) {
+        | (Some(from), Some(to)) => {
+            to_json_string(
+                &crate::constant::edition::diff(
+                    from, to,
+                ),
+            )
+        },
+        | _ => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the constants matching `query` by name, alias or symbol as a
+/// JSON array of matches, best first.
+///
+/// Returns null if `query` is not valid UTF-8.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
 
-gen_ffi_json!(rssn_get_second_radiation_constant_json, crate::constant::get_second_radiation_constant);
+pub extern "C" fn rssn_find_constants_json(
+    query: *const c_char
+) -> *mut c_char {
 
-gen_ffi_json!(
-    rssn_get_muon_g_factor_json,
-    crate::constant::get_muon_g_factor
-);
+    let _call = ffi_call!();
 
-gen_ffi_json!(
-    rssn_get_muon_mass_u_json,
-    crate::constant::get_muon_mass_u
-);
+    match unsafe {
 
-// --- Ratios & Quotients ---
-gen_ffi_json!(rssn_get_muon_electron_mass_ratio_json, crate::constant::get_muon_electron_mass_ratio);
+        c_str_to_str(query)
+    } {
+        | Some(query) => {
+            to_json_string(
+                &crate::constant::find(
+                    query,
+                ),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
 
-gen_ffi_json!(rssn_get_neutron_proton_mass_ratio_json, crate::constant::get_neutron_proton_mass_ratio);
+macro_rules! gen_ffi_json {
+    (
+        $ffi_name:ident,
+        $internal_getter:path
+    ) => {
+        /// Generates an `FFI` function that retrieves a constant value.
+        ///
+        /// This function serializes the value to a `JSON` string and returns
+        /// a `C` string pointer.
+        ///
+        /// # Safety
+        ///
+        /// The caller must free the returned string using `rssn_free_string`.
+        #[unsafe(no_mangle)]
 
-gen_ffi_json!(rssn_get_electron_muon_mass_ratio_json, crate::constant::get_electron_muon_mass_ratio);
+        pub extern "C" fn $ffi_name()
+        -> *mut std::os::raw::c_char {
 
-gen_ffi_json!(rssn_get_proton_magnetic_moment_json, crate::constant::get_proton_magnetic_moment);
+            let _call = ffi_call!();
 
-gen_ffi_json!(rssn_get_neutron_magnetic_moment_json, crate::constant::get_neutron_magnetic_moment);
+            let value =
+                $internal_getter();
 
-gen_ffi_json!(rssn_get_muon_magnetic_moment_json, crate::constant::get_muon_magnetic_moment);
+            // Serialize the value (f64 or &str) to a JSON string
+            to_json_string(&value)
+        }
+    };
+}
 
-gen_ffi_json!(rssn_get_shielded_proton_gyromagnetic_ratio_json, crate::constant::get_shielded_proton_gyromagnetic_ratio);
+// --- FFI Implementations ---
 
-gen_ffi_json!(rssn_get_molar_volume_ideal_gas_json, crate::constant::get_molar_volume_ideal_gas);
+// // --- Build Info (Original Strings) ---
+// gen_ffi_json!(rssn_get_build_date_json, crate::constant::get_build_date);
+// gen_ffi_json!(rssn_get_commit_sha_json, crate::constant::get_commit_sha);
+// gen_ffi_json!(rssn_get_rustc_version_json, crate::constant::get_rustc_version);
+// gen_ffi_json!(rssn_get_cargo_target_triple_json, crate::constant::get_cargo_target_triple);
+// gen_ffi_json!(rssn_get_system_info_json, crate::constant::get_system_info);
+// I will comment out these calls for now but personally I think it's a good idea to have them instead of the original functions.
 
-gen_ffi_json!(rssn_get_electron_charge_to_mass_quotient_json, crate::constant::get_electron_charge_to_mass_quotient);
+// --- CODATA Constants ---
+// One getter per constant, generated by `build/codata.rs` from `data/codata/allascii_2022.txt`.
+include!(concat!(
+    env!("OUT_DIR"),
+    "/codata_ffi_json.rs"
+));
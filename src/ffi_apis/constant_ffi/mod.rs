@@ -1,7 +1,8 @@
 //! FFI APIs for the constant module.
 //!
 //! This module provides three different FFI API versions:
-//! - **Handle-based**: Traditional C-style functions returning strings
+//! - **Handle-based**: Traditional C-style functions returning strings, `double`s,
+//!   handles and `#[repr(C)]` records
 //! - **JSON-based**: String serialization for easy language interop
 //! - **Bincode-based**: Binary serialization for high performance
 //!
@@ -12,6 +13,20 @@
 //! char* date = rssn_get_build_date();
 //! printf("Build date: %s\n", date);
 //! rssn_free_string(date);
+//!
+//! double mu0;
+//! if (rssn_constant_lookup("mu0", &mu0)) {
+//!     printf("mu0 = %g\n", mu0);
+//! }
+//!
+//! double c = rssn_get_speed_of_light_f64();
+//!
+//! RssnConstantTable table = rssn_get_constant_table();
+//! for (size_t i = 0; i < table.len; i++) {
+//!     printf("%s = %g %s\n", table.records[i].id,
+//!            table.records[i].value, table.records[i].unit);
+//! }
+//! rssn_free_constant_table(table);
 //! ```
 //!
 //! ## JSON-based API (Python, JavaScript, etc.)
@@ -0,0 +1,107 @@
+//! Bincode-based FFI API for the dispatcher.
+//!
+//! This provides binary serialization for high-performance interop.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_bincode_buffer;
+use crate::ffi_apis::dispatch::Method;
+use crate::ffi_apis::dispatch::MethodSchema;
+use crate::ffi_apis::dispatch::lookup;
+use crate::ffi_apis::dispatch::methods;
+
+/// Calls the method named `method` (e.g. `"constant.get"`) and returns its
+/// result as a `bincode_next` encoded buffer.
+///
+/// `params` holds the tuple of the parameters in order, in an envelope
+/// (e.g. schema `(Measured, Measured)`); it may be empty for a method
+/// without parameters.
+/// Returns an empty buffer if no such method exists, the parameters do
+/// not decode or the method fails; the last error tells which.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_call_bincode(
+    method: *const c_char,
+    params: BincodeBuffer,
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let result = unsafe {
+
+        c_str_to_str(method)
+    }
+    .and_then(lookup)
+    .and_then(|method| {
+
+        method.call_bincode(unsafe {
+
+            params.as_slice()
+        })
+    });
+
+    match result {
+        | Some(result) => {
+            BincodeBuffer::from_vec(
+                result,
+            )
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Returns the descriptions of every method, sorted by name, as a
+/// `bincode_next` encoded `Vec<MethodSchema>`.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_list_methods_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let schemas: Vec<MethodSchema> =
+        methods()
+            .iter()
+            .map(Method::schema)
+            .collect();
+
+    to_bincode_buffer(&schemas)
+}
+
+/// Returns the description of the method named `method` as a
+/// `bincode_next` encoded `MethodSchema`.
+///
+/// Returns an empty buffer if `method` is not valid UTF-8 or no such
+/// method exists.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_method_schema_bincode(
+    method: *const c_char
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let method = unsafe {
+
+        c_str_to_str(method)
+    }
+    .and_then(lookup);
+
+    match method {
+        | Some(method) => {
+            to_bincode_buffer(
+                &method.schema(),
+            )
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
@@ -0,0 +1,384 @@
+//! A check that a result holds only finite floating-point numbers.
+//!
+//! `serde_json` writes NaN and the infinities as `null`, which a caller
+//! cannot tell from an absent value, so the dispatcher refuses such
+//! results instead. [`FiniteCheck`] walks a value through its `Serialize`
+//! implementation without encoding it.
+
+use std::fmt;
+
+use serde::Serialize;
+use serde::ser;
+
+/// Returns whether every `f32` and `f64` in `value` is finite.
+
+pub(super) fn is_finite<
+    T: Serialize + ?Sized,
+>(
+    value: &T
+) -> bool {
+
+    !matches!(
+        value.serialize(FiniteCheck),
+        Err(CheckError::NonFinite)
+    )
+}
+
+/// A serializer that only checks the floats of a value.
+#[derive(Debug, Clone, Copy)]
+
+struct FiniteCheck;
+
+/// Why [`FiniteCheck`] stopped.
+#[derive(Debug)]
+
+enum CheckError {
+    /// A float is NaN or infinite.
+    NonFinite,
+    /// The `Serialize` implementation failed.
+    Custom(String),
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match self {
+            | Self::NonFinite => {
+                f.write_str(
+                    "the result is \
+                     not finite",
+                )
+            },
+            | Self::Custom(message) => {
+                f.write_str(message)
+            },
+        }
+    }
+}
+
+impl std::error::Error for CheckError {
+}
+
+impl ser::Error for CheckError {
+    fn custom<T: fmt::Display>(
+        message: T
+    ) -> Self {
+
+        Self::Custom(
+            message.to_string(),
+        )
+    }
+}
+
+/// Accepts a scalar that holds no float.
+
+macro_rules! accept {
+    ($($method:ident($ty:ty $(,)?)),* $(,)?) => {
+        $(
+            fn $method(
+                self,
+                _: $ty,
+            ) -> Result<(), CheckError> {
+
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl ser::Serializer for FiniteCheck {
+    type Error = CheckError;
+    type Ok = ();
+    type SerializeMap = Self;
+    type SerializeSeq = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+
+    accept!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(
+            &'static str,
+        ),
+    );
+
+    fn serialize_f32(
+        self,
+        value: f32,
+    ) -> Result<(), CheckError> {
+
+        self.serialize_f64(f64::from(
+            value,
+        ))
+    }
+
+    fn serialize_f64(
+        self,
+        value: f64,
+    ) -> Result<(), CheckError> {
+
+        if value.is_finite() {
+
+            Ok(())
+        } else {
+
+            Err(CheckError::NonFinite)
+        }
+    }
+
+    fn serialize_none(
+        self
+    ) -> Result<(), CheckError> {
+
+        Ok(())
+    }
+
+    fn serialize_some<
+        T: Serialize + ?Sized,
+    >(
+        self,
+        value: &T,
+    ) -> Result<(), CheckError> {
+
+        value.serialize(self)
+    }
+
+    fn serialize_unit(
+        self
+    ) -> Result<(), CheckError> {
+
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+    ) -> Result<(), CheckError> {
+
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<
+        T: Serialize + ?Sized,
+    >(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CheckError> {
+
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<
+        T: Serialize + ?Sized,
+    >(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CheckError> {
+
+        value.serialize(self)
+    }
+
+    fn serialize_seq(
+        self,
+        _: Option<usize>,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+
+    fn serialize_tuple(
+        self,
+        _: usize,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+
+    fn serialize_map(
+        self,
+        _: Option<usize>,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self, CheckError> {
+
+        Ok(self)
+    }
+}
+
+/// Implements a compound serializer of [`FiniteCheck`] that checks each
+/// element in turn.
+
+macro_rules! compound {
+    ($($trait:ident :: $method:ident),* $(,)?) => {
+        $(
+            impl ser::$trait for FiniteCheck {
+                type Error = CheckError;
+                type Ok = ();
+
+                fn $method<T: Serialize + ?Sized>(
+                    &mut self,
+                    value: &T,
+                ) -> Result<(), CheckError> {
+
+                    value.serialize(*self)
+                }
+
+                fn end(self) -> Result<(), CheckError> {
+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+compound!(
+    SerializeSeq::serialize_element,
+    SerializeTuple::serialize_element,
+    SerializeTupleStruct::serialize_field,
+    SerializeTupleVariant::serialize_field,
+);
+
+impl ser::SerializeMap for FiniteCheck {
+    type Error = CheckError;
+    type Ok = ();
+
+    fn serialize_key<
+        T: Serialize + ?Sized,
+    >(
+        &mut self,
+        key: &T,
+    ) -> Result<(), CheckError> {
+
+        key.serialize(*self)
+    }
+
+    fn serialize_value<
+        T: Serialize + ?Sized,
+    >(
+        &mut self,
+        value: &T,
+    ) -> Result<(), CheckError> {
+
+        value.serialize(*self)
+    }
+
+    fn end(
+        self
+    ) -> Result<(), CheckError> {
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct
+    for FiniteCheck
+{
+    type Error = CheckError;
+    type Ok = ();
+
+    fn serialize_field<
+        T: Serialize + ?Sized,
+    >(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CheckError> {
+
+        value.serialize(*self)
+    }
+
+    fn end(
+        self
+    ) -> Result<(), CheckError> {
+
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant
+    for FiniteCheck
+{
+    type Error = CheckError;
+    type Ok = ();
+
+    fn serialize_field<
+        T: Serialize + ?Sized,
+    >(
+        &mut self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), CheckError> {
+
+        value.serialize(*self)
+    }
+
+    fn end(
+        self
+    ) -> Result<(), CheckError> {
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+//! JSON-based FFI API for the dispatcher.
+//!
+//! This provides string-based serialization for easy language interop.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_json_string;
+use crate::ffi_apis::common::to_json_string;
+use crate::ffi_apis::dispatch::Method;
+use crate::ffi_apis::dispatch::MethodSchema;
+use crate::ffi_apis::dispatch::lookup;
+use crate::ffi_apis::dispatch::methods;
+
+/// Calls the method named `method` (e.g. `"constant.get"`) and returns its
+/// result as JSON.
+///
+/// `params_json` is a JSON array of the parameters in order, e.g.
+/// `["SPEED_OF_LIGHT"]`, or a JSON object keyed by parameter name, e.g.
+/// `{"id": "SPEED_OF_LIGHT"}`; a method without parameters also takes
+/// `null`.
+/// Returns null if no such method exists, the parameters do not match it
+/// or the method fails; the last error tells which.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_call_json(
+    method: *const c_char,
+    params_json: *const c_char,
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let result = unsafe {
+
+        c_str_to_str(method)
+    }
+    .and_then(lookup)
+    .and_then(|method| {
+
+        method.call_json(
+            from_json_string(
+                params_json,
+            )?,
+        )
+    });
+
+    match result {
+        | Some(result) => {
+            to_json_string(&result)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the descriptions of every method, sorted by name, as a JSON
+/// array of `MethodSchema` objects.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_list_methods_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let schemas: Vec<MethodSchema> =
+        methods()
+            .iter()
+            .map(Method::schema)
+            .collect();
+
+    to_json_string(&schemas)
+}
+
+/// Returns the description of the method named `method`, with the name
+/// and type of each parameter, as a JSON `MethodSchema` object.
+///
+/// Returns null if `method` is not valid UTF-8 or no such method exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_method_schema_json(
+    method: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let method = unsafe {
+
+        c_str_to_str(method)
+    }
+    .and_then(lookup);
+
+    match method {
+        | Some(method) => {
+            to_json_string(
+                &method.schema(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
@@ -0,0 +1,446 @@
+//! The methods of the dispatcher, sorted by name.
+//!
+//! A method wraps one library function: lookups that miss record a
+//! `NotFound` error, and errors of the library an `InvalidArgument` one,
+//! as the handle-based functions do.
+
+use rssn::symbolic::core::Expr;
+
+use crate::astro::AstronomicalConstant;
+use crate::astro::TimeScale;
+use crate::astro::time::LeapSecond;
+use crate::capability::Capabilities;
+use crate::constant::CodataEdition;
+use crate::constant::ConstantGroup;
+use crate::constant::Measured;
+use crate::constant::PhysicalConstant;
+use crate::constant::edition::EditionDiff;
+use crate::constant::identify::Candidate;
+use crate::constant::search::Match;
+use crate::element::Element;
+use crate::element::pattern::Peak;
+use crate::ffi_apis::constant_ffi::ConstantRecord;
+use crate::ffi_apis::constant_ffi::json::BuildInfo;
+use crate::ffi_apis::dispatch::Method;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::error::require;
+use crate::math_constant::DoubleDouble;
+use crate::math_constant::MAX_DIGITS;
+use crate::math_constant::MathConstant;
+use crate::particle::Particle;
+use crate::unit::EnergyUnit;
+use crate::unit::Quantity;
+use crate::unit::Unit;
+use crate::unit::UnitSystem;
+use crate::unit::buckingham::PiGroup;
+use crate::unit::buckingham::Scaling;
+use crate::unit::buckingham::Variable;
+
+/// Every method, sorted by name for lookup.
+
+pub(super) static METHODS: &[Method] =
+    &[
+        rpc_method!(
+            /// Returns every registered astronomical constant record.
+            "astro.all",
+            || -> Vec<AstronomicalConstant> {
+                Some(crate::astro::all().to_vec())
+            }
+        ),
+        rpc_method!(
+            /// Converts an instant, a Modified Julian Date in `from`, to the
+            /// time scale `to`.
+            "astro.convert_time",
+            |mjd: f64, from: TimeScale, to: TimeScale| -> f64 {
+                check(
+                    crate::astro::time::convert(mjd, from, to),
+                    FfiStatus::InvalidArgument,
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns the record of the astronomical constant with identifier
+            /// `id` (e.g. `"SOLAR_MASS"`).
+            "astro.get",
+            |id: String| -> AstronomicalConstant {
+                crate::ffi_apis::astro_ffi::handle::lookup(&id).copied()
+            }
+        ),
+        rpc_method!(
+            /// Returns the leap seconds inserted in UTC.
+            "astro.leap_seconds",
+            || -> Vec<LeapSecond> {
+                Some(crate::astro::time::LEAP_SECONDS.to_vec())
+            }
+        ),
+        rpc_method!(
+            /// Returns the value and standard uncertainty of the astronomical
+            /// constant with identifier `id`.
+            "astro.measured",
+            |id: String| -> Measured {
+                crate::ffi_apis::astro_ffi::handle::lookup(&id).map(AstronomicalConstant::measured)
+            }
+        ),
+        rpc_method!(
+            /// Returns the capabilities of this build: profile, features,
+            /// algorithms and exported functions.
+            "capability.get",
+            || -> Capabilities {
+                Some(crate::capability::capabilities().clone())
+            }
+        ),
+        rpc_method!(
+            /// Returns every registered constant record.
+            "constant.all",
+            || -> Vec<PhysicalConstant> {
+                Some(crate::constant::all().to_vec())
+            }
+        ),
+        rpc_method!(
+            /// Returns the build date, commit, compiler and target of the
+            /// library.
+            "constant.build_info",
+            || -> BuildInfo {
+                Some(crate::ffi_apis::constant_ffi::json::build_info())
+            }
+        ),
+        rpc_method!(
+            /// Returns the differences between two CODATA editions.
+            "constant.diff_editions",
+            |from: CodataEdition, to: CodataEdition| -> EditionDiff {
+                Some(crate::constant::edition::diff(from, to))
+            }
+        ),
+        rpc_method!(
+            /// Returns the record of one constant of a CODATA edition, by
+            /// identifier or NIST quantity name.
+            "constant.edition",
+            |edition: CodataEdition, key: String| -> PhysicalConstant {
+                require(
+                    crate::constant::edition::get(edition, &key).copied(),
+                    FfiStatus::NotFound,
+                    || format!("no constant `{key}` in {}", edition.name()),
+                )
+            }
+        ),
+        rpc_method!(
+            /// Evaluates a symbolic expression of constants with the values of
+            /// `edition`, propagating their uncertainties.
+            "constant.evaluate",
+            |expr: Expr, edition: CodataEdition| -> Measured {
+                require(
+                    crate::constant::symbolic::evaluate(&expr, edition),
+                    FfiStatus::AlgorithmFailure,
+                    || {
+                        format!(
+                            "the expression is not made of constants of {} and \
+                             evaluable operations",
+                            edition.name()
+                        )
+                    },
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns the exact value of the constant with identifier `id` in
+            /// scientific notation with `digits` significant digits, at most
+            /// `MAX_DIGITS`.
+            "constant.exact",
+            |id: String, digits: u32| -> String {
+                require(
+                    Some(digits).filter(|&digits| digits <= MAX_DIGITS),
+                    FfiStatus::InvalidArgument,
+                    || format!("at most {MAX_DIGITS} digits may be requested"),
+                )?;
+
+                crate::ffi_apis::constant_ffi::handle::lookup(&id).and_then(|record| {
+                    require(
+                        record.exact_value(),
+                        FfiStatus::InvalidArgument,
+                        || format!("`{id}` has no exact value"),
+                    )
+                    .map(|value| value.to_decimal(digits))
+                })
+            }
+        ),
+        rpc_method!(
+            /// Returns the constants matching `query` by name, alias or symbol,
+            /// best first.
+            "constant.find",
+            |query: String| -> Vec<Match> {
+                Some(crate::constant::find(&query))
+            }
+        ),
+        rpc_method!(
+            /// Returns the record of the constant with identifier `id` (e.g.
+            /// `"SPEED_OF_LIGHT"`).
+            "constant.get",
+            |id: String| -> PhysicalConstant {
+                crate::ffi_apis::constant_ffi::handle::lookup(&id).copied()
+            }
+        ),
+        rpc_method!(
+            /// Identifies `x` as a constant or a combination of constants,
+            /// returning the candidates within the relative `tolerance`,
+            /// simplest first.
+            "constant.identify",
+            |x: f64, tolerance: f64| -> Vec<Candidate> {
+                Some(crate::constant::identify::identify(x, tolerance))
+            }
+        ),
+        rpc_method!(
+            /// Returns the records of every constant in a group.
+            "constant.in_group",
+            |group: ConstantGroup| -> Vec<PhysicalConstant> {
+                Some(crate::constant::in_group(group).copied().collect())
+            }
+        ),
+        rpc_method!(
+            /// Returns the record of the one constant named by `name`, an
+            /// identifier, name, alias or symbol (e.g. `"mu0"`).
+            "constant.lookup",
+            |name: String| -> PhysicalConstant {
+                crate::ffi_apis::constant_ffi::handle::resolve(&name).copied()
+            }
+        ),
+        rpc_method!(
+            /// Returns the value and standard uncertainty of the constant with
+            /// identifier `id`.
+            "constant.measured",
+            |id: String| -> Measured {
+                crate::ffi_apis::constant_ffi::handle::lookup(&id).map(PhysicalConstant::measured)
+            }
+        ),
+        rpc_method!(
+            /// Returns the numeric record of every constant, with its flags.
+            "constant.records",
+            || -> Vec<ConstantRecord> {
+                Some(crate::ffi_apis::constant_ffi::json::constant_records())
+            }
+        ),
+        rpc_method!(
+            /// Rewrites the constants of a symbolic expression by their
+            /// defining relations and collects like terms.
+            "constant.simplify",
+            |expr: Expr| -> Expr {
+                Some(crate::constant::symbolic::simplify(&expr))
+            }
+        ),
+        rpc_method!(
+            /// Returns the element named by `key`, a symbol, name or atomic
+            /// number (e.g. `"Fe"`).
+            "element.get",
+            |key: String| -> Element {
+                require(
+                    crate::element::lookup(&key).copied(),
+                    FfiStatus::NotFound,
+                    || format!("no element with symbol, name or number `{key}`"),
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns the isotope pattern of a molecular formula, the peaks
+            /// with a relative abundance of at least `threshold`.
+            "element.isotope_pattern",
+            |formula: String, threshold: f64| -> Vec<Peak> {
+                check(
+                    crate::element::formula::parse(&formula)
+                        .and_then(|formula| formula.isotope_pattern(threshold)),
+                    FfiStatus::InvalidArgument,
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns the molar mass in kg/mol of a molecular formula (e.g.
+            /// `"CuSO4·5H2O"`).
+            "element.molar_mass",
+            |formula: String| -> f64 {
+                check(
+                    crate::element::formula::parse(&formula)
+                        .and_then(|formula| formula.molar_mass()),
+                    FfiStatus::InvalidArgument,
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns every mathematical constant.
+            "math_constant.all",
+            || -> Vec<MathConstant> {
+                Some(MathConstant::ALL.to_vec())
+            }
+        ),
+        rpc_method!(
+            /// Returns a constant correctly rounded to a number of significant
+            /// digits.
+            "math_constant.digits",
+            |constant: String, digits: u32| -> String {
+                check(crate::math_constant::lookup(&constant), FfiStatus::NotFound).and_then(
+                    |constant| {
+                        check(
+                            crate::math_constant::digits(constant, digits),
+                            FfiStatus::InvalidArgument,
+                        )
+                    },
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns a constant as a double-double.
+            "math_constant.double_double",
+            |constant: String| -> DoubleDouble {
+                check(crate::math_constant::lookup(&constant), FfiStatus::NotFound)
+                    .map(MathConstant::to_double_double)
+            }
+        ),
+        rpc_method!(
+            /// Returns the sum of two measured values, with propagated
+            /// uncertainty.
+            "measured.add",
+            |a: Measured, b: Measured| -> Measured {
+                Some(a + b)
+            }
+        ),
+        rpc_method!(
+            /// Returns the quotient of two measured values, with propagated
+            /// uncertainty.
+            "measured.div",
+            |a: Measured, b: Measured| -> Measured {
+                Some(a / b)
+            }
+        ),
+        rpc_method!(
+            /// Returns the product of two measured values, with propagated
+            /// uncertainty.
+            "measured.mul",
+            |a: Measured, b: Measured| -> Measured {
+                Some(a * b)
+            }
+        ),
+        rpc_method!(
+            /// Returns the square root of a measured value, with propagated
+            /// uncertainty.
+            "measured.sqrt",
+            |a: Measured| -> Measured {
+                Some(a.sqrt())
+            }
+        ),
+        rpc_method!(
+            /// Returns the difference of two measured values, with propagated
+            /// uncertainty.
+            "measured.sub",
+            |a: Measured, b: Measured| -> Measured {
+                Some(a - b)
+            }
+        ),
+        rpc_method!(
+            /// Returns every tabulated particle record, antiparticles excluded.
+            "particle.all",
+            || -> Vec<Particle> {
+                Some(crate::particle::all().to_vec())
+            }
+        ),
+        rpc_method!(
+            /// Returns the record of the particle or antiparticle with PDG ID
+            /// `pdg_id`.
+            "particle.by_pdg_id",
+            |pdg_id: i32| -> Particle {
+                crate::ffi_apis::particle_ffi::handle::by_pdg_id(pdg_id)
+            }
+        ),
+        rpc_method!(
+            /// Returns the record of the particle or antiparticle named by
+            /// `key`, a name (e.g. `"K-"`) or a PDG ID in decimal.
+            "particle.get",
+            |key: String| -> Particle {
+                crate::ffi_apis::particle_ffi::handle::lookup(&key)
+            }
+        ),
+        rpc_method!(
+            /// Converts `value` from the unit `from` to the unit `to` (e.g.
+            /// `"eV"` to `"J"`).
+            "unit.convert",
+            |value: f64, from: String, to: String| -> f64 {
+                check(
+                    crate::unit::convert(value, &from, &to),
+                    FfiStatus::InvalidArgument,
+                )
+            }
+        ),
+        rpc_method!(
+            /// Converts an energy equivalent `value` from `from` to `to` (e.g.
+            /// `"ElectronVolt"` to `"InverseMetre"`), with the uncertainty of the
+            /// factor.
+            "unit.convert_energy",
+            |value: f64, from: EnergyUnit, to: EnergyUnit| -> Measured {
+                Some(crate::unit::energy::convert(value, from, to))
+            }
+        ),
+        rpc_method!(
+            /// Converts `value` from the unit `from` to the unit `to`, also
+            /// across the energy equivalences of spectroscopy (e.g. `"GHz"` to
+            /// `"cm^-1"`).
+            "unit.convert_equivalent",
+            |value: f64, from: String, to: String| -> f64 {
+                check(
+                    crate::unit::convert_equivalent(value, &from, &to),
+                    FfiStatus::InvalidArgument,
+                )
+            }
+        ),
+        rpc_method!(
+            /// Returns the table of conversion factors between the energy
+            /// equivalents, row `from` and column `to` in the order of
+            /// `EnergyUnit::ALL`.
+            "unit.energy_matrix",
+            || -> [[Measured; 8]; 8] {
+                Some(crate::unit::energy::matrix())
+            }
+        ),
+        rpc_method!(
+            /// Expresses `value` in the unit `unit` (e.g. `"T"`) in a system of
+            /// units.
+            "unit.in_system",
+            |value: f64, unit: String, system: UnitSystem| -> f64 {
+                check(
+                    Quantity::with_unit(value, &unit)
+                        .and_then(|quantity| system.from_si(&quantity)),
+                    FfiStatus::InvalidArgument,
+                )
+            }
+        ),
+        rpc_method!(
+            /// Parses unit notation (e.g. `"kg m^2/s^2"`) into its scale to SI
+            /// units and its dimension.
+            "unit.parse",
+            |notation: String| -> Unit {
+                check(crate::unit::parse(&notation), FfiStatus::InvalidArgument)
+            }
+        ),
+        rpc_method!(
+            /// Returns the dimensionless groups of a set of variables by the
+            /// Buckingham π theorem.
+            "unit.pi_groups",
+            |variables: Vec<Variable>| -> Vec<PiGroup> {
+                Some(crate::unit::buckingham::pi_groups(&variables))
+            }
+        ),
+        rpc_method!(
+            /// Returns the scaling law of `target` in terms of `variables`, the
+            /// power product they determine up to dimensionless groups.
+            "unit.scaling",
+            |target: Variable, variables: Vec<Variable>| -> Scaling {
+                require(
+                    crate::unit::buckingham::scaling(&target, &variables),
+                    FfiStatus::InvalidArgument,
+                    || {
+                        format!(
+                            "the dimension of `{}` is not a power product of the \
+                             variables",
+                            target.name
+                        )
+                    },
+                )
+            }
+        ),
+    ];
@@ -0,0 +1,460 @@
+//! A single-entry dispatcher for the library.
+//!
+//! Every operation the dispatcher knows is a [`Method`] named
+//! `module.operation` (e.g. `"constant.get"`), so bindings can call one
+//! function instead of linking a symbol per operation:
+//! - **JSON-based**: `rssn_call_json` takes the parameters as a JSON array
+//!   in order, or as a JSON object keyed by parameter name
+//! - **Bincode-based**: `rssn_call_bincode` takes the parameters as a
+//!   tuple in an [`envelope`]
+//!
+//! Both return the result alone, or null (an empty buffer) and record the
+//! reason in the last error. A result holding NaN or an infinity is
+//! refused with `AlgorithmFailure`, since JSON would write it as null.
+//! `rssn_list_methods_json` and `rssn_method_schema_json` describe the
+//! methods and their parameters.
+//!
+//! # Examples
+//!
+//! ```c
+//! char* json = rssn_call_json(
+//!     "unit.convert",
+//!     "{\"value\": 1.0, \"from\": \"eV\", \"to\": \"J\"}"
+//! );
+//! // 1.602176634e-19
+//! rssn_free_string(json);
+//!
+//! char* schema = rssn_method_schema_json("unit.convert");
+//! // {"name": "unit.convert", "summary": "...",
+//! //  "params": [{"name": "value", "type": "f64"}, ...], "returns": "f64"}
+//! rssn_free_string(schema);
+//! ```
+//!
+//! New methods are declared with [`rpc_method!`](crate::rpc_method) in
+//! `methods.rs`.
+
+/// Bincode-based FFI bindings for the dispatcher.
+pub mod bincode_api;
+/// The check that results hold only finite numbers.
+mod finite;
+/// JSON-based FFI bindings for the dispatcher.
+pub mod json;
+/// The registered methods.
+mod methods;
+
+pub use bincode_api::*;
+pub use json::*;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::ffi_apis::envelope;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::error::require;
+use crate::ffi_apis::error::set_last_error;
+use crate::ffi_apis::schema::Schema;
+
+/// An operation that can be called by name through the dispatcher.
+///
+/// Methods are built with [`rpc_method!`](crate::rpc_method), which
+/// generates both decoders from one typed body.
+#[derive(Debug, Clone, Copy)]
+
+pub struct Method {
+    /// The name of the method (e.g. `"constant.get"`).
+    pub name: &'static str,
+    /// The lines of the documentation of the method.
+    pub docs: &'static [&'static str],
+    /// The name and type of each parameter, in order.
+    pub params: &'static [(
+        &'static str,
+        &'static str,
+    )],
+    /// The type of the result.
+    pub returns: &'static str,
+    /// Calls the method with its parameters as JSON values, in order.
+    pub json:
+        fn(Vec<Value>) -> Option<Value>,
+    /// Calls the method with its parameters as a `bincode_next` tuple.
+    pub bincode:
+        fn(&[u8]) -> Option<Vec<u8>>,
+}
+
+/// The description of a parameter of a [`Method`].
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct ParamSchema {
+    /// The name of the parameter, the key of its value in named parameters.
+    pub name: String,
+    /// The Rust type of the parameter (e.g. `"CodataEdition"`).
+    #[serde(rename = "type")]
+    pub ty: String,
+}
+
+/// The description of a [`Method`], as listed by the introspection
+/// functions.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct MethodSchema {
+    /// The name of the method.
+    pub name: String,
+    /// The first paragraph of the documentation of the method.
+    pub summary: String,
+    /// The parameters, in order.
+    pub params: Vec<ParamSchema>,
+    /// The Rust type of the result.
+    pub returns: String,
+}
+
+impl Method {
+    /// Returns the description of the method.
+
+    #[must_use]
+
+    pub fn schema(
+        &self
+    ) -> MethodSchema {
+
+        let summary: Vec<&str> = self
+            .docs
+            .iter()
+            .map(|line| line.trim())
+            .take_while(|line| {
+
+                !line.is_empty()
+            })
+            .collect();
+
+        MethodSchema {
+            name: self
+                .name
+                .to_string(),
+            summary: summary.join(" "),
+            params: self
+                .params
+                .iter()
+                .map(|&(name, ty)| {
+
+                    ParamSchema {
+                        name: name
+                            .to_string(
+                            ),
+                        ty: ty
+                            .to_string(
+                            ),
+                    }
+                })
+                .collect(),
+            returns: self
+                .returns
+                .to_string(),
+        }
+    }
+
+    /// Calls the method with JSON parameters and returns the JSON result.
+    ///
+    /// `params` is an array of the parameters in order, an object keyed by
+    /// parameter name, or null for a method without parameters. Returns
+    /// None and sets the last error if the parameters do not match the
+    /// method or the method fails.
+
+    #[must_use]
+
+    pub fn call_json(
+        &self,
+        params: Value,
+    ) -> Option<Value> {
+
+        let arity = self.params.len();
+
+        let params = match params {
+            | Value::Array(params) => {
+
+                require(
+                    Some(params).filter(
+                        |params| {
+
+                            params.len()
+                                == arity
+                        },
+                    ),
+                    FfiStatus::InvalidArgument,
+                    || {
+
+                        format!(
+                            "`{}` takes \
+                             {arity} \
+                             parameters",
+                            self.name
+                        )
+                    },
+                )?
+            },
+            | Value::Object(mut named) => {
+
+                let params = self
+                    .params
+                    .iter()
+                    .map(|&(name, _)| {
+
+                        require(
+                            named.remove(name),
+                            FfiStatus::InvalidArgument,
+                            || {
+
+                                format!(
+                                    "missing \
+                                     parameter \
+                                     `{name}` of \
+                                     `{}`",
+                                    self.name
+                                )
+                            },
+                        )
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+
+                if let Some(name) =
+                    named.keys().next()
+                {
+
+                    set_last_error(
+                        FfiStatus::InvalidArgument,
+                        format!(
+                            "`{}` has no \
+                             parameter \
+                             `{name}`",
+                            self.name
+                        ),
+                    );
+
+                    return None;
+                }
+
+                params
+            },
+            | Value::Null if arity == 0 => {
+                Vec::new()
+            },
+            | _ => {
+
+                set_last_error(
+                    FfiStatus::InvalidArgument,
+                    "the parameters must \
+                     be an array or an \
+                     object",
+                );
+
+                return None;
+            },
+        };
+
+        (self.json)(params)
+    }
+
+    /// Calls the method with parameters encoded as an [`envelope`] holding
+    /// a tuple, and returns the result in an envelope.
+    ///
+    /// A method without parameters also takes an empty buffer. Returns None
+    /// and sets the last error if the parameters do not decode or the
+    /// method fails.
+
+    #[must_use]
+
+    pub fn call_bincode(
+        &self,
+        params: &[u8],
+    ) -> Option<Vec<u8>> {
+
+        if !params.is_empty() {
+
+            return (self.bincode)(
+                params,
+            );
+        }
+
+        if !self
+            .params
+            .is_empty()
+        {
+
+            set_last_error(
+                FfiStatus::NullPointer,
+                "the input buffer is \
+                 empty",
+            );
+
+            return None;
+        }
+
+        let unit = check(
+            envelope::encode(&()),
+            FfiStatus::Serialization,
+        )?;
+
+        (self.bincode)(&unit)
+    }
+}
+
+/// Returns every registered method, sorted by name.
+
+#[must_use]
+
+pub const fn methods()
+-> &'static [Method] {
+
+    methods::METHODS
+}
+
+/// Returns the method named `name`, if it is registered.
+
+#[must_use]
+
+pub fn method(
+    name: &str
+) -> Option<&'static Method> {
+
+    methods::METHODS
+        .binary_search_by(|method| {
+
+            method
+                .name
+                .cmp(name)
+        })
+        .ok()
+        .and_then(|index| {
+
+            methods::METHODS.get(index)
+        })
+}
+
+/// Looks up a method by name, recording a `NotFound` error on a miss.
+
+pub(crate) fn lookup(
+    name: &str
+) -> Option<&'static Method> {
+
+    require(
+        method(name),
+        FfiStatus::NotFound,
+        || {
+
+            format!(
+                "no method named \
+                 `{name}`"
+            )
+        },
+    )
+}
+
+/// Decodes the parameters of a method from JSON values in order and applies
+/// `body` to them.
+///
+/// This is the JSON half of [`rpc_method!`](crate::rpc_method); `P` is the
+/// tuple of the parameter types.
+
+pub fn call_json<
+    P: DeserializeOwned,
+    R: Serialize,
+>(
+    params: Vec<Value>,
+    body: impl FnOnce(P) -> Option<R>,
+) -> Option<Value> {
+
+    // The unit type of a method without parameters decodes from null, not
+    // from an empty array.
+    let params = if params.is_empty() {
+
+        Value::Null
+    } else {
+
+        Value::Array(params)
+    };
+
+    let params = check(
+        serde_json::from_value(params),
+        FfiStatus::Deserialization,
+    )?;
+
+    check(
+        serde_json::to_value(finite(
+            body(params)?,
+        )?),
+        FfiStatus::Serialization,
+    )
+}
+
+/// Decodes the parameters of a method from an [`envelope`] holding a
+/// tuple and applies `body` to them.
+///
+/// This is the bincode half of [`rpc_method!`](crate::rpc_method); `P` is
+/// the tuple of the parameter types.
+
+pub fn call_bincode<
+    P: DeserializeOwned + Schema,
+    R: Serialize + Schema,
+>(
+    params: &[u8],
+    body: impl FnOnce(P) -> Option<R>,
+) -> Option<Vec<u8>> {
+
+    let params =
+        envelope::decode(params)
+            .map_err(|e| {
+
+                set_last_error(
+                    e.status(),
+                    e.to_string(),
+                );
+            })
+            .ok()?;
+
+    let result = finite(body(params)?)?;
+
+    envelope::encode(&result)
+        .map_err(|e| {
+
+            set_last_error(
+                e.status(),
+                e.to_string(),
+            );
+        })
+        .ok()
+}
+
+/// Returns `result` if it holds only finite numbers, and otherwise records
+/// an `AlgorithmFailure`.
+
+fn finite<R: Serialize>(
+    result: R
+) -> Option<R> {
+
+    require(
+        Some(result).filter(|result| {
+
+            finite::is_finite(result)
+        }),
+        FfiStatus::AlgorithmFailure,
+        || {
+
+            "the result is not finite"
+                .to_string()
+        },
+    )
+}
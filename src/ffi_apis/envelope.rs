@@ -0,0 +1,539 @@
+//! The versioned envelope around every `bincode_next` payload.
+//!
+//! `bincode_next` output carries no type information, so a host decoding
+//! a buffer with a layout that differs from the library's would read
+//! garbage. Every buffer built by [`to_bincode_buffer`] therefore starts
+//! with a fixed header, all integers little-endian:
+//!
+//! | Offset | Size | Field                                             |
+//! |--------|------|---------------------------------------------------|
+//! | 0      | 4    | magic, the bytes `RSSN`                           |
+//! | 4      | 2    | format version, [`RSSN_BINCODE_VERSION`]          |
+//! | 6      | 2    | reserved, zero                                    |
+//! | 8      | 8    | schema id of the payload, see [`schema_id`]       |
+//! | 16     | 8    | payload length in bytes                           |
+//! | 24     | 4    | CRC-32 (IEEE) of the payload                      |
+//! | 28     |      | the payload, `bincode_next` standard configuration |
+//!
+//! [`from_bincode_buffer`] checks each field before decoding the payload
+//! and records a mismatch as the last error: `InvalidEnvelope` for a
+//! damaged or foreign buffer, `TypeMismatch` for a payload of another
+//! type. Buffers passed to bincode functions must carry the envelope too.
+//!
+//! The schema id is the 64-bit FNV-1a hash of the [`shape`] of the type:
+//! its field names and types in order, not its Rust name. Hosts compute
+//! the id of the shape of their own layout with `rssn_bincode_schema_id`.
+//!
+//! [`shape`]: crate::ffi_apis::schema
+//! [`to_bincode_buffer`]: crate::ffi_apis::common::to_bincode_buffer
+//! [`from_bincode_buffer`]: crate::ffi_apis::common::from_bincode_buffer
+
+use std::fmt;
+use std::os::raw::c_char;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::schema;
+use crate::ffi_apis::schema::Schema;
+
+/// The magic bytes at the start of every envelope.
+
+pub const RSSN_BINCODE_MAGIC: [u8; 4] =
+    *b"RSSN";
+
+/// The format version written by this library, the only one it reads.
+
+pub const RSSN_BINCODE_VERSION: u16 = 1;
+
+/// The length of the envelope header in bytes.
+
+pub const RSSN_BINCODE_HEADER_LEN:
+    usize = 28;
+
+/// The header of an envelope.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq,
+)]
+
+pub struct Header {
+    /// The format version.
+    pub version: u16,
+    /// The schema id of the payload.
+    pub schema_id: u64,
+    /// The length of the payload in bytes.
+    pub len: u64,
+    /// The CRC-32 of the payload.
+    pub checksum: u32,
+}
+
+/// A buffer that cannot be decoded as the expected type.
+#[derive(
+    Debug, Clone, PartialEq, Eq,
+)]
+
+pub enum EnvelopeError {
+    /// The buffer is shorter than the header.
+    Truncated {
+        /// The length of the buffer.
+        len: usize,
+    },
+    /// The buffer does not start with [`RSSN_BINCODE_MAGIC`].
+    BadMagic([u8; 4]),
+    /// The buffer was written in another format version.
+    UnsupportedVersion(u16),
+    /// The payload length in the header does not match the buffer.
+    LengthMismatch {
+        /// The length given by the header.
+        declared: u64,
+        /// The length of the payload in the buffer.
+        actual: usize,
+    },
+    /// The payload does not match its checksum.
+    ChecksumMismatch {
+        /// The checksum given by the header.
+        declared: u32,
+        /// The checksum of the payload in the buffer.
+        actual: u32,
+    },
+    /// The payload has another schema than the expected type.
+    SchemaMismatch {
+        /// The shape of the expected type.
+        expected: String,
+        /// The schema id given by the header.
+        found: u64,
+    },
+    /// The payload does not decode to the expected type.
+    Payload(String),
+    /// The value could not be encoded.
+    Encode(String),
+}
+
+impl EnvelopeError {
+    /// Returns the status recorded for the error across the FFI boundary.
+
+    #[must_use]
+
+    pub const fn status(
+        &self
+    ) -> FfiStatus {
+
+        match self {
+            | Self::Truncated {
+                ..
+            }
+            | Self::BadMagic(_)
+            | Self::UnsupportedVersion(_)
+            | Self::LengthMismatch {
+                ..
+            }
+            | Self::ChecksumMismatch {
+                ..
+            } => {
+                FfiStatus::InvalidEnvelope
+            },
+            | Self::SchemaMismatch {
+                ..
+            } => FfiStatus::TypeMismatch,
+            | Self::Payload(_) => {
+                FfiStatus::Deserialization
+            },
+            | Self::Encode(_) => {
+                FfiStatus::Serialization
+            },
+        }
+    }
+}
+
+impl fmt::Display for EnvelopeError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match self {
+            | Self::Truncated {
+                len,
+            } => {
+                write!(
+                    f,
+                    "the buffer of {len} \
+                     bytes is shorter \
+                     than the \
+                     {RSSN_BINCODE_HEADER_LEN}-byte \
+                     envelope header"
+                )
+            },
+            | Self::BadMagic(magic) => {
+                write!(
+                    f,
+                    "the buffer starts \
+                     with {magic:02x?}, \
+                     not the envelope \
+                     magic `RSSN`"
+                )
+            },
+            | Self::UnsupportedVersion(
+                version,
+            ) => {
+                write!(
+                    f,
+                    "envelope format \
+                     version {version} is \
+                     not supported \
+                     (expected \
+                     {RSSN_BINCODE_VERSION})"
+                )
+            },
+            | Self::LengthMismatch {
+                declared,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "the envelope declares \
+                     {declared} payload \
+                     bytes but holds \
+                     {actual}"
+                )
+            },
+            | Self::ChecksumMismatch {
+                declared,
+                actual,
+            } => {
+                write!(
+                    f,
+                    "the payload checksum \
+                     is {actual:#010x}, \
+                     not {declared:#010x}"
+                )
+            },
+            | Self::SchemaMismatch {
+                expected,
+                found,
+            } => {
+                write!(
+                    f,
+                    "expected a payload of \
+                     schema `{expected}` \
+                     ({:#018x}), found \
+                     {found:#018x}",
+                    fnv1a(expected)
+                )
+            },
+            | Self::Payload(message)
+            | Self::Encode(message) => {
+                f.write_str(message)
+            },
+        }
+    }
+}
+
+impl std::error::Error
+    for EnvelopeError
+{
+}
+
+/// Returns the schema id of `T`, the FNV-1a hash of its
+/// [`shape`](schema::shape).
+
+#[must_use]
+
+pub fn schema_id<T: Schema + ?Sized>()
+-> u64 {
+
+    fnv1a(&schema::shape::<T>())
+}
+
+/// Encodes `value` as an envelope.
+///
+/// # Errors
+///
+/// Returns [`EnvelopeError::Encode`] if `value` cannot be encoded.
+
+pub fn encode<
+    T: Serialize + Schema + ?Sized,
+>(
+    value: &T
+) -> Result<Vec<u8>, EnvelopeError> {
+
+    let payload =
+        bincode_next::serde::encode_to_vec(
+            value,
+            bincode_next::config::standard(),
+        )
+        .map_err(|e| {
+
+            EnvelopeError::Encode(
+                e.to_string(),
+            )
+        })?;
+
+    let mut bytes = Vec::with_capacity(
+        RSSN_BINCODE_HEADER_LEN
+            .saturating_add(
+                payload.len(),
+            ),
+    );
+
+    bytes.extend_from_slice(
+        &RSSN_BINCODE_MAGIC,
+    );
+
+    bytes.extend_from_slice(
+        &RSSN_BINCODE_VERSION
+            .to_le_bytes(),
+    );
+
+    bytes.extend_from_slice(
+        &0_u16.to_le_bytes(),
+    );
+
+    bytes.extend_from_slice(
+        &schema_id::<T>().to_le_bytes(),
+    );
+
+    bytes.extend_from_slice(
+        &(payload.len() as u64)
+            .to_le_bytes(),
+    );
+
+    bytes.extend_from_slice(
+        &crc32(&payload).to_le_bytes(),
+    );
+
+    bytes.extend_from_slice(&payload);
+
+    Ok(bytes)
+}
+
+/// Reads the header of an envelope and returns it with the payload,
+/// checking the magic, the version, the length and the checksum.
+///
+/// # Errors
+///
+/// Returns the first check that fails.
+
+pub fn open(
+    bytes: &[u8]
+) -> Result<
+    (Header, &[u8]),
+    EnvelopeError,
+> {
+
+    let truncated = || {
+
+        EnvelopeError::Truncated {
+            len: bytes.len(),
+        }
+    };
+
+    let (magic, rest) = bytes
+        .split_first_chunk::<4>()
+        .ok_or_else(truncated)?;
+
+    let (version, rest) = rest
+        .split_first_chunk::<2>()
+        .ok_or_else(truncated)?;
+
+    let (_reserved, rest) = rest
+        .split_first_chunk::<2>()
+        .ok_or_else(truncated)?;
+
+    let (schema_id, rest) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(truncated)?;
+
+    let (len, rest) = rest
+        .split_first_chunk::<8>()
+        .ok_or_else(truncated)?;
+
+    let (checksum, payload) = rest
+        .split_first_chunk::<4>()
+        .ok_or_else(truncated)?;
+
+    if *magic != RSSN_BINCODE_MAGIC {
+
+        return Err(
+            EnvelopeError::BadMagic(
+                *magic,
+            ),
+        );
+    }
+
+    let header = Header {
+        version: u16::from_le_bytes(
+            *version,
+        ),
+        schema_id: u64::from_le_bytes(
+            *schema_id,
+        ),
+        len: u64::from_le_bytes(*len),
+        checksum: u32::from_le_bytes(
+            *checksum,
+        ),
+    };
+
+    if header.version
+        != RSSN_BINCODE_VERSION
+    {
+
+        return Err(
+            EnvelopeError::UnsupportedVersion(
+                header.version,
+            ),
+        );
+    }
+
+    if header.len
+        != payload.len() as u64
+    {
+
+        return Err(
+            EnvelopeError::LengthMismatch {
+                declared: header.len,
+                actual: payload.len(),
+            },
+        );
+    }
+
+    let actual = crc32(payload);
+
+    if header.checksum != actual {
+
+        return Err(
+            EnvelopeError::ChecksumMismatch {
+                declared: header
+                    .checksum,
+                actual,
+            },
+        );
+    }
+
+    Ok((header, payload))
+}
+
+/// Decodes an envelope holding a `T`.
+///
+/// # Errors
+///
+/// Returns an error if the envelope is damaged, holds another schema or
+/// its payload does not decode to `T` exactly.
+
+pub fn decode<
+    T: DeserializeOwned + Schema,
+>(
+    bytes: &[u8]
+) -> Result<T, EnvelopeError> {
+
+    let (header, payload) =
+        open(bytes)?;
+
+    if header.schema_id
+        != schema_id::<T>()
+    {
+
+        return Err(
+            EnvelopeError::SchemaMismatch {
+                expected: schema::shape::<
+                    T,
+                >(),
+                found: header.schema_id,
+            },
+        );
+    }
+
+    let (value, read) =
+        bincode_next::serde::decode_from_slice(
+            payload,
+            bincode_next::config::standard(),
+        )
+        .map_err(|e| {
+
+            EnvelopeError::Payload(
+                e.to_string(),
+            )
+        })?;
+
+    if read != payload.len() {
+
+        return Err(
+            EnvelopeError::Payload(
+                format!(
+                    "{} trailing \
+                     bytes after the \
+                     payload",
+                    payload
+                        .len()
+                        .saturating_sub(
+                            read
+                        )
+                ),
+            ),
+        );
+    }
+
+    Ok(value)
+}
+
+/// Returns the schema id of the shape `shape` (e.g.
+/// `"seq<{value: f64, uncertainty: f64}>"`), or 0 if `shape` is null or
+/// not valid UTF-8.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_bincode_schema_id(
+    shape: *const c_char
+) -> u64 {
+
+    let _call = ffi_call!();
+
+    unsafe {
+
+        c_str_to_str(shape)
+    }
+    .map_or(0, fnv1a)
+}
+
+/// Returns the 64-bit FNV-1a hash of `text`.
+
+fn fnv1a(text: &str) -> u64 {
+
+    text.bytes().fold(
+        0xcbf2_9ce4_8422_2325,
+        |hash, byte| {
+
+            (hash ^ u64::from(byte))
+                .wrapping_mul(
+                    0x0100_0000_01b3,
+                )
+        },
+    )
+}
+
+/// Returns the CRC-32 (IEEE 802.3, as in zlib) of `bytes`.
+
+fn crc32(bytes: &[u8]) -> u32 {
+
+    !bytes.iter().fold(
+        u32::MAX,
+        |crc, &byte| {
+
+            (0 .. 8).fold(
+                crc ^ u32::from(byte),
+                |crc, _| {
+
+                    if crc & 1 == 1 {
+
+                        (crc >> 1)
+                            ^ 0xedb8_8320
+                    } else {
+
+                        crc >> 1
+                    }
+                },
+            )
+        },
+    )
+}
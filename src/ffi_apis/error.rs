@@ -0,0 +1,399 @@
+//! Structured error reporting across the FFI boundary.
+//!
+//! A failing FFI function still returns `null`, an empty `BincodeBuffer`,
+//! `NaN` or `false`, but it also records an [`FfiError`] (a [`FfiStatus`]
+//! code, a message and the name of the function) in a thread-local "last
+//! error". Every fallible FFI function clears the record on entry, so
+//! after a call it describes that call, and the `rssn_last_error_*`
+//! functions read it without clearing it.
+//!
+//! # Examples
+//!
+//! ```c
+//! char* json = rssn_get_constant_json("NO_SUCH_CONSTANT");
+//! if (json == NULL) {
+//!     char* message = rssn_last_error_message();
+//!     printf("%d in %s: %s\n", rssn_last_error_code(),
+//!            rssn_last_error_function(), message);
+//!     // 6 in rssn_get_constant_json: no constant with identifier ...
+//!     rssn_free_string(message);
+//! }
+//! ```
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::fmt;
+use std::os::raw::c_char;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::common::to_json_string;
+
+/// The status of the last FFI call.
+#[repr(C)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum FfiStatus {
+    /// The call succeeded.
+    Ok = 0,
+    /// A required pointer argument was null, or a buffer was empty.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// A JSON or `bincode_next` argument did not decode to the expected
+    /// type.
+    Deserialization = 3,
+    /// The result could not be encoded.
+    Serialization = 4,
+    /// A returned string would contain an interior NUL byte.
+    InteriorNul = 5,
+    /// Nothing is registered under the given key or name.
+    NotFound = 6,
+    /// An argument was decoded but is out of range or inconsistent.
+    InvalidArgument = 7,
+    /// The algorithm failed on valid arguments.
+    AlgorithmFailure = 8,
+    /// A handle is null, was freed or was never issued.
+    InvalidHandle = 9,
+    /// A handle refers to an object of another type, or a `bincode_next`
+    /// argument holds a payload of another type.
+    TypeMismatch = 10,
+    /// A `bincode_next` argument has no valid envelope: it is truncated,
+    /// damaged or was written in another format version.
+    InvalidEnvelope = 11,
+    /// A name matches several entries and none of them clearly best.
+    Ambiguous = 12,
+}
+
+impl fmt::Display for FfiStatus {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(match self {
+            | Self::Ok => "ok",
+            | Self::NullPointer => {
+                "null pointer"
+            },
+            | Self::InvalidUtf8 => {
+                "invalid UTF-8"
+            },
+            | Self::Deserialization => {
+                "deserialization failed"
+            },
+            | Self::Serialization => {
+                "serialization failed"
+            },
+            | Self::InteriorNul => {
+                "interior NUL byte"
+            },
+            | Self::NotFound => {
+                "not found"
+            },
+            | Self::InvalidArgument => {
+                "invalid argument"
+            },
+            | Self::AlgorithmFailure => {
+                "algorithm failure"
+            },
+            | Self::InvalidHandle => {
+                "invalid handle"
+            },
+            | Self::TypeMismatch => {
+                "type mismatch"
+            },
+            | Self::InvalidEnvelope => {
+                "invalid envelope"
+            },
+            | Self::Ambiguous => {
+                "ambiguous"
+            },
+        })
+    }
+}
+
+/// The record of a failed FFI call.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct FfiError {
+    /// What went wrong.
+    pub code: FfiStatus,
+    /// A description of the failure.
+    pub message: String,
+    /// The FFI function that failed (e.g. `"rssn_get_constant_json"`),
+    /// empty if the error was raised outside an FFI call.
+    pub function: String,
+}
+
+impl fmt::Display for FfiError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        if self
+            .function
+            .is_empty()
+        {
+
+            write!(
+                f,
+                "{}: {}",
+                self.code, self.message
+            )
+        } else {
+
+            write!(
+                f,
+                "{} in {}: {}",
+                self.code,
+                self.function,
+                self.message
+            )
+        }
+    }
+}
+
+impl std::error::Error for FfiError {
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<FfiError>> =
+        const { RefCell::new(None) };
+
+    static CURRENT_FUNCTION: Cell<&'static str> =
+        const { Cell::new("") };
+}
+
+/// An FFI call in progress on this thread.
+///
+/// Created by [`ffi_call!`](crate::ffi_call) at the top of each fallible
+/// FFI function; errors recorded while it is alive name that function.
+
+#[must_use]
+
+pub struct FfiCall {
+    previous: &'static str,
+}
+
+impl FfiCall {
+    /// Clears the last error and enters the function with path `path`
+    /// (a `std::any::type_name` of an item inside it).
+
+    pub fn enter(
+        path: &'static str
+    ) -> Self {
+
+        let function = path
+            .strip_suffix("::f")
+            .unwrap_or(path)
+            .rsplit("::")
+            .next()
+            .unwrap_or(path);
+
+        clear_last_error();
+
+        Self {
+            previous: CURRENT_FUNCTION
+                .replace(function),
+        }
+    }
+}
+
+impl Drop for FfiCall {
+    fn drop(&mut self) {
+
+        CURRENT_FUNCTION
+            .set(self.previous);
+    }
+}
+
+/// Records an error with `code` and `message` for the current FFI call.
+
+pub fn set_last_error(
+    code: FfiStatus,
+    message: impl Into<String>,
+) {
+
+    let error = FfiError {
+        code,
+        message: message.into(),
+        function: CURRENT_FUNCTION
+            .get()
+            .to_string(),
+    };
+
+    LAST_ERROR.with_borrow_mut(
+        |last| {
+
+            *last = Some(error);
+        },
+    );
+}
+
+/// Clears the last error of this thread.
+
+pub fn clear_last_error() {
+
+    LAST_ERROR.with_borrow_mut(
+        |last| {
+
+            *last = None;
+        },
+    );
+}
+
+/// Returns the last error of this thread, if the last fallible FFI call
+/// failed.
+#[must_use]
+
+pub fn last_error() -> Option<FfiError>
+{
+
+    LAST_ERROR.with_borrow(Clone::clone)
+}
+
+/// Returns `value`, recording an error with `code` and the message built
+/// by `message` if it is `None`.
+
+pub fn require<T>(
+    value: Option<T>,
+    code: FfiStatus,
+    message: impl FnOnce() -> String,
+) -> Option<T> {
+
+    if value.is_none() {
+
+        set_last_error(code, message());
+    }
+
+    value
+}
+
+/// Returns `items[index]`, recording an `InvalidArgument` error if `index`
+/// is out of range.
+
+pub fn at_index<T>(
+    items: &[T],
+    index: usize,
+) -> Option<&T> {
+
+    require(
+        items.get(index),
+        FfiStatus::InvalidArgument,
+        || {
+
+            format!(
+                "index {index} is out \
+                 of range for {} items",
+                items.len()
+            )
+        },
+    )
+}
+
+/// Returns the value of `result`, recording an error with `code` and the
+/// error's message if it is an `Err`.
+
+pub fn check<T, E: fmt::Display>(
+    result: Result<T, E>,
+    code: FfiStatus,
+) -> Option<T> {
+
+    result
+        .map_err(|e| {
+
+            set_last_error(
+                code,
+                e.to_string(),
+            );
+        })
+        .ok()
+}
+
+/// Returns the status code of the last fallible FFI call on this thread,
+/// `Ok` if it succeeded.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_last_error_code()
+-> FfiStatus {
+
+    LAST_ERROR.with_borrow(|last| {
+
+        last.as_ref()
+            .map_or(
+                FfiStatus::Ok,
+                |e| e.code,
+            )
+    })
+}
+
+/// Returns the message of the last error on this thread as a C string.
+///
+/// Returns null if the last fallible FFI call succeeded.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_last_error_message()
+-> *mut c_char {
+
+    last_error().map_or(
+        std::ptr::null_mut(),
+        |e| to_c_string(e.message),
+    )
+}
+
+/// Returns the name of the FFI function that raised the last error on this
+/// thread as a C string.
+///
+/// Returns null if the last fallible FFI call succeeded.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_last_error_function()
+-> *mut c_char {
+
+    last_error().map_or(
+        std::ptr::null_mut(),
+        |e| to_c_string(e.function),
+    )
+}
+
+/// Returns the last error on this thread as a JSON `FfiError` object, or
+/// `null` if the last fallible FFI call succeeded.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_last_error_json()
+-> *mut c_char {
+
+    to_json_string(&last_error())
+}
+
+/// Clears the last error on this thread.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_last_error_clear()
+ {
+
+    clear_last_error();
+}
@@ -0,0 +1,705 @@
+//! A table of Rust objects lent to foreign code through opaque handles.
+//!
+//! An [`RssnHandle`] is a 64-bit id: the index of a slot in a global table
+//! and the generation of that slot. Freeing a handle bumps the generation
+//! of its slot, so a freed handle, or a copy of one, is recognized as stale
+//! (`InvalidHandle`) even after the slot is reused, instead of reaching
+//! someone else's object. Each object also carries its type, and asking
+//! for a handle as the wrong type is a `TypeMismatch` rather than a bad
+//! cast. Handle `0` is never issued and stands for "no object".
+//!
+//! Any `Clone + Send + Sync` serde type can live in the table once it
+//! implements [`HandleObject`]; [`f64`], [`Measured`], [`Quantity`] and
+//! rssn's [`Expr`] do. Objects are immutable while in the table, so they
+//! can be read from several threads at once.
+//!
+//! # Examples
+//!
+//! ```c
+//! RssnHandle h = rssn_constant_measured_handle("ELECTRON_MASS");
+//! RssnHandle c = rssn_constant_measured_handle("SPEED_OF_LIGHT");
+//! RssnHandle c2 = rssn_measured_mul(c, c);
+//! RssnHandle e = rssn_measured_mul(h, c2);
+//! double value = rssn_measured_value(e);
+//! rssn_handle_free(h);
+//! rssn_handle_free(h); // false: rssn_last_error_code() is InvalidHandle
+//! rssn_handle_free(c);
+//! rssn_handle_free(c2);
+//! rssn_handle_free(e);
+//! ```
+
+use std::any::Any;
+use std::fmt;
+use std::os::raw::c_char;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use rssn::symbolic::core::Expr;
+use serde::Deserialize;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::constant::Measured;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::error::set_last_error;
+use crate::unit::Quantity;
+
+/// An opaque handle to an object in the handle table.
+#[repr(transparent)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct RssnHandle(pub u64);
+
+impl RssnHandle {
+    /// The handle that refers to no object.
+
+    pub const NULL: Self = Self(0);
+
+    const fn new(
+        index: u32,
+        generation: u32,
+    ) -> Self {
+
+        Self(
+            ((generation as u64) << 32)
+                | index as u64,
+        )
+    }
+
+    /// Returns `true` for the null handle.
+    #[must_use]
+
+    pub const fn is_null(self) -> bool {
+
+        self.0 == 0
+    }
+
+    /// The index of the slot the handle refers to.
+
+    #[allow(
+        clippy::cast_possible_truncation
+    )]
+
+    const fn index(self) -> u32 {
+
+        self.0 as u32
+    }
+
+    /// The generation of the slot the handle was issued for.
+
+    const fn generation(self) -> u32 {
+
+        (self.0 >> 32) as u32
+    }
+}
+
+impl fmt::Display for RssnHandle {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        write!(
+            f,
+            "{}#{}",
+            self.index(),
+            self.generation()
+        )
+    }
+}
+
+/// A type whose values can be lent to foreign code through handles.
+
+pub trait HandleObject:
+    Any
+    + Clone
+    + Send
+    + Sync
+    + Serialize
+    + DeserializeOwned
+{
+    /// The name `rssn_handle_type` reports for values of this type.
+
+    const TYPE_NAME: &'static str;
+}
+
+impl HandleObject for f64 {
+    const TYPE_NAME: &'static str =
+        "f64";
+}
+
+impl HandleObject for Measured {
+    const TYPE_NAME: &'static str =
+        "Measured";
+}
+
+impl HandleObject for Quantity {
+    const TYPE_NAME: &'static str =
+        "Quantity";
+}
+
+impl HandleObject for Expr {
+    const TYPE_NAME: &'static str =
+        "Expr";
+}
+
+/// The object-safe view of a [`HandleObject`] stored in the table.
+
+trait Object: Any + Send + Sync {
+    fn type_name(&self)
+    -> &'static str;
+
+    fn duplicate(
+        &self
+    ) -> Arc<dyn Object>;
+
+    fn to_json(
+        &self
+    ) -> serde_json::Result<String>;
+
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: HandleObject> Object for T {
+    fn type_name(
+        &self
+    ) -> &'static str {
+
+        T::TYPE_NAME
+    }
+
+    fn duplicate(
+        &self
+    ) -> Arc<dyn Object> {
+
+        Arc::new(self.clone())
+    }
+
+    fn to_json(
+        &self
+    ) -> serde_json::Result<String>
+    {
+
+        serde_json::to_string(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+
+        self
+    }
+}
+
+struct Slot {
+    generation: u32,
+    object: Option<Arc<dyn Object>>,
+}
+
+struct Table {
+    slots: Vec<Slot>,
+    free: Vec<u32>,
+    live: usize,
+}
+
+static TABLE: Mutex<Table> =
+    Mutex::new(Table {
+        slots: Vec::new(),
+        free: Vec::new(),
+        live: 0,
+    });
+
+impl Table {
+    fn insert(
+        &mut self,
+        object: Arc<dyn Object>,
+    ) -> Option<RssnHandle> {
+
+        let handle =
+            if let Some(index) =
+                self.free.pop()
+            {
+
+                let slot = self
+                    .slots
+                    .get_mut(
+                    index as usize,
+                )?;
+
+                slot.object =
+                    Some(object);
+
+                RssnHandle::new(
+                    index,
+                    slot.generation,
+                )
+            } else {
+
+                let index =
+                    u32::try_from(
+                        self.slots
+                            .len(),
+                    )
+                    .ok()?;
+
+                self.slots
+                    .push(Slot {
+                        generation: 1,
+                        object: Some(
+                            object,
+                        ),
+                    });
+
+                RssnHandle::new(
+                    index, 1,
+                )
+            };
+
+        self.live = self
+            .live
+            .saturating_add(1);
+
+        Some(handle)
+    }
+
+    fn slot(
+        &self,
+        handle: RssnHandle,
+    ) -> Option<&Arc<dyn Object>> {
+
+        self.slots
+            .get(
+                handle.index() as usize,
+            )
+            .filter(|slot| {
+
+                slot.generation
+                    == handle.generation()
+            })?
+            .object
+            .as_ref()
+    }
+
+    fn remove(
+        &mut self,
+        handle: RssnHandle,
+    ) -> Option<Arc<dyn Object>> {
+
+        let slot = self
+            .slots
+            .get_mut(
+                handle.index() as usize
+            )
+            .filter(|slot| {
+
+                slot.generation
+                    == handle
+                        .generation()
+            })?;
+
+        let object =
+            slot.object.take()?;
+
+        // A slot whose generation would wrap is retired rather than risk
+        // reissuing an old handle.
+        if let Some(generation) = slot
+            .generation
+            .checked_add(1)
+        {
+
+            slot.generation =
+                generation;
+
+            self.free
+                .push(handle.index());
+        }
+
+        self.live = self
+            .live
+            .saturating_sub(1);
+
+        Some(object)
+    }
+}
+
+fn with_table<R>(
+    f: impl FnOnce(&mut Table) -> R
+) -> R {
+
+    f(&mut TABLE
+        .lock()
+        .unwrap_or_else(
+            PoisonError::into_inner,
+        ))
+}
+
+/// Returns the object of `handle`, recording an `InvalidHandle` error if
+/// there is none.
+
+fn object(
+    handle: RssnHandle
+) -> Option<Arc<dyn Object>> {
+
+    let object = with_table(|table| {
+
+        table
+            .slot(handle)
+            .cloned()
+    });
+
+    if object.is_none() {
+
+        set_last_error(
+            FfiStatus::InvalidHandle,
+            invalid(handle),
+        );
+    }
+
+    object
+}
+
+fn invalid(
+    handle: RssnHandle
+) -> String {
+
+    if handle.is_null() {
+
+        "the handle is null".to_string()
+    } else {
+
+        format!(
+            "handle {handle} is freed \
+             or was never issued"
+        )
+    }
+}
+
+fn insert_object(
+    object: Arc<dyn Object>
+) -> RssnHandle {
+
+    with_table(|table| {
+
+        table.insert(object)
+    })
+    .unwrap_or_else(|| {
+
+        set_last_error(
+            FfiStatus::InvalidArgument,
+            "the handle table is full",
+        );
+
+        RssnHandle::NULL
+    })
+}
+
+/// Moves `value` into the table and returns its handle.
+///
+/// Returns the null handle, recording an error, only if the table is full.
+
+pub fn insert<T: HandleObject>(
+    value: T
+) -> RssnHandle {
+
+    insert_object(Arc::new(value))
+}
+
+/// Applies `f` to the object of `handle`.
+///
+/// Returns `None`, recording an `InvalidHandle` or `TypeMismatch` error,
+/// if `handle` is stale or refers to an object of another type. The table
+/// is not locked while `f` runs, so `f` may create and free handles.
+
+pub fn with<T: HandleObject, R>(
+    handle: RssnHandle,
+    f: impl FnOnce(&T) -> R,
+) -> Option<R> {
+
+    let object = object(handle)?;
+
+    let value = object
+        .as_any()
+        .downcast_ref::<T>();
+
+    if value.is_none() {
+
+        set_last_error(
+            FfiStatus::TypeMismatch,
+            format!(
+                "handle {handle} \
+                 refers to a {}, not \
+                 a {}",
+                object.type_name(),
+                T::TYPE_NAME
+            ),
+        );
+    }
+
+    value.map(f)
+}
+
+/// Returns a copy of the object of `handle`.
+///
+/// Returns `None` under the same conditions as [`with`].
+
+pub fn get<T: HandleObject>(
+    handle: RssnHandle
+) -> Option<T> {
+
+    with(handle, T::clone)
+}
+
+/// Inserts a copy of the object of `handle` and returns its handle.
+///
+/// Returns the null handle, recording an `InvalidHandle` error, if
+/// `handle` is stale.
+#[must_use]
+
+pub fn duplicate(
+    handle: RssnHandle
+) -> RssnHandle {
+
+    object(handle).map_or(
+        RssnHandle::NULL,
+        |object| {
+
+            insert_object(
+                object.duplicate(),
+            )
+        },
+    )
+}
+
+/// Removes the object of `handle` from the table.
+///
+/// Returns `false`, recording an `InvalidHandle` error, if `handle` is
+/// stale, e.g. because it was already freed.
+#[must_use]
+
+pub fn remove(
+    handle: RssnHandle
+) -> bool {
+
+    let removed = with_table(|table| {
+
+        table.remove(handle)
+    });
+
+    if removed.is_none() {
+
+        set_last_error(
+            FfiStatus::InvalidHandle,
+            invalid(handle),
+        );
+    }
+
+    removed.is_some()
+}
+
+/// Returns the [`HandleObject::TYPE_NAME`] of the object of `handle`, or
+/// `None` if `handle` is stale.
+#[must_use]
+
+pub fn type_name(
+    handle: RssnHandle
+) -> Option<&'static str> {
+
+    with_table(|table| {
+
+        table
+            .slot(handle)
+            .map(|object| {
+
+                object.type_name()
+            })
+    })
+}
+
+/// Returns the number of objects in the table.
+#[must_use]
+
+pub fn len() -> usize {
+
+    with_table(|table| table.live)
+}
+
+fn decode<T: HandleObject>(
+    json: &str
+) -> Option<RssnHandle> {
+
+    check(
+        serde_json::from_str::<T>(json),
+        FfiStatus::Deserialization,
+    )
+    .map(insert)
+}
+
+/// Returns `true` if `handle` refers to an object.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_is_valid(
+    handle: RssnHandle
+) -> bool {
+
+    type_name(handle).is_some()
+}
+
+/// Returns the type name of the object of `handle` (e.g. `"Measured"`) as
+/// a C string.
+///
+/// Returns null if `handle` is stale.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_type(
+    handle: RssnHandle
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    match object(handle) {
+        | Some(object) => {
+            to_c_string(
+                object
+                    .type_name()
+                    .to_string(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns a new handle to a copy of the object of `handle`.
+///
+/// The copy must be freed on its own using `rssn_handle_free`.
+/// Returns the null handle if `handle` is stale.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_clone(
+    handle: RssnHandle
+) -> RssnHandle {
+
+    let _call = ffi_call!();
+
+    duplicate(handle)
+}
+
+/// Frees the object of `handle`.
+///
+/// Returns `false` if `handle` is stale, e.g. on a double free; the table
+/// is left untouched.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_free(
+    handle: RssnHandle
+) -> bool {
+
+    let _call = ffi_call!();
+
+    remove(handle)
+}
+
+/// Returns the number of objects in the handle table.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_count()
+-> usize {
+
+    len()
+}
+
+/// Returns the object of `handle` as JSON.
+///
+/// Returns null if `handle` is stale.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_to_json(
+    handle: RssnHandle
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    object(handle)
+        .and_then(|object| {
+
+            check(
+                object.to_json(),
+                FfiStatus::Serialization,
+            )
+        })
+        .map_or(
+            std::ptr::null_mut(),
+            to_c_string,
+        )
+}
+
+/// Creates an object of the type named `type_name` (`"f64"`,
+/// `"Measured"`, `"Quantity"` or `"Expr"`) from JSON and returns its
+/// handle.
+///
+/// Returns the null handle if an argument is not valid UTF-8, the type is
+/// unknown or the JSON does not decode to it.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_handle_from_json(
+    type_name: *const c_char,
+    json: *const c_char,
+) -> RssnHandle {
+
+    let _call = ffi_call!();
+
+    let (Some(type_name), Some(json)) = (
+        unsafe {
+
+            c_str_to_str(type_name)
+        },
+        unsafe {
+
+            c_str_to_str(json)
+        },
+    ) else {
+
+        return RssnHandle::NULL;
+    };
+
+    let handle = match type_name {
+        | f64::TYPE_NAME => {
+            decode::<f64>(json)
+        },
+        | Measured::TYPE_NAME => {
+            decode::<Measured>(json)
+        },
+        | Quantity::TYPE_NAME => {
+            decode::<Quantity>(json)
+        },
+        | Expr::TYPE_NAME => {
+            decode::<Expr>(json)
+        },
+        | _ => {
+
+            set_last_error(
+                FfiStatus::NotFound,
+                format!(
+                    "no handle type \
+                     `{type_name}`"
+                ),
+            );
+
+            None
+        },
+    };
+
+    handle.unwrap_or(RssnHandle::NULL)
+}
@@ -1,13 +1,36 @@
+#[macro_export]
+/// Enters an FFI call: clears the thread's last error and names the enclosing
+/// function in errors recorded until the returned guard is dropped.
+///
+/// Every fallible FFI function starts with `let _call = ffi_call!();`.
+
+macro_rules! ffi_call {
+    () => {
+        $crate::ffi_apis::error::FfiCall::enter(
+            std::any::type_name_of_val(&{
+                const fn f() {}
+
+                f
+            }),
+        )
+    };
+}
+
 #[macro_export]
 /// Creates a FFI-compatible function that takes a JSON string as input, deserializes it to a single argument,
 /// applies a body of logic, and returns the result as a JSON string.
+///
+/// Returns null and sets the last error if the input cannot be decoded or the result cannot be encoded.
 
 macro_rules! json_ffi_unary {
-    ($name:ident, $input_type:ty, | $arg:ident | $body:expr_2021) => {
-        #[no_mangle]
+    ($(#[$meta:meta])* $name:ident, $input_type:ty, | $arg:ident | $body:expr_2021) => {
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
 
         pub extern "C" fn $name(input_json : *const std::ffi::c_char) -> *mut std::ffi::c_char {
 
+            let _call = $crate::ffi_call!();
+
             let input : Option<$input_type> =
                 $crate::ffi_apis::common::from_json_string(input_json);
 
@@ -27,21 +50,27 @@ macro_rules! json_ffi_unary {
 #[macro_export]
 /// Creates a FFI-compatible function that takes two JSON strings as input, deserializes them to two arguments,
 /// applies a body of logic, and returns the result as a JSON string.
+///
+/// Returns null and sets the last error if an input cannot be decoded or the result cannot be encoded.
 
 macro_rules! json_ffi_binary {
-    ($name:ident, $input1_type:ty, $input2_type:ty, | $arg1:ident, $arg2:ident | $body:expr_2021) => {
-        #[no_mangle]
+    ($(#[$meta:meta])* $name:ident, $input1_type:ty, $input2_type:ty, | $arg1:ident, $arg2:ident | $body:expr_2021) => {
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
 
         pub extern "C" fn $name(
             input1_json : *const std::ffi::c_char,
             input2_json : *const std::ffi::c_char,
         ) -> *mut std::ffi::c_char {
 
+            let _call = $crate::ffi_call!();
+
             let input1 : Option<$input1_type> =
                 $crate::ffi_apis::common::from_json_string(input1_json);
 
-            let input2 : Option<$input2_type> =
-                $crate::ffi_apis::common::from_json_string(input2_json);
+            let input2 : Option<$input2_type> = input1
+                .as_ref()
+                .and_then(|_| $crate::ffi_apis::common::from_json_string(input2_json));
 
             if let (Some($arg1), Some($arg2)) = (input1, input2) {
 
@@ -57,49 +86,46 @@ macro_rules! json_ffi_binary {
 }
 
 #[macro_export]
-/// Creates a FFI-compatible function that takes a raw pointer to a single argument,
-/// dereferences it, applies a body of logic, and returns a raw pointer to the result.
+/// Creates a FFI-compatible function that takes a handle to a single argument,
+/// applies a body of logic to a reference to it, and returns a handle to the result.
+///
+/// The result has the type of the argument unless a return type is given. Returns the null
+/// handle and sets the last error if the input handle is stale or refers to another type.
 
 macro_rules! handle_ffi_unary {
-    ($name:ident, $input_type:ty, | $arg:ident | $body:expr_2021) => {
-        #[no_mangle]
-
-        pub extern "C" fn $name(input : *const $input_type) -> *mut $crate::symbolic::core::Expr {
-
-            let $arg = unsafe {
-
-                &*input
-            };
-
-            let result = $body;
-
-            Box::into_raw(Box::new(result))
-        }
+    ($(#[$meta:meta])* $name:ident, $input_type:ty, | $arg:ident | $body:expr_2021) => {
+        $crate::handle_ffi_unary!($(#[$meta])* $name, $input_type, $input_type, | $arg | $body);
     };
     // Generic return type version
-    ($name:ident, $input_type:ty, $ret_type:ty, | $arg:ident | $body:expr_2021) => {
-        #[no_mangle]
+    ($(#[$meta:meta])* $name:ident, $input_type:ty, $ret_type:ty, | $arg:ident | $body:expr_2021) => {
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
 
-        pub extern "C" fn $name(input : *const $input_type) -> *mut $ret_type {
-
-            let $arg = unsafe {
-
-                &*input
-            };
+        pub extern "C" fn $name(
+            input : $crate::ffi_apis::handle::RssnHandle
+        ) -> $crate::ffi_apis::handle::RssnHandle {
 
-            let result = $body;
+            let _call = $crate::ffi_call!();
 
-            Box::into_raw(Box::new(result))
+            $crate::ffi_apis::handle::with(input, |$arg : &$input_type| -> $ret_type { $body })
+                .map_or(
+                    $crate::ffi_apis::handle::RssnHandle::NULL,
+                    $crate::ffi_apis::handle::insert,
+                )
         }
     };
 }
 
 #[macro_export]
-/// Creates a FFI-compatible function that takes raw pointers to two arguments,
-/// dereferences them, applies a body of logic, and returns a raw pointer to the result.
+/// Creates a FFI-compatible function that takes handles to two arguments,
+/// applies a body of logic to references to them, and returns a handle to the result.
+///
+/// Returns the null handle and sets the last error if an input handle is stale or refers to
+/// another type.
 
 macro_rules! handle_ffi_binary {
     (
+        $(#[$meta:meta])*
         $name:ident,
         $input1_type:ty,
         $input2_type:ty,
@@ -108,28 +134,27 @@ macro_rules! handle_ffi_binary {
         $arg2:ident |
         $body:expr_2021
     ) => {
-        #[no_mangle]
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
 
         pub extern "C" fn $name(
-            input1: *const $input1_type,
-            input2: *const $input2_type,
-        ) -> *mut $ret_type {
-
-            let $arg1 = unsafe {
-
-                &*input1
-            };
-
-            let $arg2 = unsafe {
-
-                &*input2
-            };
-
-            let result = $body;
-
-            Box::into_raw(Box::new(
-                result,
-            ))
+            input1: $crate::ffi_apis::handle::RssnHandle,
+            input2: $crate::ffi_apis::handle::RssnHandle,
+        ) -> $crate::ffi_apis::handle::RssnHandle {
+
+            let _call = $crate::ffi_call!();
+
+            $crate::ffi_apis::handle::with(input1, |$arg1 : &$input1_type| {
+
+                $crate::ffi_apis::handle::with(input2, |$arg2 : &$input2_type| -> $ret_type {
+                    $body
+                })
+            })
+            .flatten()
+            .map_or(
+                $crate::ffi_apis::handle::RssnHandle::NULL,
+                $crate::ffi_apis::handle::insert,
+            )
         }
     };
 }
@@ -137,15 +162,20 @@ macro_rules! handle_ffi_binary {
 #[macro_export]
 /// Creates a FFI-compatible function that takes a bincode buffer as input, deserializes it to a single argument,
 /// applies a body of logic, and returns the result as a bincode buffer.
+///
+/// Returns an empty buffer and sets the last error if the input cannot be decoded or the result cannot be encoded.
 
 macro_rules! bincode_ffi_unary {
-    ($name:ident, $input_type:ty, | $arg:ident | $body:expr_2021) => {
-        #[no_mangle]
+    ($(#[$meta:meta])* $name:ident, $input_type:ty, | $arg:ident | $body:expr_2021) => {
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
 
         pub extern "C" fn $name(
             input_buf : $crate::ffi_apis::common::BincodeBuffer
         ) -> $crate::ffi_apis::common::BincodeBuffer {
 
+            let _call = $crate::ffi_call!();
+
             let input : Option<$input_type> =
                 $crate::ffi_apis::common::from_bincode_buffer(&input_buf);
 
@@ -165,21 +195,27 @@ macro_rules! bincode_ffi_unary {
 #[macro_export]
 /// Creates a FFI-compatible function that takes two bincode buffers as input, deserializes them to two arguments,
 /// applies a body of logic, and returns the result as a bincode buffer.
+///
+/// Returns an empty buffer and sets the last error if an input cannot be decoded or the result cannot be encoded.
 
 macro_rules! bincode_ffi_binary {
-    ($name:ident, $input1_type:ty, $input2_type:ty, | $arg1:ident, $arg2:ident | $body:expr_2021) => {
-        #[no_mangle]
+    ($(#[$meta:meta])* $name:ident, $input1_type:ty, $input2_type:ty, | $arg1:ident, $arg2:ident | $body:expr_2021) => {
+        $(#[$meta])*
+        #[unsafe(no_mangle)]
 
         pub extern "C" fn $name(
             input1_buf : $crate::ffi_apis::common::BincodeBuffer,
             input2_buf : $crate::ffi_apis::common::BincodeBuffer,
         ) -> $crate::ffi_apis::common::BincodeBuffer {
 
+            let _call = $crate::ffi_call!();
+
             let input1 : Option<$input1_type> =
                 $crate::ffi_apis::common::from_bincode_buffer(&input1_buf);
 
-            let input2 : Option<$input2_type> =
-                $crate::ffi_apis::common::from_bincode_buffer(&input2_buf);
+            let input2 : Option<$input2_type> = input1
+                .as_ref()
+                .and_then(|_| $crate::ffi_apis::common::from_bincode_buffer(&input2_buf));
 
             if let (Some($arg1), Some($arg2)) = (input1, input2) {
 
@@ -193,3 +229,39 @@ macro_rules! bincode_ffi_binary {
         }
     };
 }
+
+#[macro_export]
+/// Creates a dispatcher [`Method`](crate::ffi_apis::dispatch::Method) that decodes its parameters,
+/// applies a body of logic and encodes the result, both in JSON and in `bincode_next`.
+///
+/// This is [`json_ffi_unary!`] and [`json_ffi_binary!`] for any number of parameters, with the
+/// documentation, parameter names and types recorded for introspection. The body evaluates to
+/// an `Option` of the result type and sets the last error when it returns None.
+
+macro_rules! rpc_method {
+    ($(#[doc = $doc:literal])* $name:literal, || -> $ret_type:ty $body:block) => {
+        $crate::rpc_method!($(#[doc = $doc])* $name, | | -> $ret_type $body)
+    };
+    ($(#[doc = $doc:literal])* $name:literal, | $($arg:ident : $input_type:ty),* | -> $ret_type:ty $body:block) => {
+        $crate::ffi_apis::dispatch::Method {
+            name : $name,
+            docs : &[$($doc),*],
+            params : &[$((stringify!($arg), stringify!($input_type))),*],
+            returns : stringify!($ret_type),
+            json : |params| {
+
+                $crate::ffi_apis::dispatch::call_json::<($($input_type,)*), $ret_type>(
+                    params,
+                    |($($arg,)*)| -> Option<$ret_type> { $body },
+                )
+            },
+            bincode : |params| {
+
+                $crate::ffi_apis::dispatch::call_bincode::<($($input_type,)*), $ret_type>(
+                    params,
+                    |($($arg,)*)| -> Option<$ret_type> { $body },
+                )
+            },
+        }
+    };
+}
@@ -0,0 +1,120 @@
+//! Bincode-based FFI API for the math constant module.
+//!
+//! This provides binary serialization for high-performance interop.
+
+use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::common::from_bincode_buffer;
+use crate::ffi_apis::common::to_bincode_buffer;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::math_constant_ffi::json::MathConstantRequest;
+use crate::math_constant::MathConstant;
+
+/// Returns a constant correctly rounded to a number of significant
+/// digits.
+///
+/// `request_buf` holds a `bincode_next` encoded `MathConstantRequest`,
+/// and the result the encoded digits as a string.
+/// Returns an empty buffer if the request cannot be decoded, the constant
+/// is unknown or the digits are not available.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_digits_bincode(
+    request_buf: BincodeBuffer
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        MathConstantRequest,
+    > = from_bincode_buffer(
+        &request_buf,
+    );
+
+    let digits = request.and_then(
+        |request| {
+
+            check(
+                crate::math_constant::lookup(
+                    &request.constant,
+                ),
+                FfiStatus::NotFound,
+            )
+            .and_then(|constant| {
+
+                check(
+                    crate::math_constant::digits(
+                        constant,
+                        request.digits,
+                    ),
+                    FfiStatus::InvalidArgument,
+                )
+            })
+        },
+    );
+
+    match digits {
+        | Some(digits) => {
+            to_bincode_buffer(&digits)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Returns a constant as a double-double.
+///
+/// `name_buf` holds a `bincode_next` encoded string, and the result the
+/// encoded `DoubleDouble`.
+/// Returns an empty buffer if the name cannot be decoded or names no
+/// constant.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_double_double_bincode(
+    name_buf: BincodeBuffer
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let name: Option<String> =
+        from_bincode_buffer(&name_buf);
+
+    let constant = name.and_then(|name| {
+
+        check(
+            crate::math_constant::lookup(
+                &name,
+            ),
+            FfiStatus::NotFound,
+        )
+    });
+
+    match constant {
+        | Some(constant) => {
+            to_bincode_buffer(
+                &constant
+                    .to_double_double(),
+            )
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Returns every constant as a `bincode_next` buffer.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_math_constants_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    to_bincode_buffer(
+        &MathConstant::ALL,
+    )
+}
@@ -0,0 +1,101 @@
+//! Handle-based FFI API for the math constant module.
+//!
+//! This provides traditional C-style functions returning strings and
+//! plain values.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::math_constant::DoubleDouble;
+use crate::math_constant::MathConstant;
+
+/// Looks up a constant by a C string naming it, recording a `NotFound`
+/// error if it is unknown.
+
+fn constant(
+    name: *const c_char
+) -> Option<MathConstant> {
+
+    unsafe {
+
+        c_str_to_str(name)
+    }
+    .and_then(|name| {
+
+        check(
+            crate::math_constant::lookup(
+                name,
+            ),
+            FfiStatus::NotFound,
+        )
+    })
+}
+
+/// Returns a constant correctly rounded to `digits` significant digits.
+///
+/// Returns null if the constant is unknown or the digits are not
+/// available.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_digits(
+    name: *const c_char,
+    digits: u32,
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    match constant(name).and_then(
+        |constant| {
+
+            check(
+                crate::math_constant::digits(
+                    constant, digits,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    ) {
+        | Some(digits) => {
+            to_c_string(digits)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the nearest `f64` to a constant, or NaN if it is unknown.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_f64(
+    name: *const c_char
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    constant(name).map_or(
+        f64::NAN,
+        MathConstant::to_f64,
+    )
+}
+
+/// Returns a constant as a double-double, or a pair of NaNs if it is
+/// unknown.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_double_double(
+    name: *const c_char
+) -> DoubleDouble {
+
+    let _call = ffi_call!();
+
+    constant(name).map_or(
+        DoubleDouble {
+            hi: f64::NAN,
+            lo: f64::NAN,
+        },
+        MathConstant::to_double_double,
+    )
+}
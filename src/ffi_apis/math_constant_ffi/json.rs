@@ -0,0 +1,128 @@
+//! JSON-based FFI API for the math constant module.
+//!
+//! This provides string-based serialization for easy language interop.
+
+use std::os::raw::c_char;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_json_string;
+use crate::ffi_apis::common::to_json_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::math_constant::MathConstant;
+
+/// A request for the digits of a constant.
+#[derive(
+    Debug, Clone, Serialize, Deserialize,
+)]
+
+pub struct MathConstantRequest {
+    /// The identifier, symbol or name of the constant (e.g. `"pi"`).
+    pub constant: String,
+    /// The number of significant digits.
+    pub digits: u32,
+}
+
+/// Returns a constant correctly rounded to a number of significant
+/// digits.
+///
+/// `request_json` is a JSON `MathConstantRequest` object, e.g.
+/// `{"constant": "pi", "digits": 30}`, and the result the digits as a
+/// JSON string.
+/// Returns null if the request cannot be parsed, the constant is unknown
+/// or the digits are not available.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_digits_json(
+    request_json: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        MathConstantRequest,
+    > = from_json_string(request_json);
+
+    let digits = request.and_then(
+        |request| {
+
+            check(
+                crate::math_constant::lookup(
+                    &request.constant,
+                ),
+                FfiStatus::NotFound,
+            )
+            .and_then(|constant| {
+
+                check(
+                    crate::math_constant::digits(
+                        constant,
+                        request.digits,
+                    ),
+                    FfiStatus::InvalidArgument,
+                )
+            })
+        },
+    );
+
+    match digits {
+        | Some(digits) => {
+            to_json_string(&digits)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns a constant as a double-double.
+///
+/// The result is a JSON `DoubleDouble` object.
+/// Returns null if `name` is not valid UTF-8 or names no constant.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_math_constant_double_double_json(
+    name: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let constant = unsafe {
+
+        c_str_to_str(name)
+    }
+    .and_then(|name| {
+
+        check(
+            crate::math_constant::lookup(
+                name,
+            ),
+            FfiStatus::NotFound,
+        )
+    });
+
+    match constant {
+        | Some(constant) => {
+            to_json_string(
+                &constant
+                    .to_double_double(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns every constant as a JSON array.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_math_constants_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    to_json_string(&MathConstant::ALL)
+}
@@ -0,0 +1,39 @@
+//! FFI APIs for the math constant module.
+//!
+//! This module provides three different FFI API versions:
+//! - **Handle-based**: Traditional C-style functions returning strings
+//!   and plain values
+//! - **JSON-based**: String serialization for easy language interop
+//! - **Bincode-based**: Binary serialization for high performance
+//!
+//! Constants are named by identifier (`"pi"`, `"euler_gamma"`, ...),
+//! symbol or name.
+//!
+//! # Examples
+//!
+//! ## Handle-based API (C/C++)
+//! ```c
+//! char* pi = rssn_math_constant_digits("pi", 50);
+//! DoubleDouble gamma = rssn_math_constant_double_double("euler_gamma");
+//! rssn_free_string(pi);
+//! ```
+//!
+//! ## JSON-based API (Python, JavaScript, etc.)
+//! ```c
+//! char* json = rssn_math_constant_digits_json(
+//!     "{\"constant\": \"apery\", \"digits\": 20}");
+//! // "1.2020569031595942854"
+//! rssn_free_string(json);
+//! ```
+
+/// Bincode-based FFI bindings for mathematical constants.
+pub mod bincode_api;
+/// Handle-based FFI bindings for mathematical constants.
+pub mod handle;
+/// JSON-based FFI bindings for mathematical constants using serialized data.
+pub mod json;
+
+// Re-export all functions for convenience
+pub use bincode_api::*;
+pub use handle::*;
+pub use json::*;
@@ -2,6 +2,9 @@
 //!
 //! This module provides a C-compatible foreign function interface (FFI) for interacting
 //! with the core data structures and functions of the `rssn-advanced` library.
+//!
+//! Failing functions return null, an empty buffer or `NaN` and record the
+//! reason in a thread-local last error; see [`error`].
 #![allow(unsafe_code)]
 #![allow(clippy::indexing_slicing)]
 #![allow(
@@ -20,7 +23,27 @@
 
 pub mod macros;
 
+/// FFI APIs for the astro module.
+pub mod astro_ffi;
+/// FFI APIs for the capability module.
+pub mod capability_ffi;
 /// Common FFI utilities.
 pub mod common;
 /// FFI APIs for the constants module.
 pub mod constant_ffi;
+/// The single-entry dispatcher over named methods.
+pub mod dispatch;
+/// The versioned envelope around `bincode_next` payloads.
+pub mod envelope;
+/// Structured FFI errors and the thread-local last error.
+pub mod error;
+/// The generational table of objects behind opaque handles.
+pub mod handle;
+/// FFI APIs for the math constant module.
+pub mod math_constant_ffi;
+/// FFI APIs for the particle module.
+pub mod particle_ffi;
+/// The serialized shape of the types in bincode payloads.
+pub mod schema;
+/// FFI APIs for the unit module.
+pub mod unit_ffi;
@@ -0,0 +1,76 @@
+//! Bincode-based FFI API for the particle module.
+//!
+//! This provides binary serialization for high-performance interop.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_bincode_buffer;
+use crate::ffi_apis::particle_ffi::handle::by_pdg_id;
+use crate::ffi_apis::particle_ffi::handle::lookup;
+use crate::particle;
+
+/// Returns every tabulated particle record, antiparticles excluded, as a `bincode_next` buffer.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particles_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    to_bincode_buffer(&particle::all())
+}
+
+/// Returns the record of the particle or antiparticle named by `key`, a
+/// name or a PDG ID in decimal, as a `bincode_next` buffer.
+///
+/// Returns an empty buffer if `key` is not valid UTF-8 or no such particle exists.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_bincode(
+    key: *const c_char
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(key)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_bincode_buffer(&record)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Returns the record of the particle or antiparticle with PDG ID
+/// `pdg_id` as a `bincode_next` buffer.
+///
+/// Returns an empty buffer if no such particle exists.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_by_pdg_id_bincode(
+    pdg_id: i32
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    match by_pdg_id(pdg_id) {
+        | Some(record) => {
+            to_bincode_buffer(&record)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
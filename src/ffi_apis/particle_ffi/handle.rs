@@ -0,0 +1,200 @@
+//! Handle-based FFI API for the particle module.
+//!
+//! This provides traditional C-style functions returning strings and
+//! plain values.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::to_c_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::at_index;
+use crate::ffi_apis::error::require;
+use crate::particle;
+use crate::particle::Particle;
+
+/// Returns the number of tabulated particles, antiparticles excluded.
+#[unsafe(no_mangle)]
+
+pub const extern "C" fn rssn_get_particle_count()
+-> usize {
+
+    particle::all().len()
+}
+
+/// Returns the name of the tabulated particle at `index` as a C string.
+///
+/// Returns null if `index` is out of range.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_name(
+    index: usize
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    match at_index(
+        particle::all(),
+        index,
+    ) {
+        | Some(particle) => {
+            to_c_string(
+                particle
+                    .name
+                    .to_string(),
+            )
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the mass in `MeV` of the particle with PDG ID `pdg_id`.
+///
+/// Returns NaN if no such particle exists or its mass is not quoted.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_mass(
+    pdg_id: i32
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    by_pdg_id(pdg_id)
+        .and_then(|p| {
+
+            quoted(&p, "mass", p.mass)
+        })
+        .map_or(f64::NAN, |mass| {
+
+            mass.value
+        })
+}
+
+/// Returns the full width in `MeV` of the particle with PDG ID `pdg_id`,
+/// derived from the mean life if only that is quoted.
+///
+/// Returns NaN if no such particle exists or it is stable.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_width(
+    pdg_id: i32
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    by_pdg_id(pdg_id)
+        .and_then(|p| {
+
+            quoted(
+                &p,
+                "width",
+                p.decay_width(),
+            )
+        })
+        .unwrap_or(f64::NAN)
+}
+
+/// Returns the mean life in seconds of the particle with PDG ID
+/// `pdg_id`, derived from the width if only that is quoted.
+///
+/// Returns NaN if no such particle exists or it is stable.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_mean_life(
+    pdg_id: i32
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    by_pdg_id(pdg_id)
+        .and_then(|p| {
+
+            quoted(
+                &p,
+                "mean life",
+                p.mean_life(),
+            )
+        })
+        .unwrap_or(f64::NAN)
+}
+
+/// Returns the electric charge, in units of the elementary charge, of the
+/// particle with PDG ID `pdg_id`.
+///
+/// Returns NaN if no such particle exists.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_charge(
+    pdg_id: i32
+) -> f64 {
+
+    let _call = ffi_call!();
+
+    by_pdg_id(pdg_id).map_or(
+        f64::NAN,
+        |p| p.charge(),
+    )
+}
+
+/// Looks up the particle with PDG ID `pdg_id`, recording a `NotFound`
+/// error if there is none.
+
+pub(crate) fn by_pdg_id(
+    pdg_id: i32
+) -> Option<Particle> {
+
+    require(
+        particle::by_pdg_id(pdg_id),
+        FfiStatus::NotFound,
+        || {
+
+            format!(
+                "no particle with PDG \
+                 ID {pdg_id}"
+            )
+        },
+    )
+}
+
+/// Looks up the particle or antiparticle with name or PDG ID `key`,
+/// recording a `NotFound` error if there is none.
+
+pub(crate) fn lookup(
+    key: &str
+) -> Option<Particle> {
+
+    require(
+        particle::lookup(key),
+        FfiStatus::NotFound,
+        || {
+
+            format!(
+                "no particle with name \
+                 or PDG ID `{key}`"
+            )
+        },
+    )
+}
+
+/// Returns `value`, recording a `NotFound` error naming the `property` of
+/// `particle` if it is not quoted.
+
+fn quoted<T>(
+    particle: &Particle,
+    property: &str,
+    value: Option<T>,
+) -> Option<T> {
+
+    require(
+        value,
+        FfiStatus::NotFound,
+        || {
+
+            format!(
+                "no {property} is \
+                 quoted for {}",
+                particle.name
+            )
+        },
+    )
+}
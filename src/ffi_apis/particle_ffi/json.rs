@@ -0,0 +1,73 @@
+//! JSON-based FFI API for the particle module.
+//!
+//! This provides string-based serialization for easy language interop.
+
+use std::os::raw::c_char;
+
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::to_json_string;
+use crate::ffi_apis::particle_ffi::handle::by_pdg_id;
+use crate::ffi_apis::particle_ffi::handle::lookup;
+use crate::particle;
+
+/// Returns every tabulated particle record, antiparticles excluded, as a
+/// JSON array.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particles_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    to_json_string(&particle::all())
+}
+
+/// Returns the record of the particle or antiparticle named by `key`, a
+/// name (e.g. `"K-"`) or a PDG ID in decimal (e.g. `"-321"`), as a JSON
+/// object.
+///
+/// Returns null if `key` is not valid UTF-8 or no such particle exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_json(
+    key: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let record = unsafe {
+
+        c_str_to_str(key)
+    }
+    .and_then(lookup);
+
+    match record {
+        | Some(record) => {
+            to_json_string(&record)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns the record of the particle or antiparticle with PDG ID
+/// `pdg_id` as a JSON object.
+///
+/// Returns null if no such particle exists.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_particle_by_pdg_id_json(
+    pdg_id: i32
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    match by_pdg_id(pdg_id) {
+        | Some(record) => {
+            to_json_string(&record)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
@@ -0,0 +1,37 @@
+//! FFI APIs for the particle module.
+//!
+//! This module provides three different FFI API versions:
+//! - **Handle-based**: Traditional C-style functions returning strings
+//!   and plain values
+//! - **JSON-based**: String serialization for easy language interop
+//! - **Bincode-based**: Binary serialization for high performance
+//!
+//! Particles are named by PDG ID or by name (`"pi+"`, `"p~"`, `"Z0"`,
+//! ...). Masses and widths are in `MeV` and mean lives in seconds.
+//!
+//! # Examples
+//!
+//! ## Handle-based API (C/C++)
+//! ```c
+//! double m_w = rssn_get_particle_mass(24);
+//! double tau_mu = rssn_get_particle_mean_life(-13);
+//! ```
+//!
+//! ## JSON-based API (Python, JavaScript, etc.)
+//! ```c
+//! char* json = rssn_get_particle_json("H0");
+//! // {"name":"H0",...,"width":{"value":3.7,"plus":1.9,"minus":1.4},...}
+//! rssn_free_string(json);
+//! ```
+
+/// Bincode-based FFI bindings for particle data.
+pub mod bincode_api;
+/// Handle-based FFI bindings for particle data.
+pub mod handle;
+/// JSON-based FFI bindings for particle data.
+pub mod json;
+
+// Re-export all functions for convenience
+pub use bincode_api::*;
+pub use handle::*;
+pub use json::*;
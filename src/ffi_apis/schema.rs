@@ -0,0 +1,856 @@
+//! The serialized shape of the types carried in bincode envelopes.
+//!
+//! The schema id in an [`envelope`](crate::ffi_apis::envelope) header is
+//! the 64-bit FNV-1a hash of the shape of the payload type, so it follows
+//! the encoded layout rather than the Rust name: renaming a type keeps the
+//! id, while adding, removing, reordering, renaming or retyping a field
+//! changes it. A shape is written as text in this grammar:
+//!
+//! | Type                              | Shape                          |
+//! |-----------------------------------|--------------------------------|
+//! | `bool`, `char`, integers, floats  | `bool`, `char`, `i32`, `f64`, … |
+//! | `usize`, `isize`                  | `u64`, `i64`                   |
+//! | `String`, `str`                   | `string`                       |
+//! | `()`                              | `()`                           |
+//! | `Option<T>`                       | `option<T>`                    |
+//! | `Vec<T>`, `[T]`, sets             | `seq<T>`                       |
+//! | `[T; N]`                          | `[T; N]`                       |
+//! | tuples                            | `(A, B)`                       |
+//! | maps                              | `map<K, V>`                    |
+//! | structs                           | `{name: T, other: U}`          |
+//! | enums                             | `enum{A, B(T), C{x: U}}`       |
+//!
+//! References and boxes have the shape of what they point to. Fields and
+//! variants are listed in declaration order under their serialized
+//! names. Types of `rssn` are opaque and written by name (e.g. `Expr`),
+//! as their layout is not this library's to describe.
+//!
+//! Library types implement [`Schema`] in this module, and
+//! `tests/ffi_schema_test.rs` checks each shape against what `serde`
+//! writes for the type, so the two cannot drift apart; a host-side mirror
+//! of a library type can implement it with [`impl_schema!`](crate::impl_schema).
+//!
+//! # Examples
+//!
+//! ```
+//! use rssn_advanced::constant::Measured;
+//! use rssn_advanced::ffi_apis::schema::shape;
+//!
+//! assert_eq!(
+//!     shape::<Option<Measured>>(),
+//!     "option<{value: f64, uncertainty: f64}>"
+//! );
+//! ```
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use rssn::symbolic::core::Expr;
+
+use crate::astro::AstroError;
+use crate::astro::time::LeapSecond;
+use crate::astro::time::TimeScale;
+use crate::astro::registry::AstroGroup;
+use crate::astro::registry::AstronomicalConstant;
+use crate::capability::Algorithm;
+use crate::capability::Build;
+use crate::capability::Capabilities;
+use crate::capability::FfiFunction;
+use crate::constant::CodataEdition;
+use crate::constant::ConstantGroup;
+use crate::constant::Measured;
+use crate::constant::PhysicalConstant;
+use crate::constant::edition::ConstantChange;
+use crate::constant::edition::EditionDiff;
+use crate::constant::identify::Candidate;
+use crate::constant::search::Match;
+use crate::constant::search::MatchKind;
+use crate::element::AtomicWeight;
+use crate::element::Element;
+use crate::element::pattern::Peak;
+use crate::ffi_apis::astro_ffi::json::TimeConversion;
+use crate::ffi_apis::constant_ffi::ConstantRecord;
+use crate::ffi_apis::constant_ffi::json::BuildInfo;
+use crate::ffi_apis::dispatch::MethodSchema;
+use crate::ffi_apis::dispatch::ParamSchema;
+use crate::ffi_apis::math_constant_ffi::json::MathConstantRequest;
+use crate::ffi_apis::unit_ffi::json::UnitConversion;
+use crate::math_constant::DoubleDouble;
+use crate::math_constant::MathConstant;
+use crate::math_constant::MathConstantError;
+use crate::particle::Estimate;
+use crate::particle::Particle;
+use crate::particle::ParticleKind;
+use crate::unit::Dimension;
+use crate::unit::EnergyUnit;
+use crate::unit::Quantity;
+use crate::unit::Unit;
+use crate::unit::UnitError;
+use crate::unit::UnitSystem;
+use crate::unit::buckingham::PiGroup;
+use crate::unit::buckingham::Scaling;
+use crate::unit::buckingham::Variable;
+use crate::unit::catalogue::UnitSymbol;
+
+/// A type whose serialized shape is known.
+
+pub trait Schema {
+    /// Appends the shape of the type to `out`.
+
+    fn write_shape(out: &mut String);
+}
+
+/// A function that appends a shape to a string.
+
+type WriteShape = fn(&mut String);
+
+/// Returns the shape of `T`.
+
+#[must_use]
+
+pub fn shape<T: Schema + ?Sized>()
+-> String {
+
+    let mut out = String::new();
+
+    T::write_shape(&mut out);
+
+    out
+}
+
+/// Appends `items` to `out` separated by commas, writing each with
+/// `write`.
+
+#[doc(hidden)]
+
+pub fn write_list<T>(
+    out: &mut String,
+    items: &[T],
+    write: impl Fn(&mut String, &T),
+) {
+
+    for (i, item) in items
+        .iter()
+        .enumerate()
+    {
+
+        if i > 0 {
+
+            out.push_str(", ");
+        }
+
+        write(out, item);
+    }
+}
+
+/// Appends the fields of a struct or struct variant, `{name: T, …}`, to
+/// `out`.
+
+#[doc(hidden)]
+
+pub fn write_fields(
+    out: &mut String,
+    fields: &[(&str, WriteShape)],
+) {
+
+    out.push('{');
+
+    write_list(
+        out,
+        fields,
+        |out, (name, write)| {
+
+            out.push_str(name);
+
+            out.push_str(": ");
+
+            write(out);
+        },
+    );
+
+    out.push('}');
+}
+
+/// Appends a tuple of shapes, `(A, B)`, to `out`.
+
+#[doc(hidden)]
+
+pub fn write_tuple(
+    out: &mut String,
+    elements: &[WriteShape],
+) {
+
+    out.push('(');
+
+    write_list(
+        out,
+        elements,
+        |out, write| write(out),
+    );
+
+    out.push(')');
+}
+
+/// Implements [`Schema`](crate::ffi_apis::schema::Schema) for a struct or
+/// an enum from its fields or variants in declaration order.
+///
+/// A struct is given as `Type { field: Type, … }`, a tuple struct as
+/// `Type(Type, …)` and an enum as `enum Type { Unit, Tuple(Type, …),
+/// Struct { field: Type, … }, … }`. Newtype structs are written as the
+/// type they wrap. A struct field serialized under another name is given
+/// as `field as "name": Type`.
+///
+/// # Examples
+///
+/// ```
+/// use rssn_advanced::ffi_apis::schema::shape;
+///
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// rssn_advanced::impl_schema!(Point { x: f64, y: f64 });
+///
+/// assert_eq!(
+///     shape::<Point>(),
+///     "{x: f64, y: f64}"
+/// );
+/// ```
+#[macro_export]
+
+macro_rules! impl_schema {
+    (@name $field:ident) => {
+        stringify!($field)
+    };
+    (@name $field:ident $rename:literal) => {
+        $rename
+    };
+    (enum $ty:ident { $($variant:ident $(($($tuple:ty),* $(,)?))? $({$($field:ident: $field_ty:ty),* $(,)?})?),* $(,)? }) => {
+        impl $crate::ffi_apis::schema::Schema for $ty {
+            fn write_shape(out: &mut String) {
+
+                let variants: &[(&str, fn(&mut String))] = &[$((
+                    stringify!($variant),
+                    |out: &mut String| {
+
+                        $(
+                            $crate::ffi_apis::schema::write_tuple(
+                                out,
+                                &[$(<$tuple as $crate::ffi_apis::schema::Schema>::write_shape),*],
+                            );
+                        )?
+                        $(
+                            $crate::ffi_apis::schema::write_fields(
+                                out,
+                                &[$((
+                                    stringify!($field),
+                                    <$field_ty as $crate::ffi_apis::schema::Schema>::write_shape,
+                                )),*],
+                            );
+                        )?
+                        let _ = out;
+                    },
+                )),*];
+
+                out.push_str("enum{");
+
+                $crate::ffi_apis::schema::write_list(out, variants, |out, (name, write)| {
+
+                    out.push_str(name);
+
+                    write(out);
+                });
+
+                out.push('}');
+            }
+        }
+    };
+    ($ty:ident { $($field:ident $(as $rename:literal)?: $field_ty:ty),* $(,)? }) => {
+        impl $crate::ffi_apis::schema::Schema for $ty {
+            fn write_shape(out: &mut String) {
+
+                $crate::ffi_apis::schema::write_fields(
+                    out,
+                    &[$((
+                        $crate::impl_schema!(@name $field $($rename)?),
+                        <$field_ty as $crate::ffi_apis::schema::Schema>::write_shape,
+                    )),*],
+                );
+            }
+        }
+    };
+    ($ty:ident($inner:ty)) => {
+        impl $crate::ffi_apis::schema::Schema for $ty {
+            fn write_shape(out: &mut String) {
+
+                <$inner as $crate::ffi_apis::schema::Schema>::write_shape(out);
+            }
+        }
+    };
+    ($ty:ident($($element:ty),+ $(,)?)) => {
+        impl $crate::ffi_apis::schema::Schema for $ty {
+            fn write_shape(out: &mut String) {
+
+                $crate::ffi_apis::schema::write_tuple(
+                    out,
+                    &[$(<$element as $crate::ffi_apis::schema::Schema>::write_shape),+],
+                );
+            }
+        }
+    };
+}
+
+/// Implements [`Schema`] for types written as a fixed word.
+
+macro_rules! word {
+    ($($ty:ty => $word:literal),* $(,)?) => {
+        $(
+            impl Schema for $ty {
+                fn write_shape(out: &mut String) {
+
+                    out.push_str($word);
+                }
+            }
+        )*
+    };
+}
+
+word!(
+    bool => "bool",
+    char => "char",
+    i8 => "i8",
+    i16 => "i16",
+    i32 => "i32",
+    i64 => "i64",
+    i128 => "i128",
+    isize => "i64",
+    u8 => "u8",
+    u16 => "u16",
+    u32 => "u32",
+    u64 => "u64",
+    u128 => "u128",
+    usize => "u64",
+    f32 => "f32",
+    f64 => "f64",
+    str => "string",
+    String => "string",
+    () => "()",
+    Expr => "Expr",
+);
+
+/// Implements [`Schema`] for pointer types as the type they point to.
+
+macro_rules! transparent {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<T: Schema + ?Sized> Schema for $ty {
+                fn write_shape(out: &mut String) {
+
+                    T::write_shape(out);
+                }
+            }
+        )*
+    };
+}
+
+transparent!(
+    &T,
+    &mut T,
+    Box<T>,
+    std::rc::Rc<T>,
+    std::sync::Arc<T>,
+);
+
+/// Implements [`Schema`] for collections of one element type.
+
+macro_rules! wrapper {
+    ($($word:literal: $($ty:ty),*);* $(;)?) => {
+        $($(
+            impl<T: Schema> Schema for $ty {
+                fn write_shape(out: &mut String) {
+
+                    out.push_str(concat!($word, "<"));
+
+                    T::write_shape(out);
+
+                    out.push('>');
+                }
+            }
+        )*)*
+    };
+}
+
+wrapper!(
+    "option": Option<T>;
+    "seq": Vec<T>, [T], BTreeSet<T>;
+);
+
+impl<K: Schema, V: Schema> Schema
+    for BTreeMap<K, V>
+{
+    fn write_shape(out: &mut String) {
+
+        out.push_str("map<");
+
+        K::write_shape(out);
+
+        out.push_str(", ");
+
+        V::write_shape(out);
+
+        out.push('>');
+    }
+}
+
+impl<T: Schema, const N: usize> Schema
+    for [T; N]
+{
+    fn write_shape(out: &mut String) {
+
+        out.push('[');
+
+        T::write_shape(out);
+
+        out.push_str("; ");
+
+        out.push_str(&N.to_string());
+
+        out.push(']');
+    }
+}
+
+impl<T: Schema, E: Schema> Schema
+    for Result<T, E>
+{
+    fn write_shape(out: &mut String) {
+
+        out.push_str("enum{Ok(");
+
+        T::write_shape(out);
+
+        out.push_str("), Err(");
+
+        E::write_shape(out);
+
+        out.push_str(")}");
+    }
+}
+
+/// Implements [`Schema`] for tuples of the given element types.
+
+macro_rules! tuple {
+    ($(($($element:ident),+)),* $(,)?) => {
+        $(
+            impl<$($element: Schema),+> Schema for ($($element,)+) {
+                fn write_shape(out: &mut String) {
+
+                    write_tuple(out, &[$($element::write_shape),+]);
+                }
+            }
+        )*
+    };
+}
+
+tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (
+        A, B, C, D, E, F, G, H
+    ),
+);
+
+impl_schema!(
+    enum AstroError {
+        UtcOutOfRange(f64),
+        UnknownTimeScale(String),
+    }
+);
+
+impl_schema!(
+    enum AstroGroup {
+        Nominal,
+        Defining,
+        BestEstimate,
+        Derived,
+    }
+);
+
+impl_schema!(
+    AstronomicalConstant {
+        id: str,
+        name: str,
+        symbol: str,
+        value: f64,
+        uncertainty: f64,
+        relative_uncertainty: f64,
+        unit: str,
+        exact: bool,
+        group: AstroGroup,
+    }
+);
+
+impl_schema!(LeapSecond {
+    year: i32,
+    month: u32,
+    mjd: f64,
+    tai_minus_utc: f64,
+});
+
+impl_schema!(
+    enum TimeScale {
+        Tai,
+        Tt,
+        Utc,
+        Tdb,
+    }
+);
+
+impl_schema!(TimeConversion {
+    mjd: f64,
+    from: TimeScale,
+    to: TimeScale,
+});
+
+impl_schema!(Algorithm {
+    module: String,
+    name: String,
+    signature: String,
+    summary: String,
+});
+
+impl_schema!(Build {
+    date: String,
+    commit_sha: String,
+    rustc_version: String,
+    target_triple: String,
+    system_info: String,
+    profile: String,
+    opt_level: String,
+    debug_assertions: bool,
+    panic: String,
+    features: Vec<String>,
+});
+
+impl_schema!(Capabilities {
+    package: String,
+    version: String,
+    rssn_version: String,
+    build: Build,
+    codata_edition: CodataEdition,
+    codata_editions: Vec<CodataEdition>,
+    algorithms: Vec<Algorithm>,
+    ffi_functions: Vec<FfiFunction>,
+});
+
+impl_schema!(FfiFunction {
+    module: String,
+    name: String,
+    signature: String,
+    summary: String,
+    is_unsafe: bool,
+});
+
+impl_schema!(Candidate {
+    expr: Expr,
+    value: f64,
+    relative_error: f64,
+    complexity: u32,
+});
+
+impl_schema!(
+    enum CodataEdition {
+        Codata2014,
+        Codata2018,
+        Codata2022,
+    }
+);
+
+impl_schema!(ConstantChange {
+    id: str,
+    name: str,
+    unit: str,
+    old_value: f64,
+    new_value: f64,
+    old_uncertainty: f64,
+    new_uncertainty: f64,
+    difference: f64,
+    relative_difference: f64,
+    sigma_shift: Option<f64>,
+});
+
+impl_schema!(
+    enum ConstantGroup {
+        Universal,
+        Electromagnetic,
+        AtomicNuclear,
+        PhysicoChemical,
+        Adopted,
+        NonSiUnits,
+        XRay,
+        Conversion,
+    }
+);
+
+impl_schema!(ConstantRecord {
+    id: String,
+    name: String,
+    symbol: String,
+    unit: String,
+    value: f64,
+    uncertainty: f64,
+    relative_uncertainty: f64,
+    flags: u32,
+});
+
+impl_schema!(EditionDiff {
+    from: CodataEdition,
+    to: CodataEdition,
+    changed: Vec<ConstantChange>,
+    added: Vec<&str>,
+    removed: Vec<&str>,
+});
+
+impl_schema!(Match {
+    constant: PhysicalConstant,
+    key: str,
+    kind: MatchKind,
+    score: f64,
+});
+
+impl_schema!(
+    enum MatchKind {
+        Exact,
+        Normalized,
+        Prefix,
+        Substring,
+        Fuzzy,
+    }
+);
+
+impl_schema!(Measured {
+    value: f64,
+    uncertainty: f64,
+});
+
+impl_schema!(PhysicalConstant {
+    id: str,
+    name: str,
+    symbol: str,
+    value: f64,
+    digits: u32,
+    uncertainty: f64,
+    relative_uncertainty: f64,
+    unit: str,
+    exact: bool,
+    truncated: bool,
+    group: ConstantGroup,
+    edition: CodataEdition,
+});
+
+impl_schema!(
+    enum AtomicWeight {
+        Value {
+            value: f64,
+            uncertainty: f64,
+        },
+        Interval {
+            lower: f64,
+            upper: f64,
+            conventional: f64,
+        },
+    }
+);
+
+impl_schema!(Element {
+    z: u8,
+    symbol: str,
+    name: str,
+    atomic_weight: Option<AtomicWeight>,
+    mass_number: u16,
+});
+
+impl_schema!(Peak {
+    mass_number: u32,
+    mass: f64,
+    abundance: f64,
+});
+
+impl_schema!(BuildInfo {
+    build_date: String,
+    commit_sha: String,
+    rustc_version: String,
+    cargo_target_triple: String,
+    system_info: String,
+});
+
+impl_schema!(
+    MathConstantRequest {
+        constant: String,
+        digits: u32,
+    }
+);
+
+impl_schema!(MethodSchema {
+    name: String,
+    summary: String,
+    params: Vec<ParamSchema>,
+    returns: String,
+});
+
+impl_schema!(ParamSchema {
+    name: String,
+    ty as "type": String,
+});
+
+impl_schema!(UnitConversion {
+    value: f64,
+    from: String,
+    to: String,
+});
+
+impl_schema!(DoubleDouble {
+    hi: f64,
+    lo: f64,
+});
+
+impl_schema!(
+    enum MathConstant {
+        Pi,
+        E,
+        Ln2,
+        EulerGamma,
+        Catalan,
+        Apery,
+        Glaisher,
+        Khinchin,
+        FeigenbaumDelta,
+    }
+);
+
+impl_schema!(
+    enum MathConstantError {
+        UnknownConstant(String),
+        TooManyDigits {
+            constant: MathConstant,
+            requested: u32,
+            available: u32,
+        },
+    }
+);
+
+impl_schema!(Estimate {
+    value: f64,
+    plus: f64,
+    minus: f64,
+});
+
+impl_schema!(Particle {
+    name: str,
+    antiparticle: str,
+    pdg_id: i32,
+    kind: ParticleKind,
+    three_charge: i8,
+    twice_spin: u8,
+    mass: Option<Estimate>,
+    width: Option<Estimate>,
+    lifetime: Option<Estimate>,
+});
+
+impl_schema!(
+    enum ParticleKind {
+        Lepton,
+        Quark,
+        GaugeBoson,
+        ScalarBoson,
+        Meson,
+        Baryon,
+    }
+);
+
+impl_schema!(Dimension([i8; 7]));
+
+impl_schema!(
+    enum EnergyUnit {
+        Joule,
+        ElectronVolt,
+        Hertz,
+        InverseMetre,
+        Kelvin,
+        Kilogram,
+        AtomicMassUnit,
+        Hartree,
+    }
+);
+
+impl_schema!(PiGroup {
+    factors: Vec<(String, i32)>,
+});
+
+impl_schema!(Quantity {
+    value: f64,
+    dimension: Dimension,
+});
+
+impl_schema!(Scaling {
+    target: String,
+    power: i32,
+    factors: Vec<(String, i32)>,
+    groups: Vec<PiGroup>,
+});
+
+impl_schema!(Unit {
+    scale: f64,
+    offset: f64,
+    dimension: Dimension,
+});
+
+impl_schema!(
+    enum UnitError {
+        Syntax {
+            notation: String,
+            message: String,
+        },
+        UnknownUnit(String),
+        Ambiguous(String),
+        Incompatible {
+            expected: Dimension,
+            found: Dimension,
+        },
+        NoUnit {
+            dimension: Dimension,
+            system: UnitSystem,
+        },
+        InvalidRoot {
+            dimension: Dimension,
+            index: i32,
+        },
+    }
+);
+
+impl_schema!(UnitSymbol {
+    symbol: str,
+    name: str,
+    unit: Unit,
+    prefixable: bool,
+});
+
+impl_schema!(
+    enum UnitSystem {
+        Si,
+        Atomic,
+        Natural,
+        Planck,
+        GaussianCgs,
+    }
+);
+
+impl_schema!(Variable {
+    name: String,
+    dimension: Dimension,
+});
@@ -0,0 +1,156 @@
+//! Bincode-based FFI API for the unit module.
+//!
+//! This provides binary serialization for high-performance interop.
+
+use crate::ffi_apis::common::BincodeBuffer;
+use crate::ffi_apis::common::from_bincode_buffer;
+use crate::ffi_apis::common::to_bincode_buffer;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+use crate::ffi_apis::unit_ffi::json::UnitConversion;
+
+/// Converts a value between units.
+///
+/// `request_buf` holds a `bincode_next` encoded `UnitConversion`, and the
+/// result the encoded `f64`.
+/// Returns an empty buffer if the request cannot be decoded or the
+/// conversion fails.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_unit_convert_bincode(
+    request_buf: BincodeBuffer
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        UnitConversion,
+    > = from_bincode_buffer(
+        &request_buf,
+    );
+
+    let value = request.and_then(
+        |request| {
+
+            check(
+                crate::unit::convert(
+                    request.value,
+                    &request.from,
+                    &request.to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match value {
+        | Some(value) => {
+            to_bincode_buffer(&value)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Converts a value between units, also across the energy equivalences
+/// of spectroscopy (e.g. from `"GHz"` to `"cm^-1"`).
+///
+/// `request_buf` holds a `bincode_next` encoded `UnitConversion`, and the
+/// result the encoded `f64`.
+/// Returns an empty buffer if the request cannot be decoded or the
+/// conversion fails.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_unit_convert_equivalent_bincode(
+    request_buf: BincodeBuffer
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        UnitConversion,
+    > = from_bincode_buffer(
+        &request_buf,
+    );
+
+    let value = request.and_then(
+        |request| {
+
+            check(
+                crate::unit::convert_equivalent(
+                    request.value,
+                    &request.from,
+                    &request.to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match value {
+        | Some(value) => {
+            to_bincode_buffer(&value)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Parses unit notation into its scale to SI units and its dimension.
+///
+/// `notation_buf` holds a `bincode_next` encoded string, and the result
+/// the encoded `Unit`.
+/// Returns an empty buffer if the notation cannot be decoded or parsed.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_unit_parse_bincode(
+    notation_buf: BincodeBuffer
+) -> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    let notation: Option<String> =
+        from_bincode_buffer(
+            &notation_buf,
+        );
+
+    let unit = notation.and_then(
+        |notation| {
+
+            check(
+                crate::unit::parse(
+                    &notation,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match unit {
+        | Some(unit) => {
+            to_bincode_buffer(&unit)
+        },
+        | None => {
+            BincodeBuffer::empty()
+        },
+    }
+}
+
+/// Returns every unit symbol understood by the parser as a `bincode_next` buffer.
+/// The caller must free the returned buffer using `rssn_free_bincode_buffer`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_units_bincode()
+-> BincodeBuffer {
+
+    let _call = ffi_call!();
+
+    to_bincode_buffer(
+        &crate::unit::catalogue::UNITS,
+    )
+}
@@ -0,0 +1,160 @@
+//! JSON-based FFI API for the unit module.
+//!
+//! This provides string-based serialization for easy language interop.
+
+use std::os::raw::c_char;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ffi_apis::common::c_str_to_str;
+use crate::ffi_apis::common::from_json_string;
+use crate::ffi_apis::common::to_json_string;
+use crate::ffi_apis::error::FfiStatus;
+use crate::ffi_apis::error::check;
+
+/// A request to convert a value between two units.
+#[derive(
+    Debug, Clone, Serialize, Deserialize,
+)]
+
+pub struct UnitConversion {
+    /// The value to convert.
+    pub value: f64,
+    /// The unit of `value` (e.g. `"eV"`).
+    pub from: String,
+    /// The unit to convert to (e.g. `"J"`).
+    pub to: String,
+}
+
+/// Converts a value between units.
+///
+/// `request_json` is a JSON `UnitConversion` object, e.g.
+/// `{"value": 1.0, "from": "eV", "to": "J"}`, and the result the
+/// converted value as a JSON number.
+/// Returns null if the request cannot be parsed or the conversion fails.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_unit_convert_json(
+    request_json: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        UnitConversion,
+    > = from_json_string(request_json);
+
+    let value = request.and_then(
+        |request| {
+
+            check(
+                crate::unit::convert(
+                    request.value,
+                    &request.from,
+                    &request.to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match value {
+        | Some(value) => {
+            to_json_string(&value)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Converts a value between units, also across the energy equivalences
+/// of spectroscopy (e.g. from `"GHz"` to `"cm^-1"`).
+///
+/// `request_json` is a JSON `UnitConversion` object, and the result the
+/// converted value as a JSON number.
+/// Returns null if the request cannot be parsed or the conversion fails.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_unit_convert_equivalent_json(
+    request_json: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let request: Option<
+        UnitConversion,
+    > = from_json_string(request_json);
+
+    let value = request.and_then(
+        |request| {
+
+            check(
+                crate::unit::convert_equivalent(
+                    request.value,
+                    &request.from,
+                    &request.to,
+                ),
+                FfiStatus::InvalidArgument,
+            )
+        },
+    );
+
+    match value {
+        | Some(value) => {
+            to_json_string(&value)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Parses unit notation (e.g. `"J Hz⁻¹"` or `"kg m^2/s^2"`) into its
+/// scale to SI units and its dimension.
+///
+/// The result is a JSON `Unit` object.
+/// Returns null if `notation` is not valid UTF-8 or cannot be parsed.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_unit_parse_json(
+    notation: *const c_char
+) -> *mut c_char {
+
+    let _call = ffi_call!();
+
+    let unit = unsafe {
+
+        c_str_to_str(notation)
+    }
+    .and_then(|notation| {
+
+        check(
+            crate::unit::parse(
+                notation,
+            ),
+            FfiStatus::InvalidArgument,
+        )
+    });
+
+    match unit {
+        | Some(unit) => {
+            to_json_string(&unit)
+        },
+        | None => std::ptr::null_mut(),
+    }
+}
+
+/// Returns every unit symbol understood by the parser as a JSON array.
+/// The caller must free the returned string using `rssn_free_string`.
+#[unsafe(no_mangle)]
+
+pub extern "C" fn rssn_get_units_json()
+-> *mut c_char {
+
+    let _call = ffi_call!();
+
+    to_json_string(
+        &crate::unit::catalogue::UNITS,
+    )
+}
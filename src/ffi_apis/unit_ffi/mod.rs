@@ -0,0 +1,28 @@
+//! FFI APIs for the unit module.
+//!
+//! This module provides two FFI API versions:
+//! - **JSON-based**: String serialization for easy language interop
+//! - **Bincode-based**: Binary serialization for high performance
+//!
+//! A rejected conversion or parse (unknown unit, incompatible dimensions,
+//! ...) returns null or an empty buffer and records the `UnitError` as
+//! the last error.
+//!
+//! # Examples
+//!
+//! ## JSON-based API (Python, JavaScript, etc.)
+//! ```c
+//! char* json = rssn_unit_convert_json(
+//!     "{\"value\": 1.0, \"from\": \"kWh\", \"to\": \"J\"}");
+//! // 3600000.0
+//! rssn_free_string(json);
+//! ```
+
+/// Bincode-based FFI bindings for units.
+pub mod bincode_api;
+/// JSON-based FFI bindings for units using serialized data.
+pub mod json;
+
+// Re-export all functions for convenience
+pub use bincode_api::*;
+pub use json::*;
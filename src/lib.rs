@@ -57,8 +57,21 @@
     clippy::empty_line_after_doc_comments
 )]
 
+/// Astronomical constants and time scales.
+pub mod astro;
+/// Build and capability introspection.
+pub mod capability;
 /// System and physical constants.
 pub mod constant;
+/// Chemical elements, isotopes and molecular formulas.
+pub mod element;
+/// Mathematical constants to arbitrary precision.
+pub mod math_constant;
+/// Particle data from the PDG summary tables.
+pub mod particle;
+/// Physical dimensions, units and dimensioned quantities.
+pub mod unit;
+
 /// FFI APIs for the 'rssn-advanced' Library.
 #[cfg(feature = "ffi_api")]
 pub mod ffi_apis;
@@ -0,0 +1,1020 @@
+//! Fixed-point evaluation of the mathematical constants.
+//!
+//! A value `x` at precision `bits` is the integer `X ≈ x · 2^bits`. Every
+//! function here works internally with [`GUARD`] extra bits, so that the
+//! result is within a few units of the last place.
+
+use num_bigint::BigInt;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::Zero;
+
+/// The extra bits carried by the internal computations.
+
+pub(super) const GUARD: u64 = 48;
+
+/// Returns one at precision `bits`.
+
+fn one(bits: u64) -> BigInt {
+
+    BigInt::one() << bits
+}
+
+/// Multiplies two fixed-point values.
+
+fn mul(
+    a: &BigInt,
+    b: &BigInt,
+    bits: u64,
+) -> BigInt {
+
+    (a * b) >> bits
+}
+
+/// Divides two fixed-point values.
+
+fn div(
+    a: &BigInt,
+    b: &BigInt,
+    bits: u64,
+) -> BigInt {
+
+    (a << bits) / b
+}
+
+/// Returns the fixed-point square root of a fixed-point value.
+
+fn sqrt(
+    a: &BigInt,
+    bits: u64,
+) -> BigInt {
+
+    (a << bits).sqrt()
+}
+
+/// The factors of one term of a hypergeometric-type series.
+///
+/// The series is `Σₙ a(n)/b(n) · p(0)⋯p(n) / (q(0)⋯q(n))`.
+
+struct Term {
+    a: BigInt,
+    b: BigInt,
+    p: BigInt,
+    q: BigInt,
+}
+
+/// The partial products of a range of terms.
+
+struct Split {
+    p: BigInt,
+    q: BigInt,
+    b: BigInt,
+    t: BigInt,
+}
+
+/// Sums the terms `n1..n2` by binary splitting.
+
+fn split(
+    n1: u64,
+    n2: u64,
+    term: &dyn Fn(u64) -> Term,
+) -> Split {
+
+    let count = n2.saturating_sub(n1);
+
+    if count <= 1 {
+
+        let Term {
+            a,
+            b,
+            p,
+            q,
+        } = term(n1);
+
+        return Split {
+            t: a * &p,
+            p,
+            q,
+            b,
+        };
+    }
+
+    let middle =
+        n1.saturating_add(count / 2);
+
+    let left = split(n1, middle, term);
+
+    let right = split(middle, n2, term);
+
+    Split {
+        t: &right.b
+            * &right.q
+            * &left.t
+            + &left.b
+                * &left.p
+                * &right.t,
+        p: left.p * right.p,
+        q: left.q * right.q,
+        b: left.b * right.b,
+    }
+}
+
+/// Returns the sum of the first `terms` terms of a series at precision
+/// `bits`.
+
+fn series(
+    terms: u64,
+    bits: u64,
+    term: &dyn Fn(u64) -> Term,
+) -> BigInt {
+
+    let Split {
+        q,
+        b,
+        t,
+        ..
+    } = split(
+        0,
+        terms.max(1),
+        term,
+    );
+
+    (t << bits) / (b * q)
+}
+
+/// Returns the number of terms needed by a series gaining at least
+/// `bits_per_term` bits per term to reach precision `bits`.
+
+const fn terms(
+    bits: u64,
+    bits_per_term: u64,
+) -> u64 {
+
+    bits.saturating_add(16)
+        .div_ceil(bits_per_term)
+        .saturating_add(2)
+}
+
+/// Returns the integer `n` as a big integer.
+
+fn int(n: u64) -> BigInt {
+
+    BigInt::from(n)
+}
+
+/// Returns the odd integer `2n + 1`.
+
+fn odd(n: u64) -> BigInt {
+
+    int(n) * 2 + 1
+}
+
+/// Returns `atanh(1/x)` at precision `bits`, summing
+/// `Σ 1/((2n+1) x^(2n+1))`.
+
+fn atanh_inverse(
+    x: u64,
+    bits: u64,
+) -> BigInt {
+
+    let x_squared = int(x) * int(x);
+
+    // Each term gains 2 log₂ x bits.
+    let per_term = u64::from(x.ilog2())
+        .saturating_mul(2)
+        .max(1);
+
+    series(
+        terms(bits, per_term),
+        bits,
+        &|n| {
+
+            Term {
+                a: BigInt::one(),
+                b: odd(n),
+                p: BigInt::one(),
+                q: if n == 0 {
+
+                    int(x)
+                } else {
+
+                    x_squared.clone()
+                },
+            }
+        },
+    )
+}
+
+/// Returns π at precision `bits`, by the arithmetic-geometric mean
+/// iteration of Gauss, Brent and Salamin.
+
+pub(super) fn pi(bits: u64) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    let mut a = one(p);
+
+    let mut b =
+        sqrt(&(one(p) >> 1u32), p);
+
+    let mut t = one(p) >> 2u32;
+
+    let mut weight = BigInt::one();
+
+    while (&a - &b).abs()
+        > BigInt::from(4)
+    {
+
+        let next = (&a + &b) >> 1u32;
+
+        b = (&a * &b).sqrt();
+
+        let difference = &a - &next;
+
+        t -= &weight
+            * mul(
+                &difference,
+                &difference,
+                p,
+            );
+
+        a = next;
+
+        weight <<= 1u32;
+    }
+
+    let sum = &a + &b;
+
+    div(
+        &(mul(&sum, &sum, p) >> 2u32),
+        &t,
+        p,
+    ) >> GUARD
+}
+
+/// Returns `e` at precision `bits`, by binary splitting of `Σ 1/n!`.
+
+pub(super) fn e(bits: u64) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    // Sum until n! exceeds 2^p.
+    let mut count: u64 = 1;
+
+    let mut factorial = BigInt::one();
+
+    while factorial.bits()
+        < p.saturating_add(8)
+    {
+
+        count = count.saturating_add(1);
+
+        factorial *= count;
+    }
+
+    series(count, p, &|n| {
+
+        Term {
+            a: BigInt::one(),
+            b: BigInt::one(),
+            p: BigInt::one(),
+            q: if n == 0 {
+
+                BigInt::one()
+            } else {
+
+                int(n)
+            },
+        }
+    }) >> GUARD
+}
+
+/// Returns ln 2 at precision `bits`, from
+/// `ln 2 = 18 atanh(1/26) − 2 atanh(1/4801) + 8 atanh(1/8749)`.
+
+pub(super) fn ln2(bits: u64) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    (atanh_inverse(26, p) * 18
+        - atanh_inverse(4801, p) * 2
+        + atanh_inverse(8749, p) * 8)
+        >> GUARD
+}
+
+/// Returns Euler's constant γ at precision `bits`, by the Brent-McMillan
+/// algorithm.
+///
+/// With `n = 2^m`, `γ = U/V − ln n` up to `π e^(−4n)`, where
+/// `U = Σ (nᵏ/k!)² H_k` and `V = Σ (nᵏ/k!)²`.
+
+pub(super) fn euler_gamma(
+    bits: u64
+) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    // e^(−4n) < 2^(−p) for 4n log₂e > p.
+    let mut exponent: u64 = 0;
+
+    while (int(4) << exponent)
+        * int(144)
+        <= int(p.saturating_add(8))
+            * int(100)
+    {
+
+        exponent =
+            exponent.saturating_add(1);
+    }
+
+    let n_squared = BigInt::one()
+        << exponent.saturating_mul(2);
+
+    let ln_n = ln2(p) * int(exponent);
+
+    let mut weighted = -ln_n;
+
+    let mut weight = one(p);
+
+    let mut numerator =
+        weighted.clone();
+
+    let mut denominator =
+        weight.clone();
+
+    let mut k: u64 = 0;
+
+    while !(weighted.is_zero()
+        && weight.is_zero())
+    {
+
+        k = k.saturating_add(1);
+
+        weight = &weight * &n_squared
+            / int(k.saturating_mul(k));
+
+        weighted = (&weighted
+            * &n_squared
+            / int(k)
+            + &weight)
+            / int(k);
+
+        numerator += &weighted;
+
+        denominator += &weight;
+    }
+
+    div(
+        &numerator,
+        &denominator,
+        p,
+    ) >> GUARD
+}
+
+/// Returns Catalan's constant `G` at precision `bits`, from Ramanujan's
+/// series
+/// `G = π/8 · ln(2 + √3) + 3/8 · Σ (n!)² / ((2n)! (2n+1)²)`
+/// with `ln(2 + √3) = 2/√3 · Σ 1/((2n+1) 3ⁿ)`.
+
+pub(super) fn catalan(
+    bits: u64
+) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    let ramanujan = series(
+        terms(p, 2),
+        p,
+        &|n| {
+
+            let (
+                numerator,
+                denominator,
+            ) = if n == 0 {
+
+                (int(1), int(1))
+            } else {
+
+                (
+                    int(n),
+                    int(n) * 4 - 2,
+                )
+            };
+
+            Term {
+                a: BigInt::one(),
+                b: odd(n).pow(2),
+                p: numerator,
+                q: denominator,
+            }
+        },
+    );
+
+    let log_series = series(
+        terms(p, 1),
+        p,
+        &|n| {
+
+            Term {
+                a: BigInt::one(),
+                b: odd(n),
+                p: BigInt::one(),
+                q: if n == 0 {
+
+                    BigInt::one()
+                } else {
+
+                    int(3)
+                },
+            }
+        },
+    );
+
+    let sqrt_3 = sqrt(&(one(p) * 3), p);
+
+    // ln(2 + √3) = 2 S / √3
+    let log = div(
+        &(log_series << 1u32),
+        &sqrt_3,
+        p,
+    );
+
+    ((mul(&pi(p), &log, p) >> 3u32)
+        + ramanujan * 3 / 8)
+        >> GUARD
+}
+
+/// Returns Apéry's constant `ζ(3)` at precision `bits`, from the series
+/// of Amdeberhan and Zeilberger
+/// `ζ(3) = 1/64 Σ (−1)ⁿ (n!)¹⁰ (205n² + 250n + 77) / ((2n+1)!)⁵`.
+
+pub(super) fn apery(
+    bits: u64
+) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    series(
+        terms(p, 10),
+        p,
+        &|n| {
+
+            let k = int(n);
+
+            let (
+                numerator,
+                denominator,
+            ) = if n == 0 {
+
+                (int(1), int(1))
+            } else {
+
+                (
+                    -k.pow(10),
+                    (&k * odd(n))
+                        .pow(5)
+                        * 32,
+                )
+            };
+
+            Term {
+                a: &k * &k * 205
+                    + &k * 250
+                    + 77,
+                b: BigInt::one(),
+                p: numerator,
+                q: denominator,
+            }
+        },
+    ) >> 6u32
+        >> GUARD
+}
+
+/// Returns the tangent numbers `T₁, ..., T_count` (1, 2, 16, 272, ...),
+/// by the algorithm of Brent and Harvey.
+
+fn tangent_numbers(
+    count: usize
+) -> Vec<BigInt> {
+
+    let mut t: Vec<BigInt> =
+        Vec::with_capacity(count);
+
+    let mut factorial = BigInt::one();
+
+    for k in 0 .. count {
+
+        // T[k] starts as k!.
+        t.push(factorial.clone());
+
+        factorial *=
+            k.saturating_add(1);
+    }
+
+    for k in 1 .. count {
+
+        for j in k .. count {
+
+            let previous = j
+                .checked_sub(1)
+                .and_then(|i| t.get(i))
+                .cloned()
+                .unwrap_or_default();
+
+            if let Some(current) =
+                t.get_mut(j)
+            {
+
+                *current = previous
+                    * j.saturating_sub(k)
+                    + &*current
+                        * j.saturating_sub(k)
+                            .saturating_add(2);
+            }
+        }
+    }
+
+    t
+}
+
+/// Returns the Bernoulli numbers `B₂, B₄, ..., B_2count` as numerator and
+/// denominator pairs, from the tangent numbers:
+/// `B₂ₙ = (−1)ⁿ⁻¹ 2n Tₙ / (4ⁿ (4ⁿ − 1))`.
+
+fn bernoulli_numbers(
+    count: usize
+) -> Vec<(BigInt, BigInt)> {
+
+    tangent_numbers(count)
+        .into_iter()
+        .zip(1u64 ..)
+        .map(|(t, n)| {
+
+            let four_n = BigInt::one()
+                << n.saturating_mul(2);
+
+            let numerator: BigInt =
+                t * int(n) * 2u32;
+
+            let denominator =
+                &four_n * (&four_n - 1);
+
+            if n.is_multiple_of(2) {
+
+                (
+                    -numerator,
+                    denominator,
+                )
+            } else {
+
+                (
+                    numerator,
+                    denominator,
+                )
+            }
+        })
+        .collect()
+}
+
+/// Returns `exp(x)` for a fixed-point `x` of moderate size, by halving the
+/// argument and summing the Taylor series.
+
+fn exp(
+    x: &BigInt,
+    bits: u64,
+) -> BigInt {
+
+    const HALVINGS: u64 = 16;
+
+    let p =
+        bits.saturating_add(HALVINGS);
+
+    // At precision `p` the digits of `x` stand for `x / 2^HALVINGS`.
+    let y = x;
+
+    let mut sum = one(p);
+
+    let mut term = one(p);
+
+    let mut k: u64 = 0;
+
+    while !term.is_zero() {
+
+        k = k.saturating_add(1);
+
+        term =
+            mul(&term, y, p) / int(k);
+
+        sum += &term;
+    }
+
+    for _ in 0 .. HALVINGS {
+
+        sum = mul(&sum, &sum, p);
+    }
+
+    sum >> HALVINGS
+}
+
+/// Returns the primes up to `n`.
+
+fn primes(n: usize) -> Vec<usize> {
+
+    let mut composite = vec![
+            false;
+            n.saturating_add(1)
+        ];
+
+    let mut primes = Vec::new();
+
+    for i in 2 ..= n {
+
+        if composite
+            .get(i)
+            .copied()
+            .unwrap_or(true)
+        {
+
+            continue;
+        }
+
+        primes.push(i);
+
+        let mut multiple =
+            i.saturating_mul(i);
+
+        while multiple <= n {
+
+            if let Some(flag) =
+                composite
+                    .get_mut(multiple)
+            {
+
+                *flag = true;
+            }
+
+            multiple = multiple
+                .saturating_add(i);
+        }
+    }
+
+    primes
+}
+
+/// Returns the exponents of the prime factorization of `k`.
+
+fn factorize(
+    mut k: usize,
+    primes: &[usize],
+) -> Vec<(usize, u64)> {
+
+    let mut factors = Vec::new();
+
+    for &prime in primes {
+
+        if k < 2 {
+
+            break;
+        }
+
+        let mut exponent: u64 = 0;
+
+        while k.is_multiple_of(prime) {
+
+            k /= prime;
+
+            exponent = exponent
+                .saturating_add(1);
+        }
+
+        if exponent > 0 {
+
+            factors.push((
+                prime,
+                exponent,
+            ));
+        }
+    }
+
+    factors
+}
+
+/// Returns the natural logarithm of every prime up to `n`, with
+/// `ln q = ln(q − 1) + 2 atanh(1/(2q − 1))` and `ln(q − 1)` from the
+/// factorization of `q − 1`.
+
+fn prime_logarithms(
+    primes: &[usize],
+    bits: u64,
+) -> Vec<BigInt> {
+
+    let mut logs: Vec<BigInt> =
+        Vec::with_capacity(
+            primes.len(),
+        );
+
+    for &prime in primes {
+
+        let log = if prime == 2 {
+
+            ln2(bits)
+        } else {
+
+            let below = factorize(
+                prime.saturating_sub(1),
+                primes,
+            )
+            .into_iter()
+            .map(|(factor, exponent)| {
+
+                primes
+                    .iter()
+                    .position(|&q| q == factor)
+                    .and_then(|i| logs.get(i))
+                    .map_or_else(
+                        BigInt::zero,
+                        |log| log * int(exponent),
+                    )
+            })
+            .fold(BigInt::zero(), |sum, log| {
+
+                sum + log
+            });
+
+            let x =
+                u64::try_from(prime)
+                    .unwrap_or(u64::MAX)
+                    .saturating_mul(2)
+                    .saturating_sub(1);
+
+            below
+                + (atanh_inverse(
+                    x, bits,
+                ) << 1u32)
+        };
+
+        logs.push(log);
+    }
+
+    logs
+}
+
+/// Returns the Glaisher-Kinkelin constant `A` at precision `bits`.
+///
+/// The Euler-Maclaurin expansion of `Σ k ln k` gives
+///
+/// ```text
+/// ln A = Σₖ₌₁ⁿ k ln k − (n²/2 + n/2 + 1/12) ln n + n²/4
+///        + Σⱼ≥₂ B₂ⱼ / ((2j)(2j−1)(2j−2) n^(2j−2)),
+/// ```
+///
+/// an asymptotic series whose error is below its first omitted term.
+
+pub(super) fn glaisher(
+    bits: u64
+) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    let n = usize::try_from(p / 8)
+        .unwrap_or(usize::MAX)
+        .saturating_add(10);
+
+    let primes = primes(n);
+
+    let logs =
+        prime_logarithms(&primes, p);
+
+    let log_of = |k: usize| {
+
+        factorize(k, &primes)
+            .into_iter()
+            .map(|(factor, exponent)| {
+
+                primes
+                    .iter()
+                    .position(|&q| q == factor)
+                    .and_then(|i| logs.get(i))
+                    .map_or_else(
+                        BigInt::zero,
+                        |log| log * int(exponent),
+                    )
+            })
+            .fold(BigInt::zero(), |sum, log| {
+
+                sum + log
+            })
+    };
+
+    // Σ k ln k = Σ_q ln q Σ_k k v_q(k)
+    let mut weights = vec![
+        BigInt::zero(
+        );
+        primes.len()
+    ];
+
+    for k in 2 ..= n {
+
+        for (factor, exponent) in
+            factorize(k, &primes)
+        {
+
+            if let Some(weight) = primes
+                .iter()
+                .position(|&q| {
+
+                    q == factor
+                })
+                .and_then(|i| {
+
+                    weights.get_mut(i)
+                })
+            {
+
+                *weight +=
+                    BigInt::from(k)
+                        * int(exponent);
+            }
+        }
+    }
+
+    let sum = logs
+        .iter()
+        .zip(&weights)
+        .fold(
+            BigInt::zero(),
+            |sum, (log, weight)| {
+
+                sum + log * weight
+            },
+        );
+
+    let n_big = BigInt::from(n);
+
+    let n_squared = &n_big * &n_big;
+
+    let mut log_a = sum
+        - log_of(n)
+            * (&n_squared * 6
+                + &n_big * 6
+                + 1)
+            / 12
+        + ((one(p) * &n_squared)
+            >> 2u32);
+
+    // The terms of the expansion decrease until j ≈ πn; stop once they
+    // fall below the precision.
+    let mut count: usize = 1;
+
+    let mut log2_term = 0.0;
+
+    let log2_n = u32::try_from(n)
+        .map_or(32.0, |n| {
+
+            f64::from(n).log2()
+        });
+
+    let limit = -f64::from(
+        u32::try_from(p)
+            .unwrap_or(u32::MAX),
+    ) - 8.0;
+
+    loop {
+
+        if log2_term <= limit {
+
+            break;
+        }
+
+        count = count.saturating_add(1);
+
+        let j = f64::from(
+            u32::try_from(count)
+                .unwrap_or(u32::MAX),
+        );
+
+        // |B₂ⱼ| < 2 (2j)! / (2π)^(2j) and Stirling's formula bound the
+        // term by (j/(πen))^(2j) · 2n².
+        log2_term = (2.0 * j).mul_add(
+            (j / (std::f64::consts::PI
+                * std::f64::consts::E))
+                .log2()
+                - log2_n,
+            2.0f64.mul_add(log2_n, 1.0),
+        );
+    }
+
+    let bernoulli =
+        bernoulli_numbers(count);
+
+    for (j, (numerator, denominator)) in
+        (1u64 ..)
+            .zip(&bernoulli)
+            .skip(1)
+    {
+
+        let two_j = j.saturating_mul(2);
+
+        let scale = int(two_j)
+            * int(
+                two_j.saturating_sub(1)
+            )
+            * int(
+                two_j.saturating_sub(2)
+            )
+            * n_big.pow(
+                u32::try_from(
+                    j.saturating_mul(2)
+                        .saturating_sub(
+                            2,
+                        ),
+                )
+                .unwrap_or(u32::MAX),
+            );
+
+        log_a += (numerator << p)
+            / (denominator * scale);
+    }
+
+    exp(&log_a, p) >> GUARD
+}
+
+/// Returns Khinchin's constant `K₀` at precision `bits`, from
+/// `ln K₀ · ln 2 = Σₙ≥₁ (ζ(2n) − 1)/n · (1 − 1/2 + ... − 1/(2n−2) + 1/(2n−1))`
+/// with `ζ(2n) = n Tₙ π²ⁿ / ((4ⁿ − 1)(2n)!)` in terms of the tangent
+/// numbers.
+
+pub(super) fn khinchin(
+    bits: u64
+) -> BigInt {
+
+    let p = bits.saturating_add(GUARD);
+
+    // ζ(2n) − 1 < 2 · 4⁻ⁿ
+    let count = usize::try_from(p / 2)
+        .unwrap_or(usize::MAX)
+        .saturating_add(8);
+
+    let pi = pi(p);
+
+    let pi_squared = mul(&pi, &pi, p);
+
+    let mut pi_power = one(p);
+
+    let mut factorial = BigInt::one();
+
+    let mut alternating =
+        BigInt::zero();
+
+    let mut sum = BigInt::zero();
+
+    for (n, t) in (1u64 ..).zip(
+        tangent_numbers(count),
+    ) {
+
+        let two_n = n.saturating_mul(2);
+
+        pi_power = mul(
+            &pi_power,
+            &pi_squared,
+            p,
+        );
+
+        factorial *= int(two_n)
+            * int(
+                two_n.saturating_sub(1)
+            );
+
+        // 1 − 1/2 + ... + 1/(2n − 1)
+        if n > 1 {
+
+            alternating -= one(p)
+                / int(two_n
+                    .saturating_sub(2));
+        }
+
+        alternating += one(p)
+            / int(
+                two_n.saturating_sub(1)
+            );
+
+        let four_n =
+            BigInt::one() << two_n;
+
+        let zeta =
+            &pi_power * t * int(n)
+                / ((four_n - 1)
+                    * &factorial);
+
+        sum += mul(
+            &(zeta - one(p)),
+            &alternating,
+            p,
+        ) / int(n);
+    }
+
+    exp(
+        &div(&sum, &ln2(p), p),
+        p,
+    ) >> GUARD
+}
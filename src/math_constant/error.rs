@@ -0,0 +1,75 @@
+//! Errors raised when evaluating mathematical constants.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::MathConstant;
+
+/// An error raised when a mathematical constant cannot be evaluated.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum MathConstantError {
+    /// A name is not a known constant.
+    UnknownConstant(String),
+    /// More digits were requested than can be provided.
+    TooManyDigits {
+        /// The constant being evaluated.
+        constant: MathConstant,
+        /// The number of digits requested.
+        requested: u32,
+        /// The largest number of digits available.
+        available: u32,
+    },
+}
+
+impl fmt::Display
+    for MathConstantError
+{
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match self {
+            | Self::UnknownConstant(
+                name,
+            ) => {
+
+                write!(
+                    f,
+                    "unknown constant \
+                     `{name}`"
+                )
+            },
+            | Self::TooManyDigits {
+                constant,
+                requested,
+                available,
+            } => {
+
+                write!(
+                    f,
+                    "{requested} digits \
+                     of {constant} \
+                     requested, at most \
+                     {available} \
+                     available"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error
+    for MathConstantError
+{
+}
@@ -0,0 +1,744 @@
+//! Mathematical constants to arbitrary precision.
+//!
+//! [`MathConstant`] names the constants our heuristics rely on. Each is
+//! computed to any number of digits by an algorithm with a proven error
+//! bound: binary splitting of hypergeometric series (`e`, `ln 2`,
+//! Catalan's `G`, `ζ(3)`), the arithmetic-geometric mean (`π`), the
+//! Brent-McMillan algorithm (`γ`), and series in Bernoulli numbers and
+//! zeta values (Glaisher-Kinkelin `A`, Khinchin `K₀`). Results are
+//! correctly rounded and the fixed-point values behind them are cached,
+//! so that repeated requests at the same or lower precision are free.
+//!
+//! No algorithm with proven error bounds is known for Feigenbaum's `δ`,
+//! so its digits come from a table and are capped by
+//! [`MathConstant::max_digits`]. The series for `A` and `K₀` need tables
+//! that grow quadratically with the precision, so those two are capped
+//! too, at [`GLAISHER_MAX_DIGITS`] and [`KHINCHIN_MAX_DIGITS`].
+//!
+//! [`MathConstant::to_f64`] and [`MathConstant::to_double_double`] are
+//! constant shortcuts for the common precisions.
+//!
+//! ```
+//! 
+//! use rssn_advanced::math_constant;
+//! use rssn_advanced::math_constant::MathConstant;
+//!
+//! assert_eq!(
+//!     math_constant::digits(MathConstant::Pi, 10).unwrap(),
+//!     "3.141592654"
+//! );
+//!
+//! assert_eq!(
+//!     math_constant::digits(MathConstant::EulerGamma, 5).unwrap(),
+//!     "0.57722"
+//! );
+//! ```
+
+/// Fixed-point evaluation algorithms.
+mod compute;
+/// Errors raised when evaluating mathematical constants.
+pub mod error;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::LazyLock;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+pub use error::MathConstantError;
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::One;
+use num_traits::Pow;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The largest number of significant digits that may be requested.
+
+pub const MAX_DIGITS: u32 = 100_000;
+
+/// The largest number of significant digits of the Glaisher-Kinkelin
+/// constant that may be requested.
+
+pub const GLAISHER_MAX_DIGITS: u32 =
+    1_000;
+
+/// The largest number of significant digits of Khinchin's constant that
+/// may be requested.
+
+pub const KHINCHIN_MAX_DIGITS: u32 =
+    500;
+
+/// A mathematical constant.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum MathConstant {
+    /// The circle constant `π`.
+    Pi,
+    /// The base of the natural logarithm `e`.
+    E,
+    /// The natural logarithm of two.
+    Ln2,
+    /// The Euler-Mascheroni constant `γ`.
+    EulerGamma,
+    /// Catalan's constant `G = β(2)`.
+    Catalan,
+    /// Apéry's constant `ζ(3)`.
+    Apery,
+    /// The Glaisher-Kinkelin constant `A`.
+    Glaisher,
+    /// Khinchin's constant `K₀`.
+    Khinchin,
+    /// Feigenbaum's first constant `δ`.
+    FeigenbaumDelta,
+}
+
+/// A value `hi + lo` carried as an unevaluated sum of two `f64`s, with
+/// `|lo| ≤ ulp(hi)/2`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+#[repr(C)]
+
+pub struct DoubleDouble {
+    /// The leading part, the nearest `f64` to the value.
+    pub hi: f64,
+    /// The remainder.
+    pub lo: f64,
+}
+
+/// The verified digits of Feigenbaum's `δ`.
+///
+/// They were obtained by solving the Feigenbaum-Cvitanović functional
+/// equation by collocation, and agree with the published expansions. The
+/// last digit is uncertain by one.
+
+const FEIGENBAUM_DELTA_DIGITS: &str = "4.\
+     66920160910299067185320382046620161725818557747576863274565";
+
+impl MathConstant {
+    /// Every constant, in declaration order.
+
+    pub const ALL: [Self; 9] = [
+        Self::Pi,
+        Self::E,
+        Self::Ln2,
+        Self::EulerGamma,
+        Self::Catalan,
+        Self::Apery,
+        Self::Glaisher,
+        Self::Khinchin,
+        Self::FeigenbaumDelta,
+    ];
+
+    /// Returns the identifier of the constant (e.g. `"euler_gamma"`).
+    #[must_use]
+
+    pub const fn id(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Pi => "pi",
+            | Self::E => "e",
+            | Self::Ln2 => "ln2",
+            | Self::EulerGamma => {
+                "euler_gamma"
+            },
+            | Self::Catalan => {
+                "catalan"
+            },
+            | Self::Apery => "apery",
+            | Self::Glaisher => {
+                "glaisher"
+            },
+            | Self::Khinchin => {
+                "khinchin"
+            },
+            | Self::FeigenbaumDelta => {
+                "feigenbaum_delta"
+            },
+        }
+    }
+
+    /// Returns the usual symbol of the constant (e.g. `"γ"`).
+    #[must_use]
+
+    pub const fn symbol(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Pi => "π",
+            | Self::E => "e",
+            | Self::Ln2 => "ln 2",
+            | Self::EulerGamma => "γ",
+            | Self::Catalan => "G",
+            | Self::Apery => "ζ(3)",
+            | Self::Glaisher => "A",
+            | Self::Khinchin => "K₀",
+            | Self::FeigenbaumDelta => {
+                "δ"
+            },
+        }
+    }
+
+    /// Returns the name of the constant.
+    #[must_use]
+
+    pub const fn name(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Pi => "pi",
+            | Self::E => {
+                "Euler's number"
+            },
+            | Self::Ln2 => {
+                "natural logarithm of 2"
+            },
+            | Self::EulerGamma => {
+                "Euler-Mascheroni \
+                 constant"
+            },
+            | Self::Catalan => {
+                "Catalan's constant"
+            },
+            | Self::Apery => {
+                "Apéry's constant"
+            },
+            | Self::Glaisher => {
+                "Glaisher-Kinkelin \
+                 constant"
+            },
+            | Self::Khinchin => {
+                "Khinchin's constant"
+            },
+            | Self::FeigenbaumDelta => {
+                "Feigenbaum delta"
+            },
+        }
+    }
+
+    /// Looks up a constant by identifier, symbol or name, ignoring case.
+    #[must_use]
+
+    pub fn from_id(
+        key: &str
+    ) -> Option<Self> {
+
+        let key = key.trim();
+
+        Self::ALL
+            .into_iter()
+            .find(|constant| {
+
+                [
+                    constant.id(),
+                    constant.symbol(),
+                    constant.name(),
+                ]
+                .iter()
+                .any(|candidate| {
+
+                    candidate
+                        .eq_ignore_ascii_case(
+                            key,
+                        )
+                })
+            })
+    }
+
+    /// Returns the nearest `f64` to the constant.
+    #[must_use]
+
+    pub const fn to_f64(self) -> f64 {
+
+        self.to_double_double()
+            .hi
+    }
+
+    /// Returns the constant to about 32 significant digits.
+    #[must_use]
+
+    pub const fn to_double_double(
+        self
+    ) -> DoubleDouble {
+
+        let (hi, lo) = match self {
+            | Self::Pi => {
+                (
+                    std::f64::consts::PI,
+                    1.224_646_799_147_353_2e-16,
+                )
+            },
+            | Self::E => {
+                (
+                    std::f64::consts::E,
+                    1.445_646_891_729_250_2e-16,
+                )
+            },
+            | Self::Ln2 => {
+                (
+                    std::f64::consts::LN_2,
+                    2.319_046_813_846_299_6e-17,
+                )
+            },
+            | Self::EulerGamma => {
+                (
+                    0.577_215_664_901_532_9,
+                    -4.942_915_152_430_645e-18,
+                )
+            },
+            | Self::Catalan => {
+                (
+                    0.915_965_594_177_219,
+                    3.747_558_421_514_984e-18,
+                )
+            },
+            | Self::Apery => {
+                (
+                    1.202_056_903_159_594_2,
+                    4.875_891_010_379_532e-17,
+                )
+            },
+            | Self::Glaisher => {
+                (
+                    1.282_427_129_100_622_6,
+                    1.654_738_868_664_539_8e-17,
+                )
+            },
+            | Self::Khinchin => {
+                (
+                    2.685_452_001_065_306_2,
+                    2.074_616_521_706_293_2e-16,
+                )
+            },
+            | Self::FeigenbaumDelta => {
+                (
+                    4.669_201_609_102_99,
+                    2.472_930_282_230_313e-16,
+                )
+            },
+        };
+
+        DoubleDouble {
+            hi,
+            lo,
+        }
+    }
+
+    /// Returns the largest number of significant digits available, or
+    /// `None` when any number up to [`MAX_DIGITS`] can be computed.
+    #[must_use]
+
+    pub const fn max_digits(
+        self
+    ) -> Option<u32> {
+
+        match self {
+            | Self::FeigenbaumDelta => {
+
+                // One digit is uncertain and one more is needed to round.
+                #[allow(
+                    clippy::cast_possible_truncation
+                )]
+                Some(
+                    FEIGENBAUM_DELTA_DIGITS
+                        .len()
+                        .saturating_sub(3)
+                        as u32,
+                )
+            },
+            | Self::Glaisher => {
+                Some(
+                    GLAISHER_MAX_DIGITS,
+                )
+            },
+            | Self::Khinchin => {
+                Some(
+                    KHINCHIN_MAX_DIGITS,
+                )
+            },
+            | _ => None,
+        }
+    }
+
+    /// Returns whether the constant lies in `(0, 1)`.
+
+    const fn is_fraction(self) -> bool {
+
+        matches!(
+            self,
+            Self::Ln2
+                | Self::EulerGamma
+                | Self::Catalan
+        )
+    }
+}
+
+impl fmt::Display for MathConstant {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(self.name())
+    }
+}
+
+/// The most precise fixed-point value computed so far for each constant,
+/// with its precision in bits.
+
+static CACHE: LazyLock<
+    Mutex<
+        HashMap<
+            MathConstant,
+            (u64, BigInt),
+        >,
+    >,
+> = LazyLock::new(|| {
+
+    Mutex::new(HashMap::new())
+});
+
+/// Returns `X ≈ x · 2^bits` within a few units of the last place,
+/// reusing the cache when it holds at least `bits` bits.
+
+fn fixed(
+    constant: MathConstant,
+    bits: u64,
+) -> BigInt {
+
+    if let Some((cached_bits, value)) =
+        CACHE
+            .lock()
+            .unwrap_or_else(
+                PoisonError::into_inner,
+            )
+            .get(&constant)
+        && *cached_bits >= bits
+    {
+
+        return value
+            >> cached_bits
+                .saturating_sub(bits);
+    }
+
+    let value = match constant {
+        | MathConstant::Pi => {
+            compute::pi(bits)
+        },
+        | MathConstant::E => {
+            compute::e(bits)
+        },
+        | MathConstant::Ln2 => {
+            compute::ln2(bits)
+        },
+        | MathConstant::EulerGamma => {
+            compute::euler_gamma(bits)
+        },
+        | MathConstant::Catalan => {
+            compute::catalan(bits)
+        },
+        | MathConstant::Apery => {
+            compute::apery(bits)
+        },
+        | MathConstant::Glaisher => {
+            compute::glaisher(bits)
+        },
+        | MathConstant::Khinchin => {
+            compute::khinchin(bits)
+        },
+        | MathConstant::FeigenbaumDelta => {
+            tabulated(
+                FEIGENBAUM_DELTA_DIGITS,
+                bits,
+            )
+        },
+    };
+
+    let mut cache = CACHE
+        .lock()
+        .unwrap_or_else(
+            PoisonError::into_inner,
+        );
+
+    let entry = cache
+        .entry(constant)
+        .or_insert((0, BigInt::ZERO));
+
+    if entry.0 < bits {
+
+        *entry = (bits, value.clone());
+    }
+
+    drop(cache);
+
+    value
+}
+
+/// Converts a decimal string to fixed point, rounding down.
+
+fn tabulated(
+    digits: &str,
+    bits: u64,
+) -> BigInt {
+
+    let decimals = digits
+        .split_once('.')
+        .map_or(
+            0,
+            |(_, fraction)| {
+
+                fraction.len()
+            },
+        );
+
+    let mantissa: BigInt = digits
+        .replace('.', "")
+        .parse()
+        .unwrap_or_default();
+
+    (mantissa << bits)
+        / BigInt::from(10).pow(decimals)
+}
+
+/// Returns one unit in the last place of a decimal string, in fixed
+/// point and rounded up.
+
+fn tabulated_ulp(
+    digits: &str,
+    bits: u64,
+) -> BigInt {
+
+    let decimals = digits
+        .split_once('.')
+        .map_or(
+            0,
+            |(_, fraction)| {
+
+                fraction.len()
+            },
+        );
+
+    (BigInt::one() << bits)
+        / BigInt::from(10).pow(decimals)
+        + 1
+}
+
+/// Returns the constant correctly rounded to `digits` significant digits,
+/// as an integer `r` and a decimal exponent `k` with value `r / 10^k`.
+
+fn rounded(
+    constant: MathConstant,
+    digits: u32,
+) -> Result<
+    (BigInt, u32),
+    MathConstantError,
+> {
+
+    let available = constant
+        .max_digits()
+        .unwrap_or(MAX_DIGITS);
+
+    if digits == 0 || digits > available
+    {
+
+        return Err(
+            MathConstantError::TooManyDigits {
+                constant,
+                requested: digits,
+                available,
+            },
+        );
+    }
+
+    // Every constant lies in [0.1, 10).
+    let decimals =
+        if constant.is_fraction() {
+
+            digits
+        } else {
+
+            digits.saturating_sub(1)
+        };
+
+    let scale =
+        BigInt::from(10).pow(decimals);
+
+    // log₂ 10 < 10/3
+    let mut bits = u64::from(decimals)
+        .saturating_mul(10)
+        / 3
+        + 32;
+
+    // Ziv's strategy: raise the precision until both ends of the error
+    // interval round to the same decimal.
+    loop {
+
+        let value =
+            fixed(constant, bits);
+
+        let error = if constant
+            == MathConstant::FeigenbaumDelta
+        {
+
+            // One unit in the last tabulated place.
+            tabulated_ulp(
+                FEIGENBAUM_DELTA_DIGITS,
+                bits,
+            ) + 2
+        } else {
+
+            BigInt::from(16)
+        };
+
+        let half = BigInt::one()
+            << bits.saturating_sub(1);
+
+        let round = |v: BigInt| {
+
+            (v * &scale + &half) >> bits
+        };
+
+        let low =
+            round(&value - &error);
+
+        if low == round(value + error) {
+
+            return Ok((low, decimals));
+        }
+
+        if constant
+            == MathConstant::FeigenbaumDelta
+        {
+
+            return Err(
+                MathConstantError::TooManyDigits {
+                    constant,
+                    requested: digits,
+                    available: digits
+                        .saturating_sub(1),
+                },
+            );
+        }
+
+        bits = bits.saturating_add(
+            bits / 4 + 32,
+        );
+    }
+}
+
+/// Returns `constant` correctly rounded to `digits` significant digits,
+/// as a decimal string (e.g. `"3.1416"` or `"0.57722"`).
+///
+/// # Errors
+///
+/// Returns [`MathConstantError::TooManyDigits`] if `digits` is zero or
+/// exceeds the digits available for `constant`.
+
+pub fn digits(
+    constant: MathConstant,
+    digits: u32,
+) -> Result<String, MathConstantError> {
+
+    let (value, decimals) =
+        rounded(constant, digits)?;
+
+    let text = value.to_string();
+
+    let decimals =
+        usize::try_from(decimals)
+            .unwrap_or(usize::MAX);
+
+    Ok(
+        if constant.is_fraction() {
+
+            format!(
+                "0.{text:0>decimals$}"
+            )
+        } else {
+
+            let (integer, fraction) =
+                text.split_at(
+                    text.len()
+                        .saturating_sub(
+                            decimals,
+                        ),
+                );
+
+            if fraction.is_empty() {
+
+                integer.to_owned()
+            } else {
+
+                format!("{integer}.{fraction}")
+            }
+        },
+    )
+}
+
+/// Returns `constant` correctly rounded to `digits` significant digits,
+/// as an exact rational.
+///
+/// # Errors
+///
+/// Returns [`MathConstantError::TooManyDigits`] if `digits` is zero or
+/// exceeds the digits available for `constant`.
+
+pub fn approximate(
+    constant: MathConstant,
+    digits: u32,
+) -> Result<
+    BigRational,
+    MathConstantError,
+> {
+
+    let (value, decimals) =
+        rounded(constant, digits)?;
+
+    Ok(BigRational::new(
+        value,
+        BigInt::from(10).pow(decimals),
+    ))
+}
+
+/// Looks up a constant by identifier, symbol or name.
+///
+/// # Errors
+///
+/// Returns [`MathConstantError::UnknownConstant`] if `key` names no
+/// constant.
+
+pub fn lookup(
+    key: &str
+) -> Result<
+    MathConstant,
+    MathConstantError,
+> {
+
+    MathConstant::from_id(key).ok_or_else(
+        || {
+
+            MathConstantError::UnknownConstant(
+                key.to_owned(),
+            )
+        },
+    )
+}
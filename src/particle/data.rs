@@ -0,0 +1,124 @@
+//! The embedded particle table.
+//!
+//! Values are those of the 2024 edition of the Review of Particle Physics
+//! (S. Navas et al. (Particle Data Group), Phys. Rev. D 110, 030001).
+//! The electron and nucleon masses are the CODATA 2018 values the review
+//! adopts. Masses and widths are in `MeV` and mean lives in seconds. For
+//! each particle the table quotes whichever of the width and the mean
+//! life the summary tables give; the other follows from `Γτ = ħ`.
+
+use super::Estimate;
+use super::Particle;
+use super::ParticleKind;
+
+/// Expands a value column of `particles!`.
+
+macro_rules! estimate {
+    (-) => {
+
+        None
+    };
+    (($value:literal)) => {
+
+        Some(Estimate::exact(
+            $value,
+        ))
+    };
+    (
+        (
+            $value:literal,
+            $uncertainty:literal
+        )
+    ) => {
+
+        Some(Estimate::new(
+            $value,
+            $uncertainty,
+        ))
+    };
+    (
+        (
+            $value:literal, +
+            $plus:literal, -
+            $minus:literal
+        )
+    ) => {
+
+        Some(
+            Estimate::asymmetric(
+                $value,
+                $plus,
+                $minus,
+            ),
+        )
+    };
+}
+
+/// Declares the particle table.
+///
+/// Each row is `(name, antiparticle, PDG ID, kind, 3Q, 2J, mass, width,
+/// mean life)`, where a value is `(value)` when exact, `(value,
+/// uncertainty)`, `(value, +plus, -minus)` or `-` when not quoted.
+
+macro_rules! particles {
+    ($(($name:literal, $antiparticle:literal, $pdg_id:literal, $kind:ident, $three_charge:literal, $twice_spin:literal, $mass:tt, $width:tt, $lifetime:tt)),* $(,)?) => {
+        /// The tabulated particles, in order of their positive PDG IDs.
+        pub static PARTICLES: &[Particle] = &[
+            $(
+                Particle {
+                    name: $name,
+                    antiparticle: $antiparticle,
+                    pdg_id: $pdg_id,
+                    kind: ParticleKind::$kind,
+                    three_charge: $three_charge,
+                    twice_spin: $twice_spin,
+                    mass: estimate!($mass),
+                    width: estimate!($width),
+                    lifetime: estimate!($lifetime),
+                },
+            )*
+        ];
+    };
+}
+
+particles! {
+    ("t", "t~", 6, Quark, 2, 1, (172_570.0, 290.0), (1_420.0, +190.0, -150.0), -),
+    ("e-", "e+", 11, Lepton, -3, 1, (0.510_998_950_00, 0.000_000_000_15), -, -),
+    ("nu_e", "nu_e~", 12, Lepton, 0, 1, -, -, -),
+    ("mu-", "mu+", 13, Lepton, -3, 1, (105.658_375_5, 0.000_002_3), -, (2.196_981_1e-6, 0.000_002_2e-6)),
+    ("nu_mu", "nu_mu~", 14, Lepton, 0, 1, -, -, -),
+    ("tau-", "tau+", 15, Lepton, -3, 1, (1_776.93, 0.09), -, (290.3e-15, 0.5e-15)),
+    ("nu_tau", "nu_tau~", 16, Lepton, 0, 1, -, -, -),
+    ("g", "g", 21, GaugeBoson, 0, 2, (0.0), -, -),
+    ("gamma", "gamma", 22, GaugeBoson, 0, 2, (0.0), -, -),
+    ("Z0", "Z0", 23, GaugeBoson, 0, 2, (91_187.6, 2.1), (2_495.5, 2.3), -),
+    ("W+", "W-", 24, GaugeBoson, 3, 2, (80_369.2, 13.3), (2_085.0, 42.0), -),
+    ("H0", "H0", 25, ScalarBoson, 0, 0, (125_200.0, 110.0), (3.7, +1.9, -1.4), -),
+    ("pi0", "pi0", 111, Meson, 0, 0, (134.976_8, 0.000_5), -, (8.43e-17, 0.13e-17)),
+    ("rho(770)0", "rho(770)0", 113, Meson, 0, 2, (775.26, 0.23), (149.1, 0.8), -),
+    ("K_L0", "K_L0", 130, Meson, 0, 0, (497.611, 0.013), -, (5.116e-8, 0.021e-8)),
+    ("pi+", "pi-", 211, Meson, 3, 0, (139.570_39, 0.000_18), -, (2.603_3e-8, 0.000_5e-8)),
+    ("rho(770)+", "rho(770)-", 213, Meson, 3, 2, (775.11, 0.34), (149.1, 0.8), -),
+    ("eta", "eta", 221, Meson, 0, 0, (547.862, 0.017), (0.001_31, 0.000_05), -),
+    ("omega(782)", "omega(782)", 223, Meson, 0, 2, (782.66, 0.13), (8.68, 0.13), -),
+    ("K_S0", "K_S0", 310, Meson, 0, 0, (497.611, 0.013), -, (0.895_4e-10, 0.000_4e-10)),
+    ("K0", "K~0", 311, Meson, 0, 0, (497.611, 0.013), -, -),
+    ("K*(892)0", "K*(892)~0", 313, Meson, 0, 2, (895.55, 0.20), (47.3, 0.5), -),
+    ("K+", "K-", 321, Meson, 3, 0, (493.677, 0.016), -, (1.238_0e-8, 0.002_0e-8)),
+    ("K*(892)+", "K*(892)-", 323, Meson, 3, 2, (891.67, 0.26), (51.4, 0.8), -),
+    ("eta'(958)", "eta'(958)", 331, Meson, 0, 0, (957.78, 0.06), (0.188, 0.006), -),
+    ("phi(1020)", "phi(1020)", 333, Meson, 0, 2, (1_019.461, 0.016), (4.249, 0.013), -),
+    ("Delta(1232)-", "Delta(1232)~+", 1114, Baryon, -3, 3, (1_232.0, 2.0), (117.0, 3.0), -),
+    ("n", "n~", 2112, Baryon, 0, 1, (939.565_420_52, 0.000_000_54), -, (878.4, 0.5)),
+    ("Delta(1232)0", "Delta(1232)~0", 2114, Baryon, 0, 3, (1_232.0, 2.0), (117.0, 3.0), -),
+    ("p", "p~", 2212, Baryon, 3, 1, (938.272_088_16, 0.000_000_29), -, -),
+    ("Delta(1232)+", "Delta(1232)~-", 2214, Baryon, 3, 3, (1_232.0, 2.0), (117.0, 3.0), -),
+    ("Delta(1232)++", "Delta(1232)~--", 2224, Baryon, 6, 3, (1_232.0, 2.0), (117.0, 3.0), -),
+    ("Sigma-", "Sigma~+", 3112, Baryon, -3, 1, (1_197.449, 0.030), -, (1.479e-10, 0.011e-10)),
+    ("Lambda", "Lambda~", 3122, Baryon, 0, 1, (1_115.683, 0.006), -, (2.617e-10, 0.010e-10)),
+    ("Sigma0", "Sigma~0", 3212, Baryon, 0, 1, (1_192.642, 0.024), -, (7.4e-20, 0.7e-20)),
+    ("Sigma+", "Sigma~-", 3222, Baryon, 3, 1, (1_189.37, 0.07), -, (0.801_8e-10, 0.002_6e-10)),
+    ("Xi-", "Xi~+", 3312, Baryon, -3, 1, (1_321.71, 0.07), -, (1.639e-10, 0.015e-10)),
+    ("Xi0", "Xi~0", 3322, Baryon, 0, 1, (1_314.86, 0.20), -, (2.90e-10, 0.09e-10)),
+    ("Omega-", "Omega~+", 3334, Baryon, -3, 3, (1_672.45, 0.29), -, (0.821e-10, 0.011e-10)),
+}
@@ -0,0 +1,476 @@
+//! Particle data from the PDG summary tables.
+//!
+//! The leptons, the gauge and Higgs bosons, the top quark, the light
+//! mesons and the octet and decuplet baryons are recorded as
+//! [`Particle`]s with their masses, widths and mean lives, electric
+//! charges, spins and PDG Monte Carlo IDs. Values carry their
+//! uncertainties as an [`Estimate`], which may be asymmetric.
+//!
+//! Only particles are tabulated; antiparticles, with negative PDG IDs, are
+//! derived by [`Particle::antiparticle`] and found by the same lookups.
+//!
+//! ```
+//! 
+//! use rssn_advanced::particle;
+//!
+//! let pion = particle::lookup("pi-")
+//!     .unwrap();
+//!
+//! assert_eq!(pion.pdg_id, -211);
+//!
+//! assert_eq!(pion.charge(), -1.0);
+//!
+//! assert_eq!(
+//!     particle::lookup("-211"),
+//!     Some(pion)
+//! );
+//! ```
+
+/// The embedded particle table.
+mod data;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::constant::Measured;
+use crate::constant::REDUCED_PLANCK_CONSTANT_IN_EV_S;
+use crate::unit::Quantity;
+
+/// The reduced Planck constant in `MeV s`.
+
+const HBAR_MEV_S: f64 =
+    REDUCED_PLANCK_CONSTANT_IN_EV_S
+        * 1e-6;
+
+/// A central value with uncertainties, which the PDG quotes separately
+/// above and below the value when they differ.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Estimate {
+    /// The central value.
+    pub value: f64,
+    /// The uncertainty above the value.
+    pub plus: f64,
+    /// The uncertainty below the value.
+    pub minus: f64,
+}
+
+impl Estimate {
+    /// Creates an estimate with a symmetric uncertainty. The sign of
+    /// `uncertainty` is ignored.
+    #[must_use]
+
+    pub const fn new(
+        value: f64,
+        uncertainty: f64,
+    ) -> Self {
+
+        Self::asymmetric(
+            value,
+            uncertainty,
+            uncertainty,
+        )
+    }
+
+    /// Creates an estimate with separate uncertainties above and below the
+    /// value. Their signs are ignored.
+    #[must_use]
+
+    pub const fn asymmetric(
+        value: f64,
+        plus: f64,
+        minus: f64,
+    ) -> Self {
+
+        Self {
+            value,
+            plus: plus.abs(),
+            minus: minus.abs(),
+        }
+    }
+
+    /// Creates an estimate without uncertainty.
+    #[must_use]
+
+    pub const fn exact(
+        value: f64
+    ) -> Self {
+
+        Self::new(value, 0.0)
+    }
+
+    /// Returns whether the uncertainties above and below are equal.
+    #[must_use]
+
+    pub fn is_symmetric(&self) -> bool {
+
+        self.plus
+            .total_cmp(&self.minus)
+            .is_eq()
+    }
+
+    /// Returns the interval from one uncertainty below the value to one
+    /// above.
+    #[must_use]
+
+    pub fn interval(
+        &self
+    ) -> (f64, f64) {
+
+        (
+            self.value - self.minus,
+            self.value + self.plus,
+        )
+    }
+
+    /// Returns the value with a symmetric standard uncertainty, the mean of
+    /// the uncertainties above and below.
+    #[must_use]
+
+    pub const fn measured(
+        &self
+    ) -> Measured {
+
+        Measured::new(
+            self.value,
+            f64::midpoint(
+                self.plus,
+                self.minus,
+            ),
+        )
+    }
+}
+
+/// The kind of a particle.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum ParticleKind {
+    /// A charged lepton or a neutrino.
+    Lepton,
+    /// A quark.
+    Quark,
+    /// A gauge boson: the photon, the gluon, `W` or `Z`.
+    GaugeBoson,
+    /// The Higgs boson.
+    ScalarBoson,
+    /// A quark–antiquark bound state.
+    Meson,
+    /// A three-quark bound state.
+    Baryon,
+}
+
+/// A particle and its summary-table properties.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+)]
+
+pub struct Particle {
+    /// The name (e.g. `"pi+"`). Antiparticles without a name of their own
+    /// append `~` to that of the particle (e.g. `"p~"`).
+    pub name: &'static str,
+    /// The name of the antiparticle, equal to `name` for a particle that
+    /// is its own antiparticle.
+    pub antiparticle: &'static str,
+    /// The PDG Monte Carlo ID, negative for antiparticles.
+    pub pdg_id: i32,
+    /// The kind of particle.
+    pub kind: ParticleKind,
+    /// The electric charge in units of `e/3`, so that quark charges are
+    /// integers.
+    pub three_charge: i8,
+    /// Twice the spin, so that half-integer spins are integers.
+    pub twice_spin: u8,
+    /// The mass in `MeV`, `None` if not quoted (as for neutrinos).
+    pub mass: Option<Estimate>,
+    /// The full width in `MeV`, if quoted.
+    pub width: Option<Estimate>,
+    /// The mean life in seconds, if quoted.
+    pub lifetime: Option<Estimate>,
+}
+
+impl Particle {
+    /// Returns the electric charge in units of the elementary charge.
+    #[must_use]
+
+    pub fn charge(&self) -> f64 {
+
+        f64::from(self.three_charge)
+            / 3.0
+    }
+
+    /// Returns the spin quantum number.
+    #[must_use]
+
+    pub fn spin(&self) -> f64 {
+
+        f64::from(self.twice_spin) / 2.0
+    }
+
+    /// Returns whether the particle is its own antiparticle.
+    #[must_use]
+
+    pub fn is_self_conjugate(
+        &self
+    ) -> bool {
+
+        self.name == self.antiparticle
+    }
+
+    /// Returns the antiparticle: the same particle for a self-conjugate
+    /// one, otherwise the charge conjugate with the negated PDG ID.
+    #[must_use]
+
+    pub fn antiparticle(&self) -> Self {
+
+        if self.is_self_conjugate() {
+
+            return *self;
+        }
+
+        Self {
+            name: self.antiparticle,
+            antiparticle: self.name,
+            pdg_id: self
+                .pdg_id
+                .saturating_neg(),
+            three_charge: self
+                .three_charge
+                .saturating_neg(),
+            ..*self
+        }
+    }
+
+    /// Returns the mean life in seconds, quoted or obtained from the width
+    /// as `ħ/Γ`. `None` for a stable particle or if neither is quoted.
+    #[must_use]
+
+    pub fn mean_life(
+        &self
+    ) -> Option<f64> {
+
+        self.lifetime
+            .map(|tau| tau.value)
+            .or_else(|| {
+
+                self.width.map(
+                    |gamma| {
+
+                        HBAR_MEV_S
+                            / gamma
+                                .value
+                    },
+                )
+            })
+    }
+
+    /// Returns the full width in `MeV`, quoted or obtained from the mean
+    /// life as `ħ/τ`. `None` for a stable particle or if neither is quoted.
+    #[must_use]
+
+    pub fn decay_width(
+        &self
+    ) -> Option<f64> {
+
+        self.width
+            .map(|gamma| gamma.value)
+            .or_else(|| {
+
+                self.lifetime.map(
+                    |tau| {
+
+                        HBAR_MEV_S
+                            / tau.value
+                    },
+                )
+            })
+    }
+
+    /// Returns the mass as a [`Quantity`] in coherent SI units.
+    #[must_use]
+
+    pub fn mass_quantity(
+        &self
+    ) -> Option<Quantity> {
+
+        Quantity::with_unit(
+            self.mass?.value,
+            "MeV/c²",
+        )
+        .ok()
+    }
+}
+
+/// Common names accepted by [`by_name`] in addition to the table names.
+
+const ALIASES: &[(&str, &str)] = &[
+    ("electron", "e-"),
+    ("positron", "e+"),
+    ("muon", "mu-"),
+    ("tau", "tau-"),
+    ("top", "t"),
+    ("gluon", "g"),
+    ("photon", "gamma"),
+    ("Z", "Z0"),
+    ("higgs", "H0"),
+    ("proton", "p"),
+    ("antiproton", "p~"),
+    ("neutron", "n"),
+    ("antineutron", "n~"),
+];
+
+/// Returns every tabulated particle, in order of PDG ID. Antiparticles
+/// are not included.
+#[must_use]
+
+pub const fn all() -> &'static [Particle]
+{
+
+    data::PARTICLES
+}
+
+/// Looks up a particle or antiparticle by its PDG Monte Carlo ID.
+#[must_use]
+
+pub fn by_pdg_id(
+    pdg_id: i32
+) -> Option<Particle> {
+
+    let particle =
+        all()
+            .iter()
+            .find(|p| {
+
+                p.pdg_id
+                    == pdg_id
+                        .saturating_abs(
+                        )
+            })?;
+
+    if pdg_id > 0 {
+
+        Some(*particle)
+    } else if particle
+        .is_self_conjugate()
+    {
+
+        None
+    } else {
+
+        Some(particle.antiparticle())
+    }
+}
+
+/// Looks up a particle or antiparticle by name, ignoring ASCII case.
+///
+/// Common names such as `"electron"`, `"photon"` or `"proton"` are also
+/// accepted.
+#[must_use]
+
+pub fn by_name(
+    name: &str
+) -> Option<Particle> {
+
+    let name = ALIASES
+        .iter()
+        .find(|(alias, _)| {
+
+            alias.eq_ignore_ascii_case(
+                name,
+            )
+        })
+        .map_or(name, |(_, name)| {
+
+            name
+        });
+
+    all()
+        .iter()
+        .find_map(|p| {
+            if p.name
+                .eq_ignore_ascii_case(
+                    name,
+                )
+            {
+
+                Some(*p)
+            } else if p
+                .antiparticle
+                .eq_ignore_ascii_case(
+                    name,
+                )
+            {
+
+                Some(p.antiparticle())
+            } else {
+
+                None
+            }
+        })
+}
+
+/// Looks up a particle or antiparticle by PDG ID (as decimal text) or by
+/// name.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::particle;
+///
+/// let z =
+///     particle::lookup("23").unwrap();
+///
+/// assert_eq!(z.name, "Z0");
+///
+/// assert_eq!(
+///     particle::lookup("Z"),
+///     Some(z)
+/// );
+/// ```
+#[must_use]
+
+pub fn lookup(
+    key: &str
+) -> Option<Particle> {
+
+    let key = key.trim();
+
+    match key.parse() {
+        | Ok(pdg_id) => {
+            by_pdg_id(pdg_id)
+        },
+        | Err(_) => by_name(key),
+    }
+}
+
+/// Returns an iterator over the particles of kind `kind`.
+
+pub fn of_kind(
+    kind: ParticleKind
+) -> impl Iterator<Item = &'static Particle>
+{
+
+    all()
+        .iter()
+        .filter(move |p| p.kind == kind)
+}
@@ -0,0 +1,819 @@
+//! Dimensional analysis by the Buckingham π theorem.
+//!
+//! `n` physical variables whose dimensions span a space of rank `r` form
+//! `n - r` independent dimensionless groups, and any relation between the
+//! variables is a relation between the groups. [`pi_groups`] computes such
+//! a set from the integer nullspace of the dimension matrix, and
+//! [`scaling`] expresses a target quantity as a power product of the
+//! variables times an unknown function of the groups.
+//!
+//! Variables are declared with a dimension, with a unit in the notation of
+//! [`super::notation`], or from a registered constant (`G`, `c`, `ħ`, `k`,
+//! ...) by [`Variable::constant`].
+//!
+//! # Examples
+//!
+//! ```
+//! 
+//! use rssn_advanced::unit::Dimension;
+//! use rssn_advanced::unit::buckingham;
+//! use rssn_advanced::unit::buckingham::Variable;
+//!
+//! // The period of a pendulum of length l in a field g.
+//! let variables = [
+//!     Variable::with_unit("l", "m")
+//!         .unwrap(),
+//!     Variable::with_unit("g", "m s⁻²")
+//!         .unwrap(),
+//! ];
+//!
+//! let period = Variable::new(
+//!     "T",
+//!     Dimension::TIME,
+//! );
+//!
+//! let law = buckingham::scaling(
+//!     &period,
+//!     &variables,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(
+//!     law.to_string(),
+//!     "T² ∝ l g⁻¹"
+//! );
+//! ```
+
+use std::fmt;
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::One;
+use num_traits::Signed;
+use num_traits::ToPrimitive;
+use num_traits::Zero;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Dimension;
+use super::UnitError;
+use super::notation;
+use crate::constant::MatchKind;
+
+/// A physical variable and its dimension.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Variable {
+    /// The name used when formatting groups and scaling laws.
+    pub name: String,
+    /// The dimension.
+    pub dimension: Dimension,
+}
+
+impl Variable {
+    /// Creates a variable of dimension `dimension`.
+    #[must_use]
+
+    pub fn new(
+        name: &str,
+        dimension: Dimension,
+    ) -> Self {
+
+        Self {
+            name: name.to_string(),
+            dimension,
+        }
+    }
+
+    /// Creates a variable measured in `unit` (e.g. `"kg m⁻³"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] if the unit cannot be parsed.
+
+    pub fn with_unit(
+        name: &str,
+        unit: &str,
+    ) -> Result<Self, UnitError> {
+
+        Ok(Self::new(
+            name,
+            super::parse(unit)?
+                .dimension,
+        ))
+    }
+
+    /// Creates a variable for the registered constant with identifier,
+    /// symbol or alias `key` (e.g. `"G"`, `"c"`, `"hbar"` or `"k_B"`),
+    /// named by its symbol.
+    ///
+    /// Returns `None` if no constant has that key, up to case and
+    /// notation, or if its unit is not recognised.
+    #[must_use]
+
+    pub fn constant(
+        key: &str
+    ) -> Option<Self> {
+
+        let found = crate::constant::find(
+            key,
+        )
+        .into_iter()
+        .next()
+        .filter(|m| {
+
+            matches!(
+                m.kind,
+                MatchKind::Exact
+                    | MatchKind::Normalized
+            )
+        })?;
+
+        let constant = found.constant;
+
+        let name = if constant
+            .symbol
+            .is_empty()
+        {
+
+            constant.id
+        } else {
+
+            constant.symbol
+        };
+
+        Some(Self::new(
+            name,
+            constant.dimension()?,
+        ))
+    }
+}
+
+/// A dimensionless power product of variables.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct PiGroup {
+    /// The variables with nonzero exponents, in the order they were given,
+    /// with their integer exponents. The exponents have no common divisor
+    /// and the first is positive.
+    pub factors: Vec<(String, i32)>,
+}
+
+impl fmt::Display for PiGroup {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(&product(
+            &self.factors,
+        ))
+    }
+}
+
+/// A scaling law `target^power ∝ ∏ xᵢ^aᵢ · Φ(π₁, π₂, ...)`.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Scaling {
+    /// The name of the target quantity.
+    pub target: String,
+    /// The positive power of the target, the least making the exponents
+    /// of the variables integers.
+    pub power: i32,
+    /// The repeating variables with nonzero exponents and their exponents.
+    pub factors: Vec<(String, i32)>,
+    /// The dimensionless groups the unknown function depends on.
+    pub groups: Vec<PiGroup>,
+}
+
+impl Scaling {
+    /// Returns the exponent of variable `name` in the law solved for the
+    /// target, as numerator and denominator in lowest terms, or zero if
+    /// it does not occur.
+    #[must_use]
+
+    pub fn exponent(
+        &self,
+        name: &str,
+    ) -> (i32, i32) {
+
+        let numerator = self
+            .factors
+            .iter()
+            .find(|(n, _)| n == name)
+            .map_or(0, |(_, e)| *e);
+
+        let divisor =
+            i32::try_from(gcd(
+                numerator
+                    .unsigned_abs(),
+                self.power
+                    .unsigned_abs(),
+            ))
+            .unwrap_or(1)
+            .max(1);
+
+        if numerator == 0 {
+
+            (0, 1)
+        } else {
+
+            (
+                numerator
+                    .checked_div(
+                        divisor,
+                    )
+                    .unwrap_or(0),
+                self.power
+                    .checked_div(
+                        divisor,
+                    )
+                    .unwrap_or(1),
+            )
+        }
+    }
+}
+
+impl fmt::Display for Scaling {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        write!(
+            f,
+            "{}{} ",
+            self.target,
+            notation::superscript(
+                self.power
+            )
+        )?;
+
+        if self
+            .groups
+            .is_empty()
+        {
+
+            write!(
+                f,
+                "∝ {}",
+                product(&self.factors)
+            )
+        } else {
+
+            let groups: Vec<String> =
+                self.groups
+                    .iter()
+                    .map(PiGroup::to_string)
+                    .collect();
+
+            write!(
+                f,
+                "= {} Φ({})",
+                product(&self.factors),
+                groups.join(", ")
+            )
+        }
+    }
+}
+
+/// Formats a power product, or `"1"` if it is empty.
+
+fn product(
+    factors: &[(String, i32)]
+) -> String {
+
+    if factors.is_empty() {
+
+        return "1".to_string();
+    }
+
+    factors
+        .iter()
+        .map(|(name, exponent)| {
+
+            format!(
+                "{name}{}",
+                notation::superscript(
+                    *exponent
+                )
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the greatest common divisor, zero only if both are zero.
+
+const fn gcd(
+    mut a: u32,
+    mut b: u32,
+) -> u32 {
+
+    while let Some(r) = a.checked_rem(b)
+    {
+
+        a = b;
+
+        b = r;
+    }
+
+    a
+}
+
+/// The dimension matrix reduced to row echelon form.
+
+struct Reduced {
+    /// The rows, one per base quantity.
+    rows: Vec<Vec<BigRational>>,
+    /// The pivot column of each nonzero row, in order.
+    pivots: Vec<usize>,
+}
+
+/// Reduces the matrix whose columns are the dimensions of `columns`,
+/// pivoting only on the first `pivoting` of them.
+
+fn reduce(
+    columns: &[Dimension],
+    pivoting: usize,
+) -> Reduced {
+
+    let mut rows: Vec<Vec<BigRational>> =
+        (0 .. 7)
+            .map(|base| {
+
+                columns
+                    .iter()
+                    .map(|d| {
+
+                        let exponent = d
+                            .exponents()
+                            .get(base)
+                            .copied()
+                            .unwrap_or(0);
+
+                        BigRational::from_integer(
+                            BigInt::from(
+                                exponent,
+                            ),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+
+    let mut pivots = Vec::new();
+
+    for column in 0 .. pivoting {
+
+        let row = pivots.len();
+
+        let Some(found) = (row .. rows
+            .len())
+            .find(|&i| {
+
+                rows.get(i)
+                    .and_then(|r| {
+
+                        r.get(column)
+                    })
+                    .is_some_and(|x| {
+
+                        !x.is_zero()
+                    })
+            })
+        else {
+
+            continue;
+        };
+
+        rows.swap(row, found);
+
+        let Some(pivot_row) =
+            rows.get_mut(row)
+        else {
+
+            break;
+        };
+
+        let pivot = pivot_row
+            .get(column)
+            .cloned()
+            .unwrap_or_else(
+                BigRational::one,
+            );
+
+        for x in pivot_row.iter_mut() {
+
+            *x /= &pivot;
+        }
+
+        let pivot_row =
+            pivot_row.clone();
+
+        for (i, other) in rows
+            .iter_mut()
+            .enumerate()
+        {
+
+            let factor = other
+                .get(column)
+                .cloned()
+                .unwrap_or_default();
+
+            if i == row
+                || factor.is_zero()
+            {
+
+                continue;
+            }
+
+            for (x, p) in other
+                .iter_mut()
+                .zip(&pivot_row)
+            {
+
+                *x -= &factor * p;
+            }
+        }
+
+        pivots.push(column);
+    }
+
+    Reduced {
+        rows,
+        pivots,
+    }
+}
+
+/// Scales a rational vector to the integer vector with no common divisor
+/// whose first nonzero entry is positive.
+
+fn integral(
+    vector: &[BigRational]
+) -> Vec<i32> {
+
+    let lcm = vector.iter().fold(
+        BigInt::one(),
+        |lcm, x| {
+
+            let d = x.denom();
+
+            let g = big_gcd(&lcm, d);
+
+            &lcm * d / g
+        },
+    );
+
+    let scaled: Vec<BigInt> = vector
+        .iter()
+        .map(|x| {
+
+            (x * &lcm).to_integer()
+        })
+        .collect();
+
+    let divisor = scaled.iter().fold(
+        BigInt::zero(),
+        |g, x| big_gcd(&g, x),
+    );
+
+    let sign = scaled
+        .iter()
+        .find(|x| !x.is_zero())
+        .map_or_else(
+            BigInt::one,
+            Signed::signum,
+        );
+
+    let divisor = if divisor.is_zero() {
+
+        BigInt::one()
+    } else {
+
+        divisor * sign
+    };
+
+    scaled
+        .iter()
+        .map(|x| {
+
+            let e = x / &divisor;
+
+            e.to_i32()
+                .unwrap_or_else(|| {
+                    if e.is_negative() {
+
+                        i32::MIN
+                    } else {
+
+                        i32::MAX
+                    }
+                })
+        })
+        .collect()
+}
+
+/// Returns the non-negative greatest common divisor.
+
+fn big_gcd(
+    a: &BigInt,
+    b: &BigInt,
+) -> BigInt {
+
+    let (mut a, mut b) =
+        (a.abs(), b.abs());
+
+    while !b.is_zero() {
+
+        let r = &a % &b;
+
+        a = b;
+
+        b = r;
+    }
+
+    a
+}
+
+/// Pairs the names of `variables` with the nonzero `exponents`.
+
+fn named(
+    variables: &[Variable],
+    exponents: &[i32],
+) -> Vec<(String, i32)> {
+
+    variables
+        .iter()
+        .zip(exponents)
+        .filter(|(_, e)| **e != 0)
+        .map(|(v, e)| {
+
+            (v.name.clone(), *e)
+        })
+        .collect()
+}
+
+/// Returns a complete set of independent dimensionless groups of
+/// `variables`, one for each variable beyond the rank of their dimension
+/// matrix.
+///
+/// The repeating variables, which may occur in every group, are chosen
+/// greedily from the front of the list: each variable is repeating unless
+/// its dimension is a product of powers of those before it. Every other
+/// variable occurs in exactly one group, so the quantities of interest
+/// are best listed last.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::buckingham;
+/// use rssn_advanced::unit::buckingham::Variable;
+///
+/// let variables = [
+///     ("ρ", "kg m⁻³"),
+///     ("v", "m s⁻¹"),
+///     ("L", "m"),
+///     ("μ", "Pa s"),
+/// ]
+/// .map(|(name, unit)| {
+///
+///     Variable::with_unit(name, unit)
+///         .unwrap()
+/// });
+///
+/// let groups =
+///     buckingham::pi_groups(&variables);
+///
+/// // The Reynolds number.
+/// assert_eq!(
+///     groups[0].to_string(),
+///     "ρ v L μ⁻¹"
+/// );
+/// ```
+#[must_use]
+
+pub fn pi_groups(
+    variables: &[Variable]
+) -> Vec<PiGroup> {
+
+    let dimensions: Vec<Dimension> =
+        variables
+            .iter()
+            .map(|v| v.dimension)
+            .collect();
+
+    let reduced = reduce(
+        &dimensions,
+        dimensions.len(),
+    );
+
+    (0 .. variables.len())
+        .filter(|j| {
+
+            !reduced
+                .pivots
+                .contains(j)
+        })
+        .map(|free| {
+
+            // The free variable to the first power, divided by the power
+            // product of the repeating ones with its dimension.
+            let mut vector = vec![
+                BigRational::zero();
+                variables.len()
+            ];
+
+            if let Some(x) =
+                vector.get_mut(free)
+            {
+
+                *x = BigRational::one();
+            }
+
+            for (row, &pivot) in reduced
+                .rows
+                .iter()
+                .zip(&reduced.pivots)
+            {
+
+                if let (
+                    Some(x),
+                    Some(a),
+                ) = (
+                    vector
+                        .get_mut(pivot),
+                    row.get(free),
+                ) {
+
+                    *x = -a;
+                }
+            }
+
+            PiGroup {
+                factors: named(
+                    variables,
+                    &integral(&vector),
+                ),
+            }
+        })
+        .collect()
+}
+
+/// Returns the scaling law of `target` in terms of `variables`.
+///
+/// The law is the power product of the repeating variables (see
+/// [`pi_groups`]) with the dimension of the target, times a function of
+/// the dimensionless groups.
+///
+/// Returns `None` if the dimension of the target is not a product of
+/// powers of the dimensions of the variables.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::Dimension;
+/// use rssn_advanced::unit::buckingham;
+/// use rssn_advanced::unit::buckingham::Variable;
+///
+/// // The Planck length.
+/// let constants = ["G", "ħ", "c"].map(|key| {
+///
+///     Variable::constant(key).unwrap()
+/// });
+///
+/// let law = buckingham::scaling(
+///     &Variable::new(
+///         "l_P",
+///         Dimension::LENGTH,
+///     ),
+///     &constants,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     law.to_string(),
+///     "l_P² ∝ G ħ c⁻³"
+/// );
+///
+/// assert_eq!(law.exponent("G"), (1, 2));
+/// ```
+#[must_use]
+
+pub fn scaling(
+    target: &Variable,
+    variables: &[Variable],
+) -> Option<Scaling> {
+
+    let dimensions: Vec<Dimension> =
+        variables
+            .iter()
+            .map(|v| v.dimension)
+            .chain([target.dimension])
+            .collect();
+
+    let reduced = reduce(
+        &dimensions,
+        variables.len(),
+    );
+
+    let mut vector = vec![
+        BigRational::zero();
+        variables.len()
+    ];
+
+    for (i, row) in reduced
+        .rows
+        .iter()
+        .enumerate()
+    {
+
+        let value = row
+            .get(variables.len())
+            .cloned()
+            .unwrap_or_default();
+
+        match reduced
+            .pivots
+            .get(i)
+        {
+            | Some(&pivot) => {
+                if let Some(x) = vector
+                    .get_mut(pivot)
+                {
+
+                    *x = value;
+                }
+            },
+            // A remaining dimension of the target that no variable has.
+            | None if !value
+                .is_zero() =>
+            {
+
+                return None;
+            },
+            | None => {},
+        }
+    }
+
+    // target^power = ∏ xᵢ^(power aᵢ), with the target first so that its
+    // power comes out positive.
+    let mut law =
+        vec![BigRational::one()];
+
+    law.extend(
+        vector
+            .iter()
+            .map(|a| -a),
+    );
+
+    let exponents = integral(&law);
+
+    let (power, rest) = exponents
+        .split_first()
+        .map_or(
+            (1, &[][..]),
+            |(p, r)| (*p, r),
+        );
+
+    let negated: Vec<i32> = rest
+        .iter()
+        .map(|e| e.saturating_neg())
+        .collect();
+
+    Some(Scaling {
+        target: target.name.clone(),
+        power,
+        factors: named(
+            variables,
+            &negated,
+        ),
+        groups: pi_groups(variables),
+    })
+}
@@ -0,0 +1,681 @@
+//! The unit symbols and SI prefixes understood by [`parse`], and
+//! [`convert`] and [`convert_equivalent`].
+//!
+//! Every symbol resolves to a [`Unit`]: a dimension together with the
+//! factor that converts a value in that unit to coherent SI units. Units
+//! tied to measured constants (`eV`, `u`, `E_h`, ...) take their factor
+//! from the latest CODATA edition.
+//!
+//! Besides the SI units, [`UNITS`] covers the non-SI units in common use
+//! in the laboratory (`Å`, `bar`, `Torr`, `cal`, `kWh`, `°C`, ...). The
+//! degree Celsius and degree Fahrenheit are absolute temperatures when
+//! they make up the whole unit, and temperature differences inside a
+//! compound unit such as `J °C⁻¹`.
+//!
+//! `G` (gauss) and `u` (unified atomic mass unit) double as the giga and
+//! ASCII micro prefixes, so they take no prefix themselves, and `Gs`, a
+//! common spelling of the gauss, is refused as [`AMBIGUOUS`] rather than
+//! read as a gigasecond. So is `da`, more likely a day than a deci-annum.
+
+use std::f64::consts::PI;
+use std::ops::Mul;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Dimension;
+use super::EnergyUnit;
+use super::UnitError;
+use super::energy;
+use super::notation;
+use crate::astro;
+use crate::constant;
+
+/// A unit, as a scale factor to coherent SI units and a dimension.
+///
+/// A value `x` in this unit equals `x * scale + offset` in the SI units of
+/// `dimension`, where `offset` is zero except for the absolute
+/// temperature scales `°C` and `°F`.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Unit {
+    /// The value of one of this unit in coherent SI units.
+    pub scale: f64,
+    /// The SI value of the zero of this unit.
+    pub offset: f64,
+    /// The dimension.
+    pub dimension: Dimension,
+}
+
+impl Unit {
+    /// The unit of a pure number.
+
+    pub const ONE: Self = Self::new(
+        1.0,
+        Dimension::DIMENSIONLESS,
+    );
+
+    /// Creates a unit whose zero is the SI zero.
+    #[must_use]
+
+    pub const fn new(
+        scale: f64,
+        dimension: Dimension,
+    ) -> Self {
+
+        Self::affine(
+            scale,
+            0.0,
+            dimension,
+        )
+    }
+
+    /// Creates a unit whose zero lies at `offset` in SI units.
+    #[must_use]
+
+    pub const fn affine(
+        scale: f64,
+        offset: f64,
+        dimension: Dimension,
+    ) -> Self {
+
+        Self {
+            scale,
+            offset,
+            dimension,
+        }
+    }
+
+    /// Converts a value in this unit to coherent SI units.
+    #[must_use]
+
+    pub fn to_si(
+        &self,
+        value: f64,
+    ) -> f64 {
+
+        value.mul_add(
+            self.scale,
+            self.offset,
+        )
+    }
+
+    /// Converts a value in coherent SI units to this unit.
+    #[must_use]
+
+    pub fn from_si(
+        &self,
+        value: f64,
+    ) -> f64 {
+
+        (value - self.offset)
+            / self.scale
+    }
+
+    /// Raises the unit to an integer power.
+    #[must_use]
+
+    pub fn powi(
+        self,
+        n: i32,
+    ) -> Self {
+
+        Self::new(
+            self.scale.powi(n),
+            self.dimension
+                .powi(n),
+        )
+    }
+}
+
+impl Mul for Unit {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self::new(
+            self.scale * rhs.scale,
+            self.dimension
+                * rhs.dimension,
+        )
+    }
+}
+
+/// An entry of [`UNITS`].
+#[derive(Debug, Serialize)]
+
+pub struct UnitSymbol {
+    /// The symbol, e.g. `"eV"`.
+    pub symbol: &'static str,
+    /// The name, e.g. `"electronvolt"`.
+    pub name: &'static str,
+    /// The unit the symbol denotes.
+    pub unit: Unit,
+    /// Whether the symbol accepts an SI prefix.
+    pub prefixable: bool,
+}
+
+/// The SI prefixes, with both the micro sign `µ` and the Greek `μ`, and
+/// `u` as an ASCII spelling of micro.
+///
+/// `da` comes first so that it is tried before `d`.
+
+pub static PREFIXES: &[(&str, f64)] = &[
+    ("da", 1e1),
+    ("q", 1e-30),
+    ("r", 1e-27),
+    ("y", 1e-24),
+    ("z", 1e-21),
+    ("a", 1e-18),
+    ("f", 1e-15),
+    ("p", 1e-12),
+    ("n", 1e-9),
+    ("µ", 1e-6),
+    ("μ", 1e-6),
+    ("u", 1e-6),
+    ("m", 1e-3),
+    ("c", 1e-2),
+    ("d", 1e-1),
+    ("h", 1e2),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+    ("T", 1e12),
+    ("P", 1e15),
+    ("E", 1e18),
+    ("Z", 1e21),
+    ("Y", 1e24),
+    ("R", 1e27),
+    ("Q", 1e30),
+];
+
+/// Declares the [`UNITS`] table.
+///
+/// Each entry reads `"symbol", "name", scale, dimension, prefixable;`,
+/// where `dimension` is the array of base exponents, optionally followed
+/// by `, offset` before the `;` for the absolute temperature scales.
+
+macro_rules! units {
+    (
+        $(
+            $symbol:literal,
+            $name:literal,
+            $scale:expr,
+            $dimension:expr,
+            $prefixable:literal
+            $(, $offset:expr)?;
+        )*
+    ) => {
+        &[
+            $(
+                UnitSymbol {
+                    symbol: $symbol,
+                    name: $name,
+                    unit: Unit::affine(
+                        $scale,
+                        0.0 $(+ $offset)?,
+                        Dimension::from_exponents(
+                            $dimension,
+                        ),
+                    ),
+                    prefixable: $prefixable,
+                },
+            )*
+        ]
+    };
+}
+
+/// Every unit symbol understood by [`parse`].
+
+pub static UNITS: &[UnitSymbol] = units! {
+    // SI base units. The kilogram is parsed as a prefixed gram.
+    "m", "metre", 1.0, [1, 0, 0, 0, 0, 0, 0], true;
+    "g", "gram", 1e-3, [0, 1, 0, 0, 0, 0, 0], true;
+    "s", "second", 1.0, [0, 0, 1, 0, 0, 0, 0], true;
+    "A", "ampere", 1.0, [0, 0, 0, 1, 0, 0, 0], true;
+    "K", "kelvin", 1.0, [0, 0, 0, 0, 1, 0, 0], true;
+    "mol", "mole", 1.0, [0, 0, 0, 0, 0, 1, 0], true;
+    "cd", "candela", 1.0, [0, 0, 0, 0, 0, 0, 1], true;
+    // SI derived units with special names.
+    "rad", "radian", 1.0, [0, 0, 0, 0, 0, 0, 0], true;
+    "sr", "steradian", 1.0, [0, 0, 0, 0, 0, 0, 0], true;
+    "Hz", "hertz", 1.0, [0, 0, -1, 0, 0, 0, 0], true;
+    "N", "newton", 1.0, [1, 1, -2, 0, 0, 0, 0], true;
+    "Pa", "pascal", 1.0, [-1, 1, -2, 0, 0, 0, 0], true;
+    "J", "joule", 1.0, [2, 1, -2, 0, 0, 0, 0], true;
+    "W", "watt", 1.0, [2, 1, -3, 0, 0, 0, 0], true;
+    "C", "coulomb", 1.0, [0, 0, 1, 1, 0, 0, 0], true;
+    "V", "volt", 1.0, [2, 1, -3, -1, 0, 0, 0], true;
+    "F", "farad", 1.0, [-2, -1, 4, 2, 0, 0, 0], true;
+    "Ω", "ohm", 1.0, [2, 1, -3, -2, 0, 0, 0], true;
+    "ohm", "ohm", 1.0, [2, 1, -3, -2, 0, 0, 0], true;
+    "S", "siemens", 1.0, [-2, -1, 3, 2, 0, 0, 0], true;
+    "Wb", "weber", 1.0, [2, 1, -2, -1, 0, 0, 0], true;
+    "T", "tesla", 1.0, [0, 1, -2, -1, 0, 0, 0], true;
+    "H", "henry", 1.0, [2, 1, -2, -2, 0, 0, 0], true;
+    "lm", "lumen", 1.0, [0, 0, 0, 0, 0, 0, 1], true;
+    "lx", "lux", 1.0, [-2, 0, 0, 0, 0, 0, 1], true;
+    "Bq", "becquerel", 1.0, [0, 0, -1, 0, 0, 0, 0], true;
+    "Gy", "gray", 1.0, [2, 0, -2, 0, 0, 0, 0], true;
+    "Sv", "sievert", 1.0, [2, 0, -2, 0, 0, 0, 0], true;
+    "kat", "katal", 1.0, [0, 0, -1, 0, 0, 1, 0], true;
+    // Units of the constant table defined by constants.
+    "eV", "electronvolt", constant::ELECTRON_VOLT, [2, 1, -2, 0, 0, 0, 0], true;
+    "u", "unified atomic mass unit", constant::ATOMIC_MASS_CONSTANT, [0, 1, 0, 0, 0, 0, 0], false;
+    "E_h", "hartree", constant::HARTREE_ENERGY, [2, 1, -2, 0, 0, 0, 0], false;
+    "c", "speed of light in vacuum", constant::SPEED_OF_LIGHT, [1, 0, -1, 0, 0, 0, 0], false;
+    "C_90", "conventional coulomb", constant::CONVENTIONAL_VALUE_OF_COULOMB_90, [0, 0, 1, 1, 0, 0, 0], false;
+    "1", "one", 1.0, [0, 0, 0, 0, 0, 0, 0], false;
+    // Non-SI units accepted for use with the SI.
+    "min", "minute", 60.0, [0, 0, 1, 0, 0, 0, 0], false;
+    "h", "hour", 3_600.0, [0, 0, 1, 0, 0, 0, 0], false;
+    "d", "day", 86_400.0, [0, 0, 1, 0, 0, 0, 0], false;
+    "a", "Julian year", astro::JULIAN_YEAR, [0, 0, 1, 0, 0, 0, 0], true;
+    "yr", "Julian year", astro::JULIAN_YEAR, [0, 0, 1, 0, 0, 0, 0], false;
+    "°", "degree", PI / 180.0, [0, 0, 0, 0, 0, 0, 0], false;
+    "deg", "degree", PI / 180.0, [0, 0, 0, 0, 0, 0, 0], false;
+    "′", "arcminute", PI / 10_800.0, [0, 0, 0, 0, 0, 0, 0], false;
+    "arcmin", "arcminute", PI / 10_800.0, [0, 0, 0, 0, 0, 0, 0], false;
+    "″", "arcsecond", PI / 648_000.0, [0, 0, 0, 0, 0, 0, 0], false;
+    "arcsec", "arcsecond", PI / 648_000.0, [0, 0, 0, 0, 0, 0, 0], false;
+    "ha", "hectare", 1e4, [2, 0, 0, 0, 0, 0, 0], false;
+    "L", "litre", 1e-3, [3, 0, 0, 0, 0, 0, 0], true;
+    "l", "litre", 1e-3, [3, 0, 0, 0, 0, 0, 0], true;
+    "t", "tonne", 1e3, [0, 1, 0, 0, 0, 0, 0], true;
+    "Da", "dalton", constant::ATOMIC_MASS_CONSTANT, [0, 1, 0, 0, 0, 0, 0], true;
+    "°C", "degree Celsius", 1.0, [0, 0, 0, 0, 1, 0, 0], false, 273.15;
+    "degC", "degree Celsius", 1.0, [0, 0, 0, 0, 1, 0, 0], false, 273.15;
+    // Other units in common use.
+    "°F", "degree Fahrenheit", 5.0 / 9.0, [0, 0, 0, 0, 1, 0, 0], false, 459.67 * 5.0 / 9.0;
+    "degF", "degree Fahrenheit", 5.0 / 9.0, [0, 0, 0, 0, 1, 0, 0], false, 459.67 * 5.0 / 9.0;
+    "Å", "ångström", 1e-10, [1, 0, 0, 0, 0, 0, 0], false;
+    "angstrom", "ångström", 1e-10, [1, 0, 0, 0, 0, 0, 0], false;
+    "b", "barn", 1e-28, [2, 0, 0, 0, 0, 0, 0], true;
+    "in", "inch", 0.0254, [1, 0, 0, 0, 0, 0, 0], false;
+    "ft", "foot", 0.3048, [1, 0, 0, 0, 0, 0, 0], false;
+    "yd", "yard", 0.9144, [1, 0, 0, 0, 0, 0, 0], false;
+    "mi", "mile", 1_609.344, [1, 0, 0, 0, 0, 0, 0], false;
+    "nmi", "nautical mile", 1_852.0, [1, 0, 0, 0, 0, 0, 0], false;
+    "kn", "knot", 1_852.0 / 3_600.0, [1, 0, -1, 0, 0, 0, 0], false;
+    "au", "astronomical unit", astro::ASTRONOMICAL_UNIT, [1, 0, 0, 0, 0, 0, 0], false;
+    "ly", "light year", astro::LIGHT_YEAR, [1, 0, 0, 0, 0, 0, 0], false;
+    "pc", "parsec", astro::PARSEC, [1, 0, 0, 0, 0, 0, 0], true;
+    "gal", "US gallon", 3.785_411_784e-3, [3, 0, 0, 0, 0, 0, 0], false;
+    "lb", "pound", 0.453_592_37, [0, 1, 0, 0, 0, 0, 0], false;
+    "oz", "ounce", 0.028_349_523_125, [0, 1, 0, 0, 0, 0, 0], false;
+    "dyn", "dyne", 1e-5, [1, 1, -2, 0, 0, 0, 0], false;
+    "kgf", "kilogram-force", constant::STANDARD_ACCELERATION_OF_GRAVITY, [1, 1, -2, 0, 0, 0, 0], false;
+    "lbf", "pound-force", 0.453_592_37 * constant::STANDARD_ACCELERATION_OF_GRAVITY, [1, 1, -2, 0, 0, 0, 0], false;
+    "bar", "bar", 1e5, [-1, 1, -2, 0, 0, 0, 0], true;
+    "atm", "standard atmosphere", constant::STANDARD_ATMOSPHERE, [-1, 1, -2, 0, 0, 0, 0], false;
+    "Torr", "torr", constant::STANDARD_ATMOSPHERE / 760.0, [-1, 1, -2, 0, 0, 0, 0], true;
+    "mmHg", "millimetre of mercury", 133.322_387_415, [-1, 1, -2, 0, 0, 0, 0], false;
+    "psi", "pound per square inch", 0.453_592_37 * constant::STANDARD_ACCELERATION_OF_GRAVITY / (0.0254 * 0.0254), [-1, 1, -2, 0, 0, 0, 0], false;
+    "erg", "erg", 1e-7, [2, 1, -2, 0, 0, 0, 0], false;
+    "cal", "thermochemical calorie", 4.184, [2, 1, -2, 0, 0, 0, 0], true;
+    "Wh", "watt hour", 3_600.0, [2, 1, -2, 0, 0, 0, 0], true;
+    "Btu", "British thermal unit", 1_055.055_852_62, [2, 1, -2, 0, 0, 0, 0], false;
+    "hp", "horsepower", 0.453_592_37 * constant::STANDARD_ACCELERATION_OF_GRAVITY * 0.3048 * 550.0, [2, 1, -3, 0, 0, 0, 0], false;
+    "G", "gauss", 1e-4, [0, 1, -2, -1, 0, 0, 0], false;
+    "Mx", "maxwell", 1e-8, [2, 1, -2, -1, 0, 0, 0], false;
+    "Oe", "oersted", 1e3 / (4.0 * PI), [-1, 0, 0, 1, 0, 0, 0], false;
+    "Ci", "curie", 3.7e10, [0, 0, -1, 0, 0, 0, 0], true;
+    "%", "percent", 1e-2, [0, 0, 0, 0, 0, 0, 0], false;
+    "ppm", "part per million", 1e-6, [0, 0, 0, 0, 0, 0, 0], false;
+    // Atomic units.
+    "a_0", "bohr", constant::BOHR_RADIUS, [1, 0, 0, 0, 0, 0, 0], false;
+    "m_e", "electron mass", constant::ELECTRON_MASS, [0, 1, 0, 0, 0, 0, 0], false;
+};
+
+/// Symbols that read as a prefixed unit but are as often written for
+/// another unit, with the readings they stand for.
+
+pub static AMBIGUOUS: &[(
+    &str,
+    &str,
+)] = &[
+    (
+        "Gs",
+        "gauss or gigasecond",
+    ),
+    (
+        "da",
+        "day or deci-annum",
+    ),
+];
+
+/// The energy equivalents [`convert_equivalent`] converts between, one per
+/// dimension, each in coherent SI units.
+
+const EQUIVALENTS: [EnergyUnit; 5] = [
+    EnergyUnit::Joule,
+    EnergyUnit::Hertz,
+    EnergyUnit::InverseMetre,
+    EnergyUnit::Kelvin,
+    EnergyUnit::Kilogram,
+];
+
+/// Resolves a single unit symbol, with an optional SI prefix.
+///
+/// An exact match takes precedence over a prefixed reading, so `"cd"` is
+/// the candela and `"Pa"` the pascal. The [`AMBIGUOUS`] symbols resolve
+/// to nothing.
+#[must_use]
+
+pub fn lookup(
+    symbol: &str
+) -> Option<Unit> {
+
+    if AMBIGUOUS
+        .iter()
+        .any(|&(ambiguous, _)| {
+
+            ambiguous == symbol
+        })
+    {
+
+        return None;
+    }
+
+    let find = |symbol: &str| {
+
+        UNITS
+            .iter()
+            .find(|entry| {
+
+                entry.symbol == symbol
+            })
+    };
+
+    if let Some(entry) = find(symbol) {
+
+        return Some(entry.unit);
+    }
+
+    PREFIXES
+        .iter()
+        .find_map(|(prefix, factor)| {
+
+            let entry = find(
+                symbol.strip_prefix(
+                    prefix,
+                )?,
+            )
+            .filter(|entry| {
+
+                entry.prefixable
+            })?;
+
+            Some(Unit::new(
+                factor
+                    * entry.unit.scale,
+                entry.unit.dimension,
+            ))
+        })
+}
+
+/// Parses unit notation (see [`super::notation`]) into a [`Unit`].
+///
+/// # Errors
+///
+/// Returns [`UnitError::Syntax`] if the notation is malformed,
+/// [`UnitError::UnknownUnit`] if it contains an unknown symbol and
+/// [`UnitError::Ambiguous`] if it contains one of the [`AMBIGUOUS`]
+/// symbols.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit;
+/// use rssn_advanced::unit::Dimension;
+///
+/// let mev =
+///     unit::parse("MeV").unwrap();
+///
+/// assert_eq!(
+///     mev.dimension,
+///     Dimension::ENERGY
+/// );
+///
+/// assert!(
+///     (mev.scale / 1.602_176_634e-13
+///         - 1.0)
+///         .abs()
+///         < 1e-15
+/// );
+/// ```
+
+pub fn parse(
+    notation: &str
+) -> Result<Unit, UnitError> {
+
+    let factors =
+        notation::factors(notation)?;
+
+    // A lone absolute temperature scale keeps its zero.
+    if let [
+        notation::Factor {
+            symbol,
+            exponent: 1,
+        },
+    ] = factors.as_slice()
+    {
+
+        return lookup(symbol)
+            .ok_or_else(|| {
+
+                unknown(symbol)
+            });
+    }
+
+    factors
+        .into_iter()
+        .try_fold(
+            Unit::ONE,
+            |unit, factor| {
+
+                let symbol = lookup(
+                    factor.symbol,
+                )
+                .ok_or_else(|| {
+
+                    unknown(
+                        factor.symbol,
+                    )
+                })?;
+
+                Ok(unit
+                    * symbol.powi(
+                        factor.exponent,
+                    ))
+            },
+        )
+}
+
+/// Returns the error for a symbol [`lookup`] does not resolve.
+
+fn unknown(symbol: &str) -> UnitError {
+
+    let symbol = symbol.to_string();
+
+    if AMBIGUOUS
+        .iter()
+        .any(|&(ambiguous, _)| {
+
+            ambiguous == symbol
+        })
+    {
+
+        UnitError::Ambiguous(symbol)
+    } else {
+
+        UnitError::UnknownUnit(symbol)
+    }
+}
+
+/// Converts `value` from the unit `from` to the unit `to`.
+///
+/// # Errors
+///
+/// Returns a [`UnitError`] if either unit cannot be parsed, or
+/// [`UnitError::Incompatible`] if their dimensions differ.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit;
+///
+/// let joules =
+///     unit::convert(1.0, "kWh", "J")
+///         .unwrap();
+///
+/// assert!(
+///     (joules - 3.6e6).abs() < 1e-6
+/// );
+///
+/// let kelvin =
+///     unit::convert(25.0, "°C", "K")
+///         .unwrap();
+///
+/// assert!(
+///     (kelvin - 298.15).abs() < 1e-12
+/// );
+///
+/// assert!(
+///     unit::convert(1.0, "eV", "m")
+///         .is_err()
+/// );
+/// ```
+
+pub fn convert(
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, UnitError> {
+
+    let from = parse(from)?;
+
+    let to = parse(to)?;
+
+    if from.dimension != to.dimension {
+
+        return Err(
+            UnitError::Incompatible {
+                expected: from
+                    .dimension,
+                found: to.dimension,
+            },
+        );
+    }
+
+    Ok(to.from_si(from.to_si(value)))
+}
+
+/// Converts `value` from the unit `from` to the unit `to`, also across the
+/// energy equivalences of spectroscopy.
+///
+/// Units of the same dimension convert as by [`convert`]. An energy, a
+/// frequency, a wavenumber, a temperature and a mass convert into one
+/// another through `E = hν = hc/λ = kT = mc²`, as by [`energy::convert`],
+/// so `"GHz"` converts to `"cm^-1"`.
+///
+/// # Errors
+///
+/// Returns a [`UnitError`] if either unit cannot be parsed, or
+/// [`UnitError::Incompatible`] if their dimensions differ and are not
+/// both energy equivalents.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit;
+///
+/// let wavenumber =
+///     unit::convert_equivalent(
+///         1.0,
+///         "GHz",
+///         "cm^-1",
+///     )
+///     .unwrap();
+///
+/// assert!(
+///     (wavenumber - 0.033_356_41)
+///         .abs()
+///         < 1e-9
+/// );
+///
+/// assert!(
+///     unit::convert_equivalent(
+///         1.0, "eV", "m"
+///     )
+///     .is_err()
+/// );
+/// ```
+
+pub fn convert_equivalent(
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, UnitError> {
+
+    let from = parse(from)?;
+
+    let to = parse(to)?;
+
+    let equivalent = |unit: Unit| {
+
+        EQUIVALENTS
+            .into_iter()
+            .find(|equivalent| {
+
+                equivalent.dimension()
+                    == unit.dimension
+            })
+    };
+
+    if from.dimension == to.dimension {
+
+        return Ok(to.from_si(
+            from.to_si(value),
+        ));
+    }
+
+    match (
+        equivalent(from),
+        equivalent(to),
+    ) {
+        | (
+            Some(from_equivalent),
+            Some(to_equivalent),
+        ) => {
+            Ok(to.from_si(
+                energy::convert(
+                    from.to_si(value),
+                    from_equivalent,
+                    to_equivalent,
+                )
+                .value,
+            ))
+        },
+        | _ => Err(
+            UnitError::Incompatible {
+                expected: from
+                    .dimension,
+                found: to.dimension,
+            },
+        ),
+    }
+}
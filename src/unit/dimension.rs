@@ -0,0 +1,450 @@
+//! Dimension vectors over the seven SI base quantities.
+
+use std::fmt;
+use std::ops::Div;
+use std::ops::Mul;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::notation;
+
+/// One of the seven SI base quantities.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum BaseDimension {
+    /// Length, measured in metres.
+    Length,
+    /// Mass, measured in kilograms.
+    Mass,
+    /// Time, measured in seconds.
+    Time,
+    /// Electric current, measured in amperes.
+    Current,
+    /// Thermodynamic temperature, measured in kelvins.
+    Temperature,
+    /// Amount of substance, measured in moles.
+    Amount,
+    /// Luminous intensity, measured in candelas.
+    LuminousIntensity,
+}
+
+impl BaseDimension {
+    /// Every base quantity, in the order of [`Dimension::exponents`].
+
+    pub const ALL: [Self; 7] = [
+        Self::Length,
+        Self::Mass,
+        Self::Time,
+        Self::Current,
+        Self::Temperature,
+        Self::Amount,
+        Self::LuminousIntensity,
+    ];
+
+    /// Returns the dimension symbol (`"L"`, `"M"`, `"T"`, `"I"`, `"Θ"`,
+    /// `"N"` or `"J"`).
+    #[must_use]
+
+    pub const fn symbol(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Length => "L",
+            | Self::Mass => "M",
+            | Self::Time => "T",
+            | Self::Current => "I",
+            | Self::Temperature => "Θ",
+            | Self::Amount => "N",
+            | Self::LuminousIntensity => {
+                "J"
+            },
+        }
+    }
+
+    /// Returns the symbol of the SI base unit (`"m"`, `"kg"`, `"s"`, ...).
+    #[must_use]
+
+    pub const fn si_unit(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Length => "m",
+            | Self::Mass => "kg",
+            | Self::Time => "s",
+            | Self::Current => "A",
+            | Self::Temperature => "K",
+            | Self::Amount => "mol",
+            | Self::LuminousIntensity => {
+                "cd"
+            },
+        }
+    }
+}
+
+/// The exponents of the seven SI base quantities in a physical dimension,
+/// e.g. `L³ M⁻¹ T⁻²` for the Newtonian constant of gravitation.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::Dimension;
+///
+/// let velocity = Dimension::LENGTH
+///     / Dimension::TIME;
+///
+/// assert_eq!(
+///     velocity * Dimension::MASS,
+///     Dimension::MOMENTUM
+/// );
+///
+/// assert_eq!(
+///     Dimension::ENERGY.to_string(),
+///     "L² M T⁻²"
+/// );
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Dimension([i8; 7]);
+
+impl Dimension {
+    /// `L T⁻²`.
+
+    pub const ACCELERATION: Self =
+        Self([1, 0, -2, 0, 0, 0, 0]);
+    /// `L² M T⁻¹`, e.g. joule second.
+
+    pub const ACTION: Self =
+        Self([2, 1, -1, 0, 0, 0, 0]);
+    /// `N`.
+
+    pub const AMOUNT: Self =
+        Self([0, 0, 0, 0, 0, 1, 0]);
+    /// `T I`, e.g. coulomb.
+
+    pub const CHARGE: Self =
+        Self([0, 0, 1, 1, 0, 0, 0]);
+    /// `I`.
+
+    pub const CURRENT: Self =
+        Self([0, 0, 0, 1, 0, 0, 0]);
+    /// A pure number.
+
+    pub const DIMENSIONLESS: Self =
+        Self([0, 0, 0, 0, 0, 0, 0]);
+    /// `L² M T⁻²`, e.g. joule.
+
+    pub const ENERGY: Self =
+        Self([2, 1, -2, 0, 0, 0, 0]);
+    /// `L M T⁻²`, e.g. newton.
+
+    pub const FORCE: Self =
+        Self([1, 1, -2, 0, 0, 0, 0]);
+    /// `T⁻¹`, e.g. hertz.
+
+    pub const FREQUENCY: Self =
+        Self([0, 0, -1, 0, 0, 0, 0]);
+    /// `L`.
+
+    pub const LENGTH: Self =
+        Self([1, 0, 0, 0, 0, 0, 0]);
+    /// `J`.
+
+    pub const LUMINOUS_INTENSITY: Self =
+        Self([0, 0, 0, 0, 0, 0, 1]);
+    /// `M`.
+
+    pub const MASS: Self =
+        Self([0, 1, 0, 0, 0, 0, 0]);
+    /// `L M T⁻¹`.
+
+    pub const MOMENTUM: Self =
+        Self([1, 1, -1, 0, 0, 0, 0]);
+    /// `L² M T⁻³`, e.g. watt.
+
+    pub const POWER: Self =
+        Self([2, 1, -3, 0, 0, 0, 0]);
+    /// `L⁻¹ M T⁻²`, e.g. pascal.
+
+    pub const PRESSURE: Self = Self([
+        -1, 1, -2, 0, 0, 0, 0,
+    ]);
+    /// `Θ`.
+
+    pub const TEMPERATURE: Self =
+        Self([0, 0, 0, 0, 1, 0, 0]);
+    /// `T`.
+
+    pub const TIME: Self =
+        Self([0, 0, 1, 0, 0, 0, 0]);
+    /// `L T⁻¹`.
+
+    pub const VELOCITY: Self =
+        Self([1, 0, -1, 0, 0, 0, 0]);
+    /// `L² M T⁻³ I⁻¹`, e.g. volt.
+
+    pub const VOLTAGE: Self = Self([
+        2, 1, -3, -1, 0, 0, 0,
+    ]);
+
+    /// Creates a dimension from the exponents of the base quantities, in
+    /// the order of [`BaseDimension::ALL`].
+    #[must_use]
+
+    pub const fn from_exponents(
+        exponents: [i8; 7]
+    ) -> Self {
+
+        Self(exponents)
+    }
+
+    /// Returns the exponents of the base quantities, in the order of
+    /// [`BaseDimension::ALL`].
+    #[must_use]
+
+    pub const fn exponents(
+        self
+    ) -> [i8; 7] {
+
+        self.0
+    }
+
+    /// Returns the exponent of one base quantity.
+    #[must_use]
+
+    pub const fn exponent(
+        self,
+        base: BaseDimension,
+    ) -> i8 {
+
+        let [
+            length,
+            mass,
+            time,
+            current,
+            temperature,
+            amount,
+            luminous_intensity,
+        ] = self.0;
+
+        match base {
+            | BaseDimension::Length => length,
+            | BaseDimension::Mass => mass,
+            | BaseDimension::Time => time,
+            | BaseDimension::Current => current,
+            | BaseDimension::Temperature => temperature,
+            | BaseDimension::Amount => amount,
+            | BaseDimension::LuminousIntensity => luminous_intensity,
+        }
+    }
+
+    /// Returns whether all exponents are zero.
+    #[must_use]
+
+    pub fn is_dimensionless(
+        self
+    ) -> bool {
+
+        self == Self::DIMENSIONLESS
+    }
+
+    /// Raises the dimension to an integer power.
+    #[must_use]
+
+    pub fn powi(
+        self,
+        n: i32,
+    ) -> Self {
+
+        let n = i8::try_from(n)
+            .unwrap_or(
+                if n < 0 {
+
+                    i8::MIN
+                } else {
+
+                    i8::MAX
+                },
+            );
+
+        self.map(|e| {
+
+            e.saturating_mul(n)
+        })
+    }
+
+    /// Returns the `n`-th root, or `None` if an exponent is not divisible
+    /// by `n` (e.g. the square root of a volume is not a dimension).
+    #[must_use]
+
+    pub fn root(
+        self,
+        n: i32,
+    ) -> Option<Self> {
+
+        let n = i8::try_from(n)
+            .ok()
+            .filter(|&n| n != 0)?;
+
+        let mut exponents = [0; 7];
+
+        for (out, e) in exponents
+            .iter_mut()
+            .zip(self.0)
+        {
+
+            if e.checked_rem(n)? != 0 {
+
+                return None;
+            }
+
+            *out = e.checked_div(n)?;
+        }
+
+        Some(Self(exponents))
+    }
+
+    /// Returns the reciprocal dimension.
+    #[must_use]
+
+    pub fn recip(self) -> Self {
+
+        self.map(i8::saturating_neg)
+    }
+
+    /// Formats the dimension in SI base units, e.g. `"m³ kg⁻¹ s⁻²"`,
+    /// or `"1"` for a pure number.
+    #[must_use]
+
+    pub fn si_units(&self) -> String {
+
+        self.format(
+            BaseDimension::si_unit,
+        )
+    }
+
+    fn map(
+        self,
+        f: impl Fn(i8) -> i8,
+    ) -> Self {
+
+        Self(self.0.map(f))
+    }
+
+    fn zip_with(
+        self,
+        other: Self,
+        f: impl Fn(i8, i8) -> i8,
+    ) -> Self {
+
+        let mut exponents = self.0;
+
+        for (out, e) in exponents
+            .iter_mut()
+            .zip(other.0)
+        {
+
+            *out = f(*out, e);
+        }
+
+        Self(exponents)
+    }
+
+    fn format(
+        self,
+        symbol: impl Fn(
+            BaseDimension,
+        )
+            -> &'static str,
+    ) -> String {
+
+        let factors: Vec<String> =
+            BaseDimension::ALL
+                .into_iter()
+                .zip(self.0)
+                .filter(|(_, e)| *e != 0)
+                .map(|(base, e)| {
+
+                    format!(
+                        "{}{}",
+                        symbol(base),
+                        notation::superscript(
+                            i32::from(e)
+                        )
+                    )
+                })
+                .collect();
+
+        if factors.is_empty() {
+
+            "1".to_string()
+        } else {
+
+            factors.join(" ")
+        }
+    }
+}
+
+impl Mul for Dimension {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        self.zip_with(
+            rhs,
+            i8::saturating_add,
+        )
+    }
+}
+
+impl Div for Dimension {
+    type Output = Self;
+
+    fn div(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        self.zip_with(
+            rhs,
+            i8::saturating_sub,
+        )
+    }
+}
+
+impl fmt::Display for Dimension {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(&self.format(
+            BaseDimension::symbol,
+        ))
+    }
+}
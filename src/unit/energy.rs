@@ -0,0 +1,379 @@
+//! Energy equivalences between the units of NIST's energy conversion
+//! table.
+//!
+//! Spectroscopy quotes energies as joules, electronvolts, frequencies,
+//! wavenumbers, temperatures, masses, atomic mass units or hartrees. These
+//! are not the same dimension, but each is tied to an energy by a
+//! constant: `E = eV`, `E = hν`, `E = hc/λ`, `E = kT`, `E = mc²`,
+//! `E = m_u c²` and `E = E_h`. [`factor`] evaluates any of the 8 × 8
+//! conversion factors from the constant table, propagating the
+//! correlated uncertainties of `m_u` and `E_h` with
+//! [`crate::constant::propagation`]; the other five constants are exact.
+//!
+//! The factors reproduce the "... relationship" entries of the CODATA
+//! listing, which [`nist_relationship`] looks up.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Dimension;
+use crate::constant;
+use crate::constant::Dual;
+use crate::constant::Measured;
+use crate::constant::PhysicalConstant;
+use crate::constant::propagation;
+
+/// A unit in which an energy can be expressed.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum EnergyUnit {
+    /// The joule, `J`.
+    Joule,
+    /// The electronvolt, `eV`, through `E = e × 1 V`.
+    ElectronVolt,
+    /// The hertz, `Hz`, through `E = hν`.
+    Hertz,
+    /// The inverse metre, `m⁻¹`, through `E = hc/λ`.
+    InverseMetre,
+    /// The kelvin, `K`, through `E = kT`.
+    Kelvin,
+    /// The kilogram, `kg`, through `E = mc²`.
+    Kilogram,
+    /// The unified atomic mass unit, `u`, through `E = m_u c²`.
+    AtomicMassUnit,
+    /// The hartree, `E_h`.
+    Hartree,
+}
+
+/// The constants an energy unit is tied to, in the order expected by
+/// [`EnergyUnit::joules`].
+
+const INPUTS: [&str; 6] = [
+    "ELEMENTARY_CHARGE",
+    "PLANCK_CONSTANT",
+    "SPEED_OF_LIGHT",
+    "BOLTZMANN_CONSTANT",
+    "ATOMIC_MASS_CONSTANT",
+    "HARTREE_ENERGY",
+];
+
+impl EnergyUnit {
+    /// Every energy unit, in the order of the rows and columns of
+    /// [`matrix`].
+
+    pub const ALL: [Self; 8] = [
+        Self::Joule,
+        Self::ElectronVolt,
+        Self::Hertz,
+        Self::InverseMetre,
+        Self::Kelvin,
+        Self::Kilogram,
+        Self::AtomicMassUnit,
+        Self::Hartree,
+    ];
+
+    /// Returns the unit symbol (`"J"`, `"eV"`, `"Hz"`, `"m⁻¹"`, `"K"`,
+    /// `"kg"`, `"u"` or `"E_h"`).
+    #[must_use]
+
+    pub const fn symbol(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Joule => "J",
+            | Self::ElectronVolt => {
+                "eV"
+            },
+            | Self::Hertz => "Hz",
+            | Self::InverseMetre => {
+                "m⁻¹"
+            },
+            | Self::Kelvin => "K",
+            | Self::Kilogram => "kg",
+            | Self::AtomicMassUnit => {
+                "u"
+            },
+            | Self::Hartree => "E_h",
+        }
+    }
+
+    /// Returns the name used by the CODATA listing, e.g. `"electron volt"`
+    /// in `"electron volt-hertz relationship"`.
+    #[must_use]
+
+    pub const fn nist_name(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Joule => "joule",
+            | Self::ElectronVolt => {
+                "electron volt"
+            },
+            | Self::Hertz => "hertz",
+            | Self::InverseMetre => {
+                "inverse meter"
+            },
+            | Self::Kelvin => "kelvin",
+            | Self::Kilogram => {
+                "kilogram"
+            },
+            | Self::AtomicMassUnit => {
+                "atomic mass unit"
+            },
+            | Self::Hartree => {
+                "hartree"
+            },
+        }
+    }
+
+    /// Returns the unit with the given symbol, accepting `m^-1` for the
+    /// inverse metre.
+    #[must_use]
+
+    pub fn from_symbol(
+        symbol: &str
+    ) -> Option<Self> {
+
+        match symbol.trim() {
+            | "m^-1" => {
+                Some(Self::InverseMetre)
+            },
+            | symbol => {
+                Self::ALL
+                    .into_iter()
+                    .find(|unit| {
+
+                        unit.symbol()
+                            == symbol
+                    })
+            },
+        }
+    }
+
+    /// Returns the dimension of the unit.
+    #[must_use]
+
+    pub const fn dimension(
+        self
+    ) -> Dimension {
+
+        match self {
+            | Self::Joule
+            | Self::ElectronVolt
+            | Self::Hartree => {
+                Dimension::ENERGY
+            },
+            | Self::Hertz => {
+                Dimension::FREQUENCY
+            },
+            | Self::InverseMetre => {
+                Dimension::from_exponents(
+                    [
+                        -1, 0, 0, 0, 0,
+                        0, 0,
+                    ],
+                )
+            },
+            | Self::Kelvin => {
+                Dimension::TEMPERATURE
+            },
+            | Self::Kilogram
+            | Self::AtomicMassUnit => {
+                Dimension::MASS
+            },
+        }
+    }
+
+    /// Returns the energy in joules of one of this unit, given the
+    /// constants of [`INPUTS`].
+
+    fn joules(
+        self,
+        inputs: &[Dual],
+    ) -> Option<Dual> {
+
+        let [e, h, c, k, m_u, e_h]: [Dual;
+            6] = inputs
+            .try_into()
+            .ok()?;
+
+        Some(match self {
+            | Self::Joule => {
+                Dual::constant(1.0)
+            },
+            | Self::ElectronVolt => e,
+            | Self::Hertz => h,
+            | Self::InverseMetre => {
+                h * c
+            },
+            | Self::Kelvin => k,
+            | Self::Kilogram => {
+                c.powi(2)
+            },
+            | Self::AtomicMassUnit => {
+                m_u * c.powi(2)
+            },
+            | Self::Hartree => e_h,
+        })
+    }
+}
+
+impl fmt::Display for EnergyUnit {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(self.symbol())
+    }
+}
+
+/// Returns the value in `to` of one `from`, with the uncertainty
+/// propagated from the constant table.
+///
+/// The factor between two units tied to exact constants is exact.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::EnergyUnit;
+/// use rssn_advanced::unit::energy;
+///
+/// // 1 eV corresponds to 8065.54... cm⁻¹.
+/// let wavenumber = energy::factor(
+///     EnergyUnit::ElectronVolt,
+///     EnergyUnit::InverseMetre,
+/// );
+///
+/// assert!(
+///     (wavenumber.value / 100.0 - 8065.544)
+///         .abs()
+///         < 1e-3
+/// );
+///
+/// assert!(wavenumber.is_exact());
+///
+/// // The hartree carries the uncertainty of the Rydberg constant.
+/// assert!(
+///     !energy::factor(
+///         EnergyUnit::Hartree,
+///         EnergyUnit::ElectronVolt,
+///     )
+///     .is_exact()
+/// );
+/// ```
+#[must_use]
+
+pub fn factor(
+    from: EnergyUnit,
+    to: EnergyUnit,
+) -> Measured {
+
+    if from == to {
+
+        return Measured::exact(1.0);
+    }
+
+    propagation::propagate(
+        &INPUTS,
+        |x| {
+
+            from.joules(x)
+                .zip(to.joules(x))
+                .map_or(
+                    Dual::constant(
+                        f64::NAN,
+                    ),
+                    |(from, to)| {
+
+                        from / to
+                    },
+                )
+        },
+    )
+    .unwrap_or(Measured::exact(
+        f64::NAN,
+    ))
+}
+
+/// Converts an energy equivalent `value` in `from` to `to`.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::EnergyUnit;
+/// use rssn_advanced::unit::energy;
+///
+/// // Room temperature is about 25 meV.
+/// let energy = energy::convert(
+///     300.0,
+///     EnergyUnit::Kelvin,
+///     EnergyUnit::ElectronVolt,
+/// );
+///
+/// assert!(
+///     (energy.value - 0.025_852)
+///         .abs()
+///         < 1e-6
+/// );
+/// ```
+#[must_use]
+
+pub fn convert(
+    value: f64,
+    from: EnergyUnit,
+    to: EnergyUnit,
+) -> Measured {
+
+    factor(from, to) * value
+}
+
+/// Returns the 8 × 8 table of conversion factors, where row `from` and
+/// column `to` hold the [`factor`] from `from` to `to`, both in the order of
+/// [`EnergyUnit::ALL`].
+#[must_use]
+
+pub fn matrix() -> [[Measured; 8]; 8] {
+
+    EnergyUnit::ALL.map(|from| {
+
+        EnergyUnit::ALL
+            .map(|to| factor(from, to))
+    })
+}
+
+/// Returns the entry of the CODATA listing for the conversion from `from`
+/// to `to`, e.g. the "electron volt-hertz relationship", or `None` when
+/// `from` and `to` are the same.
+#[must_use]
+
+pub fn nist_relationship(
+    from: EnergyUnit,
+    to: EnergyUnit,
+) -> Option<&'static PhysicalConstant> {
+
+    let name = format!(
+        "{}-{} relationship",
+        from.nist_name(),
+        to.nist_name()
+    );
+
+    constant::all()
+        .iter()
+        .find(|c| c.name == name)
+}
@@ -0,0 +1,137 @@
+//! Errors raised while parsing units or combining dimensioned quantities.
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::Dimension;
+use super::UnitSystem;
+
+/// An error raised while parsing a unit or combining quantities.
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum UnitError {
+    /// The notation is malformed.
+    Syntax {
+        /// The notation being parsed.
+        notation: String,
+        /// A description of the problem.
+        message: String,
+    },
+    /// A symbol is not a known unit, with or without an SI prefix.
+    UnknownUnit(String),
+    /// A symbol reads as more than one unit (e.g. `Gs`, gauss or
+    /// gigasecond).
+    Ambiguous(String),
+    /// Two dimensions that must agree do not.
+    Incompatible {
+        /// The dimension required by the operation.
+        expected: Dimension,
+        /// The dimension that was supplied.
+        found: Dimension,
+    },
+    /// A dimension has no unit in a system of units, e.g. `A m⁻¹` in
+    /// Gaussian units, where `H` and the magnetization differ.
+    NoUnit {
+        /// The dimension.
+        dimension: Dimension,
+        /// The system of units.
+        system: UnitSystem,
+    },
+    /// A root was taken of a dimension whose exponents it does not divide.
+    InvalidRoot {
+        /// The dimension of the radicand.
+        dimension: Dimension,
+        /// The index of the root.
+        index: i32,
+    },
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        match self {
+            | Self::Syntax {
+                notation,
+                message,
+            } => {
+
+                write!(
+                    f,
+                    "invalid unit \
+                     `{notation}`: \
+                     {message}"
+                )
+            },
+            | Self::UnknownUnit(
+                symbol,
+            ) => {
+
+                write!(
+                    f,
+                    "unknown unit \
+                     `{symbol}`"
+                )
+            },
+            | Self::Ambiguous(
+                symbol,
+            ) => {
+
+                write!(
+                    f,
+                    "ambiguous unit \
+                     `{symbol}`"
+                )
+            },
+            | Self::Incompatible {
+                expected,
+                found,
+            } => {
+
+                write!(
+                    f,
+                    "incompatible dimensions: expected {expected}, found {found}"
+                )
+            },
+            | Self::NoUnit {
+                dimension,
+                system,
+            } => {
+
+                write!(
+                    f,
+                    "{dimension} has \
+                     no unit in \
+                     {system}"
+                )
+            },
+            | Self::InvalidRoot {
+                dimension,
+                index,
+            } => {
+
+                write!(
+                    f,
+                    "root of index \
+                     {index} of \
+                     {dimension} is \
+                     not a dimension"
+                )
+            },
+        }
+    }
+}
+
+impl std::error::Error for UnitError {
+}
@@ -0,0 +1,43 @@
+//! Physical dimensions, unit notation and dimensioned quantities.
+//!
+//! The unit strings of the constant table (`"m³ kg⁻¹ s⁻²"`, `"J Hz⁻¹"`,
+//! `"MeV/c"`, ...) are parsed by [`notation`] and resolved by
+//! [`catalogue`] into a [`Unit`]: a [`Dimension`] over the seven SI base
+//! quantities and the factor to coherent SI units. [`convert`] converts
+//! values between units of the same dimension, and [`Quantity`] carries a
+//! value with its dimension and checks dimensions at runtime.
+//!
+//! [`energy`] converts between the energy equivalents of spectroscopy
+//! (`J`, `eV`, `Hz`, `m⁻¹`, `K`, `kg`, `u`, `E_h`), which differ in
+//! dimension and are tied by constants. [`UnitSystem`] expresses
+//! constants and quantities in atomic, natural, Planck or Gaussian CGS
+//! units. [`buckingham`] finds the dimensionless groups of a set of
+//! variables and the scaling laws they imply.
+
+/// Dimensional analysis by the Buckingham π theorem.
+pub mod buckingham;
+/// The unit symbols and SI prefixes, the unit parser and conversions.
+pub mod catalogue;
+/// Dimension vectors over the SI base quantities.
+pub mod dimension;
+/// Energy equivalences between `J`, `eV`, `Hz`, `m⁻¹`, `K`, `kg`, `u` and `E_h`.
+pub mod energy;
+/// Errors raised by unit parsing and dimension checks.
+pub mod error;
+/// Tokenizer for unit notation.
+pub mod notation;
+/// Dimensioned quantities and `uom` interop.
+pub mod quantity;
+/// Atomic, natural, Planck and Gaussian CGS systems of units.
+pub mod system;
+
+pub use catalogue::Unit;
+pub use catalogue::convert;
+pub use catalogue::convert_equivalent;
+pub use catalogue::parse;
+pub use dimension::BaseDimension;
+pub use dimension::Dimension;
+pub use energy::EnergyUnit;
+pub use error::UnitError;
+pub use quantity::Quantity;
+pub use system::UnitSystem;
@@ -0,0 +1,521 @@
+//! Parser for unit notation such as `"m³ kg⁻¹ s⁻²"` or `"(GeV/c²)⁻²"`.
+//!
+//! The notation is the one used by the unit column of the constant table:
+//! unit symbols separated by spaces, each followed by an optional exponent
+//! in Unicode superscripts (`m⁻¹`) or ASCII (`m^-1`). Factors may also be
+//! joined by `·` or `*`, grouped with parentheses, and divided with `/`.
+//! A `/` divides by the whole product that follows it, up to the next
+//! `/` or closing parenthesis, so `J/mol K` reads `J mol⁻¹ K⁻¹`.
+//!
+//! `"dimensionless"` and the empty string denote a pure number.
+
+use super::UnitError;
+
+/// A unit symbol raised to an integer power.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq,
+)]
+
+pub struct Factor<'a> {
+    /// The unit symbol, possibly with an SI prefix (e.g. `"MeV"`).
+    pub symbol: &'a str,
+    /// The exponent.
+    pub exponent: i32,
+}
+
+/// Splits unit notation into its factors, with parentheses and divisions
+/// folded into the exponents.
+///
+/// # Errors
+///
+/// Returns [`UnitError::Syntax`] if the notation is malformed.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::notation;
+/// use rssn_advanced::unit::notation::Factor;
+///
+/// assert_eq!(
+///     notation::factors("(GeV/c²)⁻²")
+///         .unwrap(),
+///     [
+///         Factor { symbol: "GeV", exponent: -2 },
+///         Factor { symbol: "c", exponent: 4 },
+///     ]
+/// );
+/// ```
+
+pub fn factors(
+    notation: &str
+) -> Result<Vec<Factor<'_>>, UnitError>
+{
+
+    let trimmed = notation.trim();
+
+    if trimmed.is_empty()
+        || trimmed == "dimensionless"
+    {
+
+        return Ok(Vec::new());
+    }
+
+    let mut parser = Parser {
+        notation,
+        rest: trimmed,
+        depth: 0,
+    };
+
+    let factors =
+        parser.expression()?;
+
+    match parser.peek() {
+        | None => Ok(factors),
+        | Some(')') => {
+            Err(parser.error(
+                "unbalanced `)`",
+            ))
+        },
+        | Some(c) => {
+            Err(
+                parser.error(&format!(
+                    "unexpected `{c}`"
+                )),
+            )
+        },
+    }
+}
+
+/// Formats an exponent in Unicode superscripts, or as the empty string
+/// for an exponent of one.
+#[must_use]
+
+pub fn superscript(
+    exponent: i32
+) -> String {
+
+    if exponent == 1 {
+
+        return String::new();
+    }
+
+    exponent
+        .to_string()
+        .chars()
+        .map(|c| {
+
+            match c {
+                | '-' => '⁻',
+                | '0' => '⁰',
+                | '1' => '¹',
+                | '2' => '²',
+                | '3' => '³',
+                | '4' => '⁴',
+                | '5' => '⁵',
+                | '6' => '⁶',
+                | '7' => '⁷',
+                | '8' => '⁸',
+                | _ => '⁹',
+            }
+        })
+        .collect()
+}
+
+/// Returns the value of a superscript digit.
+
+const fn superscript_digit(
+    c: char
+) -> Option<i32> {
+
+    match c {
+        | '⁰' => Some(0),
+        | '¹' => Some(1),
+        | '²' => Some(2),
+        | '³' => Some(3),
+        | '⁴' => Some(4),
+        | '⁵' => Some(5),
+        | '⁶' => Some(6),
+        | '⁷' => Some(7),
+        | '⁸' => Some(8),
+        | '⁹' => Some(9),
+        | _ => None,
+    }
+}
+
+/// Returns whether `c` ends a unit symbol.
+
+const fn is_delimiter(c: char) -> bool {
+
+    c.is_whitespace()
+        || matches!(
+            c,
+            '/' | '*'
+                | '·'
+                | '⋅'
+                | '^'
+                | '('
+                | ')'
+                | '⁻'
+                | '⁺'
+        )
+        || superscript_digit(c)
+            .is_some()
+}
+
+/// The deepest nesting of parentheses the parser accepts, which bounds its
+/// recursion.
+
+pub const MAX_DEPTH: usize = 64;
+
+/// A recursive-descent parser over the remaining input.
+
+struct Parser<'a> {
+    notation: &'a str,
+    rest: &'a str,
+    /// The number of open parentheses.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+
+        self.rest
+            .chars()
+            .next()
+    }
+
+    fn bump(&mut self) {
+
+        let mut chars =
+            self.rest.chars();
+
+        chars.next();
+
+        self.rest = chars.as_str();
+    }
+
+    fn skip_whitespace(&mut self) {
+
+        self.rest = self
+            .rest
+            .trim_start();
+    }
+
+    fn error(
+        &self,
+        message: &str,
+    ) -> UnitError {
+
+        UnitError::Syntax {
+            notation: self
+                .notation
+                .to_string(),
+            message: message
+                .to_string(),
+        }
+    }
+
+    /// `product ('/' product)*`
+
+    fn expression(
+        &mut self
+    ) -> Result<
+        Vec<Factor<'a>>,
+        UnitError,
+    > {
+
+        let mut factors =
+            self.product()?;
+
+        loop {
+
+            self.skip_whitespace();
+
+            if self.peek() != Some('/')
+            {
+
+                return Ok(factors);
+            }
+
+            self.bump();
+
+            factors.extend(scale(
+                self.product()?,
+                -1,
+                self,
+            )?);
+        }
+    }
+
+    /// `power (separator? power)*`
+
+    fn product(
+        &mut self
+    ) -> Result<
+        Vec<Factor<'a>>,
+        UnitError,
+    > {
+
+        let mut factors =
+            self.power()?;
+
+        loop {
+
+            self.skip_whitespace();
+
+            match self.peek() {
+                | None
+                | Some('/' | ')') => {
+
+                    return Ok(factors);
+                },
+                | Some(
+                    '*' | '·' | '⋅',
+                ) => self.bump(),
+                | Some(_) => {},
+            }
+
+            factors
+                .extend(self.power()?);
+        }
+    }
+
+    /// `primary exponent?`
+
+    fn power(
+        &mut self
+    ) -> Result<
+        Vec<Factor<'a>>,
+        UnitError,
+    > {
+
+        let factors = self.primary()?;
+
+        match self.exponent()? {
+            | Some(exponent) => {
+                scale(
+                    factors,
+                    exponent,
+                    self,
+                )
+            },
+            | None => Ok(factors),
+        }
+    }
+
+    /// `'(' expression ')' | symbol`
+
+    fn primary(
+        &mut self
+    ) -> Result<
+        Vec<Factor<'a>>,
+        UnitError,
+    > {
+
+        self.skip_whitespace();
+
+        if self.peek() == Some('(') {
+
+            if self.depth >= MAX_DEPTH {
+
+                return Err(self
+                    .error(
+                        "parentheses \
+                         nested too \
+                         deeply",
+                    ));
+            }
+
+            self.bump();
+
+            self.depth = self
+                .depth
+                .saturating_add(1);
+
+            let factors =
+                self.expression()?;
+
+            self.depth = self
+                .depth
+                .saturating_sub(1);
+
+            self.skip_whitespace();
+
+            if self.peek() != Some(')')
+            {
+
+                return Err(self
+                    .error(
+                        "unbalanced \
+                         `(`",
+                    ));
+            }
+
+            self.bump();
+
+            return Ok(factors);
+        }
+
+        let end = self
+            .rest
+            .find(is_delimiter)
+            .unwrap_or(self.rest.len());
+
+        let Some((symbol, rest)) = self
+            .rest
+            .split_at_checked(end)
+            .filter(|(symbol, _)| {
+
+                !symbol.is_empty()
+            })
+        else {
+
+            return Err(self.error(
+                "expected a unit \
+                 symbol",
+            ));
+        };
+
+        self.rest = rest;
+
+        Ok(vec![Factor {
+            symbol,
+            exponent: 1,
+        }])
+    }
+
+    /// `('⁻'|'⁺')? superscript+ | '^' ('-'|'+')? digit+`
+
+    fn exponent(
+        &mut self
+    ) -> Result<Option<i32>, UnitError>
+    {
+
+        let superscript = match self
+            .peek()
+        {
+            | Some('^') => {
+
+                self.bump();
+
+                self.skip_whitespace();
+
+                false
+            },
+            | Some('⁻' | '⁺') => true,
+            | Some(c)
+                if superscript_digit(c)
+                    .is_some() =>
+            {
+                true
+            },
+            | _ => return Ok(None),
+        };
+
+        let negative = match self.peek()
+        {
+            | Some('⁻' | '-') => {
+
+                self.bump();
+
+                true
+            },
+            | Some('⁺' | '+') => {
+
+                self.bump();
+
+                false
+            },
+            | _ => false,
+        };
+
+        let mut value: Option<i32> =
+            None;
+
+        while let Some(digit) =
+            self.peek().and_then(|c| {
+
+                if superscript {
+
+                    superscript_digit(c)
+                } else {
+
+                    c.to_digit(10)
+                        .and_then(|d| {
+
+                            i32::try_from(d)
+                                .ok()
+                        })
+                }
+            })
+        {
+
+            self.bump();
+
+            value = Some(
+                value
+                    .unwrap_or(0)
+                    .checked_mul(10)
+                    .and_then(|v| {
+
+                        v.checked_add(digit)
+                    })
+                    .ok_or_else(|| {
+
+                        self.error(
+                            "exponent out of range",
+                        )
+                    })?,
+            );
+        }
+
+        let value =
+            value.ok_or_else(|| {
+
+                self.error(
+                    "expected an \
+                     exponent",
+                )
+            })?;
+
+        Ok(Some(
+            if negative {
+
+                value.saturating_neg()
+            } else {
+
+                value
+            },
+        ))
+    }
+}
+
+/// Multiplies the exponents of `factors` by `by`.
+
+fn scale<'a>(
+    factors: Vec<Factor<'a>>,
+    by: i32,
+    parser: &Parser<'a>,
+) -> Result<Vec<Factor<'a>>, UnitError>
+{
+
+    factors
+        .into_iter()
+        .map(|factor| {
+
+            Ok(Factor {
+                symbol: factor.symbol,
+                exponent: factor
+                    .exponent
+                    .checked_mul(by)
+                    .ok_or_else(|| {
+
+                        parser.error(
+                            "exponent out of range",
+                        )
+                    })?,
+            })
+        })
+        .collect()
+}
@@ -0,0 +1,522 @@
+//! Values carrying a physical dimension, checked at runtime.
+//!
+//! A [`Quantity`] stores its value in coherent SI units. Multiplication,
+//! division and integer powers combine the dimensions; addition and
+//! subtraction require equal dimensions and return a [`UnitError`]
+//! otherwise, as do roots that would give fractional exponents.
+//!
+//! Quantities convert to and from the statically typed quantities of the
+//! [`uom`] crate in the SI system with `f64` storage (`uom::si::f64::*`),
+//! with the dimension checked on the way in.
+
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::ops::Sub;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uom::typenum::Integer;
+
+use super::Dimension;
+use super::UnitError;
+use super::catalogue;
+
+/// A value in coherent SI units together with its dimension.
+///
+/// # Examples
+///
+/// ```
+/// 
+/// use rssn_advanced::unit::Dimension;
+/// use rssn_advanced::unit::Quantity;
+///
+/// let distance =
+///     Quantity::with_unit(3.0, "km").unwrap();
+///
+/// let time =
+///     Quantity::new(120.0, Dimension::TIME);
+///
+/// let speed = distance / time;
+///
+/// assert_eq!(
+///     speed.dimension,
+///     Dimension::VELOCITY
+/// );
+///
+/// assert!((speed.value - 25.0).abs() < 1e-12);
+///
+/// // Lengths and times cannot be added.
+/// assert!((distance + time).is_err());
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Serialize,
+    Deserialize,
+)]
+
+pub struct Quantity {
+    /// The value in the coherent SI units of `dimension`.
+    pub value: f64,
+    /// The dimension.
+    pub dimension: Dimension,
+}
+
+impl Quantity {
+    /// Creates a quantity from a value in coherent SI units.
+    #[must_use]
+
+    pub const fn new(
+        value: f64,
+        dimension: Dimension,
+    ) -> Self {
+
+        Self {
+            value,
+            dimension,
+        }
+    }
+
+    /// Creates a pure number.
+    #[must_use]
+
+    pub const fn dimensionless(
+        value: f64
+    ) -> Self {
+
+        Self::new(
+            value,
+            Dimension::DIMENSIONLESS,
+        )
+    }
+
+    /// Creates a quantity from a value in the unit given by `notation`
+    /// (e.g. `"MeV"` or `"m³ kg⁻¹ s⁻²"`).
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] if the notation cannot be parsed.
+
+    pub fn with_unit(
+        value: f64,
+        notation: &str,
+    ) -> Result<Self, UnitError> {
+
+        let unit =
+            catalogue::parse(notation)?;
+
+        Ok(Self::new(
+            unit.to_si(value),
+            unit.dimension,
+        ))
+    }
+
+    /// Returns the value expressed in the unit given by `notation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`UnitError`] if the notation cannot be parsed or has a
+    /// different dimension.
+
+    pub fn value_in(
+        &self,
+        notation: &str,
+    ) -> Result<f64, UnitError> {
+
+        let unit =
+            catalogue::parse(notation)?;
+
+        self.require(unit.dimension)?;
+
+        Ok(unit.from_si(self.value))
+    }
+
+    /// Returns whether the quantity is a pure number.
+    #[must_use]
+
+    pub fn is_dimensionless(
+        &self
+    ) -> bool {
+
+        self.dimension
+            .is_dimensionless()
+    }
+
+    /// Returns an error unless the quantity has the dimension `expected`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::Incompatible`] if the dimensions differ.
+
+    pub fn require(
+        &self,
+        expected: Dimension,
+    ) -> Result<(), UnitError> {
+
+        if self.dimension == expected {
+
+            Ok(())
+        } else {
+
+            Err(UnitError::Incompatible {
+                expected,
+                found: self.dimension,
+            })
+        }
+    }
+
+    /// Raises to an integer power.
+    #[must_use]
+
+    pub fn powi(
+        self,
+        n: i32,
+    ) -> Self {
+
+        Self::new(
+            self.value.powi(n),
+            self.dimension
+                .powi(n),
+        )
+    }
+
+    /// Returns the `n`-th root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::InvalidRoot`] if the root of the dimension
+    /// would have fractional exponents.
+
+    pub fn root(
+        self,
+        n: i32,
+    ) -> Result<Self, UnitError> {
+
+        let dimension = self
+            .dimension
+            .root(n)
+            .ok_or(
+                UnitError::InvalidRoot {
+                    dimension: self
+                        .dimension,
+                    index: n,
+                },
+            )?;
+
+        let value = match n {
+            | 2 => self.value.sqrt(),
+            | 3 => self.value.cbrt(),
+            | _ => {
+                self.value.powf(
+                    f64::from(n)
+                        .recip(),
+                )
+            },
+        };
+
+        Ok(Self::new(
+            value,
+            dimension,
+        ))
+    }
+
+    /// Returns the square root.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::InvalidRoot`] unless every exponent is even.
+
+    pub fn sqrt(
+        self
+    ) -> Result<Self, UnitError> {
+
+        self.root(2)
+    }
+
+    /// Returns the reciprocal.
+    #[must_use]
+
+    pub fn recip(self) -> Self {
+
+        Self::new(
+            self.value.recip(),
+            self.dimension
+                .recip(),
+        )
+    }
+
+    /// Converts to a statically typed `uom` quantity in the SI system.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::Incompatible`] if the dimension differs from
+    /// the dimension of `D`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::unit::Quantity;
+    /// use uom::si::energy::electronvolt;
+    /// use uom::si::f64::Energy;
+    ///
+    /// let energy: Energy =
+    ///     Quantity::with_unit(13.6, "eV")
+    ///         .unwrap()
+    ///         .to_uom()
+    ///         .unwrap();
+    ///
+    /// assert!(
+    ///     (energy.get::<electronvolt>()
+    ///         - 13.6)
+    ///         .abs()
+    ///         < 1e-6
+    /// );
+    /// ```
+
+    pub fn to_uom<D>(
+        self
+    ) -> Result<
+        uom::si::Quantity<
+            D,
+            uom::si::SI<f64>,
+            f64,
+        >,
+        UnitError,
+    >
+    where
+        D: uom::si::Dimension + ?Sized,
+    {
+
+        self.require(
+            uom_dimension::<D>(),
+        )?;
+
+        Ok(uom::si::Quantity {
+            dimension: PhantomData,
+            units: PhantomData,
+            value: self.value,
+        })
+    }
+}
+
+/// Returns the dimension of a `uom` SI dimension type.
+
+const fn uom_dimension<D>() -> Dimension
+where
+    D: uom::si::Dimension + ?Sized,
+{
+
+    Dimension::from_exponents([
+        D::L::I8,
+        D::M::I8,
+        D::T::I8,
+        D::I::I8,
+        D::Th::I8,
+        D::N::I8,
+        D::J::I8,
+    ])
+}
+
+impl<D>
+    From<
+        uom::si::Quantity<
+            D,
+            uom::si::SI<f64>,
+            f64,
+        >,
+    > for Quantity
+where
+    D: uom::si::Dimension + ?Sized,
+{
+    fn from(
+        quantity: uom::si::Quantity<
+            D,
+            uom::si::SI<f64>,
+            f64,
+        >
+    ) -> Self {
+
+        Self::new(
+            quantity.value,
+            uom_dimension::<D>(),
+        )
+    }
+}
+
+impl From<f64> for Quantity {
+    fn from(value: f64) -> Self {
+
+        Self::dimensionless(value)
+    }
+}
+
+impl fmt::Display for Quantity {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        fmt::Display::fmt(
+            &self.value,
+            f,
+        )?;
+
+        if self.is_dimensionless() {
+
+            Ok(())
+        } else {
+
+            write!(
+                f,
+                " {}",
+                self.dimension
+                    .si_units()
+            )
+        }
+    }
+}
+
+impl Neg for Quantity {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+
+        Self::new(
+            -self.value,
+            self.dimension,
+        )
+    }
+}
+
+impl Add for Quantity {
+    type Output =
+        Result<Self, UnitError>;
+
+    fn add(
+        self,
+        rhs: Self,
+    ) -> Self::Output {
+
+        rhs.require(self.dimension)?;
+
+        Ok(Self::new(
+            self.value + rhs.value,
+            self.dimension,
+        ))
+    }
+}
+
+impl Sub for Quantity {
+    type Output =
+        Result<Self, UnitError>;
+
+    fn sub(
+        self,
+        rhs: Self,
+    ) -> Self::Output {
+
+        rhs.require(self.dimension)?;
+
+        Ok(Self::new(
+            self.value - rhs.value,
+            self.dimension,
+        ))
+    }
+}
+
+impl Mul for Quantity {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self::new(
+            self.value * rhs.value,
+            self.dimension
+                * rhs.dimension,
+        )
+    }
+}
+
+impl Div for Quantity {
+    type Output = Self;
+
+    fn div(
+        self,
+        rhs: Self,
+    ) -> Self {
+
+        Self::new(
+            self.value / rhs.value,
+            self.dimension
+                / rhs.dimension,
+        )
+    }
+}
+
+impl Mul<f64> for Quantity {
+    type Output = Self;
+
+    fn mul(
+        self,
+        rhs: f64,
+    ) -> Self {
+
+        Self::new(
+            self.value * rhs,
+            self.dimension,
+        )
+    }
+}
+
+impl Mul<Quantity> for f64 {
+    type Output = Quantity;
+
+    fn mul(
+        self,
+        rhs: Quantity,
+    ) -> Quantity {
+
+        rhs * self
+    }
+}
+
+impl Div<f64> for Quantity {
+    type Output = Self;
+
+    fn div(
+        self,
+        rhs: f64,
+    ) -> Self {
+
+        Self::new(
+            self.value / rhs,
+            self.dimension,
+        )
+    }
+}
+
+impl Div<Quantity> for f64 {
+    type Output = Quantity;
+
+    fn div(
+        self,
+        rhs: Quantity,
+    ) -> Quantity {
+
+        Quantity::new(
+            self / rhs.value,
+            rhs.dimension
+                .recip(),
+        )
+    }
+}
@@ -0,0 +1,781 @@
+//! Systems of units other than the SI: Hartree atomic units, natural
+//! units, Planck units and Gaussian CGS units.
+//!
+//! Each [`UnitSystem`] fixes a unit for each SI base quantity, taken from
+//! the constant table (`a₀`, `m_e`, `ħ/E_h`, ... for atomic units), so
+//! that the unit of any [`Dimension`] is the corresponding product of
+//! powers. A value in the system is the SI value divided by that unit.
+//!
+//! The electromagnetic units of the atomic, natural and Planck systems
+//! follow the SI form of the equations with the charge unit chosen so that
+//! `4πε₀ = 1` (atomic and Planck units) or `ε₀ = 1` (natural units, in the
+//! Heaviside-Lorentz convention).
+//!
+//! Gaussian units build the electric quantities the same way, on the
+//! statcoulomb, but write the magnetic ones with a factor `c` (`F = q(E +
+//! v×B/c)`), so the magnetic field, flux, vector potential and moment and
+//! the quantities per tesla take the gauss-based units of
+//! [`GAUSSIAN_MAGNETIC`]. Gaussian units also give a field and its source
+//! different units where the SI gives them one dimension (`H` and the
+//! magnetization `M` in `A m⁻¹`, `D` and the polarization `P` in `C m⁻²`),
+//! so those dimensions, and any other carrying a current that is not
+//! listed as electric or magnetic, have no Gaussian unit and are refused
+//! with [`UnitError::NoUnit`].
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::BaseDimension;
+use super::Dimension;
+use super::Quantity;
+use super::UnitError;
+use super::notation;
+use crate::constant;
+use crate::constant::PhysicalConstant;
+
+/// The energy unit of [`UnitSystem::Natural`], one gigaelectronvolt.
+
+const NATURAL_ENERGY: f64 =
+    1e9 * constant::ELECTRON_VOLT;
+
+/// The dimensions carrying a current whose Gaussian unit is built on the
+/// statcoulomb like its SI-form unit: charge, current, potential, field
+/// and field gradient, resistance, conductance, capacitance, inductance,
+/// permittivity, charge and current densities, electric multipole moments
+/// and polarizabilities, the Josephson constant and the Faraday constant.
+
+const GAUSSIAN_ELECTRIC: [Dimension;
+    22] = [
+    Dimension::CHARGE,
+    Dimension::CURRENT,
+    Dimension::VOLTAGE,
+    Dimension::from_exponents([
+        1, 1, -3, -1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        0, 1, -3, -1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        2, 1, -3, -2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -2, -1, 3, 2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        3, 1, -3, -2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -3, -1, 3, 2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -2, -1, 4, 2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        2, 1, -2, -2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -3, -1, 4, 2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -3, 0, 1, 1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -1, 0, 1, 1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -2, 0, 0, 1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        1, 0, 1, 1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        2, 0, 1, 1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        0, -1, 4, 2, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -1, -2, 7, 3, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -2, -3, 10, 4, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        -2, -1, 2, 1, 0, 0, 0,
+    ]),
+    Dimension::from_exponents([
+        0, 0, 1, 1, 0, -1, 0,
+    ]),
+];
+
+/// The magnetic dimensions of Gaussian units, each with the power of `c`
+/// that its SI-form value is multiplied by and its unit symbol.
+
+pub const GAUSSIAN_MAGNETIC: [(
+    Dimension,
+    i32,
+    &str,
+); 7] = [
+    // Magnetic field B.
+    (
+        Dimension::from_exponents([
+            0, 1, -2, -1, 0, 0, 0,
+        ]),
+        1,
+        "G",
+    ),
+    // Vector potential.
+    (
+        Dimension::from_exponents([
+            1, 1, -2, -1, 0, 0, 0,
+        ]),
+        1,
+        "G cm",
+    ),
+    // Magnetic flux.
+    (
+        Dimension::from_exponents([
+            2, 1, -2, -1, 0, 0, 0,
+        ]),
+        1,
+        "Mx",
+    ),
+    // Magnetic moment.
+    (
+        Dimension::from_exponents([
+            2, 0, 0, 1, 0, 0, 0,
+        ]),
+        -1,
+        "erg G⁻¹",
+    ),
+    // Magnetizability.
+    (
+        Dimension::from_exponents([
+            2, -1, 2, 2, 0, 0, 0,
+        ]),
+        -2,
+        "erg G⁻²",
+    ),
+    // Wavenumber per tesla.
+    (
+        Dimension::from_exponents([
+            -1, -1, 2, 1, 0, 0, 0,
+        ]),
+        -1,
+        "cm⁻¹ G⁻¹",
+    ),
+    // Temperature per tesla.
+    (
+        Dimension::from_exponents([
+            0, -1, 2, 1, 1, 0, 0,
+        ]),
+        -1,
+        "K G⁻¹",
+    ),
+];
+
+/// A system of units.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+
+pub enum UnitSystem {
+    /// The International System of Units.
+    Si,
+    /// Hartree atomic units: `ħ = m_e = e = 4πε₀ = 1`, with the unit of
+    /// length `a₀` and of energy `E_h`. Temperatures are in `E_h/k`.
+    Atomic,
+    /// Natural units of particle physics: `ħ = c = ε₀ = k = 1`, with every
+    /// dimension a power of `GeV`.
+    Natural,
+    /// Planck units: `ħ = c = G = 4πε₀ = k = 1`.
+    Planck,
+    /// Gaussian CGS units: centimetre, gram, second and the
+    /// statcoulomb, for which `4πε₀ = 1`.
+    GaussianCgs,
+}
+
+impl UnitSystem {
+    /// Every unit system.
+
+    pub const ALL: [Self; 5] = [
+        Self::Si,
+        Self::Atomic,
+        Self::Natural,
+        Self::Planck,
+        Self::GaussianCgs,
+    ];
+
+    /// Returns the name of the system.
+    #[must_use]
+
+    pub const fn name(
+        self
+    ) -> &'static str {
+
+        match self {
+            | Self::Si => "SI",
+            | Self::Atomic => {
+                "Hartree atomic units"
+            },
+            | Self::Natural => {
+                "natural units"
+            },
+            | Self::Planck => {
+                "Planck units"
+            },
+            | Self::GaussianCgs => {
+                "Gaussian CGS"
+            },
+        }
+    }
+
+    /// Returns the SI value of the unit of each base quantity, in the
+    /// order of [`BaseDimension::ALL`].
+    ///
+    /// Amount of substance and luminous intensity keep their SI units in
+    /// every system.
+    #[must_use]
+
+    pub fn base_units(
+        self
+    ) -> [f64; 7] {
+
+        // ħ from the exact h: the listed ħ is truncated to ten digits.
+        let hbar =
+            constant::PLANCK_CONSTANT
+                / std::f64::consts::TAU;
+
+        let hbar_c = hbar
+            * constant::SPEED_OF_LIGHT;
+
+        let four_pi_epsilon_0 = 4.0
+            * std::f64::consts::PI
+            * constant::VACUUM_ELECTRIC_PERMITTIVITY;
+
+        match self {
+            | Self::Si => [1.0; 7],
+            | Self::Atomic => {
+
+                let time = hbar
+                    / constant::HARTREE_ENERGY;
+
+                [
+                    constant::BOHR_RADIUS,
+                    constant::ELECTRON_MASS,
+                    time,
+                    constant::ELEMENTARY_CHARGE
+                        / time,
+                    constant::HARTREE_ENERGY
+                        / constant::BOLTZMANN_CONSTANT,
+                    1.0,
+                    1.0,
+                ]
+            },
+            | Self::Natural => {
+
+                let time = hbar
+                    / NATURAL_ENERGY;
+
+                [
+                    hbar_c
+                        / NATURAL_ENERGY,
+                    NATURAL_ENERGY
+                        / constant::SPEED_OF_LIGHT
+                            .powi(2),
+                    time,
+                    (constant::VACUUM_ELECTRIC_PERMITTIVITY
+                        * hbar_c)
+                        .sqrt()
+                        / time,
+                    NATURAL_ENERGY
+                        / constant::BOLTZMANN_CONSTANT,
+                    1.0,
+                    1.0,
+                ]
+            },
+            | Self::Planck => {
+                [
+                    constant::PLANCK_LENGTH,
+                    constant::PLANCK_MASS,
+                    constant::PLANCK_TIME,
+                    (four_pi_epsilon_0
+                        * hbar_c)
+                        .sqrt()
+                        / constant::PLANCK_TIME,
+                    constant::PLANCK_TEMPERATURE,
+                    1.0,
+                    1.0,
+                ]
+            },
+            | Self::GaussianCgs => {
+
+                // One statcoulomb repels another at 1 cm with 1 dyn.
+                let statcoulomb = (four_pi_epsilon_0
+                    * 1e-5)
+                    .sqrt()
+                    * 1e-2;
+
+                [
+                    1e-2,
+                    1e-3,
+                    1.0,
+                    statcoulomb,
+                    1.0,
+                    1.0,
+                    1.0,
+                ]
+            },
+        }
+    }
+
+    /// Returns the SI value of the unit of `dimension` in this system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::constant;
+    /// use rssn_advanced::unit::Dimension;
+    /// use rssn_advanced::unit::UnitSystem;
+    ///
+    /// // The atomic unit of energy is the hartree.
+    /// let hartree = UnitSystem::Atomic
+    ///     .unit(Dimension::ENERGY)?;
+    ///
+    /// assert!(
+    ///     (hartree / constant::HARTREE_ENERGY - 1.0).abs()
+    ///         < 1e-12
+    /// );
+    ///
+    /// // The Gaussian unit of energy is the erg, and of a magnetic field
+    /// // the gauss.
+    /// assert!(
+    ///     (UnitSystem::GaussianCgs.unit(Dimension::ENERGY)? - 1e-7)
+    ///         .abs()
+    ///         < 1e-20
+    /// );
+    ///
+    /// let tesla = Dimension::MASS
+    ///     / Dimension::TIME.powi(2)
+    ///     / Dimension::CURRENT;
+    ///
+    /// assert!(
+    ///     (UnitSystem::GaussianCgs.unit(tesla)? / 1e-4 - 1.0).abs()
+    ///         < 1e-9
+    /// );
+    /// # Ok::<(), rssn_advanced::unit::UnitError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NoUnit`] for a Gaussian dimension that names
+    /// several quantities with different units, or carries a current and
+    /// is neither electric nor magnetic.
+
+    pub fn unit(
+        self,
+        dimension: Dimension,
+    ) -> Result<f64, UnitError> {
+
+        let si_form: f64 = self
+            .base_units()
+            .into_iter()
+            .zip(dimension.exponents())
+            .map(|(unit, exponent)| {
+
+                unit.powi(i32::from(
+                    exponent,
+                ))
+            })
+            .product();
+
+        match self.gaussian_magnetic(
+            dimension,
+        )? {
+            | Some((power, _)) => {
+                Ok(si_form
+                    / (constant::SPEED_OF_LIGHT
+                        * 1e2)
+                        .powi(power))
+            },
+            | None => Ok(si_form),
+        }
+    }
+
+    /// Returns the power of `c` and the symbol of the Gaussian magnetic
+    /// unit of `dimension`, or `None` if its unit is built like the SI
+    /// form.
+
+    fn gaussian_magnetic(
+        self,
+        dimension: Dimension,
+    ) -> Result<
+        Option<(i32, &'static str)>,
+        UnitError,
+    > {
+
+        if self != Self::GaussianCgs
+            || dimension.exponent(
+                BaseDimension::Current,
+            ) == 0
+            || GAUSSIAN_ELECTRIC
+                .contains(&dimension)
+        {
+
+            return Ok(None);
+        }
+
+        GAUSSIAN_MAGNETIC
+            .iter()
+            .find(|(magnetic, ..)| {
+
+                *magnetic == dimension
+            })
+            .map(
+                |&(
+                    _,
+                    power,
+                    symbol,
+                )| {
+
+                    Some((
+                        power,
+                        symbol,
+                    ))
+                },
+            )
+            .ok_or(UnitError::NoUnit {
+                dimension,
+                system: self,
+            })
+    }
+
+    /// Returns the value of an SI quantity in this system.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NoUnit`] if the dimension of `quantity` has no
+    /// unit in this system, see [`Self::unit`].
+
+    pub fn from_si(
+        self,
+        quantity: &Quantity,
+    ) -> Result<f64, UnitError> {
+
+        Ok(quantity.value
+            / self.unit(
+                quantity.dimension,
+            )?)
+    }
+
+    /// Returns the SI quantity of dimension `dimension` whose value in
+    /// this system is `value`.
+    ///
+    /// The dimension is needed because natural and Planck units do not
+    /// tell apart, say, a length from a time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::unit::Dimension;
+    /// use rssn_advanced::unit::UnitSystem;
+    ///
+    /// // 1 GeV⁻¹ of length is 0.197 fm.
+    /// let length = UnitSystem::Natural
+    ///     .to_si(
+    ///         1.0,
+    ///         Dimension::LENGTH,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert!(
+    ///     (length
+    ///         .value_in("fm")
+    ///         .unwrap()
+    ///         - 0.197_327)
+    ///         .abs()
+    ///         < 1e-6
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NoUnit`] if `dimension` has no unit in this
+    /// system, see [`Self::unit`].
+
+    pub fn to_si(
+        self,
+        value: f64,
+        dimension: Dimension,
+    ) -> Result<Quantity, UnitError>
+    {
+
+        Ok(Quantity::new(
+            value
+                * self
+                    .unit(dimension)?,
+            dimension,
+        ))
+    }
+
+    /// Returns the value of a constant in this system, or `None` if its
+    /// unit cannot be parsed or has no unit in this system.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// 
+    /// use rssn_advanced::constant;
+    /// use rssn_advanced::unit::UnitSystem;
+    ///
+    /// // In atomic units the speed of light is 1/α.
+    /// let c = UnitSystem::Atomic
+    ///     .express(
+    ///         constant::lookup("SPEED_OF_LIGHT")
+    ///             .unwrap(),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert!(
+    ///     (c - 137.035_999).abs() < 1e-5
+    /// );
+    /// ```
+    #[must_use]
+
+    pub fn express(
+        self,
+        constant: &PhysicalConstant,
+    ) -> Option<f64> {
+
+        constant
+            .quantity()
+            .and_then(|quantity| {
+
+                self.from_si(&quantity)
+                    .ok()
+            })
+    }
+
+    /// Formats the unit of `dimension` in this system, e.g. `"a₀² m_e"`
+    /// in atomic units, `"GeV⁻¹"` in natural units or `"G"` for a Gaussian
+    /// magnetic field, or `"1"` for a pure number.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::NoUnit`] if `dimension` has no unit in this
+    /// system, see [`Self::unit`].
+
+    pub fn unit_symbol(
+        self,
+        dimension: Dimension,
+    ) -> Result<String, UnitError> {
+
+        if let Some((_, symbol)) = self
+            .gaussian_magnetic(
+                dimension,
+            )?
+        {
+
+            return Ok(
+                symbol.to_string()
+            );
+        }
+
+        let symbols = match self {
+            | Self::Si => {
+
+                return Ok(dimension
+                    .si_units());
+            },
+            | Self::Natural => {
+
+                return Ok(
+                    natural_symbol(
+                        dimension,
+                    ),
+                );
+            },
+            | Self::Atomic => {
+                [
+                    "a₀",
+                    "m_e",
+                    "ħ/E_h",
+                    "e E_h/ħ",
+                    "E_h/k",
+                    "mol",
+                    "cd",
+                ]
+            },
+            | Self::Planck => {
+                [
+                    "l_P",
+                    "m_P",
+                    "t_P",
+                    "q_P/t_P",
+                    "T_P",
+                    "mol",
+                    "cd",
+                ]
+            },
+            | Self::GaussianCgs => {
+                [
+                    "cm",
+                    "g",
+                    "s",
+                    "statA",
+                    "K",
+                    "mol",
+                    "cd",
+                ]
+            },
+        };
+
+        Ok(format_units(
+            symbols
+                .into_iter()
+                .zip(
+                    dimension
+                        .exponents(),
+                )
+                .map(
+                    |(symbol, exponent)| {
+
+                        (
+                            symbol,
+                            i32::from(
+                                exponent,
+                            ),
+                        )
+                    },
+                ),
+        ))
+    }
+}
+
+impl fmt::Display for UnitSystem {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+
+        f.write_str(self.name())
+    }
+}
+
+/// Formats the natural unit of `dimension`: the mechanical, electric and
+/// thermal exponents collapse into a single power of `GeV`.
+
+fn natural_symbol(
+    dimension: Dimension
+) -> String {
+
+    let exponent = |base| {
+
+        i32::from(
+            dimension.exponent(base),
+        )
+    };
+
+    let energy = exponent(
+        BaseDimension::Mass,
+    )
+    .saturating_sub(exponent(
+        BaseDimension::Length,
+    ))
+    .saturating_sub(exponent(
+        BaseDimension::Time,
+    ))
+    .saturating_add(exponent(
+        BaseDimension::Current,
+    ))
+    .saturating_add(exponent(
+        BaseDimension::Temperature,
+    ));
+
+    format_units([
+        ("GeV", energy),
+        (
+            "mol",
+            exponent(
+                BaseDimension::Amount,
+            ),
+        ),
+        (
+            "cd",
+            exponent(
+                BaseDimension::LuminousIntensity,
+            ),
+        ),
+    ])
+}
+
+/// Joins unit symbols raised to their exponents. Compound symbols such as
+/// `ħ/E_h` are parenthesized unless they stand alone with exponent one.
+
+fn format_units<'a>(
+    factors: impl IntoIterator<
+        Item = (&'a str, i32),
+    >
+) -> String {
+
+    let factors: Vec<(&str, i32)> =
+        factors
+            .into_iter()
+            .filter(|(_, exponent)| {
+
+                *exponent != 0
+            })
+            .collect();
+
+    let alone = factors.len() == 1;
+
+    let formatted: Vec<String> =
+        factors
+            .into_iter()
+            .map(|(symbol, exponent)| {
+
+                let compound = symbol
+                    .contains([' ', '/']);
+
+                if compound
+                    && !(alone
+                        && exponent == 1)
+                {
+
+                    format!(
+                        "({symbol}){}",
+                        notation::superscript(
+                            exponent
+                        )
+                    )
+                } else {
+
+                    format!(
+                        "{symbol}{}",
+                        notation::superscript(
+                            exponent
+                        )
+                    )
+                }
+            })
+            .collect();
+
+    if formatted.is_empty() {
+
+        "1".to_string()
+    } else {
+
+        formatted.join(" ")
+    }
+}
@@ -0,0 +1,477 @@
+use std::collections::HashSet;
+
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::astro;
+use rssn_advanced::astro::AstroError;
+use rssn_advanced::astro::AstroGroup;
+use rssn_advanced::astro::TimeScale;
+use rssn_advanced::astro::time;
+use rssn_advanced::constant;
+
+#[test]
+
+fn test_registry() {
+
+    let ids: HashSet<_> = astro::all()
+        .iter()
+        .map(|c| c.id)
+        .collect();
+
+    assert_eq!(
+        ids.len(),
+        astro::all().len()
+    );
+
+    for record in astro::all() {
+
+        assert!(
+            record
+                .dimension()
+                .is_some(),
+            "{}: {}",
+            record.id,
+            record.unit
+        );
+
+        assert_eq!(
+            record.exact,
+            record.uncertainty == 0.0
+        );
+
+        assert_eq!(
+            record
+                .measured()
+                .value,
+            record.value
+        );
+    }
+
+    assert!(
+        astro::in_group(
+            AstroGroup::Nominal
+        )
+        .all(|c| c.exact)
+    );
+
+    assert_eq!(
+        astro::lookup("solar_mass")
+            .unwrap()
+            .symbol,
+        "M_☉"
+    );
+
+    assert!(
+        astro::lookup("SOLAR_MASSES")
+            .is_none()
+    );
+
+    assert_eq!(
+        astro::get_astronomical_unit(),
+        149_597_870_700.0
+    );
+}
+
+#[test]
+
+fn test_relations() {
+
+    assert_eq!(
+        astro::LIGHT_YEAR,
+        9_460_730_472_580_800.0
+    );
+
+    assert_eq!(
+        astro::LIGHT_YEAR,
+        constant::SPEED_OF_LIGHT
+            * astro::JULIAN_YEAR
+    );
+
+    assert_approx_eq!(
+        astro::PARSEC,
+        3.085_677_581_491_367e16,
+        1e2
+    );
+
+    assert_approx_eq!(
+        astro::ASTRONOMICAL_UNIT_LIGHT_TIME,
+        499.004_783_836,
+        1e-9
+    );
+
+    assert_eq!(
+        astro::JULIAN_CENTURY,
+        36_525.0 * astro::JULIAN_DAY
+    );
+
+    // Masses carry the relative uncertainty of G.
+    let g = constant::lookup(
+        "GRAVITATIONAL_CONSTANT",
+    )
+    .unwrap();
+
+    for id in [
+        "SOLAR_MASS",
+        "EARTH_MASS",
+        "JUPITER_MASS",
+    ] {
+
+        let mass =
+            astro::lookup(id).unwrap();
+
+        assert_eq!(
+            mass.group,
+            AstroGroup::Derived
+        );
+
+        assert_approx_eq!(
+            mass.relative_uncertainty,
+            g.relative_uncertainty,
+            1e-18
+        );
+    }
+
+    assert_approx_eq!(
+        astro::SOLAR_MASS / 1e30,
+        1.988_41,
+        1e-5
+    );
+
+    assert_approx_eq!(
+        astro::EARTH_MASS / 1e24,
+        5.972_17,
+        1e-5
+    );
+}
+
+#[test]
+
+fn test_calendar() {
+
+    assert_eq!(
+        time::julian_date(2000, 1, 1.5),
+        time::J2000
+    );
+
+    assert_eq!(
+        time::jd_to_mjd(
+            time::julian_date(
+                1858, 11, 17.0
+            )
+        ),
+        0.0
+    );
+
+    for step in time::LEAP_SECONDS {
+
+        let jd = time::julian_date(
+            step.year,
+            step.month,
+            1.0,
+        );
+
+        assert_eq!(
+            time::jd_to_mjd(jd),
+            step.mjd
+        );
+
+        assert_eq!(
+            time::calendar_date(jd),
+            (
+                step.year,
+                step.month,
+                1.0
+            )
+        );
+    }
+
+    let (year, month, day) =
+        time::calendar_date(
+            time::julian_date(
+                1987, 6, 19.75,
+            ),
+        );
+
+    assert_eq!(
+        (year, month),
+        (1987, 6)
+    );
+
+    assert_approx_eq!(day, 19.75, 1e-9);
+}
+
+#[test]
+
+fn test_leap_seconds() {
+
+    assert_eq!(
+        time::LEAP_SECONDS.len(),
+        28
+    );
+
+    assert_eq!(
+        time::tai_minus_utc(60_000.0),
+        Ok(37.0)
+    );
+
+    // The last second of 2016 was a leap second.
+    assert_eq!(
+        time::tai_minus_utc(57_753.999),
+        Ok(36.0)
+    );
+
+    assert_eq!(
+        time::tai_minus_utc(57_754.0),
+        Ok(37.0)
+    );
+
+    assert_eq!(
+        time::tai_minus_utc(41_316.5),
+        Err(
+            AstroError::UtcOutOfRange(
+                41_316.5
+            )
+        )
+    );
+
+    for mjd in [
+        41_317.0,
+        45_000.25,
+        57_753.9,
+        57_754.0,
+        60_000.5,
+    ] {
+
+        let tai = time::utc_to_tai(mjd)
+            .unwrap();
+
+        assert_approx_eq!(
+            time::tai_to_utc(tai)
+                .unwrap(),
+            mjd,
+            1e-10
+        );
+    }
+}
+
+#[test]
+
+fn test_time_scales() {
+
+    let j2000 =
+        time::jd_to_mjd(time::J2000);
+
+    // J2000.0 is 11:58:55.816 UTC.
+    let utc = time::convert(
+        j2000,
+        TimeScale::Tt,
+        TimeScale::Utc,
+    )
+    .unwrap();
+
+    assert_approx_eq!(
+        (j2000 - utc) * 86_400.0,
+        64.184,
+        1e-5
+    );
+
+    for mjd in [
+        45_000.0,
+        51_544.5,
+        58_000.3,
+    ] {
+
+        let difference =
+            time::tdb_minus_tt(mjd);
+
+        assert!(
+            difference.abs() < 1.7e-3
+        );
+
+        // The leading terms of the series.
+        let g = (357.53_f64
+            + 0.985_600_28
+                * (time::mjd_to_jd(
+                    mjd,
+                ) - time::J2000))
+            .to_radians();
+
+        assert_approx_eq!(
+            difference,
+            0.001_657 * g.sin()
+                + 0.000_014
+                    * (2.0 * g).sin(),
+            3e-5
+        );
+
+        for from in TimeScale::ALL {
+
+            for to in TimeScale::ALL {
+
+                let there =
+                    time::convert(
+                        mjd, from, to,
+                    )
+                    .unwrap();
+
+                let back =
+                    time::convert(
+                        there, to, from,
+                    )
+                    .unwrap();
+
+                assert_approx_eq!(
+                    back, mjd, 1e-10
+                );
+            }
+        }
+    }
+
+    assert_eq!(
+        TimeScale::from_name("tdb"),
+        Ok(TimeScale::Tdb)
+    );
+
+    assert_eq!(
+        TimeScale::from_name("GPS"),
+        Err(
+            AstroError::UnknownTimeScale(
+                "GPS".to_string()
+            )
+        )
+    );
+
+    assert!(
+        time::convert(
+            30_000.0,
+            TimeScale::Tt,
+            TimeScale::Utc
+        )
+        .is_err()
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_astro_ffi() {
+
+    use std::ffi::CStr;
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::astro_ffi::TimeConversion;
+    use rssn_advanced::ffi_apis::astro_ffi::rssn_astro_convert_time;
+    use rssn_advanced::ffi_apis::astro_ffi::rssn_astro_convert_time_bincode;
+    use rssn_advanced::ffi_apis::astro_ffi::rssn_astro_convert_time_json;
+    use rssn_advanced::ffi_apis::astro_ffi::rssn_get_astro_constant_count;
+    use rssn_advanced::ffi_apis::astro_ffi::rssn_get_astro_constant_id;
+    use rssn_advanced::ffi_apis::astro_ffi::rssn_get_astro_constant_value;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+
+    assert_eq!(
+        rssn_get_astro_constant_count(),
+        astro::all().len()
+    );
+
+    let id =
+        rssn_get_astro_constant_id(0);
+
+    let value =
+        rssn_get_astro_constant_value(
+            id,
+        );
+
+    assert_eq!(
+        unsafe {
+
+            CStr::from_ptr(value)
+        }
+        .to_str()
+        .unwrap()
+        .parse::<f64>()
+        .unwrap(),
+        astro::all()[0].value
+    );
+
+    rssn_free_string(value);
+
+    rssn_free_string(id);
+
+    let utc =
+        CString::new("UTC").unwrap();
+
+    let tai =
+        CString::new("TAI").unwrap();
+
+    assert_approx_eq!(
+        rssn_astro_convert_time(
+            60_000.0,
+            utc.as_ptr(),
+            tai.as_ptr()
+        ),
+        60_000.0 + 37.0 / 86_400.0,
+        1e-10
+    );
+
+    assert!(
+        rssn_astro_convert_time(
+            30_000.0,
+            utc.as_ptr(),
+            tai.as_ptr()
+        )
+        .is_nan()
+    );
+
+    let request = CString::new(
+        r#"{"mjd": 60000.0, "from": "Tai", "to": "Tt"}"#,
+    )
+    .unwrap();
+
+    let json =
+        rssn_astro_convert_time_json(
+            request.as_ptr(),
+        );
+
+    let result: Option<f64> =
+        from_json_string(json);
+
+    assert_approx_eq!(
+        result.unwrap(),
+        60_000.0 + 32.184 / 86_400.0,
+        1e-10
+    );
+
+    rssn_free_string(json);
+
+    let request = to_bincode_buffer(
+        &TimeConversion {
+            mjd: 30_000.0,
+            from: TimeScale::Utc,
+            to: TimeScale::Tdb,
+        },
+    );
+
+    let buffer =
+        rssn_astro_convert_time_bincode(
+            request,
+        );
+
+    assert!(
+        buffer
+            .data
+            .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::InvalidArgument
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    rssn_free_bincode_buffer(request);
+}
@@ -0,0 +1,174 @@
+use rssn_advanced::constant;
+use rssn_advanced::constant::audit;
+use rssn_advanced::constant::audit::Relation;
+
+#[test]
+
+fn test_relations_reference_known_constants()
+ {
+
+    for relation in audit::RELATIONS {
+
+        for id in std::iter::once(
+            relation.constant,
+        )
+        .chain(
+            relation
+                .inputs
+                .iter()
+                .copied(),
+        ) {
+
+            assert!(
+                constant::lookup(id)
+                    .is_some(),
+                "{}: unknown constant \
+                 {id}",
+                relation.name
+            );
+        }
+    }
+
+    assert_eq!(
+        audit::run().len(),
+        audit::RELATIONS.len()
+    );
+}
+
+#[test]
+
+fn test_listed_constants_are_consistent()
+ {
+
+    for result in audit::run() {
+
+        assert!(
+            result.passes(3.0),
+            "{}: residual {} (listed \
+             {}, derived {})",
+            result.relation,
+            result.residual,
+            result.listed,
+            result.derived
+        );
+    }
+
+    assert!(
+        audit::failures(3.0).is_empty()
+    );
+}
+
+#[test]
+
+fn test_truncated_values_are_flagged() {
+
+    let record = |id| {
+
+        constant::lookup(id).unwrap()
+    };
+
+    assert!(
+        record(
+            "REDUCED_PLANCK_CONSTANT"
+        )
+        .truncated
+    );
+
+    assert!(
+        record("MOLAR_GAS_CONSTANT")
+            .truncated
+    );
+
+    assert!(
+        !record("PLANCK_CONSTANT")
+            .truncated
+    );
+
+    assert!(
+        !record("ELECTRON_MASS")
+            .truncated
+    );
+
+    // The quoted trailing zero counts as a digit.
+    assert_eq!(
+        record("ALPHA_PARTICLE_MASS")
+            .digits,
+        11
+    );
+}
+
+#[test]
+
+fn test_inconsistent_relation_is_detected()
+ {
+
+    let wrong = Relation {
+        name: "R = 1.000 001 N_A k",
+        constant: "MOLAR_GAS_CONSTANT",
+        inputs: &[
+            "AVOGADRO_CONSTANT",
+            "BOLTZMANN_CONSTANT",
+        ],
+        derive: |x| {
+
+            Some(
+                1.000_001 * x[0] * x[1],
+            )
+        },
+    };
+
+    let result =
+        audit::check(&wrong).unwrap();
+
+    assert!(!result.passes(3.0));
+
+    assert!(result.residual < -100.0);
+
+    // Correlated constants: m_e is tied to α through R∞, so a relation
+    // that is off by a fraction of the uncertainty of m_e is still caught.
+    let wrong = Relation {
+        name: "m_e = 2hR∞/(cα²) (1 + \
+               10⁻¹⁰)",
+        constant: "ELECTRON_MASS",
+        inputs: &[
+            "PLANCK_CONSTANT",
+            "RYDBERG_CONSTANT",
+            "SPEED_OF_LIGHT",
+            "FINE_STRUCTURE_CONSTANT",
+        ],
+        derive: |x| {
+
+            Some(
+                2.0 * x[0] * x[1]
+                    / (x[2]
+                        * x[3].powi(2))
+                    * (1.0 + 1e-10),
+            )
+        },
+    };
+
+    let result =
+        audit::check(&wrong).unwrap();
+
+    assert!(
+        result
+            .derived
+            .uncertainty
+            > 1e-10
+                * result.listed.value
+    );
+
+    assert!(!result.passes(3.0));
+
+    let unknown = Relation {
+        name: "unknown",
+        constant: "NOT_A_CONSTANT",
+        inputs: &[],
+        derive: |_| None,
+    };
+
+    assert!(
+        audit::check(&unknown)
+            .is_none()
+    );
+}
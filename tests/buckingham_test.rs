@@ -0,0 +1,298 @@
+use rssn_advanced::unit::Dimension;
+use rssn_advanced::unit::buckingham;
+use rssn_advanced::unit::buckingham::Variable;
+
+fn variables(
+    list: &[(&str, &str)]
+) -> Vec<Variable> {
+
+    list.iter()
+        .map(|&(name, unit)| {
+
+            Variable::with_unit(
+                name, unit,
+            )
+            .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+
+fn test_pi_groups() {
+
+    // Drag on a sphere: six variables of rank three.
+    let drag = variables(&[
+        ("ρ", "kg m⁻³"),
+        ("v", "m s⁻¹"),
+        ("D", "m"),
+        ("μ", "Pa s"),
+        ("F", "N"),
+        ("ε", "m"),
+    ]);
+
+    let groups =
+        buckingham::pi_groups(&drag);
+
+    assert_eq!(groups.len(), 3);
+
+    assert_eq!(
+        groups[0].to_string(),
+        "ρ v D μ⁻¹"
+    );
+
+    assert_eq!(
+        groups[1].to_string(),
+        "ρ v² D² F⁻¹"
+    );
+
+    assert_eq!(
+        groups[2].to_string(),
+        "D ε⁻¹"
+    );
+
+    // Every group is dimensionless.
+    for group in &groups {
+
+        let mut exponents = [0_i32; 7];
+
+        for (name, power) in
+            &group.factors
+        {
+
+            let variable = drag
+                .iter()
+                .find(|v| {
+
+                    &v.name == name
+                })
+                .unwrap();
+
+            for (e, d) in exponents
+                .iter_mut()
+                .zip(
+                    variable
+                        .dimension
+                        .exponents(),
+                )
+            {
+
+                *e += power
+                    * i32::from(d);
+            }
+        }
+
+        assert_eq!(exponents, [0; 7]);
+    }
+
+    // A dimensionless variable is a group of its own.
+    let groups = buckingham::pi_groups(&[
+        Variable::new(
+            "L",
+            Dimension::LENGTH,
+        ),
+        Variable::new(
+            "θ",
+            Dimension::DIMENSIONLESS,
+        ),
+    ]);
+
+    assert_eq!(groups.len(), 1);
+
+    assert_eq!(
+        groups[0].factors,
+        [("θ".to_string(), 1)]
+    );
+
+    assert!(
+        buckingham::pi_groups(&[])
+            .is_empty()
+    );
+}
+
+#[test]
+
+fn test_scaling() {
+
+    let pendulum = variables(&[
+        ("l", "m"),
+        ("g", "m s⁻²"),
+        ("m", "kg"),
+    ]);
+
+    let law = buckingham::scaling(
+        &Variable::new(
+            "T",
+            Dimension::TIME,
+        ),
+        &pendulum,
+    )
+    .unwrap();
+
+    assert_eq!(
+        law.to_string(),
+        "T² ∝ l g⁻¹"
+    );
+
+    assert_eq!(law.power, 2);
+
+    assert_eq!(
+        law.exponent("l"),
+        (1, 2)
+    );
+
+    assert_eq!(
+        law.exponent("g"),
+        (-1, 2)
+    );
+
+    assert_eq!(
+        law.exponent("m"),
+        (0, 1)
+    );
+
+    assert!(
+        law.groups
+            .is_empty()
+    );
+
+    // With the amplitude, the period depends on an unknown function of it.
+    let mut swinging = pendulum;
+
+    swinging.push(Variable::new(
+        "θ",
+        Dimension::DIMENSIONLESS,
+    ));
+
+    let law = buckingham::scaling(
+        &Variable::new(
+            "T",
+            Dimension::TIME,
+        ),
+        &swinging,
+    )
+    .unwrap();
+
+    assert_eq!(law.groups.len(), 1);
+
+    assert!(
+        law.to_string()
+            .ends_with("Φ(θ)")
+    );
+
+    // No mechanical variable has the dimension of temperature.
+    assert!(
+        buckingham::scaling(
+            &Variable::new(
+                "Θ",
+                Dimension::TEMPERATURE,
+            ),
+            &swinging,
+        )
+        .is_none()
+    );
+}
+
+#[test]
+
+fn test_planck_units() {
+
+    let constants = [
+        "G",
+        "hbar",
+        "c",
+        "k_B",
+    ]
+    .map(|key| {
+
+        Variable::constant(key).unwrap()
+    });
+
+    assert_eq!(
+        constants
+            .iter()
+            .map(|v| v.name.as_str())
+            .collect::<Vec<_>>(),
+        ["G", "ħ", "c", "k"]
+    );
+
+    // The constants are independent.
+    assert!(
+        buckingham::pi_groups(
+            &constants
+        )
+        .is_empty()
+    );
+
+    for (dimension, expected) in [
+        (
+            Dimension::LENGTH,
+            [
+                (1, 2),
+                (1, 2),
+                (-3, 2),
+                (0, 1),
+            ],
+        ),
+        (
+            Dimension::MASS,
+            [
+                (-1, 2),
+                (1, 2),
+                (1, 2),
+                (0, 1),
+            ],
+        ),
+        (
+            Dimension::TIME,
+            [
+                (1, 2),
+                (1, 2),
+                (-5, 2),
+                (0, 1),
+            ],
+        ),
+        (
+            Dimension::TEMPERATURE,
+            [
+                (-1, 2),
+                (1, 2),
+                (5, 2),
+                (-1, 1),
+            ],
+        ),
+    ] {
+
+        let law = buckingham::scaling(
+            &Variable::new(
+                "X",
+                dimension,
+            ),
+            &constants,
+        )
+        .unwrap();
+
+        assert_eq!(law.power, 2);
+
+        for (variable, exponent) in
+            constants
+                .iter()
+                .zip(expected)
+        {
+
+            assert_eq!(
+                law.exponent(
+                    &variable.name
+                ),
+                exponent,
+                "{dimension:?} {}",
+                variable.name
+            );
+        }
+    }
+
+    assert!(
+        Variable::constant("xyzzy")
+            .is_none()
+    );
+}
@@ -0,0 +1,372 @@
+use rssn_advanced::capability;
+use rssn_advanced::constant::CodataEdition;
+
+#[test]
+
+fn test_capabilities() {
+
+    let capabilities =
+        capability::capabilities();
+
+    assert_eq!(
+        capabilities.package,
+        "rssn-advanced"
+    );
+
+    assert_eq!(
+        capabilities.version,
+        env!("CARGO_PKG_VERSION")
+    );
+
+    assert!(
+        capabilities
+            .rssn_version
+            .starts_with("0.")
+    );
+
+    assert_eq!(
+        capabilities.codata_edition,
+        CodataEdition::LATEST
+    );
+
+    assert_eq!(
+        capabilities.codata_editions,
+        CodataEdition::ALL
+    );
+
+    let build = &capabilities.build;
+
+    assert_eq!(
+        build.profile,
+        if cfg!(debug_assertions) {
+
+            "debug"
+        } else {
+
+            "release"
+        }
+    );
+
+    assert!(
+        !build
+            .opt_level
+            .is_empty()
+    );
+
+    assert!(
+        ["unwind", "abort"].contains(
+            &build.panic.as_str()
+        )
+    );
+
+    assert_eq!(
+        capabilities
+            .has_feature("ffi_api"),
+        cfg!(feature = "ffi_api")
+    );
+
+    assert!(
+        build
+            .features
+            .is_sorted()
+    );
+}
+
+#[test]
+
+fn test_algorithms() {
+
+    let capabilities =
+        capability::capabilities();
+
+    let scaling = capabilities
+        .algorithm(
+            "unit::buckingham::scaling",
+        )
+        .unwrap();
+
+    assert_eq!(
+        scaling.signature,
+        "fn scaling(target: \
+         &Variable, variables: \
+         &[Variable]) -> \
+         Option<Scaling>"
+    );
+
+    assert_eq!(
+        scaling.summary,
+        "Returns the scaling law of \
+         `target` in terms of \
+         `variables`."
+    );
+
+    // Private modules and methods are not listed.
+    assert!(
+        capabilities
+            .algorithms
+            .iter()
+            .all(|a| {
+
+                !a.module
+                    .starts_with(
+                        "ffi_apis",
+                    )
+                    && !a
+                        .module
+                        .ends_with(
+                            "data",
+                        )
+            })
+    );
+
+    assert!(
+        capabilities
+            .algorithm(
+                "unit::Unit::parse"
+            )
+            .is_none()
+    );
+
+    assert!(
+        capabilities
+            .algorithms
+            .iter()
+            .all(|a| {
+
+                !a.summary.is_empty()
+            })
+    );
+}
+
+#[test]
+
+fn test_ffi_functions() {
+
+    let capabilities =
+        capability::capabilities();
+
+    if !cfg!(feature = "ffi_api") {
+
+        assert!(
+            capabilities
+                .ffi_functions
+                .is_empty()
+        );
+
+        return;
+    }
+
+    assert!(
+        capabilities
+            .ffi_functions
+            .is_sorted_by(|a, b| {
+
+                a.name < b.name
+            })
+    );
+
+    let lookup = capabilities
+        .ffi_function(
+            "rssn_constant_lookup",
+        )
+        .unwrap();
+
+    assert!(lookup.is_unsafe);
+
+    assert_eq!(
+        lookup.signature,
+        "unsafe extern \"C\" fn \
+         rssn_constant_lookup(name: \
+         *const c_char, out_value: \
+         *mut f64) -> bool"
+    );
+
+    // The getters generated for every constant are listed.
+    for name in [
+        "rssn_get_speed_of_light",
+        "rssn_get_speed_of_light_json",
+        "rssn_get_speed_of_light_bincode",
+        "rssn_get_capabilities_json",
+        "rssn_free_string",
+    ] {
+
+        assert!(
+            capabilities
+                .ffi_function(name)
+                .is_some(),
+            "{name}"
+        );
+    }
+
+    assert_eq!(
+        capabilities
+            .ffi_function(
+                "rssn_get_capabilities_bincode"
+            )
+            .unwrap()
+            .signature,
+        "extern \"C\" fn rssn_get_capabilities_bincode() -> BincodeBuffer"
+    );
+
+    // Functions generated by the handle macros carry the documentation
+    // of their invocation.
+    let add = capabilities
+        .ffi_function(
+            "rssn_measured_add",
+        )
+        .unwrap();
+
+    assert_eq!(
+        add.signature,
+        "extern \"C\" fn \
+         rssn_measured_add(input1: \
+         RssnHandle, input2: \
+         RssnHandle) -> RssnHandle"
+    );
+
+    assert!(
+        add.summary
+            .starts_with(
+                "Returns a handle to \
+                 the sum"
+            )
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_capability_ffi() {
+
+    use rssn_advanced::capability::Capabilities;
+    use rssn_advanced::ffi_apis::capability_ffi::rssn_get_capabilities_bincode;
+    use rssn_advanced::ffi_apis::capability_ffi::rssn_get_capabilities_json;
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+
+    let json =
+        rssn_get_capabilities_json();
+
+    let decoded: Option<Capabilities> =
+        from_json_string(json);
+
+    assert_eq!(
+        decoded.as_ref(),
+        Some(
+            capability::capabilities()
+        )
+    );
+
+    rssn_free_string(json);
+
+    let buffer =
+        rssn_get_capabilities_bincode();
+
+    let decoded: Option<Capabilities> =
+        from_bincode_buffer(&buffer);
+
+    assert_eq!(
+        decoded.as_ref(),
+        Some(
+            capability::capabilities()
+        )
+    );
+
+    rssn_free_bincode_buffer(buffer);
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_ffi_functions_match_exports() {
+
+    use std::fs;
+    use std::path::Path;
+
+    // Every function written out in the FFI modules, whichever side of
+    // its documentation `#[unsafe(no_mangle)]` is on.
+    fn exports(
+        dir: &Path,
+        names: &mut Vec<String>,
+    ) {
+
+        for entry in
+            fs::read_dir(dir).unwrap()
+        {
+
+            let path =
+                entry.unwrap().path();
+
+            if path.is_dir() {
+
+                exports(&path, names);
+
+                continue;
+            }
+
+            let text =
+                fs::read_to_string(&path)
+                    .unwrap();
+
+            names.extend(
+                text.split(
+                    "extern \"C\" fn ",
+                )
+                .skip(1)
+                .filter_map(|rest| {
+
+                    rest.split(['(', '<'])
+                        .next()
+                        .filter(|name| {
+
+                            name.starts_with(
+                                "rssn_",
+                            )
+                        })
+                        .map(str::to_string)
+                }),
+            );
+        }
+    }
+
+    let mut names = Vec::new();
+
+    exports(
+        &Path::new(env!(
+            "CARGO_MANIFEST_DIR"
+        ))
+        .join("src/ffi_apis"),
+        &mut names,
+    );
+
+    assert!(
+        names.len() > 50
+    );
+
+    let capabilities =
+        capability::capabilities();
+
+    for name in &names {
+
+        assert!(
+            capabilities
+                .ffi_function(name)
+                .is_some(),
+            "{name}"
+        );
+    }
+
+    assert!(
+        capabilities
+            .ffi_function(
+                "rssn_free_string_constant"
+            )
+            .unwrap()
+            .summary
+            .starts_with(
+                "Frees a C string"
+            )
+    );
+}
@@ -73,3 +73,953 @@ fn test_get_system_info() {
         info
     );
 }
+
+#[test]
+
+fn test_registry_matches_constants() {
+
+    let c = constant::lookup(
+        "SPEED_OF_LIGHT",
+    )
+    .unwrap();
+
+    assert_eq!(
+        c.value,
+        constant::SPEED_OF_LIGHT
+    );
+
+    assert!(c.exact);
+
+    assert_eq!(c.symbol, "c");
+
+    let g = constant::lookup(
+        "gravitational_constant",
+    )
+    .unwrap();
+
+    assert_eq!(g.value, constant::get_gravitational_constant());
+
+    assert!(!g.exact);
+
+    assert_approx_eq::assert_approx_eq!(
+        g.relative_uncertainty,
+        2.2e-5,
+        1e-6
+    );
+}
+
+#[test]
+
+fn test_registry_identifiers_are_unique()
+ {
+
+    let all = constant::all();
+
+    for (i, a) in all
+        .iter()
+        .enumerate()
+    {
+
+        assert!(
+            all[i + 1 ..]
+                .iter()
+                .all(|b| b.id != a.id),
+            "duplicate identifier {}",
+            a.id
+        );
+
+        assert_eq!(a.edition, constant::CodataEdition::LATEST);
+    }
+}
+
+#[test]
+
+fn test_registry_groups() {
+
+    let em: Vec<_> = constant::in_group(constant::ConstantGroup::Electromagnetic)
+        .map(|c| c.id)
+        .collect();
+
+    assert!(em.contains(
+        &"JOSEPHSON_CONSTANT"
+    ));
+
+    assert!(
+        !em.contains(&"SPEED_OF_LIGHT")
+    );
+
+    assert!(
+        constant::lookup(
+            "NOT_A_CONSTANT"
+        )
+        .is_none()
+    );
+}
+
+#[test]
+
+fn test_registry_covers_codata_2022_listing()
+ {
+
+    let listing = include_str!(
+        "../data/codata/allascii_2022.\
+         txt"
+    );
+
+    assert_eq!(constant::nist_ascii::edition_year(listing), Some(2022));
+
+    let entries =
+        constant::nist_ascii::parse(
+            listing,
+        )
+        .unwrap();
+
+    assert_eq!(
+        entries.len(),
+        constant::all().len()
+    );
+
+    for (entry, record) in entries
+        .iter()
+        .zip(constant::all())
+    {
+
+        assert_eq!(
+            entry.name,
+            record.name
+        );
+
+        assert_eq!(
+            entry.value, record.value,
+            "{}",
+            entry.name
+        );
+
+        assert_eq!(
+            entry.uncertainty,
+            record.uncertainty,
+            "{}",
+            entry.name
+        );
+
+        assert_eq!(
+            entry.exact, record.exact,
+            "{}",
+            entry.name
+        );
+
+        assert_eq!(
+            entry.significant_digits(),
+            record.digits,
+            "{}",
+            entry.name
+        );
+    }
+}
+
+#[test]
+
+fn test_nist_ascii_parse_rows() {
+
+    let text = "\
+  Quantity                                                       Value                 Uncertainty           Unit
+-----------------------------------------------------------------------------------------------------------------------------
+reduced Planck constant                                     1.054 571 817... e-34    (exact)                  J s
+proton-electron mass ratio                                  1836.152 673 426         0.000 000 032            
+";
+
+    let entries =
+        constant::nist_ascii::parse(
+            text,
+        )
+        .unwrap();
+
+    assert_eq!(entries.len(), 2);
+
+    assert!(
+        entries[0].exact
+            && entries[0].truncated
+    );
+
+    assert_eq!(
+        entries[0].value,
+        1.054_571_817e-34
+    );
+
+    assert_eq!(
+        entries[0].unit,
+        "J s"
+    );
+
+    assert_eq!(
+        entries[0].significant_digits(),
+        10
+    );
+
+    assert_eq!(
+        entries[1].significant_digits(),
+        13
+    );
+
+    assert_eq!(
+        entries[1]
+            .uncertainty_text
+            .as_deref(),
+        Some("0.000000032")
+    );
+
+    assert!(
+        entries[1]
+            .unit
+            .is_empty()
+    );
+
+    assert!(
+        constant::nist_ascii::parse(
+            "no table here"
+        )
+        .is_err()
+    );
+}
+
+#[test]
+
+fn test_generated_identifiers_keep_legacy_names()
+ {
+
+    assert_eq!(
+        constant::get_proton_mass_kg(),
+        constant::lookup(
+            "PROTON_MASS_KG"
+        )
+        .unwrap()
+        .value
+    );
+
+    assert_eq!(
+        constant::lookup(
+            "PROTON_MASS_KG"
+        )
+        .unwrap()
+        .name,
+        "proton mass"
+    );
+
+    assert_eq!(
+        constant::get_muon_mass_u(),
+        constant::MUON_MASS_U
+    );
+
+    assert!(
+        constant::lookup(
+            "BOHR_MAGNETON_IN_EV_PER_T"
+        )
+        .is_some()
+    );
+
+    assert_eq!(
+        constant::nist_ascii::identifier("Newtonian constant of gravitation over h-bar c"),
+        "NEWTONIAN_CONSTANT_OF_GRAVITATION_OVER_HBAR_C"
+    );
+}
+
+#[test]
+
+fn test_edition_tables_match_listings()
+{
+
+    use constant::CodataEdition;
+    use constant::nist_ascii;
+
+    for (edition, text) in [
+        (
+            CodataEdition::Codata2014,
+            include_str!(
+                "../data/codata/\
+                 allascii_2014.txt"
+            ),
+        ),
+        (
+            CodataEdition::Codata2018,
+            include_str!(
+                "../data/codata/\
+                 allascii_2018.txt"
+            ),
+        ),
+    ] {
+
+        let entries =
+            nist_ascii::parse(text)
+                .unwrap();
+
+        let table =
+            constant::edition::constants(
+                edition,
+            );
+
+        assert_eq!(
+            entries.len(),
+            table.len()
+        );
+
+        for (entry, record) in entries
+            .iter()
+            .zip(table)
+        {
+
+            assert_eq!(
+                entry.name,
+                record.name
+            );
+
+            assert_eq!(
+                entry
+                    .value
+                    .to_bits(),
+                record
+                    .value
+                    .to_bits()
+            );
+
+            assert_eq!(
+                record.edition,
+                edition
+            );
+        }
+    }
+}
+
+#[test]
+
+fn test_edition_lookup_across_renames()
+{
+
+    use constant::CodataEdition;
+    use constant::edition;
+
+    let m_e = edition::get(
+        CodataEdition::Codata2018,
+        "electron mass",
+    )
+    .unwrap();
+
+    assert_eq!(
+        m_e.value.to_bits(),
+        9.109_383_701_5e-31_f64
+            .to_bits()
+    );
+
+    let mu_0 = edition::get(
+        CodataEdition::Codata2014,
+        "vacuum mag. permeability",
+    )
+    .unwrap();
+
+    assert_eq!(
+        mu_0.name,
+        "mag. constant"
+    );
+
+    assert!(mu_0.exact);
+
+    let latest = edition::get(
+        CodataEdition::Codata2022,
+        "mag. constant",
+    )
+    .unwrap();
+
+    assert_eq!(
+        latest.id,
+        "VACUUM_MAGNETIC_PERMEABILITY"
+    );
+
+    assert!(!latest.exact);
+
+    assert!(
+        edition::get(
+            CodataEdition::Codata2022,
+            "elementary charge over h",
+        )
+        .is_none()
+    );
+}
+
+#[test]
+
+fn test_edition_diff() {
+
+    use constant::CodataEdition;
+    use constant::edition;
+
+    let diff = edition::diff(
+        CodataEdition::Codata2018,
+        CodataEdition::Codata2022,
+    );
+
+    let m_e = diff
+        .changed
+        .iter()
+        .find(|c| {
+
+            c.id == "ELECTRON_MASS"
+        })
+        .unwrap();
+
+    assert_approx_eq::assert_approx_eq!(
+        m_e.difference,
+        m_e.new_value - m_e.old_value
+    );
+
+    assert!(
+        m_e.sigma_shift
+            .is_some()
+    );
+
+    assert!(
+        diff.changed
+            .iter()
+            .all(|c| {
+
+                c.id != "SPEED_OF_LIGHT"
+            })
+    );
+
+    assert_eq!(
+        diff.added,
+        ["ALPHA_PARTICLE_RMS_CHARGE_RADIUS"]
+    );
+
+    assert!(
+        diff.removed
+            .is_empty()
+    );
+
+    let redefinition = edition::diff(
+        CodataEdition::Codata2014,
+        CodataEdition::Codata2018,
+    );
+
+    let h = redefinition
+        .changed
+        .iter()
+        .find(|c| {
+
+            c.id == "PLANCK_CONSTANT"
+        })
+        .unwrap();
+
+    assert!(
+        h.sigma_shift
+            .is_some()
+    );
+
+    assert!(
+        h.new_uncertainty
+            .abs()
+            < f64::EPSILON
+    );
+
+    assert!(
+        redefinition
+            .removed
+            .contains(
+                &"ELEMENTARY_CHARGE_OVER_H"
+            )
+    );
+}
+
+#[test]
+
+fn test_search() {
+
+    use constant::MatchKind;
+    use constant::search;
+
+    for (alias, id) in search::aliases()
+    {
+
+        assert!(
+            constant::lookup(id)
+                .is_some(),
+            "{alias}: {id}"
+        );
+
+        assert_eq!(
+            constant::find(alias)[0]
+                .constant
+                .id,
+            *id
+        );
+    }
+
+    for (query, id) in [
+        (
+            "mu0",
+            "VACUUM_MAGNETIC_PERMEABILITY",
+        ),
+        (
+            "μ_0",
+            "VACUUM_MAGNETIC_PERMEABILITY",
+        ),
+        (
+            "ħ",
+            "REDUCED_PLANCK_CONSTANT",
+        ),
+        (
+            "bohr radius",
+            "BOHR_RADIUS",
+        ),
+        ("a_0", "BOHR_RADIUS"),
+        (
+            "bohr raduis",
+            "BOHR_RADIUS",
+        ),
+        (
+            "Boltzman constant",
+            "BOLTZMANN_CONSTANT",
+        ),
+        (
+            "R_inf",
+            "RYDBERG_CONSTANT",
+        ),
+        (
+            "Ångström star",
+            "ANGSTROM_STAR",
+        ),
+        (
+            "vacuum magnetic permeability",
+            "VACUUM_MAGNETIC_PERMEABILITY",
+        ),
+        (
+            "alpha",
+            "FINE_STRUCTURE_CONSTANT",
+        ),
+        ("G", "GRAVITATIONAL_CONSTANT"),
+    ] {
+
+        assert_eq!(
+            constant::find(query)[0]
+                .constant
+                .id,
+            id,
+            "{query}"
+        );
+    }
+
+    let matches = constant::find("a₀");
+
+    assert_eq!(
+        matches[0].kind,
+        MatchKind::Exact
+    );
+
+    assert_eq!(
+        constant::find("A0")[0].kind,
+        MatchKind::Normalized
+    );
+
+    assert_eq!(
+        constant::find("bohr raduis")
+            [0]
+        .kind,
+        MatchKind::Fuzzy
+    );
+
+    let klitzing =
+        constant::find("klitzing");
+
+    assert!(
+        klitzing
+            .iter()
+            .all(|m| {
+
+                m.kind
+                == MatchKind::Substring
+            })
+    );
+
+    assert_eq!(
+        klitzing[0]
+            .constant
+            .id,
+        "VON_KLITZING_CONSTANT"
+    );
+
+    assert!(
+        matches
+            .windows(2)
+            .all(|w| {
+
+                w[0].score >= w[1].score
+            })
+    );
+
+    assert!(
+        constant::find("ħ")
+            .iter()
+            .position(|m| {
+
+                m.constant.id
+                    == "REDUCED_PLANCK_CONSTANT_IN_EV_S"
+            })
+            .is_some_and(|i| i == 1)
+    );
+
+    assert!(
+        constant::find(" _ ")
+            .is_empty()
+    );
+
+    assert!(
+        constant::find("xyzzy")
+            .is_empty()
+    );
+
+    assert_eq!(
+        search::best("speed_of_light")
+            .unwrap()
+            .symbol,
+        "c"
+    );
+
+    // Only a clear match resolves.
+    for (query, id) in [
+        ("ħ", "REDUCED_PLANCK_CONSTANT"),
+        ("mu0", "VACUUM_MAGNETIC_PERMEABILITY"),
+        ("bohr radius", "BOHR_RADIUS"),
+        ("bohr radiu", "BOHR_RADIUS"),
+    ] {
+
+        assert_eq!(
+            search::resolve(query)
+                .map(|c| c.id),
+            Ok(id),
+            "{query}"
+        );
+    }
+
+    for query in ["mass", "the"] {
+
+        assert!(
+            !search::resolve(query)
+                .unwrap_err()
+                .is_empty(),
+            "{query}"
+        );
+    }
+
+    assert!(
+        search::resolve("xyzzy")
+            .unwrap_err()
+            .is_empty()
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_search_ffi() {
+
+    use std::ffi::CStr;
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_constant_lookup;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_find_constants_json;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+
+    let name =
+        CString::new("mu0").unwrap();
+
+    let mut value = f64::NAN;
+
+    assert!(unsafe {
+
+        rssn_constant_lookup(
+            name.as_ptr(),
+            &raw mut value,
+        )
+    });
+
+    assert_eq!(
+        value,
+        constant::VACUUM_MAGNETIC_PERMEABILITY
+    );
+
+    let name =
+        CString::new("xyzzy").unwrap();
+
+    assert!(!unsafe {
+
+        rssn_constant_lookup(
+            name.as_ptr(),
+            &raw mut value,
+        )
+    });
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::NotFound
+    );
+
+    // A partial name shared by many constants picks none of them.
+    for name in ["mass", "the"] {
+
+        let name =
+            CString::new(name).unwrap();
+
+        assert!(!unsafe {
+
+            rssn_constant_lookup(
+                name.as_ptr(),
+                &raw mut value,
+            )
+        });
+
+        assert_eq!(
+            rssn_last_error_code(),
+            FfiStatus::Ambiguous
+        );
+    }
+
+    assert_eq!(
+        value,
+        constant::VACUUM_MAGNETIC_PERMEABILITY
+    );
+
+    assert!(!unsafe {
+
+        rssn_constant_lookup(
+            std::ptr::null(),
+            &raw mut value,
+        )
+    });
+
+    let query =
+        CString::new("bohr").unwrap();
+
+    let json = rssn_find_constants_json(
+        query.as_ptr(),
+    );
+
+    let matches: serde_json::Value =
+        serde_json::from_str(
+            unsafe {
+
+                CStr::from_ptr(json)
+            }
+            .to_str()
+            .unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(
+        matches[0]["kind"],
+        "Prefix"
+    );
+
+    assert!(
+        matches
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|m| {
+
+                m["constant"]["id"]
+                    == "BOHR_RADIUS"
+            })
+    );
+
+    rssn_free_string(json);
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_numeric_ffi() {
+
+    use std::ffi::CStr;
+    use std::ffi::CString;
+
+    use rssn_advanced::constant;
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::constant_ffi::ConstantRecord;
+    use rssn_advanced::ffi_apis::constant_ffi::RSSN_CONSTANT_EXACT;
+    use rssn_advanced::ffi_apis::constant_ffi::RSSN_CONSTANT_TRUNCATED;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_free_constant_table;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_records_bincode;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_records_json;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_table;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_value_f64;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_electron_mass_f64;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_speed_of_light_f64;
+
+    assert_eq!(
+        rssn_get_speed_of_light_f64(),
+        299_792_458.0
+    );
+
+    assert_eq!(
+        rssn_get_electron_mass_f64(),
+        constant::get_electron_mass()
+    );
+
+    let id =
+        CString::new("ELECTRON_MASS")
+            .unwrap();
+
+    assert_eq!(
+        rssn_get_constant_value_f64(
+            id.as_ptr()
+        ),
+        constant::get_electron_mass()
+    );
+
+    let missing = CString::new(
+        "NO_SUCH_CONSTANT",
+    )
+    .unwrap();
+
+    assert!(
+        rssn_get_constant_value_f64(
+            missing.as_ptr()
+        )
+        .is_nan()
+    );
+
+    let table =
+        rssn_get_constant_table();
+
+    let records = unsafe {
+
+        std::slice::from_raw_parts(
+            table.records,
+            table.len,
+        )
+    };
+
+    assert_eq!(
+        records.len(),
+        constant::all().len()
+    );
+
+    for (record, expected) in records
+        .iter()
+        .zip(constant::all())
+    {
+
+        let text = |ptr| {
+
+            unsafe {
+
+                CStr::from_ptr(ptr)
+            }
+            .to_str()
+            .unwrap()
+        };
+
+        assert_eq!(
+            text(record.id),
+            expected.id
+        );
+
+        assert_eq!(
+            text(record.name),
+            expected.name
+        );
+
+        assert_eq!(
+            text(record.symbol),
+            expected.symbol
+        );
+
+        assert_eq!(
+            text(record.unit),
+            expected.unit
+        );
+
+        assert_eq!(
+            record.value,
+            expected.value
+        );
+
+        assert_eq!(
+            record.uncertainty,
+            expected.uncertainty
+        );
+
+        assert_eq!(
+            record.flags
+                & RSSN_CONSTANT_EXACT
+                != 0,
+            expected.exact
+        );
+
+        assert_eq!(
+            record.flags
+                & RSSN_CONSTANT_TRUNCATED
+                != 0,
+            expected.truncated
+        );
+    }
+
+    unsafe {
+
+        rssn_free_constant_table(table);
+    }
+
+    let json =
+        rssn_get_constant_records_json(
+        );
+
+    let from_json: Option<
+        Vec<ConstantRecord>,
+    > = from_json_string(json);
+
+    rssn_free_string(json);
+
+    let buffer =
+        rssn_get_constant_records_bincode();
+
+    let from_bincode: Option<
+        Vec<ConstantRecord>,
+    > = from_bincode_buffer(&buffer);
+
+    rssn_free_bincode_buffer(buffer);
+
+    let from_bincode =
+        from_bincode.unwrap();
+
+    assert!(
+        from_bincode
+            .iter()
+            .zip(constant::all())
+            .all(
+                |(record, expected)| {
+
+                    record.id
+                        == expected.id
+                        && record.value
+                            == expected
+                                .value
+                }
+            )
+    );
+
+    // JSON does not round-trip every `f64` bit for bit.
+    assert!(
+        from_json
+            .unwrap()
+            .iter()
+            .map(|record| &record.id)
+            .eq(from_bincode
+                .iter()
+                .map(|record| {
+                    &record.id
+                }))
+    );
+}
@@ -0,0 +1,435 @@
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::constant;
+use rssn_advanced::constant::Dual;
+use rssn_advanced::constant::correlation;
+use rssn_advanced::constant::propagation;
+
+#[test]
+
+fn test_dependencies_reference_known_quantities()
+ {
+
+    for quantity in
+        correlation::ADJUSTED_QUANTITIES
+    {
+
+        let record =
+            constant::lookup(quantity)
+                .unwrap();
+
+        assert!(!record.exact);
+    }
+
+    for (id, exponents) in
+        correlation::DEPENDENCIES
+    {
+
+        assert!(
+            constant::lookup(id)
+                .is_some(),
+            "unknown constant {id}"
+        );
+
+        for (quantity, _) in *exponents
+        {
+
+            assert!(
+                correlation::ADJUSTED_QUANTITIES
+                    .contains(quantity),
+                "{id} depends on {quantity}, which is not an adjusted quantity"
+            );
+        }
+    }
+}
+
+#[test]
+
+fn test_dependencies_reproduce_listed_uncertainties()
+ {
+
+    for (id, exponents) in
+        correlation::DEPENDENCIES
+    {
+
+        let listed =
+            constant::lookup(id)
+                .unwrap()
+                .relative_uncertainty;
+
+        let modelled = exponents
+            .iter()
+            .map(|(quantity, exponent)| {
+
+                f64::from(*exponent)
+                    * constant::lookup(
+                        quantity,
+                    )
+                    .unwrap()
+                    .relative_uncertainty
+            })
+            .map(|u| u * u)
+            .sum::<f64>()
+            .sqrt();
+
+        let ratio = modelled / listed;
+
+        assert!(
+            (0.75 .. 1.3)
+                .contains(&ratio),
+            "{id}: modelled relative \
+             uncertainty \
+             {modelled:e}, listed \
+             {listed:e}"
+        );
+    }
+}
+
+#[test]
+
+fn test_correlation_coefficients() {
+
+    let r = |a, b| {
+
+        correlation::correlation(a, b)
+            .unwrap()
+    };
+
+    assert!(
+        r(
+            "ELECTRON_MASS",
+            "FINE_STRUCTURE_CONSTANT"
+        ) < -0.99
+    );
+
+    assert!(
+        r(
+            "ELECTRON_MASS",
+            "ATOMIC_MASS_CONSTANT"
+        ) > 0.99
+    );
+
+    assert!(
+        r(
+            "BOHR_MAGNETON",
+            "ELECTRON_MASS"
+        ) < -0.99
+    );
+
+    assert_approx_eq!(
+        r(
+            "ELECTRON_MASS",
+            "GRAVITATIONAL_CONSTANT"
+        ),
+        0.0
+    );
+
+    assert_approx_eq!(
+        r(
+            "ELECTRON_MASS",
+            "SPEED_OF_LIGHT"
+        ),
+        0.0
+    );
+
+    assert_approx_eq!(
+        r(
+            "proton_mass_kg",
+            "PROTON_MASS_KG"
+        ),
+        1.0
+    );
+
+    assert_approx_eq!(
+        r(
+            "BOHR_RADIUS",
+            "ELECTRON_MASS"
+        ),
+        r(
+            "ELECTRON_MASS",
+            "BOHR_RADIUS"
+        )
+    );
+
+    assert!(
+        correlation::correlation(
+            "ELECTRON_MASS",
+            "NOT_A_CONSTANT"
+        )
+        .is_none()
+    );
+}
+
+#[test]
+
+fn test_published_correlations() {
+
+    use rssn_advanced::constant::CodataEdition;
+
+    // Every edition ships its published pairs, which take precedence
+    // over the model, in either order.
+    for edition in CodataEdition::ALL {
+
+        let table =
+            correlation::published(
+                edition,
+            );
+
+        assert!(!table.is_empty());
+
+        for &(a, b, r) in table {
+
+            assert_eq!(
+                correlation::correlation_in(
+                    edition, a, b
+                ),
+                Some(r)
+            );
+
+            assert_eq!(
+                correlation::correlation_in(
+                    edition, b, a
+                ),
+                Some(r)
+            );
+        }
+    }
+
+    assert_eq!(
+        correlation::correlation(
+            "VACUUM_MAGNETIC_PERMEABILITY",
+            "VACUUM_ELECTRIC_PERMITTIVITY"
+        ),
+        Some(-1.0)
+    );
+
+    assert_eq!(
+        correlation::correlation_in(
+            CodataEdition::Codata2014,
+            "CLASSICAL_ELECTRON_RADIUS",
+            "THOMSON_CROSS_SECTION"
+        ),
+        Some(1.0)
+    );
+}
+
+#[test]
+
+fn test_correlation_editions() {
+
+    use rssn_advanced::constant::CodataEdition;
+
+    let r = |edition, a, b| {
+
+        correlation::correlation_in(
+            edition,
+            a,
+            b,
+        )
+    };
+
+    assert!(
+        r(
+            CodataEdition::Codata2018,
+            "ELECTRON_MASS",
+            "FINE_STRUCTURE_CONSTANT"
+        )
+        .unwrap()
+            < -0.99
+    );
+
+    assert!(
+        correlation::has_correlations(
+            CodataEdition::Codata2018
+        )
+    );
+
+    assert!(
+        !correlation::has_correlations(
+            CodataEdition::Codata2014
+        )
+    );
+
+    assert_eq!(
+        r(
+            CodataEdition::Codata2014,
+            "ELECTRON_MASS",
+            "FINE_STRUCTURE_CONSTANT"
+        ),
+        None
+    );
+
+    // Exact constants and the diagonal need no correlation data.
+    assert_eq!(
+        r(
+            CodataEdition::Codata2014,
+            "ELECTRON_MASS",
+            "SPEED_OF_LIGHT"
+        ),
+        Some(0.0)
+    );
+
+    assert_eq!(
+        r(
+            CodataEdition::Codata2014,
+            "PLANCK_CONSTANT",
+            "PLANCK_CONSTANT"
+        ),
+        Some(1.0)
+    );
+}
+
+#[test]
+
+fn test_covariance_matrix() {
+
+    let ids = [
+        "ELECTRON_MASS",
+        "FINE_STRUCTURE_CONSTANT",
+        "GRAVITATIONAL_CONSTANT",
+    ];
+
+    let covariance =
+        correlation::covariance_matrix(
+            &ids,
+        )
+        .unwrap();
+
+    for (row, id) in covariance
+        .iter()
+        .zip(ids)
+    {
+
+        let record =
+            constant::lookup(id)
+                .unwrap();
+
+        let variance = row
+            .iter()
+            .zip(ids)
+            .find(|(_, other)| {
+
+                *other == id
+            })
+            .map(|(v, _)| *v)
+            .unwrap();
+
+        assert_approx_eq!(
+            variance
+                / (record.uncertainty
+                    * record
+                        .uncertainty),
+            1.0
+        );
+    }
+
+    let correlations =
+        correlation::correlation_matrix(
+            &ids,
+        )
+        .unwrap();
+
+    assert_eq!(
+        correlations.len(),
+        3
+    );
+
+    assert!(
+        correlations
+            .iter()
+            .flatten()
+            .all(|r| r.abs() <= 1.0)
+    );
+}
+
+#[test]
+
+fn test_dual_derivatives() {
+
+    let x = Dual::variable(2.0);
+
+    let y = 3.0 * x.powi(2) + x.sqrt()
+        - 1.0 / x;
+
+    assert_approx_eq!(
+        y.value,
+        12.0 + 2.0f64.sqrt() - 0.5
+    );
+
+    assert_approx_eq!(
+        y.derivative,
+        12.0 + 0.25 * 2.0f64.sqrt()
+            + 0.25
+    );
+
+    let z = (x * x.ln()).exp();
+
+    assert_approx_eq!(
+        z.derivative,
+        z.value * (2.0f64.ln() + 1.0)
+    );
+}
+
+#[test]
+
+fn test_correlated_propagation() {
+
+    // μ_B m_e = eħ/2 is exact, so the correlated uncertainties cancel.
+    let product =
+        propagation::propagate(
+            &[
+                "BOHR_MAGNETON",
+                "ELECTRON_MASS",
+            ],
+            |x| x[0] * x[1],
+        )
+        .unwrap();
+
+    let independent =
+        constant::get_bohr_magneton_measured()
+            * constant::get_electron_mass_measured();
+
+    assert_approx_eq!(
+        product.value
+            / (constant::ELEMENTARY_CHARGE
+                * constant::REDUCED_PLANCK_CONSTANT
+                / 2.0),
+        1.0
+    );
+
+    assert!(
+        product.relative_uncertainty()
+            < independent
+                .relative_uncertainty()
+                / 10.0
+    );
+
+    // Uncorrelated inputs reduce to the independent result.
+    let uncorrelated =
+        propagation::propagate(
+            &[
+                "GRAVITATIONAL_CONSTANT",
+                "ELECTRON_MASS",
+            ],
+            |x| x[0] * x[1],
+        )
+        .unwrap();
+
+    let expected =
+        constant::get_gravitational_constant_measured()
+            * constant::get_electron_mass_measured();
+
+    assert_approx_eq!(
+        uncorrelated.uncertainty
+            / expected.uncertainty,
+        1.0
+    );
+
+    assert!(
+        propagation::propagate_with_covariance(
+            &[1.0, 2.0],
+            &[vec![1.0]],
+            |x| x[0] + x[1],
+        )
+        .is_none()
+    );
+}
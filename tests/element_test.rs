@@ -0,0 +1,590 @@
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::constant;
+use rssn_advanced::element;
+use rssn_advanced::element::AtomicWeight;
+use rssn_advanced::element::ElementError;
+use rssn_advanced::element::Formula;
+use rssn_advanced::element::formula;
+
+#[test]
+
+fn test_lookup() {
+
+    assert_eq!(
+        element::all().len(),
+        118
+    );
+
+    for (i, element) in element::all()
+        .iter()
+        .enumerate()
+    {
+
+        assert_eq!(
+            usize::from(element.z),
+            i + 1
+        );
+
+        assert_eq!(
+            element::lookup(
+                element.symbol
+            ),
+            Some(element)
+        );
+
+        assert_eq!(
+            element::lookup(
+                &element
+                    .z
+                    .to_string()
+            ),
+            Some(element)
+        );
+
+        assert_eq!(
+            element::by_name(
+                &element
+                    .name
+                    .to_uppercase()
+            ),
+            Some(element)
+        );
+    }
+
+    assert_eq!(
+        element::lookup("aluminum")
+            .unwrap()
+            .symbol,
+        "Al"
+    );
+
+    assert_eq!(
+        element::by_symbol("CO"),
+        None
+    );
+
+    assert_eq!(
+        element::lookup("0"),
+        None
+    );
+
+    assert_eq!(
+        element::lookup("119"),
+        None
+    );
+
+    assert_eq!(
+        element::lookup("Og")
+            .unwrap()
+            .atomic_weight,
+        None
+    );
+}
+
+#[test]
+
+fn test_isotopes() {
+
+    let mut previous = (0, 0);
+
+    for isotope in element::isotopes() {
+
+        let key = (
+            isotope.z,
+            isotope.mass_number,
+        );
+
+        assert!(key > previous);
+
+        previous = key;
+
+        assert!(
+            isotope
+                .mass_excess()
+                .abs()
+                < 0.1,
+            "{isotope}"
+        );
+    }
+
+    for element in element::all() {
+
+        let natural: Vec<_> = element
+            .natural_isotopes()
+            .collect();
+
+        if element.z <= 98 {
+
+            assert!(
+                element
+                    .reference_isotope()
+                    .is_some(),
+                "{element}"
+            );
+        }
+
+        let Some(weight) =
+            element.atomic_weight
+        else {
+
+            assert!(
+                natural.is_empty(),
+                "{element}"
+            );
+
+            continue;
+        };
+
+        let total: f64 = natural
+            .iter()
+            .filter_map(|i| i.abundance)
+            .sum();
+
+        assert_approx_eq!(
+            total,
+            1.0,
+            1.5e-4
+        );
+
+        // The representative composition lies within the standard atomic
+        // weight, allowing for rounding of the abundances and for weights
+        // revised since the composition was measured (as for mercury).
+        let mean = natural
+            .iter()
+            .map(|i| {
+
+                i.mass
+                    * i.abundance
+                        .unwrap()
+            })
+            .sum::<f64>()
+            / total;
+
+        let slack = match weight {
+            | AtomicWeight::Value {
+                uncertainty,
+                ..
+            } => 3.0 * uncertainty,
+            | AtomicWeight::Interval {
+                ..
+            } => 0.0,
+        };
+
+        assert!(
+            weight.contains(mean)
+                || (mean
+                    - weight.value())
+                .abs()
+                    <= slack
+                || (mean
+                    - weight.value())
+                .abs()
+                    < 1e-9 * mean,
+            "{element}: {mean}"
+        );
+    }
+
+    // ¹H is a proton and an electron, less 13.6 eV of binding energy.
+    let hydrogen =
+        element::isotope(1, 1).unwrap();
+
+    assert_approx_eq!(
+        hydrogen.mass,
+        constant::PROTON_RELATIVE_ATOMIC_MASS
+            + constant::ELECTRON_RELATIVE_ATOMIC_MASS,
+        2e-8
+    );
+
+    assert_eq!(
+        element::isotope(6, 12)
+            .unwrap()
+            .mass,
+        12.0
+    );
+
+    let carbon13 =
+        element::isotope(6, 13)
+            .unwrap();
+
+    assert_eq!(
+        carbon13
+            .spin
+            .to_string(),
+        "1/2−"
+    );
+
+    assert_eq!(
+        carbon13.to_string(),
+        "13C"
+    );
+
+    assert_eq!(
+        element::isotope(26, 56)
+            .unwrap()
+            .spin
+            .to_string(),
+        "0+"
+    );
+
+    assert_eq!(
+        element::isotope(6, 14)
+            .unwrap()
+            .abundance,
+        None
+    );
+}
+
+#[test]
+
+fn test_atomic_weights() {
+
+    let carbon =
+        element::lookup("C").unwrap();
+
+    assert_eq!(
+        carbon.standard_atomic_weight(),
+        Some(12.011)
+    );
+
+    let measured = carbon
+        .atomic_weight
+        .unwrap()
+        .measured();
+
+    assert_approx_eq!(
+        measured.value,
+        12.0106,
+        1e-12
+    );
+
+    assert_approx_eq!(
+        measured.uncertainty,
+        0.002 / 12_f64.sqrt(),
+        1e-15
+    );
+
+    assert!(
+        element::lookup("Ar")
+            .unwrap()
+            .atomic_weight
+            .unwrap()
+            .contains(39.948)
+    );
+}
+
+#[test]
+
+fn test_formula() {
+
+    let glucose: Formula = "C6H12O6"
+        .parse()
+        .unwrap();
+
+    assert_eq!(glucose.count(6), 6);
+
+    assert_approx_eq!(
+        glucose
+            .relative_mass()
+            .unwrap(),
+        180.156,
+        1e-9
+    );
+
+    assert_approx_eq!(
+        glucose
+            .monoisotopic_mass()
+            .unwrap(),
+        180.063_388_1,
+        1e-6
+    );
+
+    for (a, b) in [
+        ("Ca(OH)2", "CaO2H2"),
+        (
+            "K4[Fe(CN)6]",
+            "C6FeK4N6",
+        ),
+        (
+            "CuSO4·5H2O",
+            "CuH10O9S",
+        ),
+        (
+            "CuSO4.5H2O",
+            "CuSO9H10",
+        ),
+        ("2H2O", "H4O2"),
+        ("CD4", "C[2H]4"),
+    ] {
+
+        assert_eq!(
+            formula::parse(a),
+            formula::parse(b)
+        );
+    }
+
+    assert_eq!(
+        formula::parse("K4[Fe(CN)6]")
+            .unwrap()
+            .to_string(),
+        "C6FeK4N6"
+    );
+
+    assert_eq!(
+        formula::parse("CuSO4·5H2O")
+            .unwrap()
+            .to_string(),
+        "CuH10O9S"
+    );
+
+    assert_eq!(
+        formula::parse("[13C]6H12O6")
+            .unwrap()
+            .to_string(),
+        "[13C]6H12O6"
+    );
+
+    assert_eq!(
+        formula::parse("CD3OH")
+            .unwrap()
+            .to_string(),
+        "CH[2H]3O"
+    );
+
+    // Each label adds the mass difference of ¹³C and ¹²C.
+    let labelled =
+        formula::parse("[13C]C5H12O6")
+            .unwrap();
+
+    assert_approx_eq!(
+        labelled
+            .monoisotopic_mass()
+            .unwrap()
+            - glucose
+                .monoisotopic_mass()
+                .unwrap(),
+        1.003_354_835_07,
+        1e-9
+    );
+
+    assert_approx_eq!(
+        formula::parse("NaCl")
+            .unwrap()
+            .molar_mass()
+            .unwrap(),
+        58.439_769_28
+            * constant::MOLAR_MASS_CONSTANT,
+        1e-15
+    );
+}
+
+#[test]
+
+fn test_formula_errors() {
+
+    for (text, message) in [
+        ("", "empty formula"),
+        (
+            "Ca(OH",
+            "expected `)`",
+        ),
+        (
+            "CaOH)2",
+            "unbalanced `)`",
+        ),
+        (
+            "H0",
+            "a count must be positive",
+        ),
+        ("()", "empty group"),
+        (
+            "[13]",
+            "expected an element \
+             symbol after the mass \
+             number",
+        ),
+        (
+            "[13C",
+            "expected `]`",
+        ),
+        (
+            "[2D]",
+            "`D` and `T` cannot carry \
+             a mass number",
+        ),
+        (
+            "h2o",
+            "expected an element \
+             symbol",
+        ),
+        (
+            "H2O·",
+            "expected an element \
+             symbol",
+        ),
+        (
+            "H99999999999",
+            "count out of range",
+        ),
+    ] {
+
+        assert_eq!(
+            formula::parse(text),
+            Err(
+                ElementError::Syntax {
+                    formula: text
+                        .to_string(),
+                    message: message
+                        .to_string(),
+                }
+            ),
+            "{text}"
+        );
+    }
+
+    assert_eq!(
+        formula::parse("Xx2"),
+        Err(
+            ElementError::UnknownElement(
+                "Xx".to_string()
+            )
+        )
+    );
+
+    assert_eq!(
+        formula::parse("[15C]"),
+        Err(
+            ElementError::UnknownIsotope {
+                symbol: "C".to_string(),
+                mass_number: 15,
+            }
+        )
+    );
+
+    assert_eq!(
+        formula::parse("TcO4")
+            .unwrap()
+            .relative_mass(),
+        Err(
+            ElementError::NoStandardAtomicWeight(
+                "Tc".to_string()
+            )
+        )
+    );
+
+    assert!(
+        formula::parse("[99Tc]O4")
+            .unwrap()
+            .relative_mass()
+            .is_ok()
+    );
+
+    assert_eq!(
+        formula::parse("Pu")
+            .unwrap()
+            .isotope_pattern(0.0),
+        Err(
+            ElementError::NoNaturalIsotopes(
+                "Pu".to_string()
+            )
+        )
+    );
+}
+
+#[test]
+
+fn test_isotope_pattern() {
+
+    let peaks = formula::parse("C")
+        .unwrap()
+        .isotope_pattern(0.0)
+        .unwrap();
+
+    assert_eq!(peaks.len(), 2);
+
+    assert_approx_eq!(
+        peaks[1].abundance,
+        0.0107,
+        1e-12
+    );
+
+    // The M+1 peak grows by about 1.1 % per carbon.
+    let peaks = formula::parse("C60")
+        .unwrap()
+        .isotope_pattern(1e-3)
+        .unwrap();
+
+    assert_approx_eq!(
+        peaks[1].abundance
+            / peaks[0].abundance,
+        60.0 * 0.0107 / 0.9893,
+        1e-12
+    );
+
+    assert_eq!(
+        peaks[0].mass_number,
+        720
+    );
+
+    assert_approx_eq!(
+        peaks[0].mass,
+        720.0,
+        1e-9
+    );
+
+    let total: f64 =
+        formula::parse("C60")
+            .unwrap()
+            .isotope_pattern(0.0)
+            .unwrap()
+            .iter()
+            .map(|p| p.abundance)
+            .sum();
+
+    assert_approx_eq!(
+        total, 1.0, 1e-12
+    );
+
+    // Two chlorines give the 9:6:1 triplet.
+    let peaks =
+        formula::parse("CH2Cl2")
+            .unwrap()
+            .isotope_pattern(0.0)
+            .unwrap();
+
+    let m = peaks
+        .iter()
+        .find(|p| p.mass_number == 84)
+        .unwrap();
+
+    let m2 = peaks
+        .iter()
+        .find(|p| p.mass_number == 86)
+        .unwrap();
+
+    assert!(
+        (m2.abundance / m.abundance
+            - 0.64)
+            .abs()
+            < 0.02
+    );
+
+    // The mean of the pattern is the average molecular mass.
+    let glucose =
+        formula::parse("C6H12O6")
+            .unwrap();
+
+    let mean: f64 = glucose
+        .isotope_pattern(0.0)
+        .unwrap()
+        .iter()
+        .map(|p| p.abundance * p.mass)
+        .sum();
+
+    assert_approx_eq!(
+        mean,
+        glucose
+            .relative_mass()
+            .unwrap(),
+        1e-2
+    );
+}
@@ -0,0 +1,222 @@
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::constant;
+use rssn_advanced::unit::Dimension;
+use rssn_advanced::unit::EnergyUnit;
+use rssn_advanced::unit::energy;
+
+/// One unit in the last printed digit of a listed value.
+
+fn last_digit(value: f64) -> f64 {
+
+    let digits =
+        format!("{:e}", value.abs());
+
+    let (mantissa, exponent) = digits
+        .split_once('e')
+        .unwrap();
+
+    let decimals = mantissa
+        .split_once('.')
+        .map_or(0, |(_, d)| d.len());
+
+    10f64.powi(
+        exponent
+            .parse::<i32>()
+            .unwrap()
+            - i32::try_from(decimals)
+                .unwrap(),
+    )
+}
+
+#[test]
+
+fn test_matches_nist_conversion_table()
+{
+
+    let mut compared = 0;
+
+    for from in EnergyUnit::ALL {
+
+        for to in EnergyUnit::ALL {
+
+            if from == to {
+
+                assert!(
+                    energy::nist_relationship(from, to)
+                        .is_none()
+                );
+
+                continue;
+            }
+
+            let listed =
+                energy::nist_relationship(from, to)
+                    .unwrap_or_else(|| {
+
+                        panic!(
+                            "no {from}-{to} relationship"
+                        )
+                    });
+
+            let derived =
+                energy::factor(
+                    from, to,
+                );
+
+            // The listed value is rounded to its last printed digit, and
+            // the derived value inherits the rounding of m_u and E_h.
+            let allowance = listed
+                .uncertainty
+                + derived.uncertainty
+                + last_digit(
+                    listed.value,
+                );
+
+            assert!(
+                (derived.value
+                    - listed.value)
+                    .abs()
+                    <= allowance,
+                "{}: derived {} \
+                 listed {}",
+                listed.name,
+                derived.value,
+                listed.value
+            );
+
+            assert_eq!(
+                derived.is_exact(),
+                listed.exact,
+                "{}",
+                listed.name
+            );
+
+            if !listed.exact {
+
+                // Uncertainties are printed to two significant digits.
+                assert_approx_eq!(
+                    derived.uncertainty
+                        / listed.uncertainty,
+                    1.0,
+                    0.05
+                );
+            }
+
+            compared += 1;
+        }
+    }
+
+    assert_eq!(compared, 56);
+}
+
+#[test]
+
+fn test_matrix() {
+
+    let matrix = energy::matrix();
+
+    for (i, row) in matrix
+        .iter()
+        .enumerate()
+    {
+
+        assert_eq!(
+            row[i],
+            constant::Measured::exact(
+                1.0
+            )
+        );
+
+        for (j, factor) in row
+            .iter()
+            .enumerate()
+        {
+
+            assert_approx_eq!(
+                factor.value
+                    * matrix[j][i]
+                        .value,
+                1.0,
+                1e-14
+            );
+
+            assert_approx_eq!(
+                factor.relative_uncertainty(),
+                matrix[j][i].relative_uncertainty(),
+                1e-20
+            );
+        }
+    }
+
+    // u → E_h is correlated through A_r(e): its uncertainty is far below
+    // the quadrature sum of the uncertainties of m_u and E_h.
+    let u_hartree = energy::factor(
+        EnergyUnit::AtomicMassUnit,
+        EnergyUnit::Hartree,
+    );
+
+    let independent = constant::get_atomic_mass_constant_measured()
+        .relative_uncertainty()
+        .hypot(
+            constant::get_hartree_energy_measured()
+                .relative_uncertainty(),
+        );
+
+    assert!(
+        u_hartree
+            .relative_uncertainty()
+            < independent
+    );
+}
+
+#[test]
+
+fn test_energy_units() {
+
+    assert_approx_eq!(
+        energy::convert(
+            1.0,
+            EnergyUnit::Hartree,
+            EnergyUnit::ElectronVolt,
+        )
+        .value,
+        27.211_386_245_981,
+        1e-10
+    );
+
+    assert_approx_eq!(
+        energy::convert(
+            1e9,
+            EnergyUnit::Hertz,
+            EnergyUnit::InverseMetre,
+        )
+        .value,
+        1e9 / constant::SPEED_OF_LIGHT
+    );
+
+    for unit in EnergyUnit::ALL {
+
+        assert_eq!(
+            EnergyUnit::from_symbol(
+                unit.symbol()
+            ),
+            Some(unit)
+        );
+    }
+
+    assert_eq!(
+        EnergyUnit::from_symbol("m^-1"),
+        Some(EnergyUnit::InverseMetre)
+    );
+
+    assert_eq!(
+        EnergyUnit::from_symbol("cal"),
+        None
+    );
+
+    assert_eq!(
+        EnergyUnit::InverseMetre
+            .dimension(),
+        Dimension::LENGTH.recip()
+    );
+}
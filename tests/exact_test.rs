@@ -0,0 +1,198 @@
+use num_traits::ToPrimitive;
+use rssn_advanced::constant;
+use rssn_advanced::constant::exact;
+use rssn_advanced::constant::exact::ExactValue;
+
+#[test]
+
+fn test_exact_values_match_listing() {
+
+    for id in exact::IDS {
+
+        let record =
+            constant::lookup(id)
+                .unwrap_or_else(|| {
+
+                    panic!(
+                        "unknown {id}"
+                    )
+                });
+
+        assert!(record.exact, "{id}");
+
+        let value = record
+            .exact_value()
+            .unwrap()
+            .to_f64();
+
+        if record.truncated {
+
+            // The listing truncates: its digits are a prefix of the
+            // exact value.
+            let digits = format!(
+                "{:e}",
+                record.value
+            );
+
+            let mantissa = digits
+                .split_once('e')
+                .unwrap()
+                .0
+                .replace('.', "");
+
+            let expanded = record
+                .exact_value()
+                .unwrap()
+                .to_decimal(30)
+                .replace('.', "");
+
+            assert!(
+                expanded.starts_with(
+                    &mantissa
+                ),
+                "{id}: {expanded} vs \
+                 {digits}"
+            );
+        } else {
+
+            assert_eq!(
+                value.to_bits(),
+                record
+                    .value
+                    .to_bits(),
+                "{id}: {value} vs {}",
+                record.value
+            );
+        }
+    }
+}
+
+#[test]
+
+fn test_every_exact_constant_is_covered()
+ {
+
+    let missing: Vec<&str> =
+        constant::all()
+            .iter()
+            .filter(|c| {
+
+                c.exact
+                    && exact::value(
+                        c.id,
+                    )
+                    .is_none()
+            })
+            .map(|c| c.id)
+            .collect();
+
+    assert_eq!(
+        missing,
+        [
+            "WIEN_FREQUENCY_DISPLACEMENT_LAW_CONSTANT",
+            "WIEN_DISPLACEMENT_CONSTANT",
+        ]
+    );
+
+    for id in exact::DEFINING {
+
+        assert!(
+            exact::value(id)
+                .unwrap()
+                .is_rational()
+        );
+    }
+
+    assert!(
+        constant::lookup(
+            "ELECTRON_MASS"
+        )
+        .unwrap()
+        .exact_value()
+        .is_none()
+    );
+}
+
+#[test]
+
+fn test_arbitrary_precision() {
+
+    let pi = ExactValue::pi();
+
+    assert_eq!(
+        pi.to_decimal(50),
+        "3.1415926535897932384626433832795028841971693993751e0"
+    );
+
+    assert_eq!(
+        exact::pi(40).to_f64(),
+        Some(std::f64::consts::PI)
+    );
+
+    let value =
+        |id| exact::value(id).unwrap();
+
+    assert_eq!(
+        value("VON_KLITZING_CONSTANT")
+            .to_decimal(30),
+        "2.58128074593045066600455167061e4"
+    );
+
+    assert_eq!(
+        value("STEFAN_BOLTZMANN_CONSTANT")
+            .to_decimal(30),
+        "5.67037441918442945397099673189e-8"
+    );
+
+    assert_eq!(
+        value("MOLAR_GAS_CONSTANT")
+            .to_decimal(15),
+        "8.31446261815324e0"
+    );
+
+    assert_eq!(
+        value("PLANCK_CONSTANT")
+            .to_decimal(5),
+        "6.6261e-34"
+    );
+
+    assert!(
+        value("MOLAR_GAS_CONSTANT")
+            .is_rational()
+    );
+
+    assert!(
+        !value(
+            "REDUCED_PLANCK_CONSTANT"
+        )
+        .is_rational()
+    );
+
+    assert_eq!(
+        value(
+            "REDUCED_PLANCK_CONSTANT"
+        )
+        .pi_power,
+        -1
+    );
+
+    // Carry into a new leading digit, and rounding half away from zero.
+    assert_eq!(
+        ExactValue::decimal(9999, -3)
+            .to_decimal(2),
+        "1.0e1"
+    );
+
+    assert_eq!(
+        ExactValue::decimal(-25, -1)
+            .to_decimal(1),
+        "-3e0"
+    );
+
+    assert_eq!(
+        (ExactValue::from(3)
+            / ExactValue::pi().powi(2))
+        .to_string(),
+        "3 π⁻²"
+    );
+}
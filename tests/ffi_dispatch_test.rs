@@ -0,0 +1,600 @@
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_method_registry() {
+
+    use rssn_advanced::ffi_apis::dispatch;
+    use rssn_advanced::ffi_apis::dispatch::ParamSchema;
+
+    let methods = dispatch::methods();
+
+    // Sorted and unique, so lookups can bisect.
+    assert!(
+        methods.is_sorted_by(|a, b| {
+
+            a.name < b.name
+        })
+    );
+
+    assert!(
+        methods
+            .iter()
+            .all(|m| {
+
+                !m.schema()
+                    .summary
+                    .is_empty()
+            })
+    );
+
+    let schema = dispatch::method(
+        "unit.convert",
+    )
+    .unwrap()
+    .schema();
+
+    assert_eq!(
+        schema.params,
+        [
+            ("value", "f64"),
+            ("from", "String"),
+            ("to", "String"),
+        ]
+        .map(|(name, ty)| {
+
+            ParamSchema {
+                name: name.to_string(),
+                ty: ty.to_string(),
+            }
+        })
+    );
+
+    assert_eq!(
+        schema.returns,
+        "f64"
+    );
+
+    assert_eq!(
+        schema.summary,
+        "Converts `value` from the \
+         unit `from` to the unit `to` \
+         (e.g. `\"eV\"` to `\"J\"`)."
+    );
+
+    assert!(
+        dispatch::method("unit.nope")
+            .is_none()
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_call_json() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::constant;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::dispatch::rssn_call_json;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use serde_json::Value;
+
+    let call = |method: &str,
+                params: &str|
+     -> Option<Value> {
+
+        let method =
+            CString::new(method)
+                .unwrap();
+
+        let params =
+            CString::new(params)
+                .unwrap();
+
+        let json = rssn_call_json(
+            method.as_ptr(),
+            params.as_ptr(),
+        );
+
+        // Decoding a null result would replace the last error.
+        if json.is_null() {
+
+            return None;
+        }
+
+        let result =
+            from_json_string(json);
+
+        rssn_free_string(json);
+
+        result
+    };
+
+    let expected =
+        serde_json::to_value(
+            constant::lookup(
+                "SPEED_OF_LIGHT",
+            ),
+        )
+        .unwrap();
+
+    // Positional and named parameters.
+    assert_eq!(
+        call(
+            "constant.get",
+            "[\"SPEED_OF_LIGHT\"]"
+        ),
+        Some(expected.clone())
+    );
+
+    assert_eq!(
+        call(
+            "constant.get",
+            "{\"id\": \"\
+             SPEED_OF_LIGHT\"}"
+        ),
+        Some(expected)
+    );
+
+    let joules = call(
+        "unit.convert",
+        "{\"to\": \"J\", \"value\": \
+         1.0, \"from\": \"eV\"}",
+    )
+    .unwrap();
+
+    assert!(
+        (joules
+            .as_f64()
+            .unwrap()
+            - 1.602_176_634e-19)
+            .abs()
+            < 1e-30
+    );
+
+    // Methods without parameters.
+    for params in ["[]", "{}", "null"] {
+
+        assert_eq!(
+            call(
+                "math_constant.all",
+                params
+            )
+            .unwrap()
+            .as_array()
+            .unwrap()
+            .len(),
+            9
+        );
+    }
+
+    // Operations beyond the per-module exports.
+    let gauss = call(
+        "unit.in_system",
+        "[1e-4, \"T\", \
+         \"GaussianCgs\"]",
+    )
+    .unwrap();
+
+    assert!(
+        (gauss
+            .as_f64()
+            .unwrap()
+            - 1.0)
+            .abs()
+            < 1e-9
+    );
+
+    assert_eq!(
+        call(
+            "constant.exact",
+            "[\"SPEED_OF_LIGHT\", 12]"
+        ),
+        constant::lookup(
+            "SPEED_OF_LIGHT"
+        )
+        .and_then(|c| c.exact_value())
+        .map(|value| {
+
+            Value::from(
+                value.to_decimal(12),
+            )
+        })
+    );
+
+    assert!(
+        !call(
+            "constant.identify",
+            "[299792458.0, 1e-9]"
+        )
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .is_empty()
+    );
+
+    assert_eq!(
+        call(
+            "unit.energy_matrix",
+            "[]"
+        )
+        .unwrap()
+        .as_array()
+        .unwrap()
+        .len(),
+        8
+    );
+
+    assert!(
+        call(
+            "capability.get",
+            "[]"
+        )
+        .unwrap()
+        .is_object()
+    );
+
+    for (method, params, code) in [
+        (
+            "constant.nope",
+            "[]",
+            FfiStatus::NotFound,
+        ),
+        (
+            "constant.get",
+            "[\"NO_SUCH_CONSTANT\"]",
+            FfiStatus::NotFound,
+        ),
+        (
+            "constant.get",
+            "[]",
+            FfiStatus::InvalidArgument,
+        ),
+        (
+            "constant.get",
+            "{\"key\": \"c\"}",
+            FfiStatus::InvalidArgument,
+        ),
+        (
+            "constant.get",
+            "{\"id\": \"c\", \
+             \"edition\": \
+             \"Codata2018\"}",
+            FfiStatus::InvalidArgument,
+        ),
+        (
+            "constant.get",
+            "\"SPEED_OF_LIGHT\"",
+            FfiStatus::InvalidArgument,
+        ),
+        (
+            "constant.get",
+            "[299792458]",
+            FfiStatus::Deserialization,
+        ),
+        (
+            "constant.get",
+            "[",
+            FfiStatus::Deserialization,
+        ),
+        (
+            "unit.convert",
+            "[1.0, \"eV\", \"m\"]",
+            FfiStatus::InvalidArgument,
+        ),
+        (
+            "unit.in_system",
+            "[1.0, \"A/m\", \
+             \"GaussianCgs\"]",
+            FfiStatus::InvalidArgument,
+        ),
+        (
+            "constant.lookup",
+            "[\"mass\"]",
+            FfiStatus::Ambiguous,
+        ),
+        // Unbounded precision would not return.
+        (
+            "constant.exact",
+            "[\"SPEED_OF_LIGHT\", \
+             4000000000]",
+            FfiStatus::InvalidArgument,
+        ),
+        // NaN and the infinities would encode as `null`.
+        (
+            "measured.div",
+            "[{\"value\": 1.0, \
+             \"uncertainty\": 0.0}, \
+             {\"value\": 0.0, \
+             \"uncertainty\": 0.0}]",
+            FfiStatus::AlgorithmFailure,
+        ),
+    ] {
+
+        assert_eq!(
+            call(method, params),
+            None,
+            "{method}({params})"
+        );
+
+        assert_eq!(
+            rssn_last_error_code(),
+            code,
+            "{method}({params})"
+        );
+    }
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_call_bincode() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::ffi_apis::common::BincodeBuffer;
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::dispatch::rssn_call_bincode;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use rssn_advanced::math_constant::MathConstant;
+
+    let a = Measured::new(2.0, 0.1);
+
+    let b = Measured::new(3.0, 0.2);
+
+    let method =
+        CString::new("measured.mul")
+            .unwrap();
+
+    let params =
+        to_bincode_buffer(&(a, b));
+
+    let product = rssn_call_bincode(
+        method.as_ptr(),
+        params,
+    );
+
+    assert_eq!(
+        from_bincode_buffer::<Measured>(
+            &product
+        ),
+        Some(a * b)
+    );
+
+    rssn_free_bincode_buffer(product);
+
+    rssn_free_bincode_buffer(params);
+
+    // One parameter too many: the tuple has another schema.
+    let params =
+        to_bincode_buffer(&(a, b, a));
+
+    assert!(
+        rssn_call_bincode(
+            method.as_ptr(),
+            params
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::TypeMismatch
+    );
+
+    rssn_free_bincode_buffer(params);
+
+    // Methods without parameters take an empty buffer.
+    let method = CString::new(
+        "math_constant.all",
+    )
+    .unwrap();
+
+    let all = rssn_call_bincode(
+        method.as_ptr(),
+        BincodeBuffer::empty(),
+    );
+
+    assert_eq!(
+        from_bincode_buffer::<
+            Vec<MathConstant>,
+        >(&all),
+        Some(
+            MathConstant::ALL.to_vec()
+        )
+    );
+
+    rssn_free_bincode_buffer(all);
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_method_introspection() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::dispatch;
+    use rssn_advanced::ffi_apis::dispatch::MethodSchema;
+    use rssn_advanced::ffi_apis::dispatch::rssn_list_methods_bincode;
+    use rssn_advanced::ffi_apis::dispatch::rssn_list_methods_json;
+    use rssn_advanced::ffi_apis::dispatch::rssn_method_schema_bincode;
+    use rssn_advanced::ffi_apis::dispatch::rssn_method_schema_json;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+
+    let expected: Vec<MethodSchema> =
+        dispatch::methods()
+            .iter()
+            .map(dispatch::Method::schema)
+            .collect();
+
+    let json = rssn_list_methods_json();
+
+    assert_eq!(
+        from_json_string::<
+            Vec<MethodSchema>,
+        >(json)
+        .as_ref(),
+        Some(&expected)
+    );
+
+    rssn_free_string(json);
+
+    let buffer =
+        rssn_list_methods_bincode();
+
+    assert_eq!(
+        from_bincode_buffer::<
+            Vec<MethodSchema>,
+        >(&buffer)
+        .as_ref(),
+        Some(&expected)
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    let name = CString::new(
+        "constant.edition",
+    )
+    .unwrap();
+
+    let json = rssn_method_schema_json(
+        name.as_ptr(),
+    );
+
+    let schema: serde_json::Value =
+        from_json_string(json).unwrap();
+
+    rssn_free_string(json);
+
+    assert_eq!(
+        schema["params"],
+        serde_json::json!([
+            {"name": "edition", "type": "CodataEdition"},
+            {"name": "key", "type": "String"},
+        ])
+    );
+
+    let buffer =
+        rssn_method_schema_bincode(
+            name.as_ptr(),
+        );
+
+    assert_eq!(
+        from_bincode_buffer::<
+            MethodSchema,
+        >(&buffer),
+        dispatch::method(
+            "constant.edition"
+        )
+        .map(dispatch::Method::schema)
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    let name =
+        CString::new("nope").unwrap();
+
+    assert!(
+        rssn_method_schema_json(
+            name.as_ptr()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::NotFound
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_rpc_method_macro() {
+
+    use rssn_advanced::ffi_apis::dispatch::Method;
+    use rssn_advanced::ffi_apis::envelope;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::last_error;
+    use rssn_advanced::ffi_apis::error::require;
+    use serde_json::json;
+
+    const SCALE: Method = rssn_advanced::rpc_method!(
+        /// Scales a vector.
+        ///
+        /// Not part of the summary.
+        "test.scale",
+        |k: f64, v: Vec<f64>| -> Vec<f64> {
+            require(
+                Some(v).filter(|v| !v.is_empty()),
+                FfiStatus::InvalidArgument,
+                || "empty vector".to_string(),
+            )
+            .map(|v| v.iter().map(|x| k * x).collect())
+        }
+    );
+
+    let schema = SCALE.schema();
+
+    assert_eq!(
+        schema.summary,
+        "Scales a vector."
+    );
+
+    assert_eq!(
+        schema.returns,
+        "Vec<f64>"
+    );
+
+    assert_eq!(
+        SCALE.call_json(json!([
+            2.0,
+            [1.0, 2.5]
+        ])),
+        Some(json!([2.0, 5.0]))
+    );
+
+    assert_eq!(
+        SCALE.call_json(json!({
+            "k": 2.0,
+            "v": []
+        })),
+        None
+    );
+
+    assert_eq!(
+        last_error()
+            .unwrap()
+            .message,
+        "empty vector"
+    );
+
+    let params = envelope::encode(&(
+        3.0_f64,
+        vec![1.0_f64],
+    ))
+    .unwrap();
+
+    let result = SCALE
+        .call_bincode(&params)
+        .unwrap();
+
+    let decoded: Vec<f64> =
+        envelope::decode(&result)
+            .unwrap();
+
+    assert_eq!(decoded, [3.0]);
+}
@@ -0,0 +1,462 @@
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_schema_shapes() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::capability::Capabilities;
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::ffi_apis::envelope;
+    use rssn_advanced::ffi_apis::envelope::rssn_bincode_schema_id;
+    use rssn_advanced::ffi_apis::envelope::schema_id;
+    use rssn_advanced::ffi_apis::schema::shape;
+    use rssn_advanced::math_constant::MathConstant;
+    use rssn_advanced::unit::UnitError;
+    use serde::Deserialize;
+
+    const MEASURED: &str =
+        "{value: f64, uncertainty: \
+         f64}";
+
+    assert_eq!(
+        shape::<Measured>(),
+        MEASURED
+    );
+
+    // Borrowed forms have the shape of the owned forms they encode like.
+    assert_eq!(
+        shape::<&Capabilities>(),
+        shape::<Capabilities>()
+    );
+
+    assert_eq!(
+        shape::<&[&str]>(),
+        "seq<string>"
+    );
+
+    assert_eq!(
+        shape::<&str>(),
+        shape::<String>()
+    );
+
+    assert_eq!(
+        shape::<[MathConstant; 9]>(),
+        "[enum{Pi, E, Ln2, \
+         EulerGamma, Catalan, Apery, \
+         Glaisher, Khinchin, \
+         FeigenbaumDelta}; 9]"
+    );
+
+    assert!(
+        shape::<
+            Result<f64, UnitError>,
+        >()
+        .starts_with(
+            "enum{Ok(f64), \
+             Err(enum{Syntax{notation: \
+             string, message: string}, \
+             UnknownUnit(string), "
+        )
+    );
+
+    assert_eq!(
+        shape::<(
+            Measured,
+            Option<Vec<Measured>>,
+        )>(),
+        format!(
+            "({MEASURED}, \
+             option<seq<{MEASURED}>>)"
+        )
+    );
+
+    // The id follows the fields, not the name of the type.
+    #[derive(
+        Debug, PartialEq, Deserialize,
+    )]
+
+    struct Mirror {
+        value: f64,
+        uncertainty: f64,
+    }
+
+    rssn_advanced::impl_schema!(
+        Mirror {
+            value: f64,
+            uncertainty: f64,
+        }
+    );
+
+    struct Reordered;
+
+    rssn_advanced::impl_schema!(
+        Reordered {
+            uncertainty: f64,
+            value: f64,
+        }
+    );
+
+    assert_eq!(
+        schema_id::<Mirror>(),
+        schema_id::<Measured>()
+    );
+
+    assert_ne!(
+        schema_id::<Reordered>(),
+        schema_id::<Measured>()
+    );
+
+    assert_eq!(
+        envelope::decode::<Mirror>(
+            &envelope::encode(
+                &Measured::new(
+                    2.0, 0.1
+                )
+            )
+            .unwrap()
+        ),
+        Ok(Mirror {
+            value: 2.0,
+            uncertainty: 0.1,
+        })
+    );
+
+    let vec = CString::new(format!(
+        "seq<{MEASURED}>"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        rssn_bincode_schema_id(
+            vec.as_ptr()
+        ),
+        schema_id::<Vec<Measured>>()
+    );
+
+    assert_eq!(
+        rssn_bincode_schema_id(
+            std::ptr::null()
+        ),
+        0
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_envelope_layout() {
+
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::ffi_apis::envelope;
+    use rssn_advanced::ffi_apis::envelope::RSSN_BINCODE_HEADER_LEN;
+    use rssn_advanced::ffi_apis::envelope::RSSN_BINCODE_MAGIC;
+    use rssn_advanced::ffi_apis::envelope::RSSN_BINCODE_VERSION;
+
+    let m = Measured::new(2.0, 0.1);
+
+    let bytes =
+        envelope::encode(&m).unwrap();
+
+    assert_eq!(
+        bytes[.. 4],
+        RSSN_BINCODE_MAGIC
+    );
+
+    assert_eq!(
+        u16::from_le_bytes(
+            bytes[4 .. 6]
+                .try_into()
+                .unwrap()
+        ),
+        RSSN_BINCODE_VERSION
+    );
+
+    assert_eq!(
+        u64::from_le_bytes(
+            bytes[8 .. 16]
+                .try_into()
+                .unwrap()
+        ),
+        envelope::schema_id::<Measured>(
+        )
+    );
+
+    assert_eq!(
+        u64::from_le_bytes(
+            bytes[16 .. 24]
+                .try_into()
+                .unwrap()
+        ),
+        (bytes.len()
+            - RSSN_BINCODE_HEADER_LEN)
+            as u64
+    );
+
+    let (header, payload) =
+        envelope::open(&bytes).unwrap();
+
+    assert_eq!(
+        payload,
+        &bytes
+            [RSSN_BINCODE_HEADER_LEN ..]
+    );
+
+    assert_eq!(
+        header.version,
+        RSSN_BINCODE_VERSION
+    );
+
+    assert_eq!(
+        envelope::decode::<Measured>(
+            &bytes
+        ),
+        Ok(m)
+    );
+
+    // A byte array encodes as its bytes, so the checksum is the CRC-32
+    // check value.
+    let bytes =
+        envelope::encode(b"123456789")
+            .unwrap();
+
+    assert_eq!(
+        &bytes
+            [RSSN_BINCODE_HEADER_LEN ..],
+        b"123456789"
+    );
+
+    assert_eq!(
+        bytes[24 .. 28],
+        0xcbf4_3926_u32.to_le_bytes()
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_envelope_errors() {
+
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::ffi_apis::envelope;
+    use rssn_advanced::ffi_apis::envelope::EnvelopeError;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use serde::Deserialize;
+
+    // Another layout, relabelled with the schema id it expects.
+    #[derive(Debug, Deserialize)]
+
+    struct Measured2 {
+        #[allow(dead_code)]
+        value: String,
+    }
+
+    rssn_advanced::impl_schema!(
+        Measured2 {
+            value: String
+        }
+    );
+
+    let bytes = envelope::encode(
+        &Measured::new(2.0, 0.1),
+    )
+    .unwrap();
+
+    let decode = |bytes: &[u8]| {
+
+        envelope::decode::<Measured>(
+            bytes,
+        )
+        .unwrap_err()
+    };
+
+    let error = decode(&bytes[.. 20]);
+
+    assert_eq!(
+        error,
+        EnvelopeError::Truncated {
+            len: 20
+        }
+    );
+
+    assert_eq!(
+        error.status(),
+        FfiStatus::InvalidEnvelope
+    );
+
+    let mut foreign = bytes.clone();
+
+    foreign[0] = b'X';
+
+    assert!(matches!(
+        decode(&foreign),
+        EnvelopeError::BadMagic(_)
+    ));
+
+    let mut newer = bytes.clone();
+
+    newer[4] = 2;
+
+    let error = decode(&newer);
+
+    assert_eq!(
+        error,
+        EnvelopeError::UnsupportedVersion(
+            2
+        )
+    );
+
+    assert_eq!(
+        error.to_string(),
+        "envelope format version 2 is \
+         not supported (expected 1)"
+    );
+
+    let mut longer = bytes.clone();
+
+    longer.push(0);
+
+    assert!(matches!(
+        decode(&longer),
+        EnvelopeError::LengthMismatch {
+            declared: 16,
+            actual: 17,
+        }
+    ));
+
+    let mut damaged = bytes.clone();
+
+    *damaged
+        .last_mut()
+        .unwrap() ^= 1;
+
+    assert!(matches!(
+        decode(&damaged),
+        EnvelopeError::ChecksumMismatch {
+            ..
+        }
+    ));
+
+    let error =
+        envelope::decode::<f64>(&bytes)
+            .unwrap_err();
+
+    assert_eq!(
+        error.status(),
+        FfiStatus::TypeMismatch
+    );
+
+    assert!(
+        error
+            .to_string()
+            .contains("schema `f64`")
+    );
+
+    let error = envelope::decode::<
+        Measured2,
+    >(&{
+
+        let mut renamed = bytes.clone();
+
+        renamed[8 .. 16]
+            .copy_from_slice(
+                &envelope::schema_id::<
+                    Measured2,
+                >()
+                .to_le_bytes(),
+            );
+
+        renamed
+    })
+    .unwrap_err();
+
+    assert_eq!(
+        error.status(),
+        FfiStatus::Deserialization
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_envelope_ffi() {
+
+    use rssn_advanced::constant::CodataEdition;
+    use rssn_advanced::constant::ConstantGroup;
+    use rssn_advanced::ffi_apis::common::BincodeBuffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constants_in_group_bincode;
+    use rssn_advanced::ffi_apis::envelope;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::last_error;
+
+    let group = to_bincode_buffer(
+        &ConstantGroup::Electromagnetic,
+    );
+
+    let records =
+        rssn_get_constants_in_group_bincode(
+            group,
+        );
+
+    assert!(!records.is_null());
+
+    rssn_free_bincode_buffer(records);
+
+    rssn_free_bincode_buffer(group);
+
+    // A payload of another type.
+    let edition = to_bincode_buffer(
+        &CodataEdition::Codata2018,
+    );
+
+    assert!(
+        rssn_get_constants_in_group_bincode(
+            edition
+        )
+        .is_null()
+    );
+
+    let error = last_error().unwrap();
+
+    assert_eq!(
+        error.code,
+        FfiStatus::TypeMismatch
+    );
+
+    assert!(
+        error
+            .message
+            .contains(
+            "schema `enum{Universal, "
+        )
+    );
+
+    rssn_free_bincode_buffer(edition);
+
+    // A newer format version.
+    let mut bytes = envelope::encode(
+        &ConstantGroup::Electromagnetic,
+    )
+    .unwrap();
+
+    bytes[4] = 2;
+
+    assert!(
+        rssn_get_constants_in_group_bincode(
+            BincodeBuffer {
+                data: bytes.as_mut_ptr(),
+                len: bytes.len(),
+            }
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        last_error()
+            .unwrap()
+            .code,
+        FfiStatus::InvalidEnvelope
+    );
+}
@@ -0,0 +1,425 @@
+#[cfg(feature = "ffi_api")]
+
+rssn_advanced::json_ffi_unary!(
+    test_double_json,
+    f64,
+    |x| x * 2.0
+);
+
+#[cfg(feature = "ffi_api")]
+
+rssn_advanced::bincode_ffi_binary!(
+    test_add_bincode,
+    f64,
+    f64,
+    |x, y| x + y
+);
+
+#[cfg(feature = "ffi_api")]
+
+rssn_advanced::handle_ffi_unary!(
+    test_negate_handle,
+    f64,
+    |x| -x
+);
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_last_error() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_json;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::last_error;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_clear;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_message;
+
+    let id = CString::new(
+        "NO_SUCH_CONSTANT",
+    )
+    .unwrap();
+
+    assert!(
+        rssn_get_constant_json(
+            id.as_ptr()
+        )
+        .is_null()
+    );
+
+    let error = last_error().unwrap();
+
+    assert_eq!(
+        error.code,
+        FfiStatus::NotFound
+    );
+
+    assert_eq!(
+        error.function,
+        "rssn_get_constant_json"
+    );
+
+    assert!(
+        error
+            .message
+            .contains(
+                "NO_SUCH_CONSTANT"
+            )
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::NotFound
+    );
+
+    // Reading the error does not clear it.
+    let message =
+        rssn_last_error_message();
+
+    assert!(!message.is_null());
+
+    rssn_free_string(message);
+
+    assert!(last_error().is_some());
+
+    // A successful call does.
+    let id =
+        CString::new("SPEED_OF_LIGHT")
+            .unwrap();
+
+    let json = rssn_get_constant_json(
+        id.as_ptr(),
+    );
+
+    assert!(!json.is_null());
+
+    rssn_free_string(json);
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::Ok
+    );
+
+    assert!(
+        rssn_last_error_message()
+            .is_null()
+    );
+
+    rssn_get_constant_json(
+        std::ptr::null(),
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::NullPointer
+    );
+
+    rssn_last_error_clear();
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::Ok
+    );
+
+    // The record is per thread.
+    rssn_get_constant_json(
+        std::ptr::null(),
+    );
+
+    std::thread::spawn(|| {
+
+        assert!(last_error().is_none());
+    })
+    .join()
+    .unwrap();
+
+    assert!(last_error().is_some());
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_error_codes() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::BincodeBuffer;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_bincode;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_id;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constant_value;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constants_in_group_bincode;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_get_constants_in_group_json;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::last_error;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_digits;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_mass;
+
+    let code = || {
+
+        last_error()
+            .unwrap()
+            .code
+    };
+
+    // Invalid UTF-8.
+    let bytes = [0xff_u8, 0xfe, 0];
+
+    assert!(
+        rssn_get_constant_value(
+            bytes
+                .as_ptr()
+                .cast()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::InvalidUtf8
+    );
+
+    // Well-formed JSON of the wrong type.
+    let group =
+        CString::new("\"NoSuchGroup\"")
+            .unwrap();
+
+    assert!(
+        rssn_get_constants_in_group_json(
+            group.as_ptr()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::Deserialization
+    );
+
+    assert!(
+        rssn_get_constants_in_group_bincode(
+            BincodeBuffer::empty()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::NullPointer
+    );
+
+    // Bytes without an envelope.
+    let mut garbage = vec![0xff_u8; 3];
+
+    assert!(
+        rssn_get_constants_in_group_bincode(
+            BincodeBuffer {
+                data: garbage.as_mut_ptr(),
+                len: garbage.len(),
+            }
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::InvalidEnvelope
+    );
+
+    assert!(
+        rssn_get_constant_bincode(
+            std::ptr::null()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        last_error()
+            .unwrap()
+            .function,
+        "rssn_get_constant_bincode"
+    );
+
+    assert!(
+        rssn_get_constant_id(
+            usize::MAX
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::InvalidArgument
+    );
+
+    let pi =
+        CString::new("pi").unwrap();
+
+    assert!(
+        rssn_math_constant_digits(
+            pi.as_ptr(),
+            u32::MAX
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::InvalidArgument
+    );
+
+    assert!(
+        rssn_get_particle_mass(0)
+            .is_nan()
+    );
+
+    assert_eq!(
+        code(),
+        FfiStatus::NotFound
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_last_error_json() {
+
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::error::FfiError;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_json;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_width;
+
+    assert!(
+        rssn_get_particle_width(2212)
+            .is_nan()
+    );
+
+    let json = rssn_last_error_json();
+
+    let error: Option<
+        Option<FfiError>,
+    > = from_json_string(json);
+
+    rssn_free_string(json);
+
+    let error = error
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(
+        error.code,
+        FfiStatus::NotFound
+    );
+
+    assert_eq!(
+        error.to_string(),
+        "not found in \
+         rssn_get_particle_width: no \
+         width is quoted for p"
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_generic_macros() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::last_error;
+    use rssn_advanced::ffi_apis::handle::RssnHandle;
+
+    let input =
+        CString::new("1.5").unwrap();
+
+    let json = test_double_json(
+        input.as_ptr(),
+    );
+
+    assert_eq!(
+        from_json_string::<f64>(json),
+        Some(3.0)
+    );
+
+    rssn_free_string(json);
+
+    let input =
+        CString::new("[1.5]").unwrap();
+
+    assert!(
+        test_double_json(
+            input.as_ptr()
+        )
+        .is_null()
+    );
+
+    let error = last_error().unwrap();
+
+    assert_eq!(
+        error.code,
+        FfiStatus::Deserialization
+    );
+
+    assert_eq!(
+        error.function,
+        "test_double_json"
+    );
+
+    let x = to_bincode_buffer(&1.0_f64);
+
+    let y = to_bincode_buffer(&2.0_f64);
+
+    let sum = test_add_bincode(x, y);
+
+    assert_eq!(
+        from_bincode_buffer::<f64>(
+            &sum
+        ),
+        Some(3.0)
+    );
+
+    rssn_free_bincode_buffer(sum);
+
+    assert!(
+        test_add_bincode(
+            x,
+            rssn_advanced::ffi_apis::common::BincodeBuffer::empty()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        last_error()
+            .unwrap()
+            .code,
+        FfiStatus::NullPointer
+    );
+
+    rssn_free_bincode_buffer(x);
+
+    rssn_free_bincode_buffer(y);
+
+    assert!(
+        test_negate_handle(
+            RssnHandle::NULL
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        last_error()
+            .unwrap()
+            .code,
+        FfiStatus::InvalidHandle
+    );
+}
@@ -0,0 +1,349 @@
+#[cfg(feature = "ffi_api")]
+
+rssn_advanced::handle_ffi_binary!(
+    test_scale_handle,
+    f64,
+    rssn_advanced::constant::Measured,
+    rssn_advanced::constant::Measured,
+    |k, m| *k * *m
+);
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_handle_lifecycle() {
+
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::last_error;
+    use rssn_advanced::ffi_apis::handle;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_clone;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_free;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_is_valid;
+
+    let m = handle::insert(
+        Measured::new(2.0, 0.1),
+    );
+
+    assert!(!m.is_null());
+
+    assert!(rssn_handle_is_valid(m));
+
+    assert_eq!(
+        handle::type_name(m),
+        Some("Measured")
+    );
+
+    let copy = rssn_handle_clone(m);
+
+    assert_ne!(copy, m);
+
+    assert!(rssn_handle_free(m));
+
+    // The copy outlives the original.
+    assert_eq!(
+        handle::get::<Measured>(copy),
+        Some(Measured::new(
+            2.0, 0.1
+        ))
+    );
+
+    // Double free.
+    assert!(!rssn_handle_free(m));
+
+    assert_eq!(
+        last_error()
+            .unwrap()
+            .code,
+        FfiStatus::InvalidHandle
+    );
+
+    assert!(!rssn_handle_is_valid(m));
+
+    // A freed slot is reused under a new generation: the stale handle
+    // does not reach the new object.
+    let handles: Vec<_> = (0 .. 64)
+        .map(|i| {
+            handle::insert(f64::from(i))
+        })
+        .collect();
+
+    assert!(
+        handles
+            .iter()
+            .all(|h| *h != m)
+    );
+
+    assert_eq!(
+        handle::get::<f64>(m),
+        None
+    );
+
+    // Wrong type.
+    assert_eq!(
+        handle::get::<f64>(copy),
+        None
+    );
+
+    let error = last_error().unwrap();
+
+    assert_eq!(
+        error.code,
+        FfiStatus::TypeMismatch
+    );
+
+    assert!(
+        error
+            .message
+            .contains("Measured")
+    );
+
+    for h in handles {
+
+        assert!(rssn_handle_free(h));
+    }
+
+    assert!(rssn_handle_free(
+        copy
+    ));
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_handle_json() {
+
+    use std::ffi::CStr;
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use rssn_advanced::ffi_apis::handle::RssnHandle;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_free;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_from_json;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_to_json;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_type;
+    use rssn_advanced::unit::Quantity;
+
+    let quantity = Quantity::with_unit(
+        1.5,
+        "km/h",
+    )
+    .unwrap();
+
+    let kind = CString::new("Quantity")
+        .unwrap();
+
+    let json = CString::new(
+        serde_json::to_string(
+            &quantity,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let h = rssn_handle_from_json(
+        kind.as_ptr(),
+        json.as_ptr(),
+    );
+
+    assert!(!h.is_null());
+
+    let out = rssn_handle_to_json(h);
+
+    assert_eq!(
+        from_json_string::<Quantity>(
+            out
+        ),
+        Some(quantity)
+    );
+
+    rssn_free_string(out);
+
+    let name = rssn_handle_type(h);
+
+    assert_eq!(
+        unsafe {
+
+            CStr::from_ptr(name)
+        }
+        .to_str(),
+        Ok("Quantity")
+    );
+
+    rssn_free_string(name);
+
+    assert!(rssn_handle_free(h));
+
+    assert!(
+        rssn_handle_type(h).is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::InvalidHandle
+    );
+
+    // Malformed JSON for the type.
+    let measured =
+        CString::new("Measured")
+            .unwrap();
+
+    assert_eq!(
+        rssn_handle_from_json(
+            measured.as_ptr(),
+            json.as_ptr()
+        ),
+        RssnHandle::NULL
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::Deserialization
+    );
+
+    let unknown =
+        CString::new("Optimizer")
+            .unwrap();
+
+    assert_eq!(
+        rssn_handle_from_json(
+            unknown.as_ptr(),
+            json.as_ptr()
+        ),
+        RssnHandle::NULL
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::NotFound
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_measured_handles() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::constant;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_constant_expr_handle;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_constant_measured_handle;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_expr_evaluate;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_expr_simplify;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_measured_mul;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_measured_new;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_measured_uncertainty;
+    use rssn_advanced::ffi_apis::constant_ffi::rssn_measured_value;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use rssn_advanced::ffi_apis::handle;
+    use rssn_advanced::ffi_apis::handle::rssn_handle_free;
+
+    let id =
+        CString::new("ELECTRON_MASS")
+            .unwrap();
+
+    let m_e =
+        rssn_constant_measured_handle(
+            id.as_ptr(),
+        );
+
+    let two =
+        rssn_measured_new(2.0, -0.0);
+
+    let product =
+        rssn_measured_mul(m_e, two);
+
+    let expected = constant::get_electron_mass_measured()
+        * 2.0;
+
+    assert_eq!(
+        rssn_measured_value(product),
+        expected.value
+    );
+
+    assert_eq!(
+        rssn_measured_uncertainty(
+            product
+        ),
+        expected.uncertainty
+    );
+
+    let k = handle::insert(3.0_f64);
+
+    let scaled =
+        test_scale_handle(k, m_e);
+
+    assert_eq!(
+        rssn_measured_value(scaled),
+        3.0 * expected.value / 2.0
+    );
+
+    // Arguments in the wrong order.
+    assert!(
+        test_scale_handle(m_e, k)
+            .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::TypeMismatch
+    );
+
+    assert!(
+        rssn_measured_value(k).is_nan()
+    );
+
+    for h in [
+        m_e,
+        two,
+        product,
+        k,
+        scaled,
+    ] {
+
+        assert!(rssn_handle_free(h));
+    }
+
+    let key =
+        CString::new("ħ").unwrap();
+
+    let hbar =
+        rssn_constant_expr_handle(
+            key.as_ptr(),
+        );
+
+    assert_eq!(
+        handle::type_name(hbar),
+        Some("Expr")
+    );
+
+    let simplified =
+        rssn_expr_simplify(hbar);
+
+    assert_eq!(
+        handle::type_name(simplified),
+        Some("Expr")
+    );
+
+    let value =
+        rssn_expr_evaluate(hbar);
+
+    assert_eq!(
+        rssn_measured_value(value),
+        constant::get_reduced_planck_constant()
+    );
+
+    for h in [
+        hbar,
+        simplified,
+        value,
+    ] {
+
+        assert!(rssn_handle_free(h));
+    }
+}
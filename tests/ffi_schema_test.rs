@@ -0,0 +1,1135 @@
+#[cfg(feature = "ffi_api")]
+/// Checks serialized values against the shapes their types declare.
+
+mod serialized {
+
+    use std::fmt;
+
+    use rssn_advanced::ffi_apis::schema::Schema;
+    use rssn_advanced::ffi_apis::schema::shape;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::de;
+
+    /// A JSON value with the keys of its objects in serialized order.
+    #[derive(Debug)]
+
+    pub enum Tree {
+        Null,
+        Bool,
+        Number,
+        String(String),
+        Seq(Vec<Tree>),
+        Map(Vec<(String, Tree)>),
+    }
+
+    struct TreeVisitor;
+
+    impl<'de> de::Visitor<'de>
+        for TreeVisitor
+    {
+        type Value = Tree;
+
+        fn expecting(
+            &self,
+            f: &mut fmt::Formatter<'_>,
+        ) -> fmt::Result {
+
+            f.write_str("a JSON value")
+        }
+
+        fn visit_unit<E>(
+            self
+        ) -> Result<Tree, E> {
+
+            Ok(Tree::Null)
+        }
+
+        fn visit_bool<E>(
+            self,
+            _: bool,
+        ) -> Result<Tree, E> {
+
+            Ok(Tree::Bool)
+        }
+
+        fn visit_i64<E>(
+            self,
+            _: i64,
+        ) -> Result<Tree, E> {
+
+            Ok(Tree::Number)
+        }
+
+        fn visit_u64<E>(
+            self,
+            _: u64,
+        ) -> Result<Tree, E> {
+
+            Ok(Tree::Number)
+        }
+
+        fn visit_f64<E>(
+            self,
+            _: f64,
+        ) -> Result<Tree, E> {
+
+            Ok(Tree::Number)
+        }
+
+        fn visit_str<E>(
+            self,
+            value: &str,
+        ) -> Result<Tree, E> {
+
+            Ok(Tree::String(
+                value.to_string(),
+            ))
+        }
+
+        fn visit_seq<
+            A: de::SeqAccess<'de>,
+        >(
+            self,
+            mut seq: A,
+        ) -> Result<Tree, A::Error>
+        {
+
+            let mut items = Vec::new();
+
+            while let Some(item) =
+                seq.next_element()?
+            {
+
+                items.push(item);
+            }
+
+            Ok(Tree::Seq(items))
+        }
+
+        fn visit_map<
+            A: de::MapAccess<'de>,
+        >(
+            self,
+            mut map: A,
+        ) -> Result<Tree, A::Error>
+        {
+
+            let mut entries =
+                Vec::new();
+
+            while let Some(entry) =
+                map.next_entry()?
+            {
+
+                entries.push(entry);
+            }
+
+            Ok(Tree::Map(entries))
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Tree {
+        fn deserialize<
+            D: Deserializer<'de>,
+        >(
+            deserializer: D
+        ) -> Result<Self, D::Error>
+        {
+
+            deserializer
+                .deserialize_any(
+                    TreeVisitor,
+                )
+        }
+    }
+
+    /// A shape, parsed from the text of `schema::shape`.
+    #[derive(Debug)]
+
+    pub enum Shape {
+        Word(String),
+        Option(Box<Shape>),
+        Seq(Box<Shape>),
+        Array(Box<Shape>, usize),
+        Tuple(Vec<Shape>),
+        Map(Box<Shape>),
+        Struct(Vec<(String, Shape)>),
+        Enum(Vec<(String, Payload)>),
+    }
+
+    /// The payload of an enum variant.
+    #[derive(Debug)]
+
+    pub enum Payload {
+        Unit,
+        Tuple(Vec<Shape>),
+        Struct(Vec<(String, Shape)>),
+    }
+
+    struct Parser<'a> {
+        rest: &'a str,
+    }
+
+    impl Parser<'_> {
+        fn eat(
+            &mut self,
+            token: &str,
+        ) -> bool {
+
+            match self
+                .rest
+                .strip_prefix(token)
+            {
+                | Some(rest) => {
+
+                    self.rest = rest;
+
+                    true
+                },
+                | None => false,
+            }
+        }
+
+        fn expect(
+            &mut self,
+            token: &str,
+        ) {
+
+            assert!(
+                self.eat(token),
+                "expected `{token}` \
+                 at `{}`",
+                self.rest
+            );
+        }
+
+        fn word(&mut self) -> String {
+
+            let end = self
+                .rest
+                .find(|c: char| {
+
+                    !(c.is_alphanumeric(
+                    )
+                        || c == '_')
+                })
+                .unwrap_or(
+                    self.rest.len(),
+                );
+
+            let (word, rest) = self
+                .rest
+                .split_at(end);
+
+            self.rest = rest;
+
+            word.to_string()
+        }
+
+        /// Parses `open item, item… close`.
+
+        fn list<T>(
+            &mut self,
+            close: &str,
+            mut item: impl FnMut(
+                &mut Self,
+            )
+                -> T,
+        ) -> Vec<T> {
+
+            let mut items = Vec::new();
+
+            while !self.eat(close) {
+
+                if !items.is_empty() {
+
+                    self.expect(", ");
+                }
+
+                items.push(item(self));
+            }
+
+            items
+        }
+
+        fn fields(
+            &mut self
+        ) -> Vec<(String, Shape)>
+        {
+
+            self.list("}", |parser| {
+
+                let name =
+                    parser.word();
+
+                parser.expect(": ");
+
+                (name, parser.shape())
+            })
+        }
+
+        fn shape(&mut self) -> Shape {
+
+            if self.eat("()") {
+
+                return Shape::Word(
+                    "()".to_string(),
+                );
+            }
+
+            if self.eat("(") {
+
+                return Shape::Tuple(
+                    self.list(
+                        ")",
+                        Self::shape,
+                    ),
+                );
+            }
+
+            if self.eat("{") {
+
+                return Shape::Struct(
+                    self.fields(),
+                );
+            }
+
+            if self.eat("[") {
+
+                let element =
+                    self.shape();
+
+                self.expect("; ");
+
+                let length =
+                    self.word();
+
+                self.expect("]");
+
+                return Shape::Array(
+                    Box::new(element),
+                    length
+                        .parse()
+                        .unwrap(),
+                );
+            }
+
+            let word = self.word();
+
+            match word.as_str() {
+                | "enum" => {
+
+                    self.expect("{");
+
+                    Shape::Enum(self.list(
+                        "}",
+                        |parser| {
+
+                            let name =
+                                parser.word();
+
+                            let payload = if parser
+                                .eat("(")
+                            {
+
+                                Payload::Tuple(
+                                    parser.list(
+                                        ")",
+                                        Self::shape,
+                                    ),
+                                )
+                            } else if parser
+                                .eat("{")
+                            {
+
+                                Payload::Struct(
+                                    parser.fields(),
+                                )
+                            } else {
+
+                                Payload::Unit
+                            };
+
+                            (name, payload)
+                        },
+                    ))
+                },
+                | "option" | "seq" => {
+
+                    self.expect("<");
+
+                    let inner =
+                        Box::new(
+                            self.shape(
+                            ),
+                        );
+
+                    self.expect(">");
+
+                    if word == "option"
+                    {
+
+                        Shape::Option(
+                            inner,
+                        )
+                    } else {
+
+                        Shape::Seq(
+                            inner,
+                        )
+                    }
+                },
+                | "map" => {
+
+                    self.expect("<");
+
+                    // The keys are written as JSON strings whatever their
+                    // shape, so only the values are checked.
+                    let _key =
+                        self.shape();
+
+                    self.expect(", ");
+
+                    let value =
+                        self.shape();
+
+                    self.expect(">");
+
+                    Shape::Map(
+                        Box::new(value),
+                    )
+                },
+                | _ => {
+
+                    assert!(
+                        !word
+                            .is_empty(),
+                        "unexpected \
+                         `{}`",
+                        self.rest
+                    );
+
+                    Shape::Word(word)
+                },
+            }
+        }
+    }
+
+    /// Parses the text of a shape.
+
+    pub fn parse(text: &str) -> Shape {
+
+        let mut parser = Parser {
+            rest: text,
+        };
+
+        let shape = parser.shape();
+
+        assert!(
+            parser
+                .rest
+                .is_empty(),
+            "trailing `{}` in `{text}`",
+            parser.rest
+        );
+
+        shape
+    }
+
+    fn conforms_fields(
+        tree: &Tree,
+        fields: &[(String, Shape)],
+        path: &str,
+    ) -> Result<(), String> {
+
+        let Tree::Map(entries) = tree
+        else {
+
+            return Err(format!(
+                "{path}: expected an \
+                 object, found \
+                 {tree:?}"
+            ));
+        };
+
+        let names: Vec<&str> = fields
+            .iter()
+            .map(|(name, _)| {
+
+                name.as_str()
+            })
+            .collect();
+
+        let keys: Vec<&str> = entries
+            .iter()
+            .map(|(key, _)| {
+
+                key.as_str()
+            })
+            .collect();
+
+        if names != keys {
+
+            return Err(format!(
+                "{path}: the shape \
+                 lists {names:?}, \
+                 serde writes {keys:?}"
+            ));
+        }
+
+        for (
+            (name, shape),
+            (_, value),
+        ) in fields
+            .iter()
+            .zip(entries)
+        {
+
+            conforms(
+                value,
+                shape,
+                &format!(
+                    "{path}.{name}"
+                ),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn conforms_elements(
+        items: &[Tree],
+        elements: &[Shape],
+        path: &str,
+    ) -> Result<(), String> {
+
+        if items.len() != elements.len()
+        {
+
+            return Err(format!(
+                "{path}: expected {} \
+                 elements, found {}",
+                elements.len(),
+                items.len()
+            ));
+        }
+
+        for (i, (item, element)) in
+            items
+                .iter()
+                .zip(elements)
+                .enumerate()
+        {
+
+            conforms(
+                item,
+                element,
+                &format!("{path}.{i}"),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a serialized value has the given shape.
+
+    pub fn conforms(
+        tree: &Tree,
+        shape: &Shape,
+        path: &str,
+    ) -> Result<(), String> {
+
+        let mismatch = || {
+
+            Err(format!(
+                "{path}: {tree:?} \
+                 does not have the \
+                 shape {shape:?}"
+            ))
+        };
+
+        match (shape, tree) {
+            | (
+                Shape::Word(word),
+                _,
+            ) => {
+
+                let fits = match word
+                    .as_str()
+                {
+                    | "Expr" => true,
+                    | "bool" => {
+
+                        matches!(
+                            tree,
+                            Tree::Bool
+                        )
+                    },
+                    | "char"
+                    | "string" => {
+
+                        matches!(
+                            tree,
+                            Tree::String(_)
+                        )
+                    },
+                    | "()" => {
+
+                        matches!(
+                            tree,
+                            Tree::Null
+                        )
+                    },
+                    // `serde_json` writes non-finite floats as `null`.
+                    | "f32" | "f64" => {
+
+                        matches!(
+                            tree,
+                            Tree::Number
+                                | Tree::Null
+                        )
+                    },
+                    | _ => {
+
+                        matches!(
+                            tree,
+                            Tree::Number
+                        )
+                    },
+                };
+
+                if fits {
+
+                    Ok(())
+                } else {
+
+                    mismatch()
+                }
+            },
+            | (
+                Shape::Option(_),
+                Tree::Null,
+            ) => Ok(()),
+            | (
+                Shape::Option(inner),
+                _,
+            ) => {
+                conforms(
+                    tree, inner, path,
+                )
+            },
+            | (
+                Shape::Seq(element),
+                Tree::Seq(items),
+            ) => {
+
+                for (i, item) in items
+                    .iter()
+                    .enumerate()
+                {
+
+                    conforms(
+                        item,
+                        element,
+                        &format!(
+                            "{path}.{i}"
+                        ),
+                    )?;
+                }
+
+                Ok(())
+            },
+            | (
+                Shape::Array(
+                    element,
+                    length,
+                ),
+                Tree::Seq(items),
+            ) if items.len()
+                == *length =>
+            {
+
+                for item in items {
+
+                    conforms(
+                        item,
+                        element,
+                        path,
+                    )?;
+                }
+
+                Ok(())
+            },
+            | (
+                Shape::Tuple(elements),
+                Tree::Seq(items),
+            ) => {
+                conforms_elements(
+                    items,
+                    elements,
+                    path,
+                )
+            },
+            | (
+                Shape::Map(value),
+                Tree::Map(entries),
+            ) => {
+
+                for (key, entry) in
+                    entries
+                {
+
+                    conforms(
+                        entry,
+                        value,
+                        &format!(
+                            "{path}.{key}"
+                        ),
+                    )?;
+                }
+
+                Ok(())
+            },
+            | (
+                Shape::Struct(fields),
+                _,
+            ) => {
+                conforms_fields(
+                    tree,
+                    fields,
+                    path,
+                )
+            },
+            | (
+                Shape::Enum(variants),
+                Tree::String(name),
+            ) => {
+
+                match variants.iter().find(
+                    |(variant, _)| {
+
+                        variant == name
+                    },
+                ) {
+                    | Some((
+                        _,
+                        Payload::Unit,
+                    )) => Ok(()),
+                    | _ => {
+
+                        Err(format!(
+                            "{path}: `{name}` \
+                             is not a unit \
+                             variant of \
+                             {shape:?}"
+                        ))
+                    },
+                }
+            },
+            | (
+                Shape::Enum(variants),
+                Tree::Map(entries),
+            ) if entries.len() == 1 => {
+
+                let (name, payload) =
+                    &entries[0];
+
+                let path = format!(
+                    "{path}.{name}"
+                );
+
+                match variants.iter().find(
+                    |(variant, _)| {
+
+                        variant == name
+                    },
+                ) {
+                    | Some((
+                        _,
+                        Payload::Tuple(
+                            elements,
+                        ),
+                    )) if elements.len()
+                        == 1 =>
+                    {
+
+                        conforms(
+                            payload,
+                            &elements[0],
+                            &path,
+                        )
+                    },
+                    | Some((
+                        _,
+                        Payload::Tuple(
+                            elements,
+                        ),
+                    )) => {
+
+                        let Tree::Seq(items) =
+                            payload
+                        else {
+
+                            return mismatch();
+                        };
+
+                        conforms_elements(
+                            items, elements,
+                            &path,
+                        )
+                    },
+                    | Some((
+                        _,
+                        Payload::Struct(fields),
+                    )) => {
+
+                        conforms_fields(
+                            payload, fields,
+                            &path,
+                        )
+                    },
+                    | _ => {
+
+                        Err(format!(
+                            "{path}: no such \
+                             variant with a \
+                             payload in \
+                             {shape:?}"
+                        ))
+                    },
+                }
+            },
+            | _ => mismatch(),
+        }
+    }
+
+    /// Asserts that `value` serializes in the shape its type declares.
+
+    pub fn check<
+        T: Serialize + Schema + ?Sized,
+    >(
+        value: &T
+    ) {
+
+        let json =
+            serde_json::to_string(
+                value,
+            )
+            .unwrap();
+
+        let tree: Tree =
+            serde_json::from_str(&json)
+                .unwrap();
+
+        if let Err(message) = conforms(
+            &tree,
+            &parse(&shape::<T>()),
+            std::any::type_name::<T>(),
+        ) {
+
+            panic!("{message}\n{json}");
+        }
+    }
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_shape_grammar() {
+
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::unit::UnitError;
+    use serialized::check;
+    use serialized::conforms;
+    use serialized::parse;
+
+    // The checker refuses a renamed or reordered field.
+    let tree = serde_json::from_str(
+        r#"{"uncertainty": 0.1, "value": 1.0}"#,
+    )
+    .unwrap();
+
+    assert!(
+        conforms(
+            &tree,
+            &parse(
+                "{value: f64, \
+                 uncertainty: f64}"
+            ),
+            "Measured"
+        )
+        .is_err()
+    );
+
+    assert!(
+        conforms(
+            &tree,
+            &parse(
+                "{uncertainty: f64, \
+                 value: f64}"
+            ),
+            "Measured"
+        )
+        .is_ok()
+    );
+
+    check(&Measured::new(
+        1.0, 0.1,
+    ));
+
+    check(&Some(
+        Measured::exact(2.0),
+    ));
+
+    check(
+        &UnitError::Ambiguous(
+            "da".to_string(),
+        ),
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_shapes_match_serde() {
+
+    use rssn_advanced::astro;
+    use rssn_advanced::astro::AstroError;
+    use rssn_advanced::astro::TimeScale;
+    use rssn_advanced::astro::time::LEAP_SECONDS;
+    use rssn_advanced::capability::capabilities;
+    use rssn_advanced::constant;
+    use rssn_advanced::constant::CodataEdition;
+    use rssn_advanced::constant::Measured;
+    use rssn_advanced::constant::edition;
+    use rssn_advanced::constant::identify::identify;
+    use rssn_advanced::constant::search;
+    use rssn_advanced::element;
+    use rssn_advanced::ffi_apis::astro_ffi::TimeConversion;
+    use rssn_advanced::ffi_apis::constant_ffi::ConstantRecord;
+    use rssn_advanced::ffi_apis::constant_ffi::json::BuildInfo;
+    use rssn_advanced::ffi_apis::dispatch;
+    use rssn_advanced::ffi_apis::math_constant_ffi::MathConstantRequest;
+    use rssn_advanced::ffi_apis::unit_ffi::UnitConversion;
+    use rssn_advanced::math_constant;
+    use rssn_advanced::math_constant::MathConstant;
+    use rssn_advanced::particle;
+    use rssn_advanced::unit;
+    use rssn_advanced::unit::Dimension;
+    use rssn_advanced::unit::EnergyUnit;
+    use rssn_advanced::unit::Quantity;
+    use rssn_advanced::unit::UnitError;
+    use rssn_advanced::unit::UnitSystem;
+    use rssn_advanced::unit::buckingham;
+    use rssn_advanced::unit::buckingham::Variable;
+    use rssn_advanced::unit::catalogue::UNITS;
+    use serialized::check;
+
+    check(astro::all());
+
+    check(LEAP_SECONDS);
+
+    check(&TimeScale::ALL);
+
+    check(&[
+        AstroError::UtcOutOfRange(
+            30_000.0,
+        ),
+        AstroError::UnknownTimeScale(
+            "GPS".to_string(),
+        ),
+    ]);
+
+    check(&TimeConversion {
+        mjd: 60_000.0,
+        from: TimeScale::Utc,
+        to: TimeScale::Tdb,
+    });
+
+    check(capabilities());
+
+    check(&CodataEdition::ALL);
+
+    check(constant::all());
+
+    check(&edition::diff(
+        CodataEdition::Codata2018,
+        CodataEdition::Codata2022,
+    ));
+
+    check(&search::find(
+        "electron mass",
+    ));
+
+    let candidates = identify(
+        constant::lookup(
+            "ELECTRON_MASS",
+        )
+        .unwrap()
+        .value,
+        1e-9,
+    );
+
+    assert!(!candidates.is_empty());
+
+    check(&candidates);
+
+    check(&Measured::new(
+        1.0, 0.1,
+    ));
+
+    check(&ConstantRecord {
+        id: "SPEED_OF_LIGHT"
+            .to_string(),
+        name: "speed of light in \
+               vacuum"
+            .to_string(),
+        symbol: "c".to_string(),
+        unit: "m s^-1".to_string(),
+        value: 299_792_458.0,
+        uncertainty: 0.0,
+        relative_uncertainty: 0.0,
+        flags: 1,
+    });
+
+    check(&BuildInfo {
+        build_date: String::new(),
+        commit_sha: String::new(),
+        rustc_version: String::new(),
+        cargo_target_triple:
+            String::new(),
+        system_info: String::new(),
+    });
+
+    check(element::all());
+
+    check(
+        &element::formula::parse("Cl2")
+            .unwrap()
+            .isotope_pattern(0.01)
+            .unwrap(),
+    );
+
+    check(
+        &dispatch::methods()
+            .iter()
+            .map(dispatch::Method::schema)
+            .collect::<Vec<_>>(),
+    );
+
+    check(
+        &MathConstantRequest {
+            constant: "pi".to_string(),
+            digits: 20,
+        },
+    );
+
+    check(&MathConstant::ALL);
+
+    check(
+        &MathConstant::Pi
+            .to_double_double(),
+    );
+
+    check(
+        &math_constant::lookup("tau")
+            .unwrap_err(),
+    );
+
+    check(
+        &math_constant::digits(
+            MathConstant::Khinchin,
+            math_constant::MAX_DIGITS,
+        )
+        .unwrap_err(),
+    );
+
+    check(particle::all());
+
+    check(&UnitConversion {
+        value: 1.0,
+        from: "eV".to_string(),
+        to: "J".to_string(),
+    });
+
+    check(&EnergyUnit::ALL);
+
+    check(&UnitSystem::ALL);
+
+    check(UNITS);
+
+    check(
+        &unit::parse("km/h").unwrap(),
+    );
+
+    check(&Quantity {
+        value: 1.0,
+        dimension: Dimension::VELOCITY,
+    });
+
+    check(&[
+        UnitError::Syntax {
+            notation: "(".to_string(),
+            message: "unclosed"
+                .to_string(),
+        },
+        UnitError::UnknownUnit(
+            "furlong".to_string(),
+        ),
+        UnitError::Ambiguous(
+            "da".to_string(),
+        ),
+        UnitError::Incompatible {
+            expected:
+                Dimension::VELOCITY,
+            found: Dimension::LENGTH,
+        },
+        UnitError::NoUnit {
+            dimension:
+                Dimension::LENGTH,
+            system: UnitSystem::Planck,
+        },
+        UnitError::InvalidRoot {
+            dimension:
+                Dimension::LENGTH,
+            index: 2,
+        },
+    ]);
+
+    let variables: Vec<Variable> = [
+        (
+            "G",
+            "m^3 kg^-1 s^-2",
+        ),
+        ("M", "kg"),
+        ("r", "m"),
+    ]
+    .iter()
+    .map(|&(name, unit)| {
+
+        Variable::with_unit(name, unit)
+            .unwrap()
+    })
+    .collect();
+
+    check(&variables);
+
+    check(
+        &buckingham::pi_groups(
+            &variables,
+        ),
+    );
+
+    check(
+        &buckingham::scaling(
+            &Variable::with_unit(
+                "T", "s",
+            )
+            .unwrap(),
+            &variables,
+        )
+        .unwrap(),
+    );
+}
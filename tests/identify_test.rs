@@ -0,0 +1,212 @@
+use rssn::symbolic::core::Expr;
+use rssn_advanced::constant::CodataEdition;
+use rssn_advanced::constant::identify;
+use rssn_advanced::constant::identify::IdentifyOptions;
+use rssn_advanced::constant::symbolic;
+
+#[test]
+
+fn test_identify_constants() {
+
+    for (x, tolerance, id) in [
+        (
+            1.054_571_8e-34,
+            1e-6,
+            "REDUCED_PLANCK_CONSTANT",
+        ),
+        (
+            137.036,
+            1e-5,
+            "INVERSE_FINE_STRUCTURE_CONSTANT",
+        ),
+        (
+            9.806_65,
+            1e-9,
+            "STANDARD_ACCELERATION_OF_GRAVITY",
+        ),
+        (
+            2.176_434e-8,
+            1e-6,
+            "PLANCK_MASS",
+        ),
+    ] {
+
+        let candidates =
+            identify::identify(
+                x, tolerance,
+            );
+
+        assert_eq!(
+            candidates[0].expr,
+            Expr::new_variable(id),
+            "{x}"
+        );
+
+        assert_eq!(
+            candidates[0].complexity,
+            1
+        );
+
+        for candidate in &candidates {
+
+            assert!(
+                candidate.relative_error
+                    <= tolerance
+            );
+
+            // The expression evaluates to the value it was found for.
+            let value = symbolic::evaluate(
+                &candidate.expr,
+                CodataEdition::Codata2022,
+            )
+            .unwrap()
+            .value;
+
+            assert!(
+                ((value - candidate.value)
+                    / value)
+                    .abs()
+                    < 1e-12
+            );
+        }
+
+        assert!(
+            candidates
+                .windows(2)
+                .all(|w| {
+
+                    w[0].complexity
+                        <= w[1].complexity
+                })
+        );
+    }
+}
+
+#[test]
+
+fn test_identify_combinations() {
+
+    // ħc
+    let candidates = identify::identify(
+        3.161_526_773e-26,
+        1e-8,
+    );
+
+    assert_eq!(
+        symbolic::constants_in(
+            &candidates[0].expr
+        ),
+        [
+            "REDUCED_PLANCK_CONSTANT",
+            "SPEED_OF_LIGHT"
+        ]
+    );
+
+    assert!(
+        candidates
+            .iter()
+            .any(|c| {
+
+                symbolic::constants_in(
+                    &c.expr,
+                ) == [
+                    "PLANCK_CONSTANT",
+                    "SPEED_OF_LIGHT",
+                ]
+            })
+    );
+
+    // 1/α
+    assert!(
+        identify::identify(137.036, 1e-5)
+            .iter()
+            .any(|c| {
+
+                c.expr
+                    == Expr::Div(
+                        Expr::BigInt(1.into())
+                            .into(),
+                        Expr::new_variable(
+                            "FINE_STRUCTURE_CONSTANT",
+                        )
+                        .into(),
+                    )
+            })
+    );
+
+    // The sign is carried by the coefficient.
+    let candidates = identify::identify(
+        -1.602_176_634e-19,
+        1e-9,
+    );
+
+    assert_eq!(
+        candidates[0].expr,
+        Expr::Neg(
+            Expr::new_variable(
+                "ELEMENTARY_CHARGE"
+            )
+            .into()
+        )
+    );
+
+    assert!(candidates[0].value < 0.0);
+
+    let pure = identify::identify_with(
+        std::f64::consts::FRAC_PI_4,
+        &IdentifyOptions {
+            tolerance: 1e-9,
+            max_factors: 0,
+            limit: 1,
+        },
+    );
+
+    assert_eq!(pure.len(), 1);
+
+    assert_eq!(
+        pure[0].expr,
+        Expr::Div(
+            Expr::Pi.into(),
+            Expr::BigInt(4.into())
+                .into()
+        )
+    );
+}
+
+#[test]
+
+fn test_identify_limits() {
+
+    for x in [
+        0.0,
+        f64::NAN,
+        f64::INFINITY,
+        f64::MIN_POSITIVE / 2.0,
+    ] {
+
+        assert!(
+            identify::identify(x, 1e-3)
+                .is_empty()
+        );
+    }
+
+    assert!(
+        identify::identify(
+            1.234_567_891e-100,
+            1e-9
+        )
+        .is_empty()
+    );
+
+    assert!(
+        identify::identify_with(
+            std::f64::consts::TAU,
+            &IdentifyOptions {
+                limit: 3,
+                ..IdentifyOptions::default()
+            },
+        )
+        .len()
+            <= 3
+    );
+}
@@ -0,0 +1,443 @@
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::ToPrimitive;
+use rssn_advanced::math_constant;
+use rssn_advanced::math_constant::MathConstant;
+use rssn_advanced::math_constant::MathConstantError;
+
+/// One hundred significant digits of each constant, from mpmath.
+
+const REFERENCE: [(MathConstant, &str); 8] = [
+    (
+        MathConstant::Pi,
+        "3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117068",
+    ),
+    (
+        MathConstant::E,
+        "2.718281828459045235360287471352662497757247093699959574966967627724076630353547594571382178525166427",
+    ),
+    (
+        MathConstant::Ln2,
+        "0.6931471805599453094172321214581765680755001343602552541206800094933936219696947156058633269964186875",
+    ),
+    (
+        MathConstant::EulerGamma,
+        "0.5772156649015328606065120900824024310421593359399235988057672348848677267776646709369470632917467495",
+    ),
+    (
+        MathConstant::Catalan,
+        "0.9159655941772190150546035149323841107741493742816721342664981196217630197762547694793565129261151062",
+    ),
+    (
+        MathConstant::Apery,
+        "1.202056903159594285399738161511449990764986292340498881792271555341838205786313090186455873609335258",
+    ),
+    (
+        MathConstant::Glaisher,
+        "1.282427129100622636875342568869791727767688927325001192063740021740406308858826461129736491958202374",
+    ),
+    (
+        MathConstant::Khinchin,
+        "2.685452001065306445309714835481795693820382293994462953051152345557218859537152002801141174931847698",
+    ),
+];
+
+#[test]
+
+fn test_digits() {
+
+    for (constant, reference) in
+        REFERENCE
+    {
+
+        assert_eq!(
+            math_constant::digits(
+                constant,
+                100
+            )
+            .unwrap(),
+            reference,
+            "{constant}"
+        );
+
+        // Fewer digits are served from the cache and correctly rounded.
+        assert_eq!(
+            math_constant::digits(
+                constant, 1
+            )
+            .unwrap(),
+            match constant {
+                | MathConstant::Pi => "3",
+                | MathConstant::E => "3",
+                | MathConstant::Ln2 => "0.7",
+                | MathConstant::EulerGamma => "0.6",
+                | MathConstant::Catalan => "0.9",
+                | MathConstant::Apery
+                | MathConstant::Glaisher => "1",
+                | _ => "3",
+            },
+            "{constant}"
+        );
+    }
+
+    assert_eq!(
+        math_constant::digits(
+            MathConstant::Pi,
+            6
+        )
+        .unwrap(),
+        "3.14159"
+    );
+
+    assert_eq!(
+        math_constant::digits(
+            MathConstant::E,
+            7
+        )
+        .unwrap(),
+        "2.718282"
+    );
+}
+
+#[test]
+
+fn test_feigenbaum_delta() {
+
+    let delta =
+        MathConstant::FeigenbaumDelta;
+
+    let available = delta
+        .max_digits()
+        .unwrap();
+
+    assert!(available >= 50);
+
+    assert_eq!(
+        math_constant::digits(delta, 50)
+            .unwrap(),
+        "4.6692016091029906718532038204662016172581855774758"
+    );
+
+    assert_eq!(
+        math_constant::digits(
+            delta,
+            available + 1
+        ),
+        Err(
+            MathConstantError::TooManyDigits {
+                constant: delta,
+                requested: available + 1,
+                available,
+            }
+        )
+    );
+
+    assert!(
+        MathConstant::Pi
+            .max_digits()
+            .is_none()
+    );
+
+    // The series for A and K₀ grow too costly to serve every precision.
+    for (constant, cap) in [
+        (
+            MathConstant::Glaisher,
+            math_constant::GLAISHER_MAX_DIGITS,
+        ),
+        (
+            MathConstant::Khinchin,
+            math_constant::KHINCHIN_MAX_DIGITS,
+        ),
+    ] {
+
+        assert_eq!(
+            constant.max_digits(),
+            Some(cap)
+        );
+
+        assert_eq!(
+            math_constant::digits(
+                constant,
+                math_constant::MAX_DIGITS
+            ),
+            Err(
+                MathConstantError::TooManyDigits {
+                    constant,
+                    requested:
+                        math_constant::MAX_DIGITS,
+                    available: cap,
+                }
+            )
+        );
+    }
+
+    assert!(
+        math_constant::digits(
+            MathConstant::Pi,
+            0
+        )
+        .is_err()
+    );
+}
+
+#[test]
+
+fn test_f64_and_double_double() {
+
+    for constant in MathConstant::ALL {
+
+        // 40 digits cover the double-double to well below its last bit.
+        let exact =
+            math_constant::approximate(
+                constant,
+                40,
+            )
+            .unwrap();
+
+        let pair =
+            constant.to_double_double();
+
+        assert_eq!(
+            pair.hi,
+            constant.to_f64()
+        );
+
+        assert_eq!(
+            exact
+                .to_f64()
+                .unwrap(),
+            pair.hi,
+            "{constant}"
+        );
+
+        let remainder = exact
+            - BigRational::from_float(
+                pair.hi,
+            )
+            .unwrap();
+
+        assert_eq!(
+            remainder
+                .to_f64()
+                .unwrap(),
+            pair.lo,
+            "{constant}"
+        );
+    }
+}
+
+#[test]
+
+fn test_lookup() {
+
+    for constant in MathConstant::ALL {
+
+        for key in [
+            constant.id(),
+            constant.symbol(),
+            constant.name(),
+        ] {
+
+            assert_eq!(
+                math_constant::lookup(
+                    key
+                ),
+                Ok(constant)
+            );
+        }
+    }
+
+    assert_eq!(
+        MathConstant::from_id(
+            "EULER_GAMMA"
+        ),
+        Some(MathConstant::EulerGamma)
+    );
+
+    assert_eq!(
+        math_constant::lookup("tau"),
+        Err(
+            MathConstantError::UnknownConstant(
+                "tau".to_string()
+            )
+        )
+    );
+
+    assert_eq!(
+        math_constant::approximate(
+            MathConstant::Pi,
+            3
+        )
+        .unwrap(),
+        BigRational::new(
+            BigInt::from(157),
+            BigInt::from(50)
+        )
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_math_constant_ffi() {
+
+    use std::ffi::CStr;
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use rssn_advanced::ffi_apis::math_constant_ffi::MathConstantRequest;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_digits;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_digits_bincode;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_digits_json;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_double_double;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_double_double_json;
+    use rssn_advanced::ffi_apis::math_constant_ffi::rssn_math_constant_f64;
+    use rssn_advanced::math_constant::DoubleDouble;
+
+    let pi =
+        CString::new("pi").unwrap();
+
+    let digits =
+        rssn_math_constant_digits(
+            pi.as_ptr(),
+            20,
+        );
+
+    assert_eq!(
+        unsafe {
+
+            CStr::from_ptr(digits)
+        }
+        .to_str()
+        .unwrap(),
+        "3.1415926535897932385"
+    );
+
+    rssn_free_string(digits);
+
+    assert_eq!(
+        rssn_math_constant_f64(
+            pi.as_ptr()
+        ),
+        std::f64::consts::PI
+    );
+
+    let unknown =
+        CString::new("tau").unwrap();
+
+    assert!(
+        rssn_math_constant_f64(
+            unknown.as_ptr()
+        )
+        .is_nan()
+    );
+
+    assert!(
+        rssn_math_constant_digits(
+            unknown.as_ptr(),
+            5
+        )
+        .is_null()
+    );
+
+    let gamma =
+        CString::new("γ").unwrap();
+
+    assert_eq!(
+        rssn_math_constant_double_double(
+            gamma.as_ptr()
+        ),
+        MathConstant::EulerGamma
+            .to_double_double()
+    );
+
+    let json = rssn_math_constant_double_double_json(
+        gamma.as_ptr(),
+    );
+
+    let pair: Option<DoubleDouble> =
+        from_json_string(json);
+
+    let pair = pair.unwrap();
+
+    let expected =
+        MathConstant::EulerGamma
+            .to_double_double();
+
+    assert_eq!(pair.hi, expected.hi);
+
+    // serde_json may round the last bit of a float.
+    assert!(
+        (pair.lo / expected.lo - 1.0)
+            .abs()
+            < 1e-14
+    );
+
+    rssn_free_string(json);
+
+    let request = CString::new(
+        r#"{"constant": "apery", "digits": 20}"#,
+    )
+    .unwrap();
+
+    let json =
+        rssn_math_constant_digits_json(
+            request.as_ptr(),
+        );
+
+    let result: Option<String> =
+        from_json_string(json);
+
+    assert_eq!(
+        result.unwrap(),
+        "1.2020569031595942854"
+    );
+
+    rssn_free_string(json);
+
+    assert!(
+        rssn_math_constant_double_double_json(
+            unknown.as_ptr()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::NotFound
+    );
+
+    let request = to_bincode_buffer(
+        &MathConstantRequest {
+            constant:
+                "feigenbaum_delta"
+                    .to_string(),
+            digits: 1000,
+        },
+    );
+
+    let buffer =
+        rssn_math_constant_digits_bincode(
+            request,
+        );
+
+    assert!(
+        buffer
+            .data
+            .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::InvalidArgument
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    rssn_free_bincode_buffer(request);
+}
@@ -0,0 +1,344 @@
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::constant;
+use rssn_advanced::constant::Measured;
+
+#[test]
+
+fn test_measured_getters_match_registry()
+ {
+
+    for record in constant::all() {
+
+        let measured =
+            record.measured();
+
+        assert_eq!(
+            measured
+                .value
+                .to_bits(),
+            record
+                .value
+                .to_bits()
+        );
+
+        assert_eq!(
+            measured
+                .uncertainty
+                .to_bits(),
+            record
+                .uncertainty
+                .to_bits()
+        );
+    }
+
+    let g = constant::get_gravitational_constant_measured();
+
+    assert_eq!(
+        g.value.to_bits(),
+        constant::GRAVITATIONAL_CONSTANT
+            .to_bits()
+    );
+
+    assert_approx_eq!(
+        g.uncertainty / 1e-11,
+        0.000_15
+    );
+
+    assert!(
+        constant::get_speed_of_light_measured()
+            .is_exact()
+    );
+}
+
+#[test]
+
+fn test_measured_arithmetic() {
+
+    let a = Measured::new(10.0, 0.3);
+
+    let b = Measured::new(5.0, 0.4);
+
+    let sum = a + b;
+
+    assert_approx_eq!(sum.value, 15.0);
+
+    assert_approx_eq!(
+        sum.uncertainty,
+        0.5
+    );
+
+    let difference = a - b;
+
+    assert_approx_eq!(
+        difference.value,
+        5.0
+    );
+
+    assert_approx_eq!(
+        difference.uncertainty,
+        0.5
+    );
+
+    // Relative uncertainties add in quadrature for products and quotients.
+    let product = a * b;
+
+    assert_approx_eq!(
+        product.relative_uncertainty(),
+        0.03f64.hypot(0.08)
+    );
+
+    let quotient = a / b;
+
+    assert_approx_eq!(
+        quotient.value,
+        2.0
+    );
+
+    assert_approx_eq!(
+        quotient.relative_uncertainty(),
+        0.03f64.hypot(0.08)
+    );
+
+    let scaled = 2.0 * a - 1.0;
+
+    assert_approx_eq!(
+        scaled.value,
+        19.0
+    );
+
+    assert_approx_eq!(
+        scaled.uncertainty,
+        0.6
+    );
+
+    assert_approx_eq!(
+        (-a).uncertainty,
+        0.3
+    );
+}
+
+#[test]
+
+fn test_measured_functions() {
+
+    let x = Measured::new(4.0, 0.2);
+
+    assert_approx_eq!(
+        x.sqrt().uncertainty,
+        0.05
+    );
+
+    assert_approx_eq!(
+        x.powi(3)
+            .uncertainty,
+        3.0 * 16.0 * 0.2
+    );
+
+    assert_approx_eq!(
+        x.powf(0.5)
+            .uncertainty,
+        x.sqrt().uncertainty
+    );
+
+    assert_approx_eq!(
+        x.ln().uncertainty,
+        0.05
+    );
+
+    assert_approx_eq!(
+        x.recip()
+            .uncertainty,
+        0.2 / 16.0
+    );
+
+    let angle =
+        Measured::new(0.5, 0.01);
+
+    assert_approx_eq!(
+        angle
+            .sin()
+            .uncertainty,
+        0.5f64.cos() * 0.01
+    );
+
+    assert_approx_eq!(
+        angle
+            .exp()
+            .uncertainty,
+        0.5f64.exp() * 0.01
+    );
+
+    // d(x^y)/dy = x^y ln x
+    let power = Measured::exact(2.0)
+        .pow(Measured::new(
+            3.0, 0.1,
+        ));
+
+    assert_approx_eq!(power.value, 8.0);
+
+    assert_approx_eq!(
+        power.uncertainty,
+        8.0 * 2.0f64.ln() * 0.1
+    );
+}
+
+#[test]
+
+fn test_measured_zero_operands() {
+
+    // An exact zero stays exact at a vertical tangent.
+    let zero = Measured::exact(0.0);
+
+    for root in [
+        zero.sqrt(),
+        zero.powf(0.5),
+        zero.cbrt(),
+        zero.pow(Measured::exact(0.5)),
+    ] {
+
+        assert_eq!(root, zero);
+    }
+
+    assert_eq!(
+        zero.pow(Measured::new(
+            0.5, 0.1,
+        )),
+        zero
+    );
+
+    assert_eq!(
+        zero * Measured::new(3.0, 0.2),
+        zero
+    );
+
+    // A measured zero takes the spread of the root over one sigma.
+    let x = Measured::new(0.0, 0.04);
+
+    assert_approx_eq!(
+        x.sqrt().uncertainty,
+        0.2
+    );
+
+    assert_approx_eq!(
+        x.powf(0.5)
+            .uncertainty,
+        0.2
+    );
+
+    assert_approx_eq!(
+        x.cbrt().uncertainty,
+        0.04f64.cbrt()
+    );
+
+    // A measured power bounds the base the same way.
+    assert_approx_eq!(
+        x.pow(Measured::exact(0.5))
+            .uncertainty,
+        0.2
+    );
+
+    assert_approx_eq!(
+        Measured::new(0.0, 0.1)
+            .pow(Measured::exact(0.5))
+            .uncertainty,
+        Measured::new(0.0, 0.1)
+            .sqrt()
+            .uncertainty
+    );
+
+    assert_approx_eq!(
+        x.powi(2)
+            .uncertainty,
+        0.0
+    );
+}
+
+#[test]
+
+fn test_measured_derived_constant() {
+
+    // α = e² / (4π ε₀ ħ c)
+    let e = constant::get_elementary_charge_measured();
+
+    let epsilon_0 = constant::get_vacuum_electric_permittivity_measured();
+
+    // The listed ħ is truncated, so it is derived from the exact h.
+    let hbar = constant::get_planck_constant_measured()
+        / (2.0 * std::f64::consts::PI);
+
+    let c = constant::get_speed_of_light_measured();
+
+    let alpha = e.powi(2)
+        / (4.0
+            * std::f64::consts::PI
+            * epsilon_0
+            * hbar
+            * c);
+
+    let listed = constant::get_fine_structure_constant_measured();
+
+    assert!(
+        (alpha.value - listed.value)
+            .abs()
+            < 3.0 * listed.uncertainty
+    );
+
+    // Only ε₀ carries an uncertainty, so α inherits its relative one.
+    assert_approx_eq!(
+        alpha.relative_uncertainty()
+            / epsilon_0
+                .relative_uncertainty(),
+        1.0
+    );
+}
+
+#[test]
+
+fn test_measured_display() {
+
+    let x = Measured::new(1.5, 0.25);
+
+    assert_eq!(
+        x.to_string(),
+        "1.5 ± 0.25"
+    );
+
+    assert_eq!(
+        format!("{x:.2}"),
+        "1.50e0 ± 2.50e-1"
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_measured_serialization() {
+
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::to_json_string;
+
+    let m_e = constant::get_electron_mass_measured();
+
+    let json = to_json_string(&m_e);
+
+    let decoded: Option<Measured> =
+        from_json_string(json);
+
+    assert_eq!(decoded, Some(m_e));
+
+    rssn_free_string(json);
+
+    let buffer =
+        to_bincode_buffer(&m_e);
+
+    let decoded: Option<Measured> =
+        from_bincode_buffer(&buffer);
+
+    assert_eq!(decoded, Some(m_e));
+
+    rssn_free_bincode_buffer(buffer);
+}
@@ -0,0 +1,461 @@
+use std::collections::HashSet;
+
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::constant;
+use rssn_advanced::constant::CodataEdition;
+use rssn_advanced::constant::edition;
+use rssn_advanced::particle;
+use rssn_advanced::particle::Estimate;
+use rssn_advanced::particle::ParticleKind;
+
+#[test]
+
+fn test_table() {
+
+    let ids: HashSet<_> =
+        particle::all()
+            .iter()
+            .map(|p| p.pdg_id)
+            .collect();
+
+    assert_eq!(
+        ids.len(),
+        particle::all().len()
+    );
+
+    assert!(
+        particle::all()
+            .windows(2)
+            .all(|w| {
+
+                w[0].pdg_id
+                    < w[1].pdg_id
+            })
+    );
+
+    for p in particle::all() {
+
+        assert_eq!(
+            particle::by_pdg_id(
+                p.pdg_id
+            ),
+            Some(*p)
+        );
+
+        assert_eq!(
+            particle::by_name(p.name),
+            Some(*p)
+        );
+
+        let anti = p.antiparticle();
+
+        assert_eq!(
+            anti.antiparticle(),
+            *p
+        );
+
+        assert_eq!(
+            anti.charge(),
+            -p.charge()
+        );
+
+        if p.is_self_conjugate() {
+
+            assert_eq!(
+                p.three_charge,
+                0
+            );
+
+            assert_eq!(
+                particle::by_pdg_id(
+                    -p.pdg_id
+                ),
+                None
+            );
+        } else {
+
+            assert_eq!(
+                particle::by_pdg_id(
+                    -p.pdg_id
+                ),
+                Some(anti)
+            );
+
+            assert_eq!(
+                particle::by_name(
+                    anti.name
+                ),
+                Some(anti)
+            );
+        }
+
+        // Fermions have half-integer spin.
+        assert_eq!(
+            p.twice_spin % 2 == 1,
+            matches!(
+                p.kind,
+                ParticleKind::Lepton
+                    | ParticleKind::Quark
+                    | ParticleKind::Baryon
+            )
+        );
+    }
+
+    assert_eq!(
+        particle::of_kind(
+            ParticleKind::Lepton
+        )
+        .count(),
+        6
+    );
+
+    assert_eq!(
+        particle::lookup("electron")
+            .unwrap()
+            .pdg_id,
+        11
+    );
+
+    assert_eq!(
+        particle::lookup("-2212")
+            .unwrap()
+            .name,
+        "p~"
+    );
+
+    assert_eq!(
+        particle::lookup("t~")
+            .unwrap()
+            .charge(),
+        -2.0 / 3.0
+    );
+
+    assert_eq!(
+        particle::lookup("-22"),
+        None
+    );
+
+    assert_eq!(
+        particle::lookup("X"),
+        None
+    );
+}
+
+#[test]
+
+fn test_values() {
+
+    // The review takes the electron and nucleon masses from CODATA 2018.
+    for (name, id) in [
+        (
+            "e-",
+            "ELECTRON_MASS_ENERGY_EQUIVALENT_IN_MEV",
+        ),
+        (
+            "p",
+            "PROTON_MASS_ENERGY_EQUIVALENT_IN_MEV",
+        ),
+        (
+            "n",
+            "NEUTRON_MASS_ENERGY_EQUIVALENT_IN_MEV",
+        ),
+    ] {
+
+        let mass = particle::lookup(name)
+            .unwrap()
+            .mass
+            .unwrap();
+
+        let codata = edition::get(
+            CodataEdition::Codata2018,
+            id,
+        )
+        .unwrap();
+
+        assert_eq!(
+            mass,
+            Estimate::new(
+                codata.value,
+                codata.uncertainty
+            )
+        );
+    }
+
+    let higgs =
+        particle::lookup("H0").unwrap();
+
+    let width = higgs.width.unwrap();
+
+    assert!(!width.is_symmetric());
+
+    assert_eq!(
+        width,
+        Estimate::asymmetric(
+            3.7, 1.9, 1.4
+        )
+    );
+
+    assert_approx_eq!(
+        width.interval().0,
+        2.3,
+        1e-12
+    );
+
+    assert_approx_eq!(
+        width
+            .measured()
+            .uncertainty,
+        1.65,
+        1e-12
+    );
+
+    // Γτ = ħ.
+    let muon = particle::lookup("mu+")
+        .unwrap();
+
+    assert_approx_eq!(
+        muon.decay_width().unwrap()
+            * muon.mean_life().unwrap(),
+        constant::REDUCED_PLANCK_CONSTANT_IN_EV_S
+            * 1e-6,
+        1e-30
+    );
+
+    let w =
+        particle::lookup("W-").unwrap();
+
+    assert_approx_eq!(
+        w.mean_life()
+            .unwrap()
+            * 1e25,
+        3.157,
+        1e-3
+    );
+
+    assert_eq!(
+        particle::lookup("p")
+            .unwrap()
+            .mean_life(),
+        None
+    );
+
+    assert_eq!(
+        particle::lookup("nu_mu~")
+            .unwrap()
+            .mass,
+        None
+    );
+
+    let z = particle::lookup("Z0")
+        .unwrap()
+        .mass_quantity()
+        .unwrap();
+
+    assert_approx_eq!(
+        z.value / 1.625_6e-25,
+        1.0,
+        1e-3
+    );
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_particle_ffi() {
+
+    use std::ffi::CStr;
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_bincode;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_by_pdg_id_bincode;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_by_pdg_id_json;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_charge;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_count;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_json;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_mass;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_mean_life;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_name;
+    use rssn_advanced::ffi_apis::particle_ffi::rssn_get_particle_width;
+    use serde::Deserialize;
+
+    #[derive(Deserialize)]
+
+    struct Record {
+        name: String,
+        antiparticle: String,
+        pdg_id: i32,
+        kind: ParticleKind,
+        three_charge: i8,
+        twice_spin: u8,
+        mass: Option<Estimate>,
+        width: Option<Estimate>,
+        lifetime: Option<Estimate>,
+    }
+
+    // The bincode envelope checks the fields, not the name of the type.
+    rssn_advanced::impl_schema!(Record {
+        name: String,
+        antiparticle: String,
+        pdg_id: i32,
+        kind: ParticleKind,
+        three_charge: i8,
+        twice_spin: u8,
+        mass: Option<Estimate>,
+        width: Option<Estimate>,
+        lifetime: Option<Estimate>,
+    });
+
+    assert_eq!(
+        rssn_get_particle_count(),
+        particle::all().len()
+    );
+
+    let name =
+        rssn_get_particle_name(0);
+
+    assert_eq!(
+        unsafe {
+
+            CStr::from_ptr(name)
+        }
+        .to_str()
+        .unwrap(),
+        particle::all()[0].name
+    );
+
+    rssn_free_string(name);
+
+    assert_eq!(
+        rssn_get_particle_mass(-211),
+        139.570_39
+    );
+
+    assert!(
+        rssn_get_particle_mass(12)
+            .is_nan()
+    );
+
+    assert_eq!(
+        rssn_get_particle_width(23),
+        2_495.5
+    );
+
+    assert!(
+        rssn_get_particle_mean_life(
+            2212
+        )
+        .is_nan()
+    );
+
+    assert_eq!(
+        rssn_get_particle_charge(-24),
+        -1.0
+    );
+
+    let key =
+        CString::new("H0").unwrap();
+
+    let json = rssn_get_particle_json(
+        key.as_ptr(),
+    );
+
+    let record: serde_json::Value =
+        serde_json::from_str(
+            unsafe {
+
+                CStr::from_ptr(json)
+            }
+            .to_str()
+            .unwrap(),
+        )
+        .unwrap();
+
+    assert_eq!(record["pdg_id"], 25);
+
+    assert_eq!(
+        record["width"]["minus"],
+        1.4
+    );
+
+    rssn_free_string(json);
+
+    assert!(
+        rssn_get_particle_by_pdg_id_json(
+            -23
+        )
+        .is_null()
+    );
+
+    let key = CString::new("Sigma~-")
+        .unwrap();
+
+    let buffer =
+        rssn_get_particle_bincode(
+            key.as_ptr(),
+        );
+
+    let record: Record =
+        from_bincode_buffer(&buffer)
+            .unwrap();
+
+    assert_eq!(record.pdg_id, -3222);
+
+    assert_eq!(
+        record.antiparticle,
+        "Sigma+"
+    );
+
+    assert_eq!(
+        record.kind,
+        ParticleKind::Baryon
+    );
+
+    assert_eq!(record.twice_spin, 1);
+
+    assert_eq!(
+        record.three_charge,
+        -3
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    let buffer =
+        rssn_get_particle_by_pdg_id_bincode(
+            6,
+        );
+
+    let record: Record =
+        from_bincode_buffer(&buffer)
+            .unwrap();
+
+    assert_eq!(record.name, "t");
+
+    assert_eq!(
+        record.mass,
+        Some(Estimate::new(
+            172_570.0,
+            290.0
+        ))
+    );
+
+    assert_eq!(
+        record.lifetime,
+        None
+    );
+
+    assert_eq!(
+        record.width,
+        Some(
+            Estimate::asymmetric(
+                1_420.0,
+                190.0,
+                150.0
+            )
+        )
+    );
+
+    rssn_free_bincode_buffer(buffer);
+}
@@ -0,0 +1,432 @@
+use rssn::symbolic::core::Expr;
+use rssn_advanced::constant;
+use rssn_advanced::constant::CodataEdition;
+use rssn_advanced::constant::correlation;
+use rssn_advanced::constant::edition;
+use rssn_advanced::constant::symbolic;
+
+fn symbol(key: &str) -> Expr {
+
+    symbolic::constant(key)
+        .unwrap_or_else(|| {
+
+            panic!("unknown {key}")
+        })
+}
+
+fn squared(expr: Expr) -> Expr {
+
+    Expr::new_pow(
+        expr,
+        Expr::new_constant(2.0),
+    )
+}
+
+#[test]
+
+fn test_symbols() {
+
+    assert_eq!(
+        symbolic::symbols().len(),
+        constant::all().len()
+    );
+
+    for (key, id) in [
+        (
+            "h",
+            "PLANCK_CONSTANT",
+        ),
+        (
+            "ħ",
+            "REDUCED_PLANCK_CONSTANT",
+        ),
+        (
+            "c",
+            "SPEED_OF_LIGHT",
+        ),
+        (
+            "e",
+            "ELEMENTARY_CHARGE",
+        ),
+        (
+            "m_e",
+            "ELECTRON_MASS",
+        ),
+        (
+            "k",
+            "BOLTZMANN_CONSTANT",
+        ),
+        (
+            "speed_of_light",
+            "SPEED_OF_LIGHT",
+        ),
+    ] {
+
+        assert_eq!(
+            symbol(key),
+            Expr::new_variable(id),
+            "{key}"
+        );
+    }
+
+    assert!(
+        symbolic::constant("x")
+            .is_none()
+    );
+
+    let expr = Expr::new_add(
+        Expr::new_mul(
+            symbol("m_e"),
+            squared(symbol("c")),
+        ),
+        Expr::new_variable("x"),
+    );
+
+    assert_eq!(
+        symbolic::constants_in(&expr),
+        [
+            "ELECTRON_MASS",
+            "SPEED_OF_LIGHT"
+        ]
+    );
+}
+
+#[test]
+
+fn test_relations_hold() {
+
+    let rules = symbolic::rules();
+
+    assert!(rules.len() > 30);
+
+    for edition in CodataEdition::ALL {
+
+        for rule in &rules {
+
+            let Expr::Variable(id) =
+                &rule.lhs
+            else {
+
+                panic!("{:?}", rule.lhs)
+            };
+
+            let Some(record) =
+                edition::get(
+                    edition,
+                    id,
+                )
+            else {
+
+                continue;
+            };
+
+            // CODATA 2014 has no correlations to propagate the
+            // uncertainties of its measured constants with, but the
+            // substituted values still evaluate.
+            let value =
+                symbolic::evaluate(
+                    &rule.rhs,
+                    edition,
+                )
+                .map_or_else(
+                    || {
+
+                        assert!(
+                            !correlation::has_correlations(
+                                edition
+                            )
+                        );
+
+                        symbolic::evaluate(
+                            &symbolic::substitute(
+                                &rule.rhs,
+                                edition,
+                            ),
+                            edition,
+                        )
+                        .unwrap()
+                        .value
+                    },
+                    |value| value.value,
+                );
+
+            // The listing rounds or truncates derived values to their
+            // last digit.
+            let tolerance = 2.0 * record
+                .relative_uncertainty
+                + 1e-9;
+
+            assert!(
+                (value / record.value
+                    - 1.0)
+                    .abs()
+                    < tolerance,
+                "{edition:?}: {id} = \
+                 {} vs {}",
+                value,
+                record.value
+            );
+
+            assert_eq!(
+                symbolic::relation(id),
+                Some(rule.rhs.clone())
+            );
+        }
+    }
+}
+
+#[test]
+
+fn test_simplify() {
+
+    // R_K K_J² = 4/h
+    let product = Expr::new_mul(
+        symbol("R_K"),
+        squared(symbol("K_J")),
+    );
+
+    assert_eq!(
+        symbolic::simplify(&product),
+        symbolic::simplify(
+            &Expr::new_div(
+                Expr::new_constant(4.0),
+                symbol("h"),
+            )
+        )
+    );
+
+    assert_eq!(
+        symbolic::simplify(
+            &Expr::new_mul(
+                product,
+                symbol("h"),
+            )
+        ),
+        Expr::BigInt(4.into())
+    );
+
+    // μ_B/μ_N = m_p/m_e
+    assert_eq!(
+        symbolic::simplify(
+            &Expr::new_div(
+                symbol("μ_B"),
+                symbol("μ_N"),
+            )
+        ),
+        symbolic::simplify(&symbol(
+            "PROTON_ELECTRON_MASS_RATIO"
+        ))
+    );
+
+    // 4πε₀ħc = e²/α
+    let four_pi_epsilon_0 =
+        Expr::new_mul(
+            Expr::new_mul(
+                Expr::new_constant(4.0),
+                Expr::new_pi(),
+            ),
+            symbol("ε₀"),
+        );
+
+    let difference = Expr::new_sub(
+        Expr::new_mul(
+            four_pi_epsilon_0,
+            Expr::new_mul(
+                symbol("ħ"),
+                symbol("c"),
+            ),
+        ),
+        Expr::new_div(
+            squared(symbol("e")),
+            symbol("α"),
+        ),
+    );
+
+    assert_eq!(
+        symbolic::simplify(&difference),
+        Expr::BigInt(0.into())
+    );
+
+    // Other variables and functions are kept.
+    let x = Expr::new_variable("x");
+
+    let kept =
+        Expr::new_sqrt(Expr::new_mul(
+            x.clone(),
+            Expr::new_div(
+                symbol("F"),
+                symbol("N_A"),
+            ),
+        ));
+
+    assert_eq!(
+        symbolic::simplify(&kept),
+        Expr::Sqrt(
+            Expr::new_mul(
+                x,
+                symbol("e")
+            )
+            .to_ast()
+            .unwrap()
+            .into()
+        )
+    );
+}
+
+#[test]
+
+fn test_substitute_and_evaluate() {
+
+    let m_e = symbol("m_e");
+
+    for edition in CodataEdition::ALL {
+
+        let listed = edition::get(
+            edition,
+            "ELECTRON_MASS",
+        )
+        .unwrap();
+
+        assert_eq!(
+            symbolic::substitute(
+                &m_e,
+                edition
+            ),
+            Expr::Constant(
+                listed.value
+            )
+        );
+
+        let value = symbolic::evaluate(
+            &m_e,
+            edition,
+        )
+        .unwrap();
+
+        assert_eq!(
+            value.value,
+            listed.value
+        );
+
+        assert_eq!(
+            value.uncertainty,
+            listed.uncertainty
+        );
+    }
+
+    // α² m_e c² reproduces E_h. Its uncertainty is far below that of
+    // m_e, which is almost fully anti-correlated with α².
+    let hartree = Expr::new_mul(
+        squared(symbol("α")),
+        Expr::new_mul(
+            symbol("m_e"),
+            squared(symbol("c")),
+        ),
+    );
+
+    let value = symbolic::evaluate(
+        &hartree,
+        CodataEdition::LATEST,
+    )
+    .unwrap();
+
+    let listed = constant::lookup(
+        "HARTREE_ENERGY",
+    )
+    .unwrap();
+
+    assert!(
+        (value.value / listed.value
+            - 1.0)
+            .abs()
+            < 1e-10
+    );
+
+    assert!(
+        value.relative_uncertainty()
+            < 1e-11
+    );
+
+    assert!(
+        constant::lookup(
+            "ELECTRON_MASS"
+        )
+        .unwrap()
+        .relative_uncertainty
+            > 1e-10
+    );
+
+    // `h` and `e` are exact in CODATA 2018 but were measured and almost
+    // fully correlated in CODATA 2014, which has no correlations.
+    let h_over_e = Expr::new_div(
+        symbol("h"),
+        symbol("e"),
+    );
+
+    let value = symbolic::evaluate(
+        &h_over_e,
+        CodataEdition::Codata2018,
+    )
+    .unwrap();
+
+    assert_eq!(
+        value.uncertainty,
+        0.0
+    );
+
+    assert!(
+        symbolic::evaluate(
+            &h_over_e,
+            CodataEdition::Codata2014
+        )
+        .is_none()
+    );
+
+    // A single measured constant needs no correlation.
+    let h = edition::get(
+        CodataEdition::Codata2014,
+        "PLANCK_CONSTANT",
+    )
+    .unwrap();
+
+    let value = symbolic::evaluate(
+        &Expr::new_mul(
+            symbol("h"),
+            symbol("c"),
+        ),
+        CodataEdition::Codata2014,
+    )
+    .unwrap();
+
+    assert!(
+        (value.relative_uncertainty()
+            / h.relative_uncertainty
+            - 1.0)
+            .abs()
+            < 1e-6
+    );
+
+    // Unbound variables cannot be evaluated but survive substitution.
+    let free = Expr::new_mul(
+        Expr::new_variable("x"),
+        symbol("c"),
+    );
+
+    assert!(
+        symbolic::evaluate(
+            &free,
+            CodataEdition::LATEST
+        )
+        .is_none()
+    );
+
+    assert_eq!(
+        symbolic::constants_in(
+            &symbolic::substitute(
+                &free,
+                CodataEdition::LATEST
+            )
+        ),
+        Vec::<&str>::new()
+    );
+}
@@ -0,0 +1,368 @@
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::constant;
+use rssn_advanced::unit::Dimension;
+use rssn_advanced::unit::Quantity;
+use rssn_advanced::unit::UnitSystem;
+
+fn express(
+    system: UnitSystem,
+    id: &str,
+) -> f64 {
+
+    system
+        .express(
+            constant::lookup(id)
+                .unwrap(),
+        )
+        .unwrap()
+}
+
+#[test]
+
+fn test_atomic_units() {
+
+    let mut compared = 0;
+
+    for record in constant::all() {
+
+        if !record
+            .id
+            .starts_with(
+                "ATOMIC_UNIT_OF_",
+            )
+        {
+
+            continue;
+        }
+
+        let value = express(
+            UnitSystem::Atomic,
+            record.id,
+        );
+
+        assert!(
+            (value - 1.0).abs() < 1e-9,
+            "{}: {value}",
+            record.id
+        );
+
+        compared += 1;
+    }
+
+    assert!(compared > 20);
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::Atomic,
+            "SPEED_OF_LIGHT"
+        ),
+        constant::INVERSE_FINE_STRUCTURE_CONSTANT,
+        1e-7
+    );
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::Atomic,
+            "PROTON_MASS_KG"
+        ),
+        constant::PROTON_ELECTRON_MASS_RATIO,
+        1e-6
+    );
+}
+
+#[test]
+
+fn test_natural_and_planck_units() {
+
+    let alpha =
+        constant::FINE_STRUCTURE_CONSTANT;
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::Natural,
+            "ELECTRON_MASS"
+        ),
+        0.510_998_95e-3,
+        1e-12
+    );
+
+    // Heaviside-Lorentz: α = e²/4π.
+    assert_approx_eq!(
+        express(
+            UnitSystem::Natural,
+            "ELEMENTARY_CHARGE"
+        ),
+        (4.0 * std::f64::consts::PI
+            * alpha)
+            .sqrt(),
+        1e-12
+    );
+
+    // The listed ħ is truncated to ten digits.
+    for id in [
+        "SPEED_OF_LIGHT",
+        "REDUCED_PLANCK_CONSTANT",
+        "BOLTZMANN_CONSTANT",
+    ] {
+
+        assert_approx_eq!(
+            express(
+                UnitSystem::Natural,
+                id
+            ),
+            1.0,
+            1e-9
+        );
+    }
+
+    for id in [
+        "SPEED_OF_LIGHT",
+        "REDUCED_PLANCK_CONSTANT",
+        "BOLTZMANN_CONSTANT",
+        "GRAVITATIONAL_CONSTANT",
+    ] {
+
+        assert_approx_eq!(
+            express(
+                UnitSystem::Planck,
+                id
+            ),
+            1.0,
+            1e-4
+        );
+    }
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::Planck,
+            "ELEMENTARY_CHARGE"
+        ),
+        alpha.sqrt(),
+        1e-12
+    );
+
+    assert_eq!(
+        UnitSystem::Natural
+            .unit_symbol(
+                Dimension::LENGTH
+            )
+            .unwrap(),
+        "GeV⁻¹"
+    );
+
+    assert_eq!(
+        UnitSystem::Natural
+            .unit_symbol(
+                Dimension::VELOCITY
+            )
+            .unwrap(),
+        "1"
+    );
+}
+
+#[test]
+
+fn test_gaussian_units() {
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::GaussianCgs,
+            "ELEMENTARY_CHARGE"
+        ) / 4.803_204_71e-10,
+        1.0,
+        1e-8
+    );
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::GaussianCgs,
+            "VACUUM_ELECTRIC_PERMITTIVITY"
+        ),
+        1.0 / (4.0
+            * std::f64::consts::PI)
+    );
+
+    assert_approx_eq!(
+        express(
+            UnitSystem::GaussianCgs,
+            "ELECTRON_VOLT"
+        ) / 1.602_176_634e-12,
+        1.0
+    );
+
+    // 1 statvolt is 299.792458 V.
+    assert_approx_eq!(
+        UnitSystem::GaussianCgs
+            .unit(Dimension::VOLTAGE)
+            .unwrap(),
+        299.792_458,
+        1e-6
+    );
+
+    assert_eq!(
+        UnitSystem::GaussianCgs
+            .unit_symbol(
+                Dimension::FORCE
+            )
+            .unwrap(),
+        "cm g s⁻²"
+    );
+}
+
+#[test]
+
+fn test_gaussian_magnetic_units() {
+
+    use rssn_advanced::unit::UnitError;
+
+    let gaussian =
+        UnitSystem::GaussianCgs;
+
+    // 1 G is 10⁻⁴ T, up to the measured μ₀/4π × 10⁷ of the 2019 SI.
+    assert_approx_eq!(
+        gaussian
+            .from_si(
+                &Quantity::with_unit(
+                    1e-4, "T"
+                )
+                .unwrap()
+            )
+            .unwrap(),
+        1.0,
+        1e-9
+    );
+
+    let tesla =
+        Quantity::with_unit(1.0, "T")
+            .unwrap()
+            .dimension;
+
+    assert_eq!(
+        gaussian
+            .unit_symbol(tesla)
+            .unwrap(),
+        "G"
+    );
+
+    assert_approx_eq!(
+        gaussian
+            .to_si(1.0, tesla)
+            .unwrap()
+            .value
+            / 1e-4,
+        1.0,
+        1e-9
+    );
+
+    // μ_B is 9.274 × 10⁻²¹ erg/G.
+    assert_approx_eq!(
+        express(
+            gaussian,
+            "BOHR_MAGNETON"
+        ) / 9.274_010_078_3e-21,
+        1.0,
+        1e-8
+    );
+
+    // Φ₀ = hc/2e is 2.068 × 10⁻⁷ Mx.
+    assert_approx_eq!(
+        express(
+            gaussian,
+            "MAGNETIC_FLUX_QUANTUM"
+        ) / 2.067_833_848e-7,
+        1.0,
+        1e-8
+    );
+
+    // `A m⁻¹` is H in oersted but the magnetization in emu/cm³.
+    let per_metre =
+        Quantity::with_unit(1.0, "A/m")
+            .unwrap();
+
+    assert_eq!(
+        gaussian.from_si(&per_metre),
+        Err(UnitError::NoUnit {
+            dimension: per_metre
+                .dimension,
+            system: gaussian,
+        })
+    );
+
+    assert!(
+        gaussian
+            .express(
+                constant::lookup(
+                    "VACUUM_MAGNETIC_PERMEABILITY"
+                )
+                .unwrap()
+            )
+            .is_none()
+    );
+
+    // Other systems keep the SI form.
+    assert_approx_eq!(
+        UnitSystem::Si
+            .from_si(&per_metre)
+            .unwrap(),
+        1.0
+    );
+}
+
+#[test]
+
+fn test_round_trip() {
+
+    let pressure =
+        Quantity::with_unit(1.0, "atm")
+            .unwrap();
+
+    for system in UnitSystem::ALL {
+
+        let value = system
+            .from_si(&pressure)
+            .unwrap();
+
+        let back = system
+            .to_si(
+                value,
+                pressure.dimension,
+            )
+            .unwrap();
+
+        assert_eq!(
+            back.dimension,
+            pressure.dimension
+        );
+
+        assert_approx_eq!(
+            back.value / pressure.value,
+            1.0,
+            1e-12
+        );
+    }
+
+    assert_approx_eq!(
+        UnitSystem::Si
+            .from_si(&pressure)
+            .unwrap(),
+        101_325.0
+    );
+
+    assert_eq!(
+        UnitSystem::Atomic
+            .unit_symbol(
+                Dimension::CHARGE
+            )
+            .unwrap(),
+        "(ħ/E_h) (e E_h/ħ)"
+    );
+
+    assert_eq!(
+        UnitSystem::Atomic
+            .unit_symbol(
+                Dimension::ENERGY
+            )
+            .unwrap(),
+        "a₀² m_e (ħ/E_h)⁻²"
+    );
+}
@@ -0,0 +1,798 @@
+use assert_approx_eq::assert_approx_eq;
+use rssn_advanced::astro;
+use rssn_advanced::constant;
+use rssn_advanced::constant::CodataEdition;
+use rssn_advanced::constant::edition;
+use rssn_advanced::unit;
+use rssn_advanced::unit::Dimension;
+use rssn_advanced::unit::Quantity;
+use rssn_advanced::unit::UnitError;
+use rssn_advanced::unit::notation;
+use uom::si::energy::joule;
+use uom::si::f64::Energy;
+use uom::si::f64::Length;
+use uom::si::length::meter;
+
+#[test]
+
+fn test_every_constant_unit_parses() {
+
+    for edition in CodataEdition::ALL {
+
+        for record in
+            edition::constants(edition)
+        {
+
+            assert!(
+                record
+                    .dimension()
+                    .is_some(),
+                "{}: cannot parse \
+                 unit `{}`",
+                record.id,
+                record.unit
+            );
+        }
+    }
+}
+
+#[test]
+
+fn test_constant_dimensions() {
+
+    let dimension = |id| {
+
+        constant::lookup(id)
+            .unwrap()
+            .dimension()
+            .unwrap()
+    };
+
+    assert_eq!(
+        dimension(
+            "GRAVITATIONAL_CONSTANT"
+        ),
+        Dimension::from_exponents([
+            3, -1, -2, 0, 0, 0, 0
+        ])
+    );
+
+    assert_eq!(
+        dimension("PLANCK_CONSTANT"),
+        Dimension::ACTION
+    );
+
+    assert_eq!(
+        dimension("FARADAY_CONSTANT"),
+        Dimension::CHARGE
+            / Dimension::AMOUNT
+    );
+
+    assert_eq!(
+        dimension(
+            "FINE_STRUCTURE_CONSTANT"
+        ),
+        Dimension::DIMENSIONLESS
+    );
+
+    assert_eq!(
+        dimension(
+            "ATOMIC_UNIT_OF_MOMENTUM"
+        ),
+        Dimension::MOMENTUM
+    );
+
+    assert_eq!(
+        dimension(
+            "STEFAN_BOLTZMANN_CONSTANT"
+        ),
+        Dimension::POWER
+            / Dimension::LENGTH.powi(2)
+            / Dimension::TEMPERATURE
+                .powi(4)
+    );
+}
+
+#[test]
+
+fn test_constant_quantities_use_si_units()
+ {
+
+    // Every "... in MeV" energy equivalent matches the value in joules.
+    let mut compared = 0;
+
+    for record in constant::all() {
+
+        let Some(base) = record
+            .name
+            .strip_suffix(" in MeV")
+        else {
+
+            continue;
+        };
+
+        let Some(joules) =
+            constant::all()
+                .iter()
+                .find(|other| {
+
+                    other.name == base
+                        && other.unit
+                            == "J"
+                })
+        else {
+
+            continue;
+        };
+
+        let mev = record
+            .quantity()
+            .unwrap();
+
+        assert_eq!(
+            mev.dimension,
+            Dimension::ENERGY
+        );
+
+        assert_approx_eq!(
+            mev.value
+                / joules
+                    .quantity()
+                    .unwrap()
+                    .value,
+            1.0,
+            2.0 * (record
+                .relative_uncertainty
+                + joules.relative_uncertainty)
+                + 1e-12
+        );
+
+        compared += 1;
+    }
+
+    assert!(compared > 5);
+
+    let m_p = constant::lookup(
+        "PROTON_MASS_U",
+    )
+    .unwrap()
+    .quantity()
+    .unwrap();
+
+    assert_approx_eq!(
+        m_p.value
+            / constant::PROTON_MASS_KG,
+        1.0,
+        1e-9
+    );
+}
+
+#[test]
+
+fn test_notation() {
+
+    let parse = |notation| {
+
+        unit::parse(notation).unwrap()
+    };
+
+    for (unicode, ascii) in [
+        (
+            "m³ kg⁻¹ s⁻²",
+            "m^3 kg^-1 s^-2",
+        ),
+        (
+            "J Hz⁻¹ mol⁻¹",
+            "J/(Hz mol)",
+        ),
+        (
+            "W m⁻² K⁻⁴",
+            "W·m^-2·K^-4",
+        ),
+        (
+            "(GeV/c²)⁻²",
+            "GeV^-2 c^4",
+        ),
+    ] {
+
+        assert_eq!(
+            parse(unicode),
+            parse(ascii),
+            "{unicode} vs {ascii}"
+        );
+    }
+
+    assert_eq!(
+        parse("J/mol K").dimension,
+        parse("J mol⁻¹ K⁻¹").dimension
+    );
+
+    assert_approx_eq!(
+        parse("kg").scale,
+        1.0
+    );
+
+    assert_approx_eq!(
+        parse("µm").scale,
+        1e-6
+    );
+
+    assert_approx_eq!(
+        parse("dam").scale,
+        10.0
+    );
+
+    assert_eq!(
+        parse("cd").dimension,
+        Dimension::LUMINOUS_INTENSITY
+    );
+
+    assert_eq!(
+        notation::superscript(-12),
+        "⁻¹²"
+    );
+
+    assert_eq!(
+        unit::parse("m furlong"),
+        Err(
+            UnitError::UnknownUnit(
+                "furlong".to_string()
+            )
+        )
+    );
+
+    for malformed in [
+        "m^",
+        "(m s",
+        "m s)",
+        "/ s",
+    ] {
+
+        assert!(
+            matches!(
+                unit::parse(malformed),
+                Err(
+                    UnitError::Syntax { .. }
+                )
+            ),
+            "{malformed}"
+        );
+    }
+
+    // Nesting is bounded, so hostile input cannot overflow the stack.
+    let nested = |depth| {
+
+        format!(
+            "{}m{}",
+            "(".repeat(depth),
+            ")".repeat(depth)
+        )
+    };
+
+    assert_eq!(
+        parse(&nested(
+            notation::MAX_DEPTH
+        )),
+        parse("m")
+    );
+
+    for depth in [
+        notation::MAX_DEPTH + 1,
+        200_000,
+    ] {
+
+        assert!(matches!(
+            unit::parse(&nested(depth)),
+            Err(
+                UnitError::Syntax { .. }
+            )
+        ));
+    }
+}
+
+#[test]
+
+fn test_quantity_arithmetic() {
+
+    let mass =
+        Quantity::with_unit(2.0, "kg")
+            .unwrap();
+
+    let speed = Quantity::with_unit(
+        3.0,
+        "km/s",
+    )
+    .unwrap();
+
+    let energy =
+        0.5 * mass * speed.powi(2);
+
+    assert_eq!(
+        energy.dimension,
+        Dimension::ENERGY
+    );
+
+    assert_approx_eq!(
+        energy
+            .value_in("MJ")
+            .unwrap(),
+        9.0
+    );
+
+    assert!(matches!(
+        energy.value_in("m"),
+        Err(
+            UnitError::Incompatible { .. }
+        )
+    ));
+
+    assert!((energy + mass).is_err());
+
+    let total =
+        (energy + energy).unwrap();
+
+    assert_approx_eq!(
+        total.value,
+        1.8e7
+    );
+
+    let area =
+        Quantity::with_unit(4.0, "cm²")
+            .unwrap();
+
+    assert_approx_eq!(
+        area.sqrt()
+            .unwrap()
+            .value_in("cm")
+            .unwrap(),
+        2.0
+    );
+
+    assert_eq!(
+        area.powi(3)
+            .root(2)
+            .unwrap()
+            .dimension,
+        Dimension::LENGTH.powi(3)
+    );
+
+    assert!(matches!(
+        speed.sqrt(),
+        Err(
+            UnitError::InvalidRoot { .. }
+        )
+    ));
+
+    assert_eq!(
+        energy.to_string(),
+        "9000000 m² kg s⁻²"
+    );
+}
+
+#[test]
+
+fn test_uom_interop() {
+
+    let length =
+        Length::new::<meter>(1.5);
+
+    let quantity =
+        Quantity::from(length);
+
+    assert_eq!(
+        quantity.dimension,
+        Dimension::LENGTH
+    );
+
+    let back: Length = quantity
+        .to_uom()
+        .unwrap();
+
+    assert_approx_eq!(
+        back.get::<meter>(),
+        1.5
+    );
+
+    let wrong: Result<
+        Energy,
+        UnitError,
+    > = quantity.to_uom();
+
+    assert!(wrong.is_err());
+
+    let hartree = constant::lookup(
+        "HARTREE_ENERGY",
+    )
+    .unwrap()
+    .quantity()
+    .unwrap();
+
+    let energy: Energy = hartree
+        .to_uom()
+        .unwrap();
+
+    assert_approx_eq!(
+        energy.get::<joule>()
+            / constant::HARTREE_ENERGY,
+        1.0
+    );
+}
+
+#[test]
+
+fn test_convert() {
+
+    let convert = |value, from, to| {
+
+        unit::convert(value, from, to)
+            .unwrap()
+    };
+
+    assert_approx_eq!(
+        convert(1.0, "eV", "J"),
+        constant::ELECTRON_VOLT
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "kWh", "MJ"),
+        3.6
+    );
+
+    assert_approx_eq!(
+        convert(760.0, "Torr", "atm"),
+        1.0,
+        1e-6
+    );
+
+    assert_approx_eq!(
+        convert(212.0, "°F", "°C"),
+        100.0,
+        1e-9
+    );
+
+    assert_approx_eq!(
+        convert(0.0, "degC", "K"),
+        273.15
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "um", "µm"),
+        1.0
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "Å", "nm"),
+        0.1
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "mi", "km"),
+        1.609_344
+    );
+
+    // The astronomical units are those of `astro`.
+    for (symbol, metres) in [
+        (
+            "au",
+            astro::ASTRONOMICAL_UNIT,
+        ),
+        (
+            "ly",
+            astro::LIGHT_YEAR,
+        ),
+        ("pc", astro::PARSEC),
+    ] {
+
+        assert_eq!(
+            convert(1.0, symbol, "m"),
+            metres
+        );
+    }
+
+    assert_approx_eq!(
+        convert(
+            1.0,
+            "J/(mol K)",
+            "cal mol⁻¹ K⁻¹"
+        ),
+        1.0 / 4.184
+    );
+
+    assert_eq!(
+        unit::convert(1.0, "eV", "m"),
+        Err(
+            UnitError::Incompatible {
+                expected:
+                    Dimension::ENERGY,
+                found:
+                    Dimension::LENGTH,
+            }
+        )
+    );
+
+    assert!(matches!(
+        unit::convert(
+            1.0,
+            "J",
+            "parsec"
+        ),
+        Err(UnitError::UnknownUnit(_))
+    ));
+
+    // The gauss and the atomic mass unit double as prefixes, so they
+    // take none, and `Gs` is neither gauss nor gigasecond.
+    assert_eq!(
+        unit::parse("Gs"),
+        Err(
+            UnitError::Ambiguous(
+                "Gs".to_string()
+            )
+        )
+    );
+
+    assert_eq!(
+        unit::convert(1.0, "Gs", "s"),
+        Err(
+            UnitError::Ambiguous(
+                "Gs".to_string()
+            )
+        )
+    );
+
+    assert_eq!(
+        unit::parse("da"),
+        Err(
+            UnitError::Ambiguous(
+                "da".to_string()
+            )
+        )
+    );
+
+    for symbol in ["kG", "mu", "ku"] {
+
+        assert_eq!(
+            unit::parse(symbol),
+            Err(
+                UnitError::UnknownUnit(
+                    symbol.to_string()
+                )
+            ),
+            "{symbol}"
+        );
+    }
+
+    assert_approx_eq!(
+        convert(1.0, "GHz", "MHz"),
+        1e3
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "us", "ns"),
+        1e3
+    );
+}
+
+#[test]
+
+fn test_convert_equivalent() {
+
+    let convert = |value, from, to| {
+
+        unit::convert_equivalent(
+            value, from, to,
+        )
+        .unwrap()
+    };
+
+    // ν / c in cm⁻¹.
+    assert_approx_eq!(
+        convert(1.0, "GHz", "cm^-1"),
+        1e9 / constant::SPEED_OF_LIGHT
+            / 100.0,
+        1e-15
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "cm⁻¹", "GHz"),
+        constant::SPEED_OF_LIGHT / 1e7,
+        1e-9
+    );
+
+    assert_approx_eq!(
+        convert(1.0, "eV", "cm^-1"),
+        8_065.544,
+        1e-3
+    );
+
+    assert_approx_eq!(
+        convert(300.0, "K", "meV"),
+        25.852,
+        1e-3
+    );
+
+    assert_approx_eq!(
+        convert(0.0, "°C", "K"),
+        273.15
+    );
+
+    assert!(matches!(
+        unit::convert_equivalent(
+            1.0, "eV", "m"
+        ),
+        Err(
+            UnitError::Incompatible { .. }
+        )
+    ));
+}
+
+#[test]
+
+fn test_unit_symbols_are_unique() {
+
+    let mut symbols: Vec<&str> =
+        unit::catalogue::UNITS
+            .iter()
+            .map(|entry| entry.symbol)
+            .collect();
+
+    let count = symbols.len();
+
+    symbols.sort_unstable();
+
+    symbols.dedup();
+
+    assert_eq!(symbols.len(), count);
+}
+
+#[cfg(feature = "ffi_api")]
+#[test]
+
+fn test_unit_ffi() {
+
+    use std::ffi::CString;
+
+    use rssn_advanced::ffi_apis::common::from_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::from_json_string;
+    use rssn_advanced::ffi_apis::common::rssn_free_bincode_buffer;
+    use rssn_advanced::ffi_apis::common::rssn_free_string;
+    use rssn_advanced::ffi_apis::common::to_bincode_buffer;
+    use rssn_advanced::ffi_apis::error::FfiStatus;
+    use rssn_advanced::ffi_apis::error::rssn_last_error_code;
+    use rssn_advanced::ffi_apis::unit_ffi::UnitConversion;
+    use rssn_advanced::ffi_apis::unit_ffi::rssn_unit_convert_bincode;
+    use rssn_advanced::ffi_apis::unit_ffi::rssn_unit_convert_equivalent_bincode;
+    use rssn_advanced::ffi_apis::unit_ffi::rssn_unit_convert_equivalent_json;
+    use rssn_advanced::ffi_apis::unit_ffi::rssn_unit_convert_json;
+    use rssn_advanced::ffi_apis::unit_ffi::rssn_unit_parse_json;
+    use rssn_advanced::unit::Unit;
+
+    let request = CString::new(
+        r#"{"value": 1.0, "from": "kWh", "to": "J"}"#,
+    )
+    .unwrap();
+
+    let json = rssn_unit_convert_json(
+        request.as_ptr(),
+    );
+
+    let result: Option<f64> =
+        from_json_string(json);
+
+    assert_approx_eq!(
+        result.unwrap(),
+        3.6e6
+    );
+
+    rssn_free_string(json);
+
+    let notation =
+        CString::new("km/h").unwrap();
+
+    let json = rssn_unit_parse_json(
+        notation.as_ptr(),
+    );
+
+    let parsed: Option<Unit> =
+        from_json_string(json);
+
+    assert_eq!(
+        parsed
+            .unwrap()
+            .dimension,
+        Dimension::VELOCITY
+    );
+
+    rssn_free_string(json);
+
+    let request = to_bincode_buffer(
+        &UnitConversion {
+            value: 1.0,
+            from: "eV".to_string(),
+            to: "s".to_string(),
+        },
+    );
+
+    let buffer =
+        rssn_unit_convert_bincode(
+            request,
+        );
+
+    assert!(
+        buffer
+            .data
+            .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::InvalidArgument
+    );
+
+    let ambiguous = CString::new(
+        r#"{"value": 1.0, "from": "da", "to": "s"}"#,
+    )
+    .unwrap();
+
+    assert!(
+        rssn_unit_convert_json(
+            ambiguous.as_ptr()
+        )
+        .is_null()
+    );
+
+    assert_eq!(
+        rssn_last_error_code(),
+        FfiStatus::InvalidArgument
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    rssn_free_bincode_buffer(request);
+
+    let request = CString::new(
+        r#"{"value": 1.0, "from": "GHz", "to": "cm^-1"}"#,
+    )
+    .unwrap();
+
+    let json =
+        rssn_unit_convert_equivalent_json(
+            request.as_ptr(),
+        );
+
+    let result: Option<f64> =
+        from_json_string(json);
+
+    assert_approx_eq!(
+        result.unwrap(),
+        0.033_356_41,
+        1e-9
+    );
+
+    rssn_free_string(json);
+
+    let request = to_bincode_buffer(
+        &UnitConversion {
+            value: 1.0,
+            from: "eV".to_string(),
+            to: "K".to_string(),
+        },
+    );
+
+    let buffer =
+        rssn_unit_convert_equivalent_bincode(
+            request,
+        );
+
+    let result: Option<f64> =
+        from_bincode_buffer(&buffer);
+
+    assert_approx_eq!(
+        result.unwrap(),
+        11_604.518,
+        1e-3
+    );
+
+    rssn_free_bincode_buffer(buffer);
+
+    rssn_free_bincode_buffer(request);
+}